@@ -14,21 +14,23 @@ export type WagerProgram = {
   },
   "instructions": [
     {
-      "name": "createGameSession",
+      "name": "abortMatch",
       "discriminator": [
-        130,
-        34,
-        251,
-        80,
-        77,
-        159,
-        113,
-        224
+        165,
+        210,
+        81,
+        124,
+        173,
+        175,
+        87,
+        201
       ],
       "accounts": [
         {
           "name": "gameServer",
-          "writable": true,
+          "docs": [
+            "The game server authority that created the session"
+          ],
           "signer": true
         },
         {
@@ -62,6 +64,9 @@ export type WagerProgram = {
         },
         {
           "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -92,45 +97,12 @@ export type WagerProgram = {
                 "path": "vault"
               },
               {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
-                ]
+                "kind": "account",
+                "path": "tokenProgram"
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "gameSession"
               }
             ],
             "program": {
@@ -174,24 +146,16 @@ export type WagerProgram = {
         },
         {
           "name": "mint",
-          "writable": true,
-          "address": "BzeqmCjLZvMLSTrge9qZnyV8N2zNKBwAxQcZH2XEzFXG"
+          "docs": [
+            "Wager token of the session"
+          ]
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "tokenProgram"
         },
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
       "args": [
@@ -200,39 +164,89 @@ export type WagerProgram = {
           "type": "string"
         },
         {
-          "name": "betAmount",
-          "type": "u64"
+          "name": "reason",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "acceptAuthority",
+      "discriminator": [
+        107,
+        86,
+        198,
+        91,
+        33,
+        12,
+        107,
+        160
+      ],
+      "accounts": [
+        {
+          "name": "pendingAuthority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "gameMode",
-          "type": {
-            "defined": {
-              "name": "gameMode"
-            }
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "distributeWinnings",
+      "name": "addPrize",
       "discriminator": [
-        208,
-        254,
-        127,
-        148,
-        78,
-        104,
-        249,
-        250
+        72,
+        182,
+        203,
+        140,
+        3,
+        163,
+        192,
+        98
       ],
       "accounts": [
         {
-          "name": "gameServer",
-          "docs": [
-            "The game server authority that created the session"
-          ],
+          "name": "sponsor",
+          "writable": true,
           "signer": true
         },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "gameSession",
           "writable": true,
@@ -264,6 +278,9 @@ export type WagerProgram = {
         },
         {
           "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -294,78 +311,12 @@ export type WagerProgram = {
                 "path": "vault"
               },
               {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
-                ]
+                "kind": "account",
+                "path": "tokenProgram"
               },
               {
-                "kind": "const",
-                "value": [
-                  163,
-                  89,
-                  195,
-                  20,
-                  36,
-                  68,
-                  115,
-                  155,
-                  147,
-                  107,
-                  39,
-                  204,
-                  7,
-                  172,
-                  237,
-                  155,
-                  208,
-                  225,
-                  90,
-                  198,
-                  138,
-                  212,
-                  176,
-                  112,
-                  94,
-                  199,
-                  189,
-                  52,
-                  211,
-                  144,
-                  68,
-                  139
-                ]
+                "kind": "account",
+                "path": "mint"
               }
             ],
             "program": {
@@ -408,8 +359,21 @@ export type WagerProgram = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "sponsorTokenAccount",
+          "docs": [
+            "Sponsor's token account, required for SPL sessions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
         },
         {
           "name": "associatedTokenProgram",
@@ -426,31 +390,45 @@ export type WagerProgram = {
           "type": "string"
         },
         {
-          "name": "winningTeam",
-          "type": "u8"
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "joinUser",
+      "name": "applyRating",
       "discriminator": [
-        34,
+        189,
+        111,
+        150,
+        117,
+        143,
+        26,
         15,
-        119,
-        81,
-        119,
-        149,
-        25,
-        240
+        212
       ],
       "accounts": [
         {
-          "name": "user",
-          "writable": true,
+          "name": "caller",
           "signer": true
         },
         {
-          "name": "gameServer"
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "gameSession",
@@ -482,133 +460,209 @@ export type WagerProgram = {
           }
         },
         {
-          "name": "userTokenAccount",
-          "writable": true
-        },
-        {
-          "name": "vault",
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the rated player"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
-                  117,
+                  112,
                   108,
-                  116
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
                 ]
               },
               {
                 "kind": "arg",
-                "path": "sessionId"
+                "path": "player"
               }
             ]
           }
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
         },
         {
-          "name": "vaultTokenAccount",
+          "name": "player",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "approveOperation",
+      "discriminator": [
+        13,
+        149,
+        177,
+        120,
+        180,
+        11,
+        252,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "Signing set key approving the operation, pays for the approval account"
+          ],
           "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "vault"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
-                "kind": "account",
-                "path": "mint"
+                "kind": "arg",
+                "path": "sessionId"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
+            ]
+          }
+        },
+        {
+          "name": "approval",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "kind",
+          "type": {
+            "defined": {
+              "name": "approvalKind"
             }
           }
         },
         {
-          "name": "mint",
+          "name": "winningTeam",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "banPlayer",
+      "discriminator": [
+        20,
+        123,
+        183,
+        191,
+        29,
+        55,
+        244,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "authority",
           "writable": true,
-          "address": "BzeqmCjLZvMLSTrge9qZnyV8N2zNKBwAxQcZH2XEzFXG"
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "playerBan",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  98,
+                  97,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "player"
+              }
+            ]
+          }
         },
         {
           "name": "systemProgram",
@@ -617,35 +671,53 @@ export type WagerProgram = {
       ],
       "args": [
         {
-          "name": "sessionId",
-          "type": "string"
+          "name": "player",
+          "type": "pubkey"
         },
         {
-          "name": "team",
+          "name": "reason",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "payToSpawn",
+      "name": "castDisputeVote",
       "discriminator": [
-        55,
-        158,
-        177,
-        30,
-        46,
-        243,
-        227,
-        129
+        36,
+        68,
+        171,
+        27,
+        90,
+        153,
+        151,
+        98
       ],
       "accounts": [
         {
-          "name": "user",
+          "name": "arbiter",
+          "docs": [
+            "Council member casting the vote, paying for the tally on the first vote"
+          ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "gameServer"
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "gameSession",
@@ -677,162 +749,69 @@ export type WagerProgram = {
           }
         },
         {
-          "name": "userTokenAccount",
-          "writable": true
-        },
-        {
-          "name": "vault",
+          "name": "disputeVotes",
+          "docs": [
+            "Council votes cast on the session's result"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
+                  100,
+                  105,
+                  115,
+                  112,
                   117,
-                  108,
-                  116
+                  116,
+                  101,
+                  95,
+                  118,
+                  111,
+                  116,
+                  101,
+                  115
                 ]
               },
               {
-                "kind": "arg",
-                "path": "sessionId"
+                "kind": "account",
+                "path": "gameSession"
               }
             ]
           }
         },
         {
-          "name": "vaultTokenAccount",
+          "name": "serverBond",
+          "docs": [
+            "slashed on an overturn if the operator posted one"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "vault"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  115,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  114,
+                  118,
+                  101,
+                  114,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  98,
+                  111,
+                  110,
+                  100
                 ]
               },
               {
-                "kind": "const",
-                "value": [
-                  163,
-                  89,
-                  195,
-                  20,
-                  36,
-                  68,
-                  115,
-                  155,
-                  147,
-                  107,
-                  39,
-                  204,
-                  7,
-                  172,
-                  237,
-                  155,
-                  208,
-                  225,
-                  90,
-                  198,
-                  138,
-                  212,
-                  176,
-                  112,
-                  94,
-                  199,
-                  189,
-                  52,
-                  211,
-                  144,
-                  68,
-                  139
-                ]
+                "kind": "account",
+                "path": "gameSession"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -844,98 +823,28 @@ export type WagerProgram = {
           "type": "string"
         },
         {
-          "name": "team",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "recordKill",
-      "discriminator": [
-        199,
-        67,
-        232,
-        200,
-        144,
-        122,
-        230,
-        56
-      ],
-      "accounts": [
-        {
-          "name": "gameSession",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  103,
-                  97,
-                  109,
-                  101,
-                  95,
-                  115,
-                  101,
-                  115,
-                  115,
-                  105,
-                  111,
-                  110
-                ]
-              },
-              {
-                "kind": "arg",
-                "path": "sessionId"
-              }
-            ]
-          }
-        },
-        {
-          "name": "gameServer",
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "sessionId",
-          "type": "string"
-        },
-        {
-          "name": "killerTeam",
-          "type": "u8"
-        },
-        {
-          "name": "killer",
-          "type": "pubkey"
-        },
-        {
-          "name": "victimTeam",
+          "name": "winningTeam",
           "type": "u8"
-        },
-        {
-          "name": "victim",
-          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "refundWager",
+      "name": "claimEarnings",
       "discriminator": [
-        208,
-        62,
-        96,
-        78,
-        126,
-        46,
-        251,
-        157
+        49,
+        99,
+        161,
+        170,
+        22,
+        233,
+        54,
+        140
       ],
       "accounts": [
         {
-          "name": "gameServer",
+          "name": "player",
           "docs": [
-            "The game server authority that created the session"
+            "Player claiming their own earnings"
           ],
           "signer": true
         },
@@ -968,8 +877,15 @@ export type WagerProgram = {
             ]
           }
         },
+        {
+          "name": "playerTokenAccount",
+          "writable": true
+        },
         {
           "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -1000,78 +916,12 @@ export type WagerProgram = {
                 "path": "vault"
               },
               {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
-                ]
+                "kind": "account",
+                "path": "tokenProgram"
               },
               {
-                "kind": "const",
-                "value": [
-                  163,
-                  89,
-                  195,
-                  20,
-                  36,
-                  68,
-                  115,
-                  155,
-                  147,
-                  107,
-                  39,
-                  204,
-                  7,
-                  172,
-                  237,
-                  155,
-                  208,
-                  225,
-                  90,
-                  198,
-                  138,
-                  212,
-                  176,
-                  112,
-                  94,
-                  199,
-                  189,
-                  52,
-                  211,
-                  144,
-                  68,
-                  139
-                ]
+                "kind": "account",
+                "path": "gameSession"
               }
             ],
             "program": {
@@ -1114,16 +964,49 @@ export type WagerProgram = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the claiming player"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
       "args": [
@@ -1132,205 +1015,21239 @@ export type WagerProgram = {
           "type": "string"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "gameSession",
-      "discriminator": [
-        150,
-        116,
-        20,
-        197,
-        205,
-        121,
-        220,
-        240
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "invalidGameState",
-      "msg": "Game session is not in the correct state"
-    },
-    {
-      "code": 6001,
-      "name": "invalidTeamSelection",
-      "msg": "Invalid team selection. Team must be 0 or 1"
     },
     {
-      "code": 6002,
-      "name": "teamIsFull",
-      "msg": "Team is already full"
-    },
-    {
-      "code": 6003,
-      "name": "insufficientFunds",
-      "msg": "Insufficient funds to join the game"
+      "name": "claimReferralRewards",
+      "discriminator": [
+        23,
+        112,
+        76,
+        162,
+        157,
+        106,
+        203,
+        246
+      ],
+      "accounts": [
+        {
+          "name": "referrer",
+          "signer": true,
+          "relations": [
+            "referralCredit"
+          ]
+        },
+        {
+          "name": "refereeWallet",
+          "docs": [
+            "Referee who paid the credit's rent, refunded when it closes"
+          ],
+          "writable": true
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralCredit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  99,
+                  114,
+                  101,
+                  100,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "arg",
+                "path": "referee"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA that owns the fee token accounts"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "referrerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "referee",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "claimSpectatorPayout",
+      "discriminator": [
+        200,
+        41,
+        52,
+        223,
+        111,
+        56,
+        204,
+        72
+      ],
+      "accounts": [
+        {
+          "name": "bettor",
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spectatorPool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spectatorBet",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  98,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              },
+              {
+                "kind": "account",
+                "path": "bettor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bettorTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "poolTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "spectatorPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "claimStreakBonus",
+      "discriminator": [
+        85,
+        6,
+        120,
+        20,
+        8,
+        104,
+        244,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics holding the player's streak"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewards",
+          "docs": [
+            "Rewards vault PDA that owns the bonus token account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardsTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "rewards"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint streak bonuses are paid in"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTimeoutRefund",
+      "discriminator": [
+        223,
+        7,
+        30,
+        48,
+        35,
+        13,
+        15,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "docs": [
+            "Player reclaiming their own bet"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "docs": [
+            "Player's token account, omitted for native SOL sessions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "activeExposure",
+          "docs": [
+            "Open bets of the refunded player, released for this session if passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  116,
+                  105,
+                  118,
+                  101,
+                  95,
+                  101,
+                  120,
+                  112,
+                  111,
+                  115,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "betReceipt",
+          "docs": [
+            "joined with one"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  101,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "claimUnclaimed",
+      "discriminator": [
+        83,
+        180,
+        69,
+        217,
+        176,
+        246,
+        35,
+        175
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "docs": [
+            "Payee of the withheld payout"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "unclaimedPayout",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  110,
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  101,
+                  100,
+                  95,
+                  112,
+                  97,
+                  121,
+                  111,
+                  117,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "closeBetReceipt",
+      "discriminator": [
+        98,
+        3,
+        223,
+        116,
+        195,
+        216,
+        99,
+        251
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "docs": [
+            "Player the receipt was issued to, who receives its rent"
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "betReceipt"
+          ]
+        },
+        {
+          "name": "gameSession"
+        },
+        {
+          "name": "betReceipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  101,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeSession",
+      "discriminator": [
+        68,
+        114,
+        178,
+        140,
+        222,
+        38,
+        248,
+        211
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "Session creator, receives the reclaimed rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA holding the session's creation fee"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "sessionMetadata",
+          "docs": [
+            "Discovery details, closed with the session when it has them"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "announcements",
+          "docs": [
+            "Announcement board, closed with the session when it has one"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  110,
+                  110,
+                  111,
+                  117,
+                  110,
+                  99,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "matchResult",
+          "docs": [
+            "Permanent result record, required to close a completed session"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  116,
+                  99,
+                  104,
+                  95,
+                  114,
+                  101,
+                  115,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "closeSessionKick",
+      "discriminator": [
+        30,
+        167,
+        82,
+        110,
+        182,
+        239,
+        26,
+        100
+      ],
+      "accounts": [
+        {
+          "name": "captain",
+          "docs": [
+            "Captain who kicked the player, who receives the marker's rent"
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "sessionKick"
+          ]
+        },
+        {
+          "name": "gameSession"
+        },
+        {
+          "name": "sessionKick",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  107,
+                  105,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "session_kick.player",
+                "account": "sessionKick"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeSettlementTable",
+      "discriminator": [
+        204,
+        236,
+        23,
+        238,
+        225,
+        226,
+        106,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "The game server authority that created the session, receiving the table's rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Session vault, the table's authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lookupTable",
+          "writable": true
+        },
+        {
+          "name": "addressLookupTableProgram",
+          "address": "AddressLookupTab1e1111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "collectPledges",
+      "discriminator": [
+        246,
+        140,
+        153,
+        158,
+        215,
+        177,
+        241,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA approved as delegate over the pledged bets"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "lobbyIndex",
+          "docs": [
+            "Discovery index listing the session, updated once the lobby starts"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "commitResult",
+      "discriminator": [
+        45,
+        73,
+        224,
+        222,
+        48,
+        67,
+        191,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "A key of the game server signing set, paying for the commitment on",
+            "the first proposal"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "resultCommitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "createBracketMatch",
+      "discriminator": [
+        153,
+        248,
+        252,
+        123,
+        79,
+        242,
+        50,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "tournament"
+          ]
+        },
+        {
+          "name": "tournament",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bracketMatch",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tournamentId",
+          "type": "string"
+        },
+        {
+          "name": "round",
+          "type": "u8"
+        },
+        {
+          "name": "matchIndex",
+          "type": "u8"
+        },
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "createFromTemplate",
+      "discriminator": [
+        223,
+        110,
+        151,
+        6,
+        6,
+        188,
+        219,
+        153
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "Operator that created the template"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA holding the creation fee"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionTemplate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  116,
+                  101,
+                  109,
+                  112,
+                  108,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameServer"
+              },
+              {
+                "kind": "account",
+                "path": "session_template.template_id",
+                "account": "sessionTemplate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that owns the escrow token account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "matchLog",
+          "docs": [
+            "Ring buffer of recent kills, created when the template keeps a match log"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  116,
+                  99,
+                  104,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lobbyIndex",
+          "docs": [
+            "Discovery index of open lobbies with the template's mint, bet and mode"
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the template"
+          ]
+        },
+        {
+          "name": "registeredMint",
+          "docs": [
+            "Registry entry permitting the mint as a wager token"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  101,
+                  114,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceFeed",
+          "docs": [
+            "Price feed registered for the mint, required when USD bounds are enforced"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceUpdate",
+          "docs": [
+            "Pyth price update for the registered feed"
+          ],
+          "optional": true
+        },
+        {
+          "name": "instructions",
+          "docs": [
+            "for CPI when the config restricts callers"
+          ],
+          "optional": true,
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "createGameSession",
+      "discriminator": [
+        130,
+        34,
+        251,
+        80,
+        77,
+        159,
+        113,
+        224
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA holding the creation fee"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that owns the escrow token account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "matchLog",
+          "docs": [
+            "Ring buffer of recent kills, created when `log_capacity` is non-zero"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  116,
+                  99,
+                  104,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionMetadata",
+          "docs": [
+            "Discovery details, created when metadata is supplied"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lobbyIndex",
+          "docs": [
+            "Discovery index of open lobbies with the session's mint, bet and mode"
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token for this session"
+          ]
+        },
+        {
+          "name": "registeredMint",
+          "docs": [
+            "Registry entry permitting the mint as a wager token"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  101,
+                  114,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceFeed",
+          "docs": [
+            "Price feed registered for the mint, required when USD bounds are enforced"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceUpdate",
+          "docs": [
+            "Pyth price update for the registered feed"
+          ],
+          "optional": true
+        },
+        {
+          "name": "instructions",
+          "docs": [
+            "for CPI when the config restricts callers"
+          ],
+          "optional": true,
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "betAmount",
+          "type": "u64"
+        },
+        {
+          "name": "gameMode",
+          "type": {
+            "defined": {
+              "name": "gameMode"
+            }
+          }
+        },
+        {
+          "name": "teamCount",
+          "type": "u8"
+        },
+        {
+          "name": "spawnEarningsBps",
+          "type": "u16"
+        },
+        {
+          "name": "logCapacity",
+          "type": "u16"
+        },
+        {
+          "name": "teamACapacity",
+          "type": "u8"
+        },
+        {
+          "name": "teamBCapacity",
+          "type": "u8"
+        },
+        {
+          "name": "payoutSchedule",
+          "type": {
+            "defined": {
+              "name": "payoutSchedule"
+            }
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "sessionMetadataParams"
+              }
+            }
+          }
+        },
+        {
+          "name": "access",
+          "type": {
+            "defined": {
+              "name": "sessionAccess"
+            }
+          }
+        },
+        {
+          "name": "maxKillsPerPlayer",
+          "type": "u16"
+        },
+        {
+          "name": "maxMatchKills",
+          "type": "u16"
+        },
+        {
+          "name": "seriesLength",
+          "type": "u8"
+        },
+        {
+          "name": "minRating",
+          "type": "u16"
+        },
+        {
+          "name": "maxRating",
+          "type": "u16"
+        },
+        {
+          "name": "refundPolicy",
+          "type": {
+            "defined": {
+              "name": "refundPolicy"
+            }
+          }
+        },
+        {
+          "name": "requiresPass",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "joinWindow",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "joinWindow"
+              }
+            }
+          }
+        },
+        {
+          "name": "winCondition",
+          "type": {
+            "defined": {
+              "name": "winCondition"
+            }
+          }
+        },
+        {
+          "name": "freeroll",
+          "type": "bool"
+        },
+        {
+          "name": "assistWeightBps",
+          "type": "u16"
+        },
+        {
+          "name": "minReadyPlayers",
+          "type": "u8"
+        },
+        {
+          "name": "maxStake",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createRecurringMatch",
+      "discriminator": [
+        146,
+        141,
+        90,
+        4,
+        234,
+        195,
+        78,
+        145
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Operator owning the template"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionTemplate",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  116,
+                  101,
+                  109,
+                  112,
+                  108,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "session_template.template_id",
+                "account": "sessionTemplate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "recurringMatch",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  117,
+                  114,
+                  114,
+                  105,
+                  110,
+                  103,
+                  95,
+                  109,
+                  97,
+                  116,
+                  99,
+                  104
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "arg",
+                "path": "prefix"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "prefix",
+          "type": "string"
+        },
+        {
+          "name": "intervalSecs",
+          "type": "i64"
+        },
+        {
+          "name": "firstStart",
+          "type": "i64"
+        },
+        {
+          "name": "joinSecs",
+          "type": "i64"
+        },
+        {
+          "name": "minPlayers",
+          "type": "u8"
+        },
+        {
+          "name": "maxInstances",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "createSessionTemplate",
+      "discriminator": [
+        117,
+        33,
+        214,
+        111,
+        30,
+        105,
+        36,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Operator launching sessions from the template"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionTemplate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  116,
+                  101,
+                  109,
+                  112,
+                  108,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "arg",
+                "path": "templateId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the template's sessions"
+          ]
+        },
+        {
+          "name": "registeredMint",
+          "docs": [
+            "Registry entry permitting the mint as a wager token"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  101,
+                  114,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "templateId",
+          "type": "u64"
+        },
+        {
+          "name": "betAmount",
+          "type": "u64"
+        },
+        {
+          "name": "terms",
+          "type": {
+            "defined": {
+              "name": "sessionTerms"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createSettlementTable",
+      "discriminator": [
+        126,
+        40,
+        62,
+        5,
+        104,
+        69,
+        144,
+        0
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "The game server authority that created the session, paying the table's rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Session vault, the table's authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lookupTable",
+          "docs": [
+            "vault and `recent_slot` derive"
+          ],
+          "writable": true
+        },
+        {
+          "name": "addressLookupTableProgram",
+          "address": "AddressLookupTab1e1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createSpectatorPool",
+      "discriminator": [
+        118,
+        54,
+        235,
+        79,
+        195,
+        107,
+        223,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spectatorPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount",
+          "docs": [
+            "Escrow for spectator stakes, owned by the pool PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "spectatorPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "createTournament",
+      "discriminator": [
+        158,
+        137,
+        233,
+        231,
+        73,
+        132,
+        191,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Operator that will report match results"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tournament",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that owns the entry fee escrow token account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token entry fees are paid in"
+          ]
+        },
+        {
+          "name": "registeredMint",
+          "docs": [
+            "Registry entry permitting the mint as a wager token"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  101,
+                  114,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tournamentId",
+          "type": "string"
+        },
+        {
+          "name": "entryFee",
+          "type": "u64"
+        },
+        {
+          "name": "teamCount",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "declareForfeit",
+      "discriminator": [
+        201,
+        97,
+        252,
+        187,
+        143,
+        127,
+        174,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "docs": [
+            "A key of the game server signing set, or anyone once a team went",
+            "inactive"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "approval",
+          "docs": [
+            "Signing set approvals for a server-declared forfeit, needed when the",
+            "threshold exceeds the caller's own signature"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "forfeitingTeam",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "delegateSessionAuthority",
+      "discriminator": [
+        217,
+        123,
+        52,
+        177,
+        243,
+        78,
+        11,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Game server authority that created the session"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "reporter",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "deregisterMint",
+      "discriminator": [
+        127,
+        185,
+        110,
+        231,
+        156,
+        207,
+        61,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "registeredMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  101,
+                  114,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "registered_mint.mint",
+                "account": "registeredMint"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "distributeTournamentPrize",
+      "discriminator": [
+        71,
+        198,
+        0,
+        191,
+        214,
+        62,
+        124,
+        180
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "tournament"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tournament",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the entry fees and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "tournament.mint",
+                "account": "tournament"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "championTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA that owns the fee token accounts"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "tournament.mint",
+                "account": "tournament"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the tournament"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "tournamentId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "distributeWinnings",
+      "discriminator": [
+        208,
+        254,
+        127,
+        148,
+        78,
+        104,
+        249,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "docs": [
+            "The session authority, or anyone finalizing a result whose dispute",
+            "window has passed; pays for the unclaimed payout account if needed"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA that owns the fee token accounts and holds native SOL fees",
+            "(must be pre-funded to rent exemption before collecting SOL fees)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "approval",
+          "docs": [
+            "Signing set approvals for an immediate settlement, needed when the",
+            "threshold exceeds the caller's own signature"
+          ],
+          "optional": true
+        },
+        {
+          "name": "jackpot",
+          "docs": [
+            "Jackpot of the session mint, required while the config routes a cut of",
+            "the rake into it"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  97,
+                  99,
+                  107,
+                  112,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "docs": [
+            "Jackpot token account for the mint, unused by native SOL sessions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "SPL Memo program, supplied to record a settlement summary"
+          ],
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "unclaimedPayout",
+          "docs": [
+            "Holds payouts to closed or frozen token accounts, required when a",
+            "page contains one"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  110,
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  101,
+                  100,
+                  95,
+                  112,
+                  97,
+                  121,
+                  111,
+                  117,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "declaration",
+          "type": {
+            "defined": {
+              "name": "winnerDeclaration"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "emergencyPause",
+      "discriminator": [
+        21,
+        143,
+        27,
+        142,
+        200,
+        181,
+        210,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "estimateDistributionChunks",
+      "discriminator": [
+        110,
+        226,
+        205,
+        170,
+        63,
+        199,
+        130,
+        56
+      ],
+      "accounts": [
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "winningTeam",
+          "type": "u8"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "distributionPlan"
+        }
+      }
+    },
+    {
+      "name": "extendSettlementTable",
+      "discriminator": [
+        44,
+        67,
+        201,
+        220,
+        198,
+        177,
+        245,
+        152
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "The game server authority that created the session, paying the table's rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Session vault, the table's authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lookupTable",
+          "docs": [
+            "program checks against the vault's signature"
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "addressLookupTableProgram",
+          "address": "AddressLookupTab1e1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "firstPlayer",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "finalizeDistribution",
+      "discriminator": [
+        12,
+        246,
+        59,
+        197,
+        66,
+        128,
+        169,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "docs": [
+            "The session authority, or anyone finalizing a result whose dispute",
+            "window has passed; pays for the unclaimed payout account if needed"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA that owns the fee token accounts and holds native SOL fees",
+            "(must be pre-funded to rent exemption before collecting SOL fees)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "approval",
+          "docs": [
+            "Signing set approvals for an immediate settlement, needed when the",
+            "threshold exceeds the caller's own signature"
+          ],
+          "optional": true
+        },
+        {
+          "name": "jackpot",
+          "docs": [
+            "Jackpot of the session mint, required while the config routes a cut of",
+            "the rake into it"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  97,
+                  99,
+                  107,
+                  112,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "docs": [
+            "Jackpot token account for the mint, unused by native SOL sessions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "SPL Memo program, supplied to record a settlement summary"
+          ],
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "unclaimedPayout",
+          "docs": [
+            "Holds payouts to closed or frozen token accounts, required when a",
+            "page contains one"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  110,
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  101,
+                  100,
+                  95,
+                  112,
+                  97,
+                  121,
+                  111,
+                  117,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "formMatch",
+      "discriminator": [
+        169,
+        105,
+        54,
+        21,
+        39,
+        121,
+        180,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone may form a match and pays for the session accounts"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "matchQueue",
+          "writable": true
+        },
+        {
+          "name": "queueTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "matchQueue"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that owns the escrow token account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "fundFreeroll",
+      "discriminator": [
+        249,
+        217,
+        77,
+        174,
+        122,
+        68,
+        74,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "sponsor",
+          "docs": [
+            "Session authority that created the freeroll"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "sponsorTokenAccount",
+          "docs": [
+            "Sponsor's token account, required for sponsor deposits of SPL tokens"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA that owns the fee token accounts and holds native SOL fees"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "fromTreasury",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "initializeConfig",
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Must be the program's upgrade authority"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "8PRQvPo16yG8EP5fESDEuJunZBLJ3UFBGvN6CKLZGBUQ"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "configParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeJackpot",
+      "discriminator": [
+        203,
+        117,
+        104,
+        67,
+        62,
+        238,
+        90,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "jackpot",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  97,
+                  99,
+                  107,
+                  112,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "jackpot"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "joinQueue",
+      "discriminator": [
+        157,
+        115,
+        48,
+        109,
+        65,
+        86,
+        203,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "matchQueue",
+          "docs": [
+            "Queue for this bet and game mode, created by its first player"
+          ],
+          "writable": true
+        },
+        {
+          "name": "queueTokenAccount",
+          "docs": [
+            "Escrow of the queued bets, owned by the queue PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "matchQueue"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the queued player, created on first join"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerBan",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  98,
+                  97,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the queue"
+          ]
+        },
+        {
+          "name": "registeredMint",
+          "docs": [
+            "Registry entry permitting the mint as a wager token"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  101,
+                  114,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceFeed",
+          "docs": [
+            "Price feed registered for the mint, required when USD bounds are enforced"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceUpdate",
+          "docs": [
+            "Pyth price update for the registered feed"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "betAmount",
+          "type": "u64"
+        },
+        {
+          "name": "gameMode",
+          "type": {
+            "defined": {
+              "name": "gameMode"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "joinUser",
+      "discriminator": [
+        34,
+        15,
+        119,
+        81,
+        119,
+        149,
+        25,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameServer"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the joining player, created on first join"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "betReceipt",
+          "docs": [
+            "Receipt for the player's bet, closed when it is refunded"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  101,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referral",
+          "docs": [
+            "Referrer of the joining player, recorded on the first referred join"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralCredit",
+          "docs": [
+            "Referrer's cut of the rake on this session, required with `referral`"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  99,
+                  114,
+                  101,
+                  100,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lobbyIndex",
+          "docs": [
+            "Discovery index listing the session, updated once the lobby fills"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "passTokenAccount",
+          "docs": [
+            "Player's season pass token account, required by pass-gated sessions"
+          ],
+          "optional": true
+        },
+        {
+          "name": "activeExposure",
+          "docs": [
+            "Bets the player has open across sessions, created on their first",
+            "tracked join; required while the config caps exposure"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  116,
+                  105,
+                  118,
+                  101,
+                  95,
+                  101,
+                  120,
+                  112,
+                  111,
+                  115,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerBan",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  98,
+                  97,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionKick",
+          "docs": [
+            "exist"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  107,
+                  105,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "docs": [
+            "for CPI when the config restricts callers"
+          ],
+          "optional": true,
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        },
+        {
+          "name": "referrer",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "credential",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "joinCredential"
+              }
+            }
+          }
+        },
+        {
+          "name": "stake",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "joinUserDelegated",
+      "discriminator": [
+        119,
+        155,
+        146,
+        16,
+        176,
+        165,
+        247,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userTokenAccount",
+          "docs": [
+            "Token account the bet is pledged from and later collected from"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA approved as delegate over the pledged bet"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the joining player, created on first join"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "betReceipt",
+          "docs": [
+            "Receipt for the player's bet, closed when it is refunded"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  101,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "passTokenAccount",
+          "docs": [
+            "Player's season pass token account, required by pass-gated sessions"
+          ],
+          "optional": true
+        },
+        {
+          "name": "activeExposure",
+          "docs": [
+            "Bets the player has open across sessions, created on their first",
+            "tracked join; required while the config caps exposure"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  116,
+                  105,
+                  118,
+                  101,
+                  95,
+                  101,
+                  120,
+                  112,
+                  111,
+                  115,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerBan",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  98,
+                  97,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionKick",
+          "docs": [
+            "exist"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  107,
+                  105,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "docs": [
+            "for CPI when the config restricts callers"
+          ],
+          "optional": true,
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        },
+        {
+          "name": "credential",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "joinCredential"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "joinUserSol",
+      "discriminator": [
+        163,
+        194,
+        113,
+        171,
+        19,
+        144,
+        1,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the escrowed lamports and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the joining player, created on first join"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "betReceipt",
+          "docs": [
+            "Receipt for the player's bet, closed when it is refunded"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  101,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lobbyIndex",
+          "docs": [
+            "Discovery index listing the session, updated once the lobby fills"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "passTokenAccount",
+          "docs": [
+            "Player's season pass token account, required by pass-gated sessions"
+          ],
+          "optional": true
+        },
+        {
+          "name": "activeExposure",
+          "docs": [
+            "Bets the player has open across sessions, created on their first",
+            "tracked join; required while the config caps exposure"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  116,
+                  105,
+                  118,
+                  101,
+                  95,
+                  101,
+                  120,
+                  112,
+                  111,
+                  115,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerBan",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  98,
+                  97,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionKick",
+          "docs": [
+            "exist"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  107,
+                  105,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "docs": [
+            "for CPI when the config restricts callers"
+          ],
+          "optional": true,
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        },
+        {
+          "name": "credential",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "joinCredential"
+              }
+            }
+          }
+        },
+        {
+          "name": "stake",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "kickPlayer",
+      "discriminator": [
+        230,
+        225,
+        244,
+        193,
+        58,
+        11,
+        192,
+        199
+      ],
+      "accounts": [
+        {
+          "name": "captain",
+          "docs": [
+            "Captain of the kicked player's team, who pays for the kick marker"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "docs": [
+            "Kicked player's wallet, refunded directly in native SOL sessions"
+          ],
+          "writable": true
+        },
+        {
+          "name": "playerTokenAccount",
+          "docs": [
+            "Kicked player's token account, omitted for native SOL sessions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the kicked player"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "betReceipt",
+          "docs": [
+            "joined with one"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  101,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionKick",
+          "docs": [
+            "Marker barring the kicked player from taking a seat again"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  107,
+                  105,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "activeExposure",
+          "docs": [
+            "Open bets of the kicked player, released for this session if passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  116,
+                  105,
+                  118,
+                  101,
+                  95,
+                  101,
+                  120,
+                  112,
+                  111,
+                  115,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        },
+        {
+          "name": "player",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "leaveQueue",
+      "discriminator": [
+        95,
+        75,
+        87,
+        92,
+        172,
+        245,
+        65,
+        97
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Queued player withdrawing their bet"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "matchQueue",
+          "writable": true
+        },
+        {
+          "name": "queueTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "matchQueue"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "match_queue.mint",
+                "account": "matchQueue"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the leaving player"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the queue"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "leaveSession",
+      "discriminator": [
+        54,
+        198,
+        240,
+        145,
+        215,
+        73,
+        7,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "docs": [
+            "Player withdrawing their own bet"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "docs": [
+            "Player's token account, omitted for native SOL sessions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA that owns the fee token accounts and holds native SOL fees"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the leaving player"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "betReceipt",
+          "docs": [
+            "joined with one"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  101,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "activeExposure",
+          "docs": [
+            "Open bets of the leaving player, released for this session if passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  116,
+                  105,
+                  118,
+                  101,
+                  95,
+                  101,
+                  120,
+                  112,
+                  111,
+                  115,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "lockSession",
+      "discriminator": [
+        63,
+        245,
+        60,
+        30,
+        109,
+        12,
+        58,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lobbyIndex",
+          "docs": [
+            "Discovery index listing the session, if it is still indexed"
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "migratePlayerStats",
+      "discriminator": [
+        96,
+        209,
+        44,
+        229,
+        146,
+        12,
+        180,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for any additional rent required by the larger layout"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "player",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "migrateSession",
+      "discriminator": [
+        176,
+        128,
+        4,
+        35,
+        63,
+        1,
+        118,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for any additional rent required by the larger layout"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "mintTrophy",
+      "discriminator": [
+        215,
+        184,
+        115,
+        168,
+        63,
+        228,
+        76,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for the trophy's mint, token account and metadata"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player"
+        },
+        {
+          "name": "trophyAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  111,
+                  112,
+                  104,
+                  121,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "trophyMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  111,
+                  112,
+                  104,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "trophyMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "trophyMint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "masterEdition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "trophyMint"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  100,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "overrideResult",
+      "discriminator": [
+        203,
+        9,
+        207,
+        29,
+        106,
+        228,
+        103,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "arbiter",
+          "docs": [
+            "The arbiter registered in the config"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "approval",
+          "docs": [
+            "Signing set approvals for the corrected result, needed when the",
+            "threshold exceeds the arbiter's own signature"
+          ],
+          "optional": true
+        },
+        {
+          "name": "serverBond",
+          "docs": [
+            "slashed on an overturn if the operator posted one"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  114,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "winningTeam",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "parkTournamentFunds",
+      "discriminator": [
+        134,
+        185,
+        83,
+        202,
+        5,
+        179,
+        91,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "tournament"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tournament",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the entry fees and signs for the adapter"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "tournament.mint",
+                "account": "tournament"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "yieldProgram"
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the tournament"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "tournamentId",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "payToSpawn",
+      "discriminator": [
+        55,
+        158,
+        177,
+        30,
+        46,
+        243,
+        227,
+        129
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameServer"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the purchasing player"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerBan",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  98,
+                  97,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "placeSpectatorBet",
+      "discriminator": [
+        55,
+        208,
+        18,
+        231,
+        14,
+        163,
+        75,
+        83
+      ],
+      "accounts": [
+        {
+          "name": "bettor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spectatorPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spectatorBet",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  98,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              },
+              {
+                "kind": "account",
+                "path": "bettor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bettorTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "poolTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "spectatorPool"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "postAnnouncement",
+      "discriminator": [
+        156,
+        13,
+        82,
+        79,
+        162,
+        60,
+        192,
+        101
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "announcements",
+          "docs": [
+            "Announcement board of the session, created with its first post"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  110,
+                  110,
+                  111,
+                  117,
+                  110,
+                  99,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "text",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "postBond",
+      "discriminator": [
+        168,
+        151,
+        202,
+        119,
+        163,
+        58,
+        147,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "operator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "serverBond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  114,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "operator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "discriminator": [
+        20,
+        148,
+        236,
+        198,
+        76,
+        119,
+        99,
+        142
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "pruneLobbyIndex",
+      "discriminator": [
+        230,
+        179,
+        242,
+        3,
+        66,
+        145,
+        158,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "lobbyIndex",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "pubkey"
+        },
+        {
+          "name": "betAmount",
+          "type": "u64"
+        },
+        {
+          "name": "gameMode",
+          "type": {
+            "defined": {
+              "name": "gameMode"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "purchaseSpawns",
+      "discriminator": [
+        46,
+        33,
+        35,
+        128,
+        210,
+        66,
+        215,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameServer"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the purchasing player"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerBan",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  98,
+                  97,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        },
+        {
+          "name": "count",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "reclaimFreerollPrize",
+      "discriminator": [
+        67,
+        2,
+        63,
+        83,
+        247,
+        244,
+        96,
+        115
+      ],
+      "accounts": [
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true
+        },
+        {
+          "name": "authorityTokenAccount",
+          "docs": [
+            "Session authority's token account receiving the sponsor's SPL deposits"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA that owns the fee token accounts and holds native SOL fees"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "reclaimSponsoredPrize",
+      "discriminator": [
+        98,
+        57,
+        41,
+        124,
+        29,
+        179,
+        198,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "recordKill",
+      "discriminator": [
+        199,
+        67,
+        232,
+        200,
+        144,
+        122,
+        230,
+        56
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameServer",
+          "signer": true
+        },
+        {
+          "name": "killerStats",
+          "docs": [
+            "Lifetime statistics of the killer"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "killer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "victimStats",
+          "docs": [
+            "Lifetime statistics of the victim"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "victim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "matchLog",
+          "docs": [
+            "Ring buffer of recent kills, required when the session keeps one"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  116,
+                  99,
+                  104,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "killerTeam",
+          "type": "u8"
+        },
+        {
+          "name": "killer",
+          "type": "pubkey"
+        },
+        {
+          "name": "victimTeam",
+          "type": "u8"
+        },
+        {
+          "name": "victim",
+          "type": "pubkey"
+        },
+        {
+          "name": "assists",
+          "type": {
+            "array": [
+              "pubkey",
+              2
+            ]
+          }
+        },
+        {
+          "name": "round",
+          "type": "u64"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "recordKillsBatch",
+      "discriminator": [
+        186,
+        178,
+        220,
+        115,
+        84,
+        78,
+        74,
+        109
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameServer",
+          "signer": true
+        },
+        {
+          "name": "matchLog",
+          "docs": [
+            "Ring buffer of recent kills, required when the session keeps one"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  116,
+                  99,
+                  104,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "kills",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "killRecord"
+              }
+            }
+          }
+        },
+        {
+          "name": "round",
+          "type": "u64"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "recordMatchResult",
+      "discriminator": [
+        37,
+        251,
+        4,
+        178,
+        56,
+        184,
+        50,
+        210
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA whose ledger provides the settlement totals"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "matchResult",
+          "docs": [
+            "Result record, keyed by the session's creation time as well so a",
+            "reused session id never collides with an earlier record"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  116,
+                  99,
+                  104,
+                  95,
+                  114,
+                  101,
+                  115,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "recordRoundResult",
+      "discriminator": [
+        232,
+        49,
+        80,
+        98,
+        43,
+        189,
+        175,
+        167
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameServer",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "winningTeam",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "recoverSettlement",
+      "discriminator": [
+        225,
+        103,
+        100,
+        226,
+        205,
+        67,
+        57,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameServer",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "refundWager",
+      "discriminator": [
+        208,
+        62,
+        96,
+        78,
+        126,
+        46,
+        251,
+        157
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "The game server authority that created the session, paying any",
+            "forfeit penalty the session's refund policy imposes"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "creatorTokenAccount",
+          "docs": [
+            "Creator's token account funding a forfeit penalty, omitted otherwise"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "SPL Memo program, supplied to record a refund summary"
+          ],
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "registerMint",
+      "discriminator": [
+        242,
+        43,
+        74,
+        162,
+        217,
+        214,
+        191,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "registeredMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  101,
+                  114,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "minBet",
+          "type": "u64"
+        },
+        {
+          "name": "maxBet",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "registerTournamentTeam",
+      "discriminator": [
+        199,
+        191,
+        93,
+        230,
+        94,
+        196,
+        61,
+        165
+      ],
+      "accounts": [
+        {
+          "name": "captain",
+          "docs": [
+            "Team captain paying the entry fee and receiving any prize"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tournament",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "captainTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the entry fees and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "tournament.mint",
+                "account": "tournament"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the tournament"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "tournamentId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "releaseExposure",
+      "discriminator": [
+        54,
+        170,
+        101,
+        145,
+        245,
+        124,
+        110,
+        113
+      ],
+      "accounts": [
+        {
+          "name": "activeExposure",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  116,
+                  105,
+                  118,
+                  101,
+                  95,
+                  101,
+                  120,
+                  112,
+                  111,
+                  115,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "player"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "player",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "reportMatchResult",
+      "discriminator": [
+        45,
+        189,
+        144,
+        162,
+        101,
+        165,
+        16,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "tournament"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tournament",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bracketMatch",
+          "writable": true
+        },
+        {
+          "name": "nextMatch",
+          "docs": [
+            "Match the winner advances into, omitted for the final"
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tournamentId",
+          "type": "string"
+        },
+        {
+          "name": "winnerSide",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "requestBondWithdrawal",
+      "discriminator": [
+        66,
+        235,
+        186,
+        164,
+        34,
+        105,
+        80,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "operator",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "serverBond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  114,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "operator"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "requestMatchRandomness",
+      "discriminator": [
+        21,
+        203,
+        64,
+        103,
+        198,
+        57,
+        119,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "networkState",
+          "docs": [
+            "VRF network configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  111,
+                  45,
+                  118,
+                  114,
+                  102,
+                  45,
+                  110,
+                  101,
+                  116,
+                  119,
+                  111,
+                  114,
+                  107,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  117,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                7,
+                71,
+                177,
+                26,
+                250,
+                145,
+                180,
+                209,
+                249,
+                34,
+                242,
+                123,
+                14,
+                186,
+                193,
+                218,
+                178,
+                59,
+                33,
+                41,
+                164,
+                190,
+                243,
+                79,
+                50,
+                164,
+                123,
+                88,
+                245,
+                206,
+                252,
+                120
+              ]
+            }
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "vrfRequest",
+          "writable": true
+        },
+        {
+          "name": "vrf",
+          "address": "VRFzZoJdhFWL8rkvu87LpKM3RbcVezpMEc6X5GVDr7y"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "revealMatchRandomness",
+      "discriminator": [
+        60,
+        226,
+        247,
+        43,
+        25,
+        77,
+        11,
+        109
+      ],
+      "accounts": [
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vrfRequest",
+          "docs": [
+            "Randomness request for the session's VRF seed"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "revealResult",
+      "discriminator": [
+        251,
+        165,
+        27,
+        86,
+        52,
+        234,
+        133,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "A key of the game server signing set"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "resultCommitment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "committer",
+          "writable": true,
+          "relations": [
+            "resultCommitment"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "declaration",
+          "type": {
+            "defined": {
+              "name": "winnerDeclaration"
+            }
+          }
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPriceFeed",
+      "discriminator": [
+        13,
+        15,
+        231,
+        129,
+        61,
+        7,
+        28,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "priceFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "feedId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setReady",
+      "discriminator": [
+        105,
+        78,
+        7,
+        162,
+        181,
+        167,
+        186,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lobbyIndex",
+          "docs": [
+            "Discovery index listing the session, updated once the match starts"
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "ready",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "spawnNextInstance",
+      "discriminator": [
+        110,
+        236,
+        94,
+        230,
+        44,
+        72,
+        169,
+        65
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "docs": [
+            "Anyone cranking the schedule, pays the instance's rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "recurringMatch",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  117,
+                  114,
+                  114,
+                  105,
+                  110,
+                  103,
+                  95,
+                  109,
+                  97,
+                  116,
+                  99,
+                  104
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "recurring_match.authority",
+                "account": "recurringMatch"
+              },
+              {
+                "kind": "account",
+                "path": "recurring_match.prefix",
+                "account": "recurringMatch"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionTemplate",
+          "writable": true
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that owns the escrow token account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "matchLog",
+          "docs": [
+            "Ring buffer of recent kills, created when the template keeps a match log"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  116,
+                  99,
+                  104,
+                  95,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lobbyIndex",
+          "docs": [
+            "Discovery index of open lobbies with the template's mint, bet and mode"
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the template"
+          ]
+        },
+        {
+          "name": "registeredMint",
+          "docs": [
+            "Registry entry permitting the mint as a wager token"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  101,
+                  114,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceFeed",
+          "docs": [
+            "Price feed registered for the mint, required when USD bounds are enforced"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceUpdate",
+          "docs": [
+            "Pyth price update for the registered feed"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "submitResult",
+      "discriminator": [
+        240,
+        42,
+        89,
+        180,
+        10,
+        239,
+        9,
+        214
+      ],
+      "accounts": [
+        {
+          "name": "gameServer",
+          "docs": [
+            "A key of the game server signing set"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "approval",
+          "docs": [
+            "Signing set approvals for the result, needed when the threshold",
+            "exceeds the submitter's own signature"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "declaration",
+          "type": {
+            "defined": {
+              "name": "winnerDeclaration"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "submitScore",
+      "discriminator": [
+        212,
+        128,
+        45,
+        22,
+        112,
+        82,
+        85,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameServer",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        },
+        {
+          "name": "score",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "substitutePlayer",
+      "discriminator": [
+        247,
+        62,
+        56,
+        244,
+        94,
+        184,
+        68,
+        138
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "docs": [
+            "Player giving up their slot"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "replacement",
+          "docs": [
+            "Player taking over the slot and paying for it"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "docs": [
+            "Leaving player's token account, omitted for native SOL sessions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "replacementTokenAccount",
+          "docs": [
+            "Replacement's token account, omitted for native SOL sessions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the leaving player"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "replacementStats",
+          "docs": [
+            "Lifetime statistics of the replacement, created on first join"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "replacement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "betReceipt",
+          "docs": [
+            "have been paid if they joined with one"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  101,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "replacementReceipt",
+          "docs": [
+            "Receipt for the replacement, who now backs the slot's bet"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  101,
+                  116,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "replacement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "replacementPassAccount",
+          "docs": [
+            "Replacement's season pass token account, required by pass-gated sessions"
+          ],
+          "optional": true
+        },
+        {
+          "name": "replacementPlayerBan",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  98,
+                  97,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "replacement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "replacementSessionKick",
+          "docs": [
+            "not exist"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110,
+                  95,
+                  107,
+                  105,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              },
+              {
+                "kind": "account",
+                "path": "replacement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "topUp",
+      "discriminator": [
+        236,
+        225,
+        96,
+        9,
+        60,
+        106,
+        77,
+        208
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameServer"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the funds and tracks deposits/payouts"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "playerStats",
+          "docs": [
+            "Lifetime statistics of the player topping up"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "transferCaptaincy",
+      "discriminator": [
+        182,
+        81,
+        48,
+        220,
+        112,
+        208,
+        213,
+        4
+      ],
+      "accounts": [
+        {
+          "name": "captain",
+          "docs": [
+            "Current captain of the team"
+          ],
+          "signer": true
+        },
+        {
+          "name": "gameSession",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        },
+        {
+          "name": "team",
+          "type": "u8"
+        },
+        {
+          "name": "newCaptain",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "triggerJackpot",
+      "discriminator": [
+        83,
+        158,
+        217,
+        205,
+        124,
+        48,
+        10,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameSession",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sessionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "jackpot",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  97,
+                  99,
+                  107,
+                  112,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "gameSession"
+              }
+            ]
+          }
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "jackpot"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the session"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionId",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "unbanPlayer",
+      "discriminator": [
+        177,
+        253,
+        127,
+        40,
+        199,
+        6,
+        214,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerBan",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  98,
+                  97,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "player"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "player",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "unpause",
+      "discriminator": [
+        169,
+        144,
+        4,
+        38,
+        10,
+        141,
+        188,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "configParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawBond",
+      "discriminator": [
+        222,
+        199,
+        141,
+        31,
+        188,
+        93,
+        155,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "operator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "serverBond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  114,
+                  95,
+                  98,
+                  111,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "operator"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawFees",
+      "discriminator": [
+        198,
+        212,
+        171,
+        109,
+        144,
+        215,
+        174,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA that owns the fee token accounts and holds native SOL fees"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawTournamentFunds",
+      "discriminator": [
+        160,
+        143,
+        210,
+        169,
+        179,
+        242,
+        92,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "tournament"
+          ]
+        },
+        {
+          "name": "tournament",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Vault PDA that holds the entry fees and signs for the adapter"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  117,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tournamentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "tournament.mint",
+                "account": "tournament"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "yieldProgram"
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Wager token of the tournament"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "tournamentId",
+          "type": "string"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "activeExposure",
+      "discriminator": [
+        205,
+        115,
+        171,
+        29,
+        87,
+        92,
+        229,
+        199
+      ]
+    },
+    {
+      "name": "announcements",
+      "discriminator": [
+        189,
+        132,
+        202,
+        142,
+        141,
+        206,
+        239,
+        149
+      ]
+    },
+    {
+      "name": "approval",
+      "discriminator": [
+        233,
+        9,
+        153,
+        49,
+        11,
+        222,
+        59,
+        130
+      ]
+    },
+    {
+      "name": "betReceipt",
+      "discriminator": [
+        220,
+        192,
+        65,
+        5,
+        23,
+        202,
+        146,
+        102
+      ]
+    },
+    {
+      "name": "bracketMatch",
+      "discriminator": [
+        166,
+        144,
+        101,
+        54,
+        222,
+        248,
+        138,
+        233
+      ]
+    },
+    {
+      "name": "disputeVotes",
+      "discriminator": [
+        74,
+        81,
+        92,
+        157,
+        8,
+        11,
+        151,
+        69
+      ]
+    },
+    {
+      "name": "gameSession",
+      "discriminator": [
+        150,
+        116,
+        20,
+        197,
+        205,
+        121,
+        220,
+        240
+      ]
+    },
+    {
+      "name": "jackpot",
+      "discriminator": [
+        140,
+        46,
+        88,
+        182,
+        39,
+        85,
+        23,
+        131
+      ]
+    },
+    {
+      "name": "lobbyIndex",
+      "discriminator": [
+        70,
+        149,
+        41,
+        2,
+        117,
+        130,
+        4,
+        17
+      ]
+    },
+    {
+      "name": "matchLog",
+      "discriminator": [
+        49,
+        109,
+        128,
+        177,
+        64,
+        255,
+        56,
+        187
+      ]
+    },
+    {
+      "name": "matchQueue",
+      "discriminator": [
+        186,
+        184,
+        159,
+        96,
+        38,
+        234,
+        164,
+        181
+      ]
+    },
+    {
+      "name": "matchResultRecord",
+      "discriminator": [
+        190,
+        239,
+        129,
+        193,
+        66,
+        70,
+        56,
+        198
+      ]
+    },
+    {
+      "name": "networkState",
+      "discriminator": [
+        212,
+        237,
+        148,
+        56,
+        97,
+        245,
+        51,
+        169
+      ]
+    },
+    {
+      "name": "playerBan",
+      "discriminator": [
+        237,
+        254,
+        254,
+        254,
+        217,
+        118,
+        15,
+        2
+      ]
+    },
+    {
+      "name": "playerStats",
+      "discriminator": [
+        169,
+        146,
+        242,
+        176,
+        102,
+        118,
+        231,
+        172
+      ]
+    },
+    {
+      "name": "priceFeed",
+      "discriminator": [
+        189,
+        103,
+        252,
+        23,
+        152,
+        35,
+        243,
+        156
+      ]
+    },
+    {
+      "name": "priceUpdateV2",
+      "discriminator": [
+        34,
+        241,
+        35,
+        99,
+        157,
+        126,
+        244,
+        205
+      ]
+    },
+    {
+      "name": "programConfig",
+      "discriminator": [
+        196,
+        210,
+        90,
+        231,
+        144,
+        149,
+        140,
+        63
+      ]
+    },
+    {
+      "name": "randomnessV2",
+      "discriminator": [
+        139,
+        239,
+        184,
+        215,
+        227,
+        86,
+        191,
+        226
+      ]
+    },
+    {
+      "name": "recurringMatch",
+      "discriminator": [
+        98,
+        176,
+        116,
+        5,
+        195,
+        215,
+        45,
+        92
+      ]
+    },
+    {
+      "name": "referral",
+      "discriminator": [
+        30,
+        235,
+        136,
+        224,
+        106,
+        107,
+        49,
+        64
+      ]
+    },
+    {
+      "name": "referralCredit",
+      "discriminator": [
+        243,
+        232,
+        70,
+        44,
+        7,
+        3,
+        157,
+        108
+      ]
+    },
+    {
+      "name": "registeredMint",
+      "discriminator": [
+        212,
+        180,
+        185,
+        29,
+        7,
+        213,
+        52,
+        56
+      ]
+    },
+    {
+      "name": "resultCommitment",
+      "discriminator": [
+        203,
+        124,
+        124,
+        69,
+        99,
+        81,
+        98,
+        109
+      ]
+    },
+    {
+      "name": "serverBond",
+      "discriminator": [
+        139,
+        111,
+        76,
+        229,
+        227,
+        225,
+        169,
+        166
+      ]
+    },
+    {
+      "name": "sessionKick",
+      "discriminator": [
+        74,
+        146,
+        204,
+        250,
+        36,
+        73,
+        184,
+        223
+      ]
+    },
+    {
+      "name": "sessionMetadata",
+      "discriminator": [
+        123,
+        199,
+        255,
+        103,
+        27,
+        70,
+        239,
+        63
+      ]
+    },
+    {
+      "name": "sessionTemplate",
+      "discriminator": [
+        39,
+        239,
+        220,
+        69,
+        236,
+        131,
+        193,
+        126
+      ]
+    },
+    {
+      "name": "spectatorBet",
+      "discriminator": [
+        188,
+        206,
+        95,
+        79,
+        160,
+        145,
+        235,
+        128
+      ]
+    },
+    {
+      "name": "spectatorPool",
+      "discriminator": [
+        8,
+        229,
+        168,
+        64,
+        124,
+        207,
+        105,
+        6
+      ]
+    },
+    {
+      "name": "tournament",
+      "discriminator": [
+        175,
+        139,
+        119,
+        242,
+        115,
+        194,
+        57,
+        92
+      ]
+    },
+    {
+      "name": "unclaimedPayout",
+      "discriminator": [
+        31,
+        249,
+        167,
+        185,
+        59,
+        167,
+        38,
+        151
+      ]
+    },
+    {
+      "name": "vault",
+      "discriminator": [
+        211,
+        8,
+        232,
+        43,
+        2,
+        152,
+        117,
+        119
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "announcementPosted",
+      "discriminator": [
+        235,
+        218,
+        82,
+        253,
+        240,
+        119,
+        171,
+        56
+      ]
+    },
+    {
+      "name": "authorityAccepted",
+      "discriminator": [
+        166,
+        192,
+        219,
+        188,
+        41,
+        209,
+        195,
+        26
+      ]
+    },
+    {
+      "name": "authorityProposed",
+      "discriminator": [
+        244,
+        117,
+        94,
+        112,
+        53,
+        151,
+        35,
+        89
+      ]
+    },
+    {
+      "name": "betPledged",
+      "discriminator": [
+        116,
+        184,
+        92,
+        139,
+        148,
+        229,
+        11,
+        17
+      ]
+    },
+    {
+      "name": "bondPosted",
+      "discriminator": [
+        39,
+        196,
+        2,
+        142,
+        202,
+        74,
+        107,
+        205
+      ]
+    },
+    {
+      "name": "bondSlashed",
+      "discriminator": [
+        59,
+        7,
+        252,
+        195,
+        234,
+        156,
+        42,
+        54
+      ]
+    },
+    {
+      "name": "bondWithdrawalRequested",
+      "discriminator": [
+        91,
+        120,
+        212,
+        190,
+        108,
+        71,
+        160,
+        105
+      ]
+    },
+    {
+      "name": "bondWithdrawn",
+      "discriminator": [
+        111,
+        192,
+        154,
+        231,
+        58,
+        115,
+        130,
+        103
+      ]
+    },
+    {
+      "name": "captaincyTransferred",
+      "discriminator": [
+        72,
+        29,
+        64,
+        197,
+        33,
+        44,
+        10,
+        137
+      ]
+    },
+    {
+      "name": "creationFeeSettled",
+      "discriminator": [
+        173,
+        107,
+        219,
+        67,
+        217,
+        215,
+        99,
+        175
+      ]
+    },
+    {
+      "name": "creatorPenaltyPaid",
+      "discriminator": [
+        241,
+        249,
+        185,
+        242,
+        87,
+        64,
+        250,
+        212
+      ]
+    },
+    {
+      "name": "disputeResolved",
+      "discriminator": [
+        121,
+        64,
+        249,
+        153,
+        139,
+        128,
+        236,
+        187
+      ]
+    },
+    {
+      "name": "disputeVoteCast",
+      "discriminator": [
+        193,
+        34,
+        94,
+        69,
+        4,
+        179,
+        143,
+        87
+      ]
+    },
+    {
+      "name": "earningsClaimed",
+      "discriminator": [
+        106,
+        170,
+        154,
+        105,
+        21,
+        43,
+        189,
+        97
+      ]
+    },
+    {
+      "name": "exposureReleased",
+      "discriminator": [
+        178,
+        125,
+        189,
+        161,
+        184,
+        110,
+        84,
+        73
+      ]
+    },
+    {
+      "name": "feeShareRouted",
+      "discriminator": [
+        238,
+        0,
+        35,
+        232,
+        38,
+        228,
+        121,
+        221
+      ]
+    },
+    {
+      "name": "feesCollected",
+      "discriminator": [
+        233,
+        23,
+        117,
+        225,
+        107,
+        178,
+        254,
+        8
+      ]
+    },
+    {
+      "name": "feesWithdrawn",
+      "discriminator": [
+        234,
+        15,
+        0,
+        119,
+        148,
+        241,
+        40,
+        21
+      ]
+    },
+    {
+      "name": "forfeitDeclared",
+      "discriminator": [
+        166,
+        116,
+        176,
+        46,
+        1,
+        195,
+        77,
+        191
+      ]
+    },
+    {
+      "name": "freerollFunded",
+      "discriminator": [
+        163,
+        205,
+        157,
+        165,
+        156,
+        199,
+        102,
+        98
+      ]
+    },
+    {
+      "name": "freerollPrizeReclaimed",
+      "discriminator": [
+        237,
+        225,
+        144,
+        104,
+        255,
+        31,
+        230,
+        178
+      ]
+    },
+    {
+      "name": "jackpotFunded",
+      "discriminator": [
+        120,
+        124,
+        209,
+        77,
+        60,
+        100,
+        17,
+        179
+      ]
+    },
+    {
+      "name": "jackpotPaid",
+      "discriminator": [
+        21,
+        219,
+        85,
+        157,
+        45,
+        247,
+        26,
+        76
+      ]
+    },
+    {
+      "name": "jackpotWon",
+      "discriminator": [
+        37,
+        157,
+        14,
+        36,
+        53,
+        182,
+        158,
+        175
+      ]
+    },
+    {
+      "name": "killRecorded",
+      "discriminator": [
+        137,
+        57,
+        251,
+        202,
+        132,
+        254,
+        18,
+        191
+      ]
+    },
+    {
+      "name": "matchAborted",
+      "discriminator": [
+        144,
+        52,
+        115,
+        105,
+        39,
+        31,
+        225,
+        112
+      ]
+    },
+    {
+      "name": "matchFormed",
+      "discriminator": [
+        70,
+        124,
+        82,
+        184,
+        14,
+        201,
+        86,
+        139
+      ]
+    },
+    {
+      "name": "matchResultRecorded",
+      "discriminator": [
+        196,
+        125,
+        40,
+        215,
+        160,
+        194,
+        138,
+        241
+      ]
+    },
+    {
+      "name": "matchResultReported",
+      "discriminator": [
+        132,
+        83,
+        90,
+        29,
+        104,
+        183,
+        239,
+        62
+      ]
+    },
+    {
+      "name": "mintDeregistered",
+      "discriminator": [
+        97,
+        66,
+        169,
+        41,
+        230,
+        144,
+        21,
+        160
+      ]
+    },
+    {
+      "name": "mintRegistered",
+      "discriminator": [
+        91,
+        135,
+        88,
+        182,
+        239,
+        182,
+        5,
+        85
+      ]
+    },
+    {
+      "name": "operationApproved",
+      "discriminator": [
+        88,
+        215,
+        108,
+        12,
+        27,
+        76,
+        178,
+        81
+      ]
+    },
+    {
+      "name": "pauseUpdated",
+      "discriminator": [
+        203,
+        203,
+        33,
+        225,
+        130,
+        103,
+        90,
+        105
+      ]
+    },
+    {
+      "name": "payoutWithheld",
+      "discriminator": [
+        208,
+        190,
+        102,
+        80,
+        84,
+        182,
+        222,
+        212
+      ]
+    },
+    {
+      "name": "playerBanUpdated",
+      "discriminator": [
+        117,
+        40,
+        247,
+        221,
+        12,
+        204,
+        203,
+        182
+      ]
+    },
+    {
+      "name": "playerDequeued",
+      "discriminator": [
+        85,
+        90,
+        143,
+        173,
+        105,
+        134,
+        22,
+        128
+      ]
+    },
+    {
+      "name": "playerEliminated",
+      "discriminator": [
+        113,
+        7,
+        112,
+        31,
+        140,
+        23,
+        12,
+        120
+      ]
+    },
+    {
+      "name": "playerJoined",
+      "discriminator": [
+        39,
+        144,
+        49,
+        106,
+        108,
+        210,
+        183,
+        38
+      ]
+    },
+    {
+      "name": "playerKicked",
+      "discriminator": [
+        186,
+        191,
+        11,
+        155,
+        79,
+        127,
+        166,
+        144
+      ]
+    },
+    {
+      "name": "playerLeft",
+      "discriminator": [
+        7,
+        106,
+        62,
+        150,
+        175,
+        170,
+        96,
+        84
+      ]
+    },
+    {
+      "name": "playerQueued",
+      "discriminator": [
+        30,
+        143,
+        76,
+        78,
+        167,
+        114,
+        70,
+        241
+      ]
+    },
+    {
+      "name": "playerReadied",
+      "discriminator": [
+        83,
+        61,
+        122,
+        131,
+        218,
+        146,
+        142,
+        130
+      ]
+    },
+    {
+      "name": "playerSubstituted",
+      "discriminator": [
+        249,
+        123,
+        131,
+        137,
+        164,
+        119,
+        33,
+        97
+      ]
+    },
+    {
+      "name": "pledgeCollected",
+      "discriminator": [
+        137,
+        155,
+        242,
+        95,
+        196,
+        182,
+        87,
+        238
+      ]
+    },
+    {
+      "name": "pledgeDefaulted",
+      "discriminator": [
+        182,
+        71,
+        135,
+        73,
+        79,
+        126,
+        112,
+        109
+      ]
+    },
+    {
+      "name": "prizeAdded",
+      "discriminator": [
+        198,
+        189,
+        108,
+        191,
+        245,
+        23,
+        92,
+        12
+      ]
+    },
+    {
+      "name": "randomnessRequested",
+      "discriminator": [
+        10,
+        64,
+        183,
+        29,
+        104,
+        63,
+        90,
+        149
+      ]
+    },
+    {
+      "name": "randomnessRevealed",
+      "discriminator": [
+        64,
+        201,
+        250,
+        123,
+        9,
+        234,
+        196,
+        41
+      ]
+    },
+    {
+      "name": "ratingUpdated",
+      "discriminator": [
+        56,
+        99,
+        247,
+        53,
+        155,
+        42,
+        245,
+        247
+      ]
+    },
+    {
+      "name": "recurringInstanceSpawned",
+      "discriminator": [
+        66,
+        18,
+        197,
+        192,
+        94,
+        14,
+        227,
+        166
+      ]
+    },
+    {
+      "name": "recurringMatchCreated",
+      "discriminator": [
+        41,
+        125,
+        107,
+        137,
+        222,
+        85,
+        117,
+        135
+      ]
+    },
+    {
+      "name": "referralRecorded",
+      "discriminator": [
+        128,
+        33,
+        88,
+        93,
+        58,
+        242,
+        121,
+        168
+      ]
+    },
+    {
+      "name": "referralRewardsClaimed",
+      "discriminator": [
+        178,
+        107,
+        76,
+        169,
+        252,
+        154,
+        45,
+        235
+      ]
+    },
+    {
+      "name": "refundIssued",
+      "discriminator": [
+        249,
+        16,
+        159,
+        159,
+        93,
+        186,
+        145,
+        206
+      ]
+    },
+    {
+      "name": "resultCommitted",
+      "discriminator": [
+        197,
+        81,
+        109,
+        4,
+        128,
+        173,
+        1,
+        109
+      ]
+    },
+    {
+      "name": "resultOverridden",
+      "discriminator": [
+        81,
+        196,
+        242,
+        10,
+        175,
+        51,
+        67,
+        128
+      ]
+    },
+    {
+      "name": "resultSubmitted",
+      "discriminator": [
+        156,
+        153,
+        163,
+        89,
+        245,
+        144,
+        63,
+        94
+      ]
+    },
+    {
+      "name": "roundRecorded",
+      "discriminator": [
+        170,
+        154,
+        167,
+        75,
+        241,
+        106,
+        84,
+        139
+      ]
+    },
+    {
+      "name": "scoreSubmitted",
+      "discriminator": [
+        15,
+        74,
+        143,
+        188,
+        62,
+        88,
+        81,
+        104
+      ]
+    },
+    {
+      "name": "sessionAuthorityDelegated",
+      "discriminator": [
+        226,
+        157,
+        201,
+        132,
+        39,
+        176,
+        127,
+        184
+      ]
+    },
+    {
+      "name": "sessionCreated",
+      "discriminator": [
+        107,
+        111,
+        254,
+        25,
+        21,
+        122,
+        220,
+        225
+      ]
+    },
+    {
+      "name": "sessionCreatedFromTemplate",
+      "discriminator": [
+        72,
+        222,
+        255,
+        183,
+        4,
+        40,
+        152,
+        154
+      ]
+    },
+    {
+      "name": "sessionFlagged",
+      "discriminator": [
+        235,
+        98,
+        202,
+        0,
+        208,
+        177,
+        250,
+        28
+      ]
+    },
+    {
+      "name": "sessionLocked",
+      "discriminator": [
+        17,
+        48,
+        51,
+        68,
+        3,
+        162,
+        116,
+        92
+      ]
+    },
+    {
+      "name": "sessionSettled",
+      "discriminator": [
+        127,
+        161,
+        59,
+        136,
+        23,
+        168,
+        191,
+        149
+      ]
+    },
+    {
+      "name": "sessionStarted",
+      "discriminator": [
+        97,
+        241,
+        44,
+        75,
+        210,
+        66,
+        122,
+        96
+      ]
+    },
+    {
+      "name": "sessionTemplateCreated",
+      "discriminator": [
+        123,
+        229,
+        244,
+        217,
+        176,
+        238,
+        17,
+        167
+      ]
+    },
+    {
+      "name": "settlementRecovered",
+      "discriminator": [
+        120,
+        214,
+        169,
+        117,
+        46,
+        47,
+        142,
+        21
+      ]
+    },
+    {
+      "name": "settlementTableClosed",
+      "discriminator": [
+        69,
+        229,
+        224,
+        44,
+        127,
+        178,
+        211,
+        142
+      ]
+    },
+    {
+      "name": "settlementTableCreated",
+      "discriminator": [
+        5,
+        233,
+        120,
+        117,
+        37,
+        248,
+        238,
+        224
+      ]
+    },
+    {
+      "name": "settlementTableDeactivated",
+      "discriminator": [
+        98,
+        155,
+        83,
+        60,
+        220,
+        118,
+        119,
+        189
+      ]
+    },
+    {
+      "name": "settlementTableExtended",
+      "discriminator": [
+        134,
+        111,
+        252,
+        121,
+        137,
+        161,
+        139,
+        85
+      ]
+    },
+    {
+      "name": "spawnPurchased",
+      "discriminator": [
+        32,
+        208,
+        135,
+        175,
+        218,
+        138,
+        145,
+        143
+      ]
+    },
+    {
+      "name": "spawnsToppedUp",
+      "discriminator": [
+        217,
+        157,
+        113,
+        191,
+        211,
+        127,
+        168,
+        202
+      ]
+    },
+    {
+      "name": "spectatorBetPlaced",
+      "discriminator": [
+        116,
+        205,
+        16,
+        237,
+        247,
+        77,
+        254,
+        4
+      ]
+    },
+    {
+      "name": "spectatorPayoutClaimed",
+      "discriminator": [
+        68,
+        30,
+        200,
+        56,
+        153,
+        131,
+        141,
+        234
+      ]
+    },
+    {
+      "name": "sponsoredPrizeReclaimed",
+      "discriminator": [
+        249,
+        121,
+        175,
+        163,
+        181,
+        62,
+        31,
+        101
+      ]
+    },
+    {
+      "name": "streakBonusClaimed",
+      "discriminator": [
+        71,
+        181,
+        140,
+        182,
+        20,
+        9,
+        126,
+        220
+      ]
+    },
+    {
+      "name": "tournamentCompleted",
+      "discriminator": [
+        67,
+        47,
+        75,
+        4,
+        191,
+        61,
+        1,
+        150
+      ]
+    },
+    {
+      "name": "tournamentCreated",
+      "discriminator": [
+        102,
+        32,
+        240,
+        45,
+        52,
+        64,
+        97,
+        0
+      ]
+    },
+    {
+      "name": "tournamentFundsParked",
+      "discriminator": [
+        96,
+        229,
+        157,
+        116,
+        191,
+        26,
+        187,
+        122
+      ]
+    },
+    {
+      "name": "tournamentFundsWithdrawn",
+      "discriminator": [
+        131,
+        164,
+        87,
+        13,
+        231,
+        40,
+        235,
+        103
+      ]
+    },
+    {
+      "name": "tournamentTeamRegistered",
+      "discriminator": [
+        71,
+        123,
+        40,
+        93,
+        243,
+        131,
+        19,
+        51
+      ]
+    },
+    {
+      "name": "trophyMinted",
+      "discriminator": [
+        105,
+        248,
+        138,
+        132,
+        214,
+        157,
+        41,
+        134
+      ]
+    },
+    {
+      "name": "unclaimedPayoutClaimed",
+      "discriminator": [
+        79,
+        192,
+        255,
+        170,
+        133,
+        129,
+        167,
+        79
+      ]
+    },
+    {
+      "name": "winningsDistributed",
+      "discriminator": [
+        8,
+        11,
+        13,
+        47,
+        10,
+        197,
+        186,
+        111
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidGameState",
+      "msg": "Game session is not in the correct state"
+    },
+    {
+      "code": 6001,
+      "name": "invalidTeamSelection",
+      "msg": "Invalid team selection for this session"
+    },
+    {
+      "code": 6002,
+      "name": "teamIsFull",
+      "msg": "Team is already full"
+    },
+    {
+      "code": 6003,
+      "name": "insufficientFunds",
+      "msg": "Insufficient funds to join the game"
+    },
+    {
+      "code": 6004,
+      "name": "invalidPlayerCount",
+      "msg": "Invalid number of players for this game mode"
+    },
+    {
+      "code": 6005,
+      "name": "notAllPlayersJoined",
+      "msg": "All players not joined"
+    },
+    {
+      "code": 6006,
+      "name": "gameNotCompleted",
+      "msg": "Game is not in completed state"
+    },
+    {
+      "code": 6007,
+      "name": "unauthorizedDistribution",
+      "msg": "Only the game authority can distribute winnings"
+    },
+    {
+      "code": 6008,
+      "name": "invalidWinningTeam",
+      "msg": "Invalid winning team selection"
+    },
+    {
+      "code": 6009,
+      "name": "totalPotCalculationError",
+      "msg": "Failed to calculate total pot due to arithmetic overflow"
+    },
+    {
+      "code": 6010,
+      "name": "noWinnersFound",
+      "msg": "No winners found in the winning team"
+    },
+    {
+      "code": 6011,
+      "name": "winningsCalculationError",
+      "msg": "Failed to calculate per-player winnings"
+    },
+    {
+      "code": 6012,
+      "name": "incompleteDistribution",
+      "msg": "Failed to distribute all funds from game session"
+    },
+    {
+      "code": 6013,
+      "name": "invalidTeam",
+      "msg": "Invalid team"
+    },
+    {
+      "code": 6014,
+      "name": "playerAccountNotFound",
+      "msg": "Player account not found in winners"
+    },
+    {
+      "code": 6015,
+      "name": "invalidWinner",
+      "msg": "Invalid winning team selection"
+    },
+    {
+      "code": 6016,
+      "name": "arithmeticError",
+      "msg": "Arithmetic error"
+    },
+    {
+      "code": 6017,
+      "name": "invalidMint",
+      "msg": "Invalid mint address provided"
+    },
+    {
+      "code": 6018,
+      "name": "invalidRemainingAccounts",
+      "msg": "Invalid remaining accounts provided"
+    },
+    {
+      "code": 6019,
+      "name": "invalidWinnerTokenAccount",
+      "msg": "Invalid winner token account owner"
+    },
+    {
+      "code": 6020,
+      "name": "invalidTokenMint",
+      "msg": "Invalid token mint"
+    },
+    {
+      "code": 6021,
+      "name": "invalidSpawns",
+      "msg": "Invalid spawns"
+    },
+    {
+      "code": 6022,
+      "name": "unauthorizedKill",
+      "msg": "Unauthorized kill"
+    },
+    {
+      "code": 6023,
+      "name": "unauthorizedPayToSpawn",
+      "msg": "Unauthorized pay to spawn"
+    },
+    {
+      "code": 6024,
+      "name": "playerNotFound",
+      "msg": "Player not found"
+    },
+    {
+      "code": 6025,
+      "name": "invalidPlayerTokenAccount",
+      "msg": "Invalid player token account"
+    },
+    {
+      "code": 6026,
+      "name": "invalidPlayer",
+      "msg": "Invalid player"
+    },
+    {
+      "code": 6027,
+      "name": "playerHasNoSpawns",
+      "msg": "Player has no spawns"
+    },
+    {
+      "code": 6028,
+      "name": "gameNotInProgress",
+      "msg": "Game is not in progress"
+    },
+    {
+      "code": 6029,
+      "name": "invalidSessionId",
+      "msg": "Invalid session ID format"
+    },
+    {
+      "code": 6030,
+      "name": "alreadyProcessing",
+      "msg": "Operation already in progress"
+    },
+    {
+      "code": 6031,
+      "name": "arithmeticOverflow",
+      "msg": "Arithmetic overflow in calculation"
+    },
+    {
+      "code": 6032,
+      "name": "arithmeticUnderflow",
+      "msg": "Arithmetic underflow in calculation"
+    },
+    {
+      "code": 6033,
+      "name": "invalidBetAmount",
+      "msg": "Invalid bet amount"
+    },
+    {
+      "code": 6034,
+      "name": "slotAlreadyOccupied",
+      "msg": "Slot already occupied"
+    },
+    {
+      "code": 6035,
+      "name": "invalidKill",
+      "msg": "Invalid kill data"
+    },
+    {
+      "code": 6036,
+      "name": "tooManyRemainingAccounts",
+      "msg": "Too many remaining accounts"
+    },
+    {
+      "code": 6037,
+      "name": "reentrancyDetected",
+      "msg": "Reentrancy attack detected"
+    },
+    {
+      "code": 6038,
+      "name": "invalidAuthority",
+      "msg": "Invalid authority"
+    },
+    {
+      "code": 6039,
+      "name": "sessionIdTooLong",
+      "msg": "Session ID too long"
+    },
+    {
+      "code": 6040,
+      "name": "invalidSessionIdFormat",
+      "msg": "Session ID contains invalid characters"
+    },
+    {
+      "code": 6041,
+      "name": "invalidTeamCount",
+      "msg": "Invalid number of teams for a game session"
+    },
+    {
+      "code": 6042,
+      "name": "invalidBetLimits",
+      "msg": "Minimum bet must be positive and not exceed maximum bet"
+    },
+    {
+      "code": 6043,
+      "name": "vaultInvariantViolated",
+      "msg": "Vault accounting invariant violated"
+    },
+    {
+      "code": 6044,
+      "name": "invalidSessionTimeout",
+      "msg": "Session timeout must be positive"
+    },
+    {
+      "code": 6045,
+      "name": "sessionNotExpired",
+      "msg": "Game session has not expired yet"
+    },
+    {
+      "code": 6046,
+      "name": "invalidSpawnEarningsBps",
+      "msg": "Spawn earnings rate cannot exceed 10000 basis points"
+    },
+    {
+      "code": 6047,
+      "name": "nativeSolSession",
+      "msg": "Native SOL sessions must be joined with join_user_sol"
+    },
+    {
+      "code": 6048,
+      "name": "notNativeSolSession",
+      "msg": "Session is not denominated in native SOL"
+    },
+    {
+      "code": 6049,
+      "name": "unsupportedNativeGameMode",
+      "msg": "Pay-to-spawn is not supported for native SOL sessions"
+    },
+    {
+      "code": 6050,
+      "name": "programPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6051,
+      "name": "invalidStatusTransition",
+      "msg": "Invalid game session status transition"
+    },
+    {
+      "code": 6052,
+      "name": "settlementNotStale",
+      "msg": "Settlement is not stale enough to recover"
+    },
+    {
+      "code": 6053,
+      "name": "invalidKillReportSigner",
+      "msg": "Kill report signer must be a valid public key"
+    },
+    {
+      "code": 6054,
+      "name": "missingKillReportSignature",
+      "msg": "Kill report must be preceded by an ed25519 signature verification"
+    },
+    {
+      "code": 6055,
+      "name": "invalidKillReportSignature",
+      "msg": "Kill report signature does not match the registered signer or report"
+    },
+    {
+      "code": 6056,
+      "name": "staleKillReport",
+      "msg": "Kill report round has already been recorded"
+    },
+    {
+      "code": 6057,
+      "name": "invalidPlayerStats",
+      "msg": "Player stats account does not belong to the player"
+    },
+    {
+      "code": 6058,
+      "name": "invalidFeeBps",
+      "msg": "Protocol fee exceeds the maximum allowed rate"
+    },
+    {
+      "code": 6059,
+      "name": "invalidWithdrawAmount",
+      "msg": "Withdrawal amount must be positive"
+    },
+    {
+      "code": 6060,
+      "name": "unsupportedSessionVersion",
+      "msg": "Game session account version is not supported"
+    },
+    {
+      "code": 6061,
+      "name": "sessionAlreadyMigrated",
+      "msg": "Game session account is already at the current version"
+    },
+    {
+      "code": 6062,
+      "name": "invalidTournamentSize",
+      "msg": "Tournament size must be a power of two within the supported range"
+    },
+    {
+      "code": 6063,
+      "name": "invalidTournamentState",
+      "msg": "Tournament is not in the correct state"
+    },
+    {
+      "code": 6064,
+      "name": "tournamentFull",
+      "msg": "Tournament has no open entry slots"
+    },
+    {
+      "code": 6065,
+      "name": "invalidBracketMatch",
+      "msg": "Bracket match does not belong to this tournament position"
+    },
+    {
+      "code": 6066,
+      "name": "matchAlreadyDecided",
+      "msg": "Bracket match already has a winner"
+    },
+    {
+      "code": 6067,
+      "name": "matchNotReady",
+      "msg": "Bracket match is still waiting for its teams"
+    },
+    {
+      "code": 6068,
+      "name": "spectatorPoolUnsupported",
+      "msg": "Spectator betting is only available for winner-takes-all team sessions"
+    },
+    {
+      "code": 6069,
+      "name": "spectatorBettingClosed",
+      "msg": "Spectator betting is closed for this session"
+    },
+    {
+      "code": 6070,
+      "name": "playerCannotSpectate",
+      "msg": "Players cannot bet as spectators on their own session"
+    },
+    {
+      "code": 6071,
+      "name": "spectatorTeamMismatch",
+      "msg": "Spectator stakes can only be added to the team already backed"
+    },
+    {
+      "code": 6072,
+      "name": "spectatorBetAlreadyClaimed",
+      "msg": "Spectator payout already claimed"
+    },
+    {
+      "code": 6073,
+      "name": "sessionNotSettled",
+      "msg": "Game session has not been settled yet"
+    },
+    {
+      "code": 6074,
+      "name": "invalidOraclePrice",
+      "msg": "Oracle price is invalid, stale, or for the wrong feed"
+    },
+    {
+      "code": 6075,
+      "name": "missingPriceFeed",
+      "msg": "USD bet bounds require the mint's price feed and a price update"
+    },
+    {
+      "code": 6076,
+      "name": "sessionCloseCooldown",
+      "msg": "Session cannot be closed before the cool-down elapses"
+    },
+    {
+      "code": 6077,
+      "name": "vaultNotEmpty",
+      "msg": "Vault still holds funds"
+    },
+    {
+      "code": 6078,
+      "name": "distributionInProgress",
+      "msg": "Distribution is partially paid and must be resumed"
+    },
+    {
+      "code": 6079,
+      "name": "invalidLogCapacity",
+      "msg": "Match log capacity exceeds the maximum allowed"
+    },
+    {
+      "code": 6080,
+      "name": "missingMatchLog",
+      "msg": "Session keeps a match log that must be supplied"
+    },
+    {
+      "code": 6081,
+      "name": "invalidDisputeWindow",
+      "msg": "Dispute window must be non-negative and requires an arbiter"
+    },
+    {
+      "code": 6082,
+      "name": "disputeWindowRequired",
+      "msg": "Session results must be submitted and finalized after the dispute window"
+    },
+    {
+      "code": 6083,
+      "name": "disputeWindowOpen",
+      "msg": "Dispute window is still open"
+    },
+    {
+      "code": 6084,
+      "name": "disputeWindowClosed",
+      "msg": "Dispute window has closed"
+    },
+    {
+      "code": 6085,
+      "name": "unauthorizedArbiter",
+      "msg": "Only the arbiter can override a submitted result"
+    },
+    {
+      "code": 6086,
+      "name": "resultNotOverridable",
+      "msg": "Only winner-takes-all team results can be overridden"
+    },
+    {
+      "code": 6087,
+      "name": "invalidGameServerKeys",
+      "msg": "Game server keys must be distinct, non-empty and meet the threshold"
+    },
+    {
+      "code": 6088,
+      "name": "unauthorizedGameServer",
+      "msg": "Signer is not in the game server signing set"
+    },
+    {
+      "code": 6089,
+      "name": "insufficientApprovals",
+      "msg": "Not enough game server approvals for this operation"
+    },
+    {
+      "code": 6090,
+      "name": "approvalMismatch",
+      "msg": "Approval does not match this session, operation or result"
+    },
+    {
+      "code": 6091,
+      "name": "invalidReferralBps",
+      "msg": "Referral cut cannot exceed 10000 basis points"
+    },
+    {
+      "code": 6092,
+      "name": "invalidReferrer",
+      "msg": "Referrer must be another valid account, supplied with its referral accounts"
+    },
+    {
+      "code": 6093,
+      "name": "invalidTeamCapacity",
+      "msg": "Team capacities must be 1..=10 and only apply to two-team sessions"
+    },
+    {
+      "code": 6094,
+      "name": "invalidPayoutSchedule",
+      "msg": "Payout schedule shares must fit the winning team and sum to 10000 basis points"
+    },
+    {
+      "code": 6095,
+      "name": "invalidSessionMetadata",
+      "msg": "Session metadata is empty, too long or contains control characters"
+    },
+    {
+      "code": 6096,
+      "name": "queueUnsupported",
+      "msg": "Matchmaking queues only support fixed-bet SPL mints without transfer fees"
+    },
+    {
+      "code": 6097,
+      "name": "playerAlreadyQueued",
+      "msg": "Player is already waiting in this queue"
+    },
+    {
+      "code": 6098,
+      "name": "queueFull",
+      "msg": "Matchmaking queue is full"
+    },
+    {
+      "code": 6099,
+      "name": "playerNotQueued",
+      "msg": "Player is not waiting in this queue"
+    },
+    {
+      "code": 6100,
+      "name": "notEnoughQueuedPlayers",
+      "msg": "Not enough queued players to fill a session"
+    },
+    {
+      "code": 6101,
+      "name": "invalidSubstitute",
+      "msg": "Replacement must be a valid account not already in the session"
+    },
+    {
+      "code": 6102,
+      "name": "playerAlreadyJoined",
+      "msg": "Player already holds a slot in this session"
+    },
+    {
+      "code": 6103,
+      "name": "invalidJoinCredential",
+      "msg": "Private session requires a valid join code or allowlist proof"
+    },
+    {
+      "code": 6104,
+      "name": "killRateExceeded",
+      "msg": "Too many kills recorded for this session in the current slot"
+    },
+    {
+      "code": 6105,
+      "name": "killCapExceeded",
+      "msg": "Kill exceeds the session's per-player or per-match cap"
+    },
+    {
+      "code": 6106,
+      "name": "payoutExceedsEscrow",
+      "msg": "Computed payouts exceed the escrowed balance"
+    },
+    {
+      "code": 6107,
+      "name": "earningsAlreadyClaimed",
+      "msg": "Earnings for this slot have already been claimed"
+    },
+    {
+      "code": 6108,
+      "name": "nothingToClaim",
+      "msg": "Player has no earnings to claim"
+    },
+    {
+      "code": 6109,
+      "name": "invalidSeriesLength",
+      "msg": "Series length must be odd and at most 7, for winner-takes-all team sessions"
+    },
+    {
+      "code": 6110,
+      "name": "seriesNotDecided",
+      "msg": "No team has won the series yet"
+    },
+    {
+      "code": 6111,
+      "name": "seriesAlreadyDecided",
+      "msg": "Series has already been decided"
+    },
+    {
+      "code": 6112,
+      "name": "invalidTopUpAmount",
+      "msg": "Top-up must be a positive multiple of the session bet"
+    },
+    {
+      "code": 6113,
+      "name": "topUpLimitExceeded",
+      "msg": "Top-up exceeds the session's allowance for this player"
+    },
+    {
+      "code": 6114,
+      "name": "playerEliminated",
+      "msg": "Player has been eliminated from the match"
+    },
+    {
+      "code": 6115,
+      "name": "invalidRandomnessState",
+      "msg": "VRF randomness is not in the correct state for this session"
+    },
+    {
+      "code": 6116,
+      "name": "randomnessNotFulfilled",
+      "msg": "VRF randomness has not been fulfilled yet"
+    },
+    {
+      "code": 6117,
+      "name": "randomnessPending",
+      "msg": "Match setup is waiting on VRF randomness"
+    },
+    {
+      "code": 6118,
+      "name": "invalidJackpotBps",
+      "msg": "Jackpot and referral cuts together cannot exceed 10000 basis points"
+    },
+    {
+      "code": 6119,
+      "name": "invalidJackpot",
+      "msg": "Jackpot accounts for the session mint must be supplied"
+    },
+    {
+      "code": 6120,
+      "name": "jackpotNotWon",
+      "msg": "Session has not won the jackpot"
+    },
+    {
+      "code": 6121,
+      "name": "streakBroken",
+      "msg": "Win streak was broken by a session that was not won"
+    },
+    {
+      "code": 6122,
+      "name": "streakBonusUnavailable",
+      "msg": "Streak bonuses are not configured"
+    },
+    {
+      "code": 6123,
+      "name": "playerStatsAlreadyMigrated",
+      "msg": "Player stats account is already at the current layout"
+    },
+    {
+      "code": 6124,
+      "name": "invalidRatingBounds",
+      "msg": "Maximum rating must be zero or at least the minimum rating"
+    },
+    {
+      "code": 6125,
+      "name": "ratingOutOfRange",
+      "msg": "Player rating is outside the lobby's rating bounds"
+    },
+    {
+      "code": 6126,
+      "name": "ratingAlreadyApplied",
+      "msg": "Rating change for this session has already been applied"
+    },
+    {
+      "code": 6127,
+      "name": "lobbyIndexFull",
+      "msg": "Lobby index is full and must be pruned"
+    },
+    {
+      "code": 6128,
+      "name": "invalidCreatorPenaltyBps",
+      "msg": "Creator penalty cannot exceed 10000 basis points"
+    },
+    {
+      "code": 6129,
+      "name": "invalidCreatorTokenAccount",
+      "msg": "Creator token account is required to pay the forfeit penalty"
+    },
+    {
+      "code": 6130,
+      "name": "invalidAccountSize",
+      "msg": "Account was not allocated the size of its type"
+    },
+    {
+      "code": 6131,
+      "name": "accountNotRentExempt",
+      "msg": "Account does not hold enough lamports to be rent-exempt"
+    },
+    {
+      "code": 6132,
+      "name": "invalidTrophyUri",
+      "msg": "Trophy base URI is too long"
+    },
+    {
+      "code": 6133,
+      "name": "trophiesUnavailable",
+      "msg": "Trophies are not configured"
+    },
+    {
+      "code": 6134,
+      "name": "notSessionWinner",
+      "msg": "Only members of the session's winning team can receive a trophy"
+    },
+    {
+      "code": 6135,
+      "name": "missingSeasonPass",
+      "msg": "Session requires holding its season pass"
+    },
+    {
+      "code": 6136,
+      "name": "playerIsBanned",
+      "msg": "Player is banned from joining sessions"
+    },
+    {
+      "code": 6137,
+      "name": "invalidCpiCallers",
+      "msg": "CPI callers must be distinct, non-empty and within the maximum"
+    },
+    {
+      "code": 6138,
+      "name": "unauthorizedCpiCaller",
+      "msg": "Calling program is not allowed to invoke sessions by CPI"
+    },
+    {
+      "code": 6139,
+      "name": "duplicateKillReport",
+      "msg": "Kill report sequence number has already been recorded"
+    },
+    {
+      "code": 6140,
+      "name": "killReportOutOfOrder",
+      "msg": "Kill report sequence number skips ahead of the next expected one"
+    },
+    {
+      "code": 6141,
+      "name": "invalidKillBatch",
+      "msg": "Kill batch must hold between one and the maximum number of kills"
+    },
+    {
+      "code": 6142,
+      "name": "notTeamCaptain",
+      "msg": "Only the team captain can manage the team"
+    },
+    {
+      "code": 6143,
+      "name": "creationFeesReserved",
+      "msg": "Withdrawal would spend creation fees still held for their sessions"
+    },
+    {
+      "code": 6144,
+      "name": "missingUnclaimedPayout",
+      "msg": "Payee's token account is closed or frozen and the unclaimed payout account was not supplied"
+    },
+    {
+      "code": 6145,
+      "name": "unclaimedPayoutsFull",
+      "msg": "Unclaimed payout account is full"
+    },
+    {
+      "code": 6146,
+      "name": "distributionComputeExhausted",
+      "msg": "Not enough compute left to pay a single payee"
+    },
+    {
+      "code": 6147,
+      "name": "invalidPdaBump",
+      "msg": "Account is not the canonical program address for its seeds"
+    },
+    {
+      "code": 6148,
+      "name": "invalidJoinWindow",
+      "msg": "Join deadline must be in the future with player minimums the lobby can seat"
+    },
+    {
+      "code": 6149,
+      "name": "joinDeadlinePassed",
+      "msg": "Session's join deadline has passed"
+    },
+    {
+      "code": 6150,
+      "name": "joinDeadlineNotReached",
+      "msg": "Session has no join deadline or it has not passed yet"
+    },
+    {
+      "code": 6151,
+      "name": "exposureCapExceeded",
+      "msg": "Join would exceed the wallet's cap on bets open across sessions"
+    },
+    {
+      "code": 6152,
+      "name": "tooManyOpenSessions",
+      "msg": "Wallet already has the maximum number of open sessions tracked"
+    },
+    {
+      "code": 6153,
+      "name": "missingActiveExposure",
+      "msg": "Active exposure account is required while an exposure cap is set"
+    },
+    {
+      "code": 6154,
+      "name": "exposureStillActive",
+      "msg": "Session still holds the player's bet"
+    },
+    {
+      "code": 6155,
+      "name": "invalidFeeSplit",
+      "msg": "Fee split needs up to four distinct recipients with shares summing to 10000 bps"
+    },
+    {
+      "code": 6156,
+      "name": "invalidFeeRecipient",
+      "msg": "Fee recipient account does not match the session's fee split"
+    },
+    {
+      "code": 6157,
+      "name": "invalidWinCondition",
+      "msg": "Win condition is not supported by this game mode"
+    },
+    {
+      "code": 6158,
+      "name": "resultContradictsWinCondition",
+      "msg": "Reported winner contradicts the session's win condition"
+    },
+    {
+      "code": 6159,
+      "name": "scoreAlreadyDecided",
+      "msg": "A team has already won the match on score"
+    },
+    {
+      "code": 6160,
+      "name": "invalidScore",
+      "msg": "Score must increase, and an objective capture is submitted as 1"
+    },
+    {
+      "code": 6161,
+      "name": "invalidWinnerDeclaration",
+      "msg": "Declared result is not valid for this session"
+    },
+    {
+      "code": 6162,
+      "name": "drawNotPossible",
+      "msg": "Match cannot be declared a draw, a team is ahead under its win condition"
+    },
+    {
+      "code": 6163,
+      "name": "forfeitNotSupported",
+      "msg": "Only two-team winner-takes-all sessions can be forfeited"
+    },
+    {
+      "code": 6164,
+      "name": "teamNotInactive",
+      "msg": "Team has not been inactive for the forfeit timeout, or its opponent is inactive too"
+    },
+    {
+      "code": 6165,
+      "name": "invalidForfeitTerms",
+      "msg": "Invalid forfeit timeout or penalty"
+    },
+    {
+      "code": 6166,
+      "name": "auditReviewRequired",
+      "msg": "Session was flagged for suspicious play, its result must go through a reviewed dispute window"
+    },
+    {
+      "code": 6167,
+      "name": "templateOutdated",
+      "msg": "Config rake no longer matches the session template"
+    },
+    {
+      "code": 6168,
+      "name": "invalidSessionTemplate",
+      "msg": "Session template does not belong to this schedule"
+    },
+    {
+      "code": 6169,
+      "name": "invalidRecurringSchedule",
+      "msg": "Invalid recurring match interval or join window"
+    },
+    {
+      "code": 6170,
+      "name": "recurringMatchNotDue",
+      "msg": "Next instance of the recurring match is not due yet"
+    },
+    {
+      "code": 6171,
+      "name": "recurringMatchFinished",
+      "msg": "Recurring match has spawned all of its instances"
+    },
+    {
+      "code": 6172,
+      "name": "invalidFreerollSponsors",
+      "msg": "Freeroll sponsors must be distinct, non-default and within the limit"
+    },
+    {
+      "code": 6173,
+      "name": "unauthorizedFreerollSponsor",
+      "msg": "Only the config authority or a whitelisted sponsor can run freerolls"
+    },
+    {
+      "code": 6174,
+      "name": "invalidFreeroll",
+      "msg": "Freerolls take no bet, no spawn payments and refund in full"
+    },
+    {
+      "code": 6175,
+      "name": "missingSponsorTokenAccount",
+      "msg": "Sponsor token account is required for an SPL freeroll prize"
+    },
+    {
+      "code": 6176,
+      "name": "invalidPrizeAmount",
+      "msg": "Prize deposits must be positive"
+    },
+    {
+      "code": 6177,
+      "name": "prizeSponsorshipUnavailable",
+      "msg": "Session does not take sponsored prizes"
+    },
+    {
+      "code": 6178,
+      "name": "prizeSponsorLimitReached",
+      "msg": "Session already has the maximum number of prize sponsors"
+    },
+    {
+      "code": 6179,
+      "name": "invalidAssistWeight",
+      "msg": "Assist weight lets a kill's assists take more than the kill"
+    },
+    {
+      "code": 6180,
+      "name": "readinessNotRequired",
+      "msg": "Session does not require players to report ready"
+    },
+    {
+      "code": 6181,
+      "name": "missingMatchResult",
+      "msg": "Completed sessions need their result record before closing"
+    },
+    {
+      "code": 6182,
+      "name": "pledgesNotDue",
+      "msg": "Pledged bets are collected once the lobby is full or its join deadline has passed"
+    },
+    {
+      "code": 6183,
+      "name": "noOutstandingPledges",
+      "msg": "Session has no pledged bets to collect"
+    },
+    {
+      "code": 6184,
+      "name": "unauthorizedPledgeCollection",
+      "msg": "Only the session authority can collect pledged bets"
+    },
+    {
+      "code": 6185,
+      "name": "invalidYieldPrograms",
+      "msg": "Yield programs must be distinct, non-default and within the limit, with a treasury share of at most 100%"
+    },
+    {
+      "code": 6186,
+      "name": "yieldProgramNotAllowed",
+      "msg": "Yield program is not whitelisted or differs from the one holding parked funds"
+    },
+    {
+      "code": 6187,
+      "name": "invalidYieldTransfer",
+      "msg": "Yield program moved a different amount than requested"
+    },
+    {
+      "code": 6188,
+      "name": "yieldPrincipalShortfall",
+      "msg": "Yield program returned less than the parked principal"
+    },
+    {
+      "code": 6189,
+      "name": "yieldFundsParked",
+      "msg": "Parked tournament funds must be withdrawn before payout"
+    },
+    {
+      "code": 6190,
+      "name": "invalidStake",
+      "msg": "Stake must be between the session bet and its maximum stake"
+    },
+    {
+      "code": 6191,
+      "name": "invalidStakeRange",
+      "msg": "Maximum stake must be at least the bet and within the config's maximum, in winner-takes-all team sessions"
+    },
+    {
+      "code": 6192,
+      "name": "stakeMismatch",
+      "msg": "Recorded team stakes do not reconcile with the seats or the vault"
+    },
+    {
+      "code": 6193,
+      "name": "invalidAnnouncement",
+      "msg": "Announcement must be 1 to 64 bytes of text without control characters"
+    },
+    {
+      "code": 6194,
+      "name": "announcementsFull",
+      "msg": "Session announcement board is full"
+    },
+    {
+      "code": 6195,
+      "name": "unauthorizedAnnouncement",
+      "msg": "Only the session authority can post announcements"
+    },
+    {
+      "code": 6196,
+      "name": "invalidClockSkew",
+      "msg": "Clock skew tolerance exceeds the maximum or closes the dispute window"
+    },
+    {
+      "code": 6197,
+      "name": "operationExpired",
+      "msg": "Operation's deadline has passed"
+    },
+    {
+      "code": 6198,
+      "name": "operationPremature",
+      "msg": "Operation is not open until its deadline"
+    },
+    {
+      "code": 6199,
+      "name": "notPendingAuthority",
+      "msg": "Signer is not the config's pending authority"
+    },
+    {
+      "code": 6200,
+      "name": "counterOverflow",
+      "msg": "Kill, spawn or assist counter is full"
+    },
+    {
+      "code": 6201,
+      "name": "missingBetReceipt",
+      "msg": "Player joined with a bet receipt, which the refund must close"
+    },
+    {
+      "code": 6202,
+      "name": "betReceiptMismatch",
+      "msg": "Bet receipt does not match the player's seat"
+    },
+    {
+      "code": 6203,
+      "name": "betReceiptInUse",
+      "msg": "Bet receipt is still backing a seat in an unsettled session"
+    },
+    {
+      "code": 6204,
+      "name": "invalidBondSlashBps",
+      "msg": "Bond slash must not exceed 10000 basis points"
+    },
+    {
+      "code": 6205,
+      "name": "invalidBondAmount",
+      "msg": "Bond amount must be greater than zero"
+    },
+    {
+      "code": 6206,
+      "name": "insufficientBond",
+      "msg": "Bond holds fewer lamports than requested"
+    },
+    {
+      "code": 6207,
+      "name": "bondWithdrawalLocked",
+      "msg": "No bond withdrawal is pending or it is still locked"
+    },
+    {
+      "code": 6208,
+      "name": "invalidArbiterCouncil",
+      "msg": "Arbiter council must hold distinct non-default keys, at most MAX_ARBITERS"
+    },
+    {
+      "code": 6209,
+      "name": "notOnArbiterCouncil",
+      "msg": "Signer doesn't sit on the arbiter council"
+    },
+    {
+      "code": 6210,
+      "name": "resultCommitRequired",
+      "msg": "Results must be committed and revealed while the config requires it"
+    },
+    {
+      "code": 6211,
+      "name": "resultCommitmentMismatch",
+      "msg": "Revealed result and salt don't match the commitment"
+    },
+    {
+      "code": 6212,
+      "name": "revealTooEarly",
+      "msg": "Result can't be revealed in the slot it was committed"
+    },
+    {
+      "code": 6213,
+      "name": "transferAmountMismatch",
+      "msg": "Token transfer moved a different amount than intended"
+    },
+    {
+      "code": 6214,
+      "name": "invalidLookupTable",
+      "msg": "Lookup table address doesn't match the vault's table for the slot"
+    },
+    {
+      "code": 6215,
+      "name": "councilDecidesDisputes",
+      "msg": "Disputes are decided by the arbiter council"
+    },
+    {
+      "code": 6216,
+      "name": "resultCommitmentPending",
+      "msg": "Result commitment is missing signing set approvals"
+    },
+    {
+      "code": 6217,
+      "name": "resultAlreadyCommitted",
+      "msg": "Result commitment already reached the threshold"
+    },
+    {
+      "code": 6218,
+      "name": "settlementTableOpen",
+      "msg": "Session's settlement lookup table is still open"
+    },
+    {
+      "code": 6219,
+      "name": "betReceiptClaimed",
+      "msg": "Bet receipt was already refunded or paid out"
+    },
+    {
+      "code": 6220,
+      "name": "sessionKickInUse",
+      "msg": "Kick marker can't be closed before its session settles"
+    },
+    {
+      "code": 6221,
+      "name": "bondAlreadySlashed",
+      "msg": "Session's result was already overturned against the operator's bond"
+    }
+  ],
+  "types": [
+    {
+      "name": "activeExposure",
+      "docs": [
+        "Bets a wallet has open across unsettled sessions, capped by the config's",
+        "`max_exposure`. Joins add to it; leaving, kicks and timeout refunds release",
+        "their entry directly, and `release_exposure` releases sessions settled in",
+        "bulk once they no longer hold the player's bet."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "exposureEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "announcement",
+      "docs": [
+        "A status line posted to a session's announcement board"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "postedAt",
+            "type": "i64"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "text",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "announcementPosted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "index",
+            "type": "u8"
+          },
+          {
+            "name": "text",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "announcements",
+      "docs": [
+        "Append-only board of match status lines the session authority posts,",
+        "created with its first announcement and closed with the session"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "announcement"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "approval",
+      "docs": [
+        "Approvals collected from the game server signing set for a sensitive",
+        "operation on a session, so the threshold can be met across transactions"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "approvalKind"
+              }
+            }
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "approvers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "approvalKind",
+      "docs": [
+        "Sensitive game server operations gated by the signing threshold"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "distributeWinnings"
+          },
+          {
+            "name": "submitResult"
+          },
+          {
+            "name": "overrideResult"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "betPledged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "betReceipt",
+      "docs": [
+        "One player's bet in a session, created when they join and closed when the",
+        "bet is refunded, or by the player once the session is settled. A receipt",
+        "that still exists has not been claimed back."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "joinedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "receiptStatus"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondPosted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "operator",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondSlashed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "operator",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondWithdrawalRequested",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "operator",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "unlockAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "operator",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bracketMatch",
+      "docs": [
+        "One match of a tournament bracket, played as a linked game session"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tournament",
+            "type": "pubkey"
+          },
+          {
+            "name": "round",
+            "type": "u8"
+          },
+          {
+            "name": "matchIndex",
+            "type": "u8"
+          },
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "teams",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "winner",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "captaincyTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "previousCaptain",
+            "type": "pubkey"
+          },
+          {
+            "name": "captain",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configParams",
+      "docs": [
+        "Admin-tunable program parameters"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minBet",
+            "type": "u64"
+          },
+          {
+            "name": "maxBet",
+            "type": "u64"
+          },
+          {
+            "name": "sessionTimeoutSecs",
+            "type": "i64"
+          },
+          {
+            "name": "killReportSigner",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "cancellationFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "usdMinBet",
+            "type": "u64"
+          },
+          {
+            "name": "usdMaxBet",
+            "type": "u64"
+          },
+          {
+            "name": "maxPriceAgeSecs",
+            "type": "u64"
+          },
+          {
+            "name": "arbiter",
+            "type": "pubkey"
+          },
+          {
+            "name": "disputeWindowSecs",
+            "type": "i64"
+          },
+          {
+            "name": "gameServerKeys",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "gameServerThreshold",
+            "type": "u8"
+          },
+          {
+            "name": "referralBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxTopUpMultiple",
+            "type": "u8"
+          },
+          {
+            "name": "mapPoolSize",
+            "type": "u8"
+          },
+          {
+            "name": "jackpotBps",
+            "type": "u16"
+          },
+          {
+            "name": "jackpotInterval",
+            "type": "u64"
+          },
+          {
+            "name": "rewardsMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "streakMilestone",
+            "type": "u16"
+          },
+          {
+            "name": "streakBonus",
+            "type": "u64"
+          },
+          {
+            "name": "eloKFactor",
+            "type": "u16"
+          },
+          {
+            "name": "creatorPenaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "trophyUri",
+            "type": "string"
+          },
+          {
+            "name": "cpiCallers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "creationFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "maxExposure",
+            "type": "u64"
+          },
+          {
+            "name": "feeSplit",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeShare"
+                }
+              }
+            }
+          },
+          {
+            "name": "drawFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "forfeitTimeoutSecs",
+            "type": "i64"
+          },
+          {
+            "name": "forfeitPenaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "freerollSponsors",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "yieldPrograms",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "yieldTreasuryBps",
+            "type": "u16"
+          },
+          {
+            "name": "clockSkewSecs",
+            "type": "i64"
+          },
+          {
+            "name": "bondSlashBps",
+            "type": "u16"
+          },
+          {
+            "name": "arbiters",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "commitRevealResults",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creationFeeSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "refunded",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorPenaltyPaid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeResolved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "previousTeam",
+            "type": "u8"
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "votes",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeVote",
+      "docs": [
+        "One council member's vote on a disputed result"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "arbiter",
+            "type": "pubkey"
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeVoteCast",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "arbiter",
+            "type": "pubkey"
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "support",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeVotes",
+      "docs": [
+        "Votes the arbiter council has cast on a session's submitted result"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "disputeVote"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "distributionPlan",
+      "docs": [
+        "How a session's remaining distribution splits into transactions that",
+        "each stay within the distribution compute budget"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "payees",
+            "type": "u16"
+          },
+          {
+            "name": "paid",
+            "type": "u16"
+          },
+          {
+            "name": "pageSize",
+            "type": "u16"
+          },
+          {
+            "name": "chunks",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "earningsClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "exposureEntry",
+      "docs": [
+        "Bet a wallet has open in one session"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "exposureReleased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeShare",
+      "docs": [
+        "One recipient's share of the house rake, e.g. the operator, a map",
+        "creator or the protocol"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "bps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeShareRouted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feesCollected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feesWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "forfeitDeclared",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "forfeitingTeam",
+            "type": "u8"
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "inactivity",
+            "type": "bool"
+          },
+          {
+            "name": "penaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "disputeDeadline",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "freeForAllPlayer",
+      "docs": [
+        "Represents an individual entrant in a free-for-all session"
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "slot",
+            "type": {
+              "defined": {
+                "name": "playerSlot"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "freerollFunded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "funder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "fromTreasury",
+            "type": "bool"
+          },
+          {
+            "name": "prizePool",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "freerollPrizeReclaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "sponsorPrize",
+            "type": "u64"
+          },
+          {
+            "name": "treasuryPrize",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fulfilledRequest",
+      "docs": [
+        "Fulfilled request representation."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "client",
+            "type": "pubkey"
+          },
+          {
+            "name": "seed",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "randomness",
+            "docs": [
+              "Generated randomness.",
+              "",
+              "Please look into the account history logs to observe the individual components."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "gameMode",
+      "docs": [
+        "Game mode defining the team sizes"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "winnerTakesAllOneVsOne"
+          },
+          {
+            "name": "winnerTakesAllThreeVsThree"
+          },
+          {
+            "name": "winnerTakesAllFiveVsFive"
+          },
+          {
+            "name": "payToSpawnOneVsOne"
+          },
+          {
+            "name": "payToSpawnThreeVsThree"
+          },
+          {
+            "name": "payToSpawnFiveVsFive"
+          },
+          {
+            "name": "freeForAllFourPlayers"
+          },
+          {
+            "name": "freeForAllEightPlayers"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gameSession",
+      "docs": [
+        "Represents a game session between teams with its own pool. Stored",
+        "zero-copy so large rosters never pass through Borsh on the stack; fields",
+        "are ordered to keep the `repr(C)` layout free of implicit padding."
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "sessionIdLen",
+            "type": "u8"
+          },
+          {
+            "name": "gameMode",
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "type": "u8"
+          },
+          {
+            "name": "teamCount",
+            "type": "u8"
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vaultBump",
+            "type": "u8"
+          },
+          {
+            "name": "sessionId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionBet",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "sessionExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "lastProcessedAt",
+            "type": "i64"
+          },
+          {
+            "name": "lastKillRound",
+            "type": "u64"
+          },
+          {
+            "name": "settledAt",
+            "type": "i64"
+          },
+          {
+            "name": "teams",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "team"
+                  }
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "ffaPlayers",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "freeForAllPlayer"
+                  }
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "spawnEarningsBps",
+            "type": "u16"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "distributionCursor",
+            "type": "u16"
+          },
+          {
+            "name": "vaultTokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "ffaCount",
+            "type": "u8"
+          },
+          {
+            "name": "logCapacity",
+            "type": "u16"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "disputeWindowSecs",
+            "type": "i64"
+          },
+          {
+            "name": "disputeDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "teamCapacities",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "payoutShareCount",
+            "type": "u8"
+          },
+          {
+            "name": "payoutPadding",
+            "type": "u8"
+          },
+          {
+            "name": "payoutSharesBps",
+            "type": {
+              "array": [
+                "u16",
+                8
+              ]
+            }
+          },
+          {
+            "name": "payoutPaddingTail",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "accessKind",
+            "type": "u8"
+          },
+          {
+            "name": "accessPadding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "accessRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "lastKillSlot",
+            "type": "u64"
+          },
+          {
+            "name": "killsThisSlot",
+            "type": "u16"
+          },
+          {
+            "name": "killRatePadding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "maxKillsPerPlayer",
+            "type": "u16"
+          },
+          {
+            "name": "maxMatchKills",
+            "type": "u16"
+          },
+          {
+            "name": "matchKills",
+            "type": "u16"
+          },
+          {
+            "name": "killCapPadding",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "seriesLength",
+            "type": "u8"
+          },
+          {
+            "name": "roundsPlayed",
+            "type": "u8"
+          },
+          {
+            "name": "roundWins",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "seriesPadding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "roundKills",
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u16",
+                    8
+                  ]
+                },
+                7
+              ]
+            }
+          },
+          {
+            "name": "maxTopUpMultiple",
+            "type": "u8"
+          },
+          {
+            "name": "topUpPadding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "vrfSeed",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "randomness",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "mapPoolSize",
+            "type": "u8"
+          },
+          {
+            "name": "vrfState",
+            "type": "u8"
+          },
+          {
+            "name": "mapIndex",
+            "type": "u8"
+          },
+          {
+            "name": "startingTeam",
+            "type": "u8"
+          },
+          {
+            "name": "vrfPadding",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "minRating",
+            "type": "u16"
+          },
+          {
+            "name": "maxRating",
+            "type": "u16"
+          },
+          {
+            "name": "ratingPadding",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "refundPolicy",
+            "type": "u8"
+          },
+          {
+            "name": "refundPadding",
+            "type": "u8"
+          },
+          {
+            "name": "creatorPenaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "refundPaddingTail",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "requiresPass",
+            "type": "pubkey"
+          },
+          {
+            "name": "reportSequence",
+            "type": "u64"
+          },
+          {
+            "name": "captains",
+            "type": {
+              "array": [
+                "pubkey",
+                8
+              ]
+            }
+          },
+          {
+            "name": "creationFee",
+            "type": "u64"
+          },
+          {
+            "name": "joinDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "minPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "locked",
+            "type": "u8"
+          },
+          {
+            "name": "joinPadding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "minPlayersPerTeam",
+            "type": "u8"
+          },
+          {
+            "name": "shortHandedPadding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "feeSplitRecipients",
+            "type": {
+              "array": [
+                "pubkey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "feeSplitBps",
+            "type": {
+              "array": [
+                "u16",
+                4
+              ]
+            }
+          },
+          {
+            "name": "feeSplitCount",
+            "type": "u8"
+          },
+          {
+            "name": "feeSplitPadding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "winCondition",
+            "type": "u8"
+          },
+          {
+            "name": "winPadding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "targetScore",
+            "type": "u32"
+          },
+          {
+            "name": "teamScores",
+            "type": {
+              "array": [
+                "u32",
+                8
+              ]
+            }
+          },
+          {
+            "name": "matchResult",
+            "type": "u8"
+          },
+          {
+            "name": "drawPadding",
+            "type": "u8"
+          },
+          {
+            "name": "drawFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "drawPaddingTail",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "startedAt",
+            "type": "i64"
+          },
+          {
+            "name": "teamActiveAt",
+            "type": {
+              "array": [
+                "i64",
+                8
+              ]
+            }
+          },
+          {
+            "name": "forfeitTimeoutSecs",
+            "type": "i64"
+          },
+          {
+            "name": "forfeitPenaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "forfeitPadding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "auditFlags",
+            "type": "u8"
+          },
+          {
+            "name": "bondSlashed",
+            "type": "u8"
+          },
+          {
+            "name": "auditPadding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "template",
+            "type": "pubkey"
+          },
+          {
+            "name": "freeroll",
+            "type": "u8"
+          },
+          {
+            "name": "freerollPadding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "sponsorPrize",
+            "type": "u64"
+          },
+          {
+            "name": "treasuryPrize",
+            "type": "u64"
+          },
+          {
+            "name": "prizeSponsors",
+            "type": {
+              "array": [
+                "pubkey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "prizeSponsorAmounts",
+            "type": {
+              "array": [
+                "u64",
+                4
+              ]
+            }
+          },
+          {
+            "name": "sponsoredAmount",
+            "type": "u64"
+          },
+          {
+            "name": "prizeSponsorCount",
+            "type": "u8"
+          },
+          {
+            "name": "prizeSponsorPadding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "assistWeightBps",
+            "type": "u16"
+          },
+          {
+            "name": "assistPadding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "minReadyPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "readyPadding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "maxStake",
+            "type": "u64"
+          },
+          {
+            "name": "clockSkewSecs",
+            "type": "i64"
+          },
+          {
+            "name": "settlementTable",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "jackpot",
+      "docs": [
+        "Side pool of a wager mint funded by a slice of every session's rake and",
+        "won by the winning team of every `jackpot_interval`th team session"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "balance",
+            "type": "u64"
+          },
+          {
+            "name": "eligibleSessions",
+            "type": "u64"
+          },
+          {
+            "name": "pendingSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingPrize",
+            "type": "u64"
+          },
+          {
+            "name": "totalAwarded",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "jackpotFunded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "balance",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "jackpotPaid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "jackpotWon",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "prize",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "joinCredential",
+      "docs": [
+        "Credential a player presents to join a private session"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "joinCode",
+            "fields": [
+              "bytes"
+            ]
+          },
+          {
+            "name": "merkleProof",
+            "fields": [
+              {
+                "vec": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "joinWindow",
+      "docs": [
+        "Deadline after which a session stops taking joins and can be locked by",
+        "anyone, starting with at least `min_players` (and `min_players_per_team`",
+        "on every team) or becoming refundable"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "name": "minPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "minPlayersPerTeam",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "killLogEntry",
+      "docs": [
+        "A single kill recorded in a session's match log"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "killer",
+            "type": "pubkey"
+          },
+          {
+            "name": "victim",
+            "type": "pubkey"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "killRecord",
+      "docs": [
+        "Single kill carried by a batched kill report"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "killerTeam",
+            "type": "u8"
+          },
+          {
+            "name": "killer",
+            "type": "pubkey"
+          },
+          {
+            "name": "victimTeam",
+            "type": "u8"
+          },
+          {
+            "name": "victim",
+            "type": "pubkey"
+          },
+          {
+            "name": "assists",
+            "type": {
+              "array": [
+                "pubkey",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "killRecorded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "killer",
+            "type": "pubkey"
+          },
+          {
+            "name": "killerTeam",
+            "type": "u8"
+          },
+          {
+            "name": "victim",
+            "type": "pubkey"
+          },
+          {
+            "name": "victimTeam",
+            "type": "u8"
+          },
+          {
+            "name": "assists",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "lobbyIndex",
+      "docs": [
+        "Open lobbies sharing one (mint, bet, game mode) bucket, so clients can",
+        "list joinable sessions without scanning every program account"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "betAmount",
+            "type": "u64"
+          },
+          {
+            "name": "gameMode",
+            "type": {
+              "defined": {
+                "name": "gameMode"
+              }
+            }
+          },
+          {
+            "name": "sessions",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "matchAborted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "reason",
+            "type": "u8"
+          },
+          {
+            "name": "refunded",
+            "type": "u64"
+          },
+          {
+            "name": "spawnsReturned",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "matchFormed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "queue",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "players",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "matchLog",
+      "docs": [
+        "Ring buffer of the most recent kills in a session, kept so disputes can be",
+        "audited against individual events rather than only the aggregate counters"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "capacity",
+            "type": "u16"
+          },
+          {
+            "name": "totalKills",
+            "type": "u64"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "killLogEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "matchQueue",
+      "docs": [
+        "Escrow and waiting list of solo players for one (mint, bet, game mode)",
+        "bucket, drained into a new session by `form_match`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "betAmount",
+            "type": "u64"
+          },
+          {
+            "name": "gameMode",
+            "type": {
+              "defined": {
+                "name": "gameMode"
+              }
+            }
+          },
+          {
+            "name": "players",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "matchResult",
+      "docs": [
+        "Outcome of a team match as declared by the game server"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "win"
+          },
+          {
+            "name": "draw"
+          },
+          {
+            "name": "forfeit"
+          }
+        ]
+      }
+    },
+    {
+      "name": "matchResultRecord",
+      "docs": [
+        "Permanent record of a settled match, written once the session completes",
+        "so indexers and dispute processes keep a compact result after the",
+        "mutable session account is closed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionBet",
+            "type": "u64"
+          },
+          {
+            "name": "result",
+            "type": {
+              "defined": {
+                "name": "matchResult"
+              }
+            }
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "winner",
+            "type": "pubkey"
+          },
+          {
+            "name": "players",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "playerResult"
+                }
+              }
+            }
+          },
+          {
+            "name": "totalPaidOut",
+            "type": "u64"
+          },
+          {
+            "name": "totalFees",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "startedAt",
+            "type": "i64"
+          },
+          {
+            "name": "settledAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "matchResultRecorded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "matchResult",
+            "type": "pubkey"
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "winner",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "matchResultReported",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tournamentId",
+            "type": "string"
+          },
+          {
+            "name": "round",
+            "type": "u8"
+          },
+          {
+            "name": "matchIndex",
+            "type": "u8"
+          },
+          {
+            "name": "winner",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintDeregistered",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintRegistered",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "minBet",
+            "type": "u64"
+          },
+          {
+            "name": "maxBet",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "networkConfiguration",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "requestFee",
+            "type": "u64"
+          },
+          {
+            "name": "fulfillmentAuthorities",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "tokenFeeConfig",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "oraoTokenFeeConfig"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "networkState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "networkConfiguration"
+              }
+            }
+          },
+          {
+            "name": "numReceived",
+            "docs": [
+              "Total number of received requests."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "operationApproved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "approvalKind"
+              }
+            }
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "approver",
+            "type": "pubkey"
+          },
+          {
+            "name": "approvals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oraoTokenFeeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "ORAO token mint address."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "ORAO token treasury account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Fee in ORAO SPL token smallest units."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "payoutSchedule",
+      "docs": [
+        "How a winner-takes-all prize pool is split within the winning team"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "winnerTeamEqual"
+          },
+          {
+            "name": "topFragger603010"
+          },
+          {
+            "name": "custom",
+            "fields": [
+              {
+                "vec": "u16"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "payoutWithheld",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingRequest",
+      "docs": [
+        "Pending request representation."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "client",
+            "type": "pubkey"
+          },
+          {
+            "name": "seed",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "responses",
+            "docs": [
+              "Responses collected so far."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "randomnessResponse"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerBan",
+      "docs": [
+        "Marker excluding a player flagged by the anti-cheat backend from joining",
+        "sessions or buying spawns; its absence is what admits a player"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "bannedAt",
+            "type": "i64"
+          },
+          {
+            "name": "reason",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerBanUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "banned",
+            "type": "bool"
+          },
+          {
+            "name": "reason",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerDequeued",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "queue",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerEliminated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerJoined",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerKicked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "captain",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "refund",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerLeft",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "refund",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerQueued",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "queue",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerReadied",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "ready",
+            "type": "bool"
+          },
+          {
+            "name": "readyCount",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerResult",
+      "docs": [
+        "One player's line in a settled match's result record"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "kills",
+            "type": "u16"
+          },
+          {
+            "name": "payout",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerSlot",
+      "docs": [
+        "Counters and state of one roster slot. Counters are a byte wide and fail",
+        "with `CounterOverflow` once full rather than wrap."
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rating",
+            "type": "u16"
+          },
+          {
+            "name": "spawns",
+            "type": "u8"
+          },
+          {
+            "name": "kills",
+            "type": "u8"
+          },
+          {
+            "name": "spawnsPurchased",
+            "type": "u8"
+          },
+          {
+            "name": "topUps",
+            "type": "u8"
+          },
+          {
+            "name": "assists",
+            "type": "u8"
+          },
+          {
+            "name": "assistsConceded",
+            "type": "u8"
+          },
+          {
+            "name": "firstVictim",
+            "type": "u8"
+          },
+          {
+            "name": "flags",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerStats",
+      "docs": [
+        "Lifetime statistics for a player accumulated across sessions"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "kills",
+            "type": "u64"
+          },
+          {
+            "name": "deaths",
+            "type": "u64"
+          },
+          {
+            "name": "spawnsPurchased",
+            "type": "u64"
+          },
+          {
+            "name": "gamesPlayed",
+            "type": "u64"
+          },
+          {
+            "name": "totalWagered",
+            "type": "u64"
+          },
+          {
+            "name": "totalWon",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "winStreak",
+            "type": "u64"
+          },
+          {
+            "name": "bestWinStreak",
+            "type": "u64"
+          },
+          {
+            "name": "streakGames",
+            "type": "u64"
+          },
+          {
+            "name": "pendingBonuses",
+            "type": "u64"
+          },
+          {
+            "name": "rating",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerSubstituted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "replacement",
+            "type": "pubkey"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pledgeCollected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pledgeDefaulted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceFeed",
+      "docs": [
+        "Pyth price feed registered by the admin for a wager mint"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "feedId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceFeedMessage",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feedId",
+            "docs": [
+              "`FeedId` but avoid the type alias because of compatibility issues with Anchor's `idl-build` feature."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "price",
+            "type": "i64"
+          },
+          {
+            "name": "conf",
+            "type": "u64"
+          },
+          {
+            "name": "exponent",
+            "type": "i32"
+          },
+          {
+            "name": "publishTime",
+            "docs": [
+              "The timestamp of this price update in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "prevPublishTime",
+            "docs": [
+              "The timestamp of the previous price update. This field is intended to allow users to",
+              "identify the single unique price update for any moment in time:",
+              "for any time t, the unique update is the one such that prev_publish_time < t <= publish_time.",
+              "",
+              "Note that there may not be such an update while we are migrating to the new message-sending logic,",
+              "as some price updates on pythnet may not be sent to other chains (because the message-sending",
+              "logic may not have triggered). We can solve this problem by making the message-sending mandatory",
+              "(which we can do once publishers have migrated over).",
+              "",
+              "Additionally, this field may be equal to publish_time if the message is sent on a slot where",
+              "where the aggregation was unsuccesful. This problem will go away once all publishers have",
+              "migrated over to a recent version of pyth-agent."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "emaPrice",
+            "type": "i64"
+          },
+          {
+            "name": "emaConf",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceUpdateV2",
+      "docs": [
+        "A price update account. This account is used by the Pyth Receiver program to store a verified price update from a Pyth price feed.",
+        "It contains:",
+        "- `write_authority`: The write authority for this account. This authority can close this account to reclaim rent or update the account to contain a different price update.",
+        "- `verification_level`: The [`VerificationLevel`] of this price update. This represents how many Wormhole guardian signatures have been verified for this price update.",
+        "- `price_message`: The actual price update.",
+        "- `posted_slot`: The slot at which this price update was posted."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "writeAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "verificationLevel",
+            "type": {
+              "defined": {
+                "name": "verificationLevel"
+              }
+            }
+          },
+          {
+            "name": "priceMessage",
+            "type": {
+              "defined": {
+                "name": "priceFeedMessage"
+              }
+            }
+          },
+          {
+            "name": "postedSlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prizeAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "sponsor",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "sponsoredAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prizeSponsor",
+      "docs": [
+        "A wallet's deposit into a session's sponsored prize"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sponsor",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programConfig",
+      "docs": [
+        "Program-wide configuration managed by the admin"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "minBet",
+            "type": "u64"
+          },
+          {
+            "name": "maxBet",
+            "type": "u64"
+          },
+          {
+            "name": "sessionTimeoutSecs",
+            "type": "i64"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "killReportSigner",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "cancellationFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "usdMinBet",
+            "type": "u64"
+          },
+          {
+            "name": "usdMaxBet",
+            "type": "u64"
+          },
+          {
+            "name": "maxPriceAgeSecs",
+            "type": "u64"
+          },
+          {
+            "name": "arbiter",
+            "type": "pubkey"
+          },
+          {
+            "name": "disputeWindowSecs",
+            "type": "i64"
+          },
+          {
+            "name": "gameServerKeys",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "gameServerThreshold",
+            "type": "u8"
+          },
+          {
+            "name": "referralBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxTopUpMultiple",
+            "type": "u8"
+          },
+          {
+            "name": "mapPoolSize",
+            "type": "u8"
+          },
+          {
+            "name": "jackpotBps",
+            "type": "u16"
+          },
+          {
+            "name": "jackpotInterval",
+            "type": "u64"
+          },
+          {
+            "name": "rewardsMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "streakMilestone",
+            "type": "u16"
+          },
+          {
+            "name": "streakBonus",
+            "type": "u64"
+          },
+          {
+            "name": "eloKFactor",
+            "type": "u16"
+          },
+          {
+            "name": "creatorPenaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "trophyUri",
+            "type": "string"
+          },
+          {
+            "name": "cpiCallers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "creationFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "maxExposure",
+            "type": "u64"
+          },
+          {
+            "name": "feeSplit",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeShare"
+                }
+              }
+            }
+          },
+          {
+            "name": "drawFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "forfeitTimeoutSecs",
+            "type": "i64"
+          },
+          {
+            "name": "forfeitPenaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "freerollSponsors",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "heldCreationFees",
+            "type": "u64"
+          },
+          {
+            "name": "yieldPrograms",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "yieldTreasuryBps",
+            "type": "u16"
+          },
+          {
+            "name": "clockSkewSecs",
+            "type": "i64"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "bondSlashBps",
+            "type": "u16"
+          },
+          {
+            "name": "arbiters",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "commitRevealResults",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "randomnessRequested",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "seed",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "randomnessResponse",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "pubkey"
+          },
+          {
+            "name": "randomness",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "randomnessRevealed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "randomness",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "mapIndex",
+            "type": "u8"
+          },
+          {
+            "name": "startingTeam",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "randomnessV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "request",
+            "type": {
+              "defined": {
+                "name": "requestAccount"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ratingUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldRating",
+            "type": "u64"
+          },
+          {
+            "name": "newRating",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "receiptStatus",
+      "docs": [
+        "Claim status of a bet receipt"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "open"
+          },
+          {
+            "name": "refunded"
+          },
+          {
+            "name": "paidOut"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recurringInstanceSpawned",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recurringMatch",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "instance",
+            "type": "u32"
+          },
+          {
+            "name": "nextStart",
+            "type": "i64"
+          },
+          {
+            "name": "reimbursed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recurringMatch",
+      "docs": [
+        "Schedule launching a new session from a template every interval, so",
+        "ladders run without an off-chain cron job. Lamports held above the",
+        "account's rent reimburse whoever spawns an instance for its rent."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "template",
+            "type": "pubkey"
+          },
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "intervalSecs",
+            "type": "i64"
+          },
+          {
+            "name": "nextStart",
+            "type": "i64"
+          },
+          {
+            "name": "joinSecs",
+            "type": "i64"
+          },
+          {
+            "name": "minPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "maxInstances",
+            "type": "u32"
+          },
+          {
+            "name": "instances",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recurringMatchCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recurringMatch",
+            "type": "pubkey"
+          },
+          {
+            "name": "template",
+            "type": "pubkey"
+          },
+          {
+            "name": "prefix",
+            "type": "string"
+          },
+          {
+            "name": "intervalSecs",
+            "type": "i64"
+          },
+          {
+            "name": "firstStart",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referral",
+      "docs": [
+        "Referrer recorded for a player on their first referred join"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referee",
+            "type": "pubkey"
+          },
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralCredit",
+      "docs": [
+        "Referrer's cut of the rake on a referee's bet in one session, claimable",
+        "once the session has been settled"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "referee",
+            "type": "pubkey"
+          },
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralRecorded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referee",
+            "type": "pubkey"
+          },
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralRewardsClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          },
+          {
+            "name": "referee",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "refundIssued",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "refundPolicy",
+      "docs": [
+        "What players get back when they leave a session or it is aborted"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "fullAnytimeBeforeStart"
+          },
+          {
+            "name": "forfeitAfterReady"
+          },
+          {
+            "name": "proRataOnAbort"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredMint",
+      "docs": [
+        "Wager token the admin permits lobbies to be created in, optionally with",
+        "bet limits of its own in place of the config's"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "minBet",
+            "type": "u64"
+          },
+          {
+            "name": "maxBet",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "requestAccount",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pending",
+            "fields": [
+              {
+                "defined": {
+                  "name": "pendingRequest"
+                }
+              }
+            ]
+          },
+          {
+            "name": "fulfilled",
+            "fields": [
+              {
+                "defined": {
+                  "name": "fulfilledRequest"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "resultCommitment",
+      "docs": [
+        "Hash the game server signing set committed a session's result to,",
+        "revealed in a later slot so the result can't be picked after watching the",
+        "reveal's mempool"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "committer",
+            "type": "pubkey"
+          },
+          {
+            "name": "hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "committedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "approvers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "resultCommitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "gameServer",
+            "type": "pubkey"
+          },
+          {
+            "name": "hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "approvals",
+            "type": "u8"
+          },
+          {
+            "name": "committed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "resultOverridden",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "arbiter",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousTeam",
+            "type": "u8"
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "resultSubmitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "draw",
+            "type": "bool"
+          },
+          {
+            "name": "disputeDeadline",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "roundRecorded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "round",
+            "type": "u8"
+          },
+          {
+            "name": "winningTeam",
+            "type": "u8"
+          },
+          {
+            "name": "roundWins",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "scoreSubmitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "score",
+            "type": "u32"
+          },
+          {
+            "name": "decided",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "serverBond",
+      "docs": [
+        "Lamports an operator posts as collateral for the results it reports,",
+        "slashed to the wronged players when the arbiter overturns one. Withdrawals",
+        "wait out a dispute window so a bond can't be pulled ahead of a slash."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "operator",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "pendingWithdrawal",
+            "type": "u64"
+          },
+          {
+            "name": "unlockAt",
+            "type": "i64"
+          },
+          {
+            "name": "totalSlashed",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "invalidPlayerCount",
-      "msg": "Invalid number of players for this game mode"
+      "name": "sessionAccess",
+      "docs": [
+        "Who may join a session"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "open"
+          },
+          {
+            "name": "joinCode",
+            "fields": [
+              {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            ]
+          },
+          {
+            "name": "allowlist",
+            "fields": [
+              {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "notAllPlayersJoined",
-      "msg": "All players not joined"
+      "name": "sessionAuthorityDelegated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "betAmount",
+            "type": "u64"
+          },
+          {
+            "name": "gameMode",
+            "type": {
+              "defined": {
+                "name": "gameMode"
+              }
+            }
+          },
+          {
+            "name": "teamCount",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionCreatedFromTemplate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "template",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionsCreated",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionFlagged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "auditFlags",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionKick",
+      "docs": [
+        "Marker barring a player kicked from a session from taking a seat in it",
+        "again; its absence is what admits a player"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "captain",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionLocked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "players",
+            "type": "u8"
+          },
+          {
+            "name": "started",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionMetadata",
+      "docs": [
+        "Discovery details for a session, created alongside it by the operator"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "gameName",
+            "type": "string"
+          },
+          {
+            "name": "mapId",
+            "type": "string"
+          },
+          {
+            "name": "region",
+            "type": "string"
+          },
+          {
+            "name": "custom",
+            "type": "string"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionMetadataParams",
+      "docs": [
+        "Operator-supplied discovery details for a new session"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameName",
+            "type": "string"
+          },
+          {
+            "name": "mapId",
+            "type": "string"
+          },
+          {
+            "name": "region",
+            "type": "string"
+          },
+          {
+            "name": "custom",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "outcome",
+            "type": "string"
+          },
+          {
+            "name": "totalPaidOut",
+            "type": "u64"
+          },
+          {
+            "name": "sponsoredAmount",
+            "type": "u64"
+          },
+          {
+            "name": "sponsors",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "prizeSponsor"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionStarted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionStatus",
+      "docs": [
+        "Lifecycle status of a game session"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "created"
+          },
+          {
+            "name": "filling"
+          },
+          {
+            "name": "inProgress"
+          },
+          {
+            "name": "settling"
+          },
+          {
+            "name": "completed"
+          },
+          {
+            "name": "refunded"
+          },
+          {
+            "name": "resultSubmitted"
+          },
+          {
+            "name": "refundable"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "gameNotCompleted",
-      "msg": "Game is not in completed state"
+      "name": "sessionTemplate",
+      "docs": [
+        "Reusable game configuration an operator launches lobbies from with",
+        "`create_from_template`, so players can check a session runs on the terms",
+        "the template advertises"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "templateId",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "betAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "terms",
+            "type": {
+              "defined": {
+                "name": "sessionTerms"
+              }
+            }
+          },
+          {
+            "name": "sessionsCreated",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "unauthorizedDistribution",
-      "msg": "Only the game authority can distribute winnings"
+      "name": "sessionTemplateCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "template",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "templateId",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "betAmount",
+            "type": "u64"
+          },
+          {
+            "name": "gameMode",
+            "type": {
+              "defined": {
+                "name": "gameMode"
+              }
+            }
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "invalidWinningTeam",
-      "msg": "Invalid winning team selection"
+      "name": "sessionTerms",
+      "docs": [
+        "Game configuration a session is created with, fixed by a session",
+        "template for every lobby launched from it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameMode",
+            "type": {
+              "defined": {
+                "name": "gameMode"
+              }
+            }
+          },
+          {
+            "name": "teamCount",
+            "type": "u8"
+          },
+          {
+            "name": "spawnEarningsBps",
+            "type": "u16"
+          },
+          {
+            "name": "logCapacity",
+            "type": "u16"
+          },
+          {
+            "name": "teamACapacity",
+            "type": "u8"
+          },
+          {
+            "name": "teamBCapacity",
+            "type": "u8"
+          },
+          {
+            "name": "payoutSchedule",
+            "type": {
+              "defined": {
+                "name": "payoutSchedule"
+              }
+            }
+          },
+          {
+            "name": "maxKillsPerPlayer",
+            "type": "u16"
+          },
+          {
+            "name": "maxMatchKills",
+            "type": "u16"
+          },
+          {
+            "name": "seriesLength",
+            "type": "u8"
+          },
+          {
+            "name": "refundPolicy",
+            "type": {
+              "defined": {
+                "name": "refundPolicy"
+              }
+            }
+          },
+          {
+            "name": "winCondition",
+            "type": {
+              "defined": {
+                "name": "winCondition"
+              }
+            }
+          },
+          {
+            "name": "assistWeightBps",
+            "type": "u16"
+          },
+          {
+            "name": "minReadyPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "maxStake",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "totalPotCalculationError",
-      "msg": "Failed to calculate total pot due to arithmetic overflow"
+      "name": "settlementRecovered",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "sessionStatus"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "noWinnersFound",
-      "msg": "No winners found in the winning team"
+      "name": "settlementTableClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "lookupTable",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "winningsCalculationError",
-      "msg": "Failed to calculate per-player winnings"
+      "name": "settlementTableCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "lookupTable",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "incompleteDistribution",
-      "msg": "Failed to distribute all funds from game session"
+      "name": "settlementTableDeactivated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "lookupTable",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "invalidTeam",
-      "msg": "Invalid team"
+      "name": "settlementTableExtended",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "lookupTable",
+            "type": "pubkey"
+          },
+          {
+            "name": "addresses",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "playerAccountNotFound",
-      "msg": "Player account not found in winners"
+      "name": "spawnPurchased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "spawns",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "invalidWinner",
-      "msg": "Invalid winning team selection"
+      "name": "spawnsToppedUp",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "spawns",
+            "type": "u16"
+          },
+          {
+            "name": "totalTopUp",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "arithmeticError",
-      "msg": "Arithmetic error"
+      "name": "spectatorBet",
+      "docs": [
+        "A single spectator's stake in a session's spectator pool"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "bettor",
+            "type": "pubkey"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "claimed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "invalidMint",
-      "msg": "Invalid mint address provided"
+      "name": "spectatorBetPlaced",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "bettor",
+            "type": "pubkey"
+          },
+          {
+            "name": "team",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "invalidRemainingAccounts",
-      "msg": "Invalid remaining accounts provided"
+      "name": "spectatorPayoutClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "bettor",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "invalidWinnerTokenAccount",
-      "msg": "Invalid winner token account owner"
+      "name": "spectatorPool",
+      "docs": [
+        "Pari-mutuel pool where spectators bet on the winning team of a session"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
+          },
+          {
+            "name": "teamTotals",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "totalStaked",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "invalidTokenMint",
-      "msg": "Invalid token mint"
+      "name": "sponsoredPrizeReclaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
+          },
+          {
+            "name": "sponsors",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "prizeSponsor"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "invalidSpawns",
-      "msg": "Invalid spawns"
+      "name": "streakBonusClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "winStreak",
+            "type": "u64"
+          },
+          {
+            "name": "bonuses",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "unauthorizedKill",
-      "msg": "Unauthorized kill"
+      "name": "team",
+      "docs": [
+        "Represents a team in the game"
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "players",
+            "type": {
+              "array": [
+                "pubkey",
+                20
+              ]
+            }
+          },
+          {
+            "name": "totalBet",
+            "type": "u64"
+          },
+          {
+            "name": "stakes",
+            "type": {
+              "array": [
+                "u64",
+                20
+              ]
+            }
+          },
+          {
+            "name": "slots",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "playerSlot"
+                  }
+                },
+                20
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "unauthorizedPayToSpawn",
-      "msg": "Unauthorized pay to spawn"
+      "name": "tournament",
+      "docs": [
+        "Single-elimination tournament whose entry fees form one pot for the champion"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tournamentId",
+            "type": "string"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "entryFee",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "teamCount",
+            "type": "u8"
+          },
+          {
+            "name": "entrants",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "champion",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "tournamentStatus"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vaultBump",
+            "type": "u8"
+          },
+          {
+            "name": "yieldProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "parkedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "accruedYield",
+            "type": "u64"
+          },
+          {
+            "name": "yieldTreasuryBps",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "playerNotFound",
-      "msg": "Player not found"
+      "name": "tournamentCompleted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tournamentId",
+            "type": "string"
+          },
+          {
+            "name": "champion",
+            "type": "pubkey"
+          },
+          {
+            "name": "prize",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "invalidPlayerTokenAccount",
-      "msg": "Invalid player token account"
+      "name": "tournamentCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tournamentId",
+            "type": "string"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "entryFee",
+            "type": "u64"
+          },
+          {
+            "name": "teamCount",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "invalidPlayer",
-      "msg": "Invalid player"
+      "name": "tournamentFundsParked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tournamentId",
+            "type": "string"
+          },
+          {
+            "name": "yieldProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6027,
-      "name": "playerHasNoSpawns",
-      "msg": "Player has no spawns"
+      "name": "tournamentFundsWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tournamentId",
+            "type": "string"
+          },
+          {
+            "name": "yieldProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "earned",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "gameNotInProgress",
-      "msg": "Game is not in progress"
-    }
-  ],
-  "types": [
-    {
-      "name": "gameMode",
+      "name": "tournamentStatus",
       "docs": [
-        "Game mode defining the team sizes"
+        "Lifecycle status of a tournament"
       ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "winnerTakesAllOneVsOne"
+            "name": "registering"
           },
           {
-            "name": "winnerTakesAllThreeVsThree"
+            "name": "inProgress"
           },
           {
-            "name": "winnerTakesAllFiveVsFive"
-          },
+            "name": "completed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tournamentTeamRegistered",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "payToSpawnOneVsOne"
+            "name": "tournamentId",
+            "type": "string"
           },
           {
-            "name": "payToSpawnThreeVsThree"
+            "name": "captain",
+            "type": "pubkey"
           },
           {
-            "name": "payToSpawnFiveVsFive"
+            "name": "seed",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "gameSession",
-      "docs": [
-        "Represents a game session between teams with its own pool"
-      ],
+      "name": "trophyMinted",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1339,122 +22256,220 @@ export type WagerProgram = {
             "type": "string"
           },
           {
-            "name": "authority",
+            "name": "player",
             "type": "pubkey"
           },
           {
-            "name": "sessionBet",
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "score",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "unclaimedEntry",
+      "docs": [
+        "Payout held back for a payee whose token account could not receive it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "unclaimedPayout",
+      "docs": [
+        "Payouts of a session withheld because the payee's token account was closed",
+        "or frozen at distribution time, claimable later with `claim_unclaimed`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
           },
           {
-            "name": "gameMode",
+            "name": "entries",
             "type": {
-              "defined": {
-                "name": "gameMode"
+              "vec": {
+                "defined": {
+                  "name": "unclaimedEntry"
+                }
               }
             }
           },
           {
-            "name": "teamA",
-            "type": {
-              "defined": {
-                "name": "team"
-              }
-            }
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "unclaimedPayoutClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
           },
           {
-            "name": "teamB",
-            "type": {
-              "defined": {
-                "name": "team"
-              }
-            }
+            "name": "player",
+            "type": "pubkey"
           },
           {
-            "name": "status",
-            "type": {
-              "defined": {
-                "name": "gameStatus"
-              }
-            }
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vault",
+      "docs": [
+        "Escrow vault PDA that owns the session's token account and tracks fund flows"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameSession",
+            "type": "pubkey"
           },
           {
-            "name": "createdAt",
-            "type": "i64"
+            "name": "totalDeposited",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "totalPaidOut",
+            "type": "u64"
           },
           {
-            "name": "vaultBump",
-            "type": "u8"
+            "name": "totalRefunded",
+            "type": "u64"
           },
           {
-            "name": "vaultTokenBump",
-            "type": "u8"
+            "name": "totalFees",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "gameStatus",
+      "name": "verificationLevel",
       "docs": [
-        "Status of a game session"
+        "Pyth price updates are bridged to all blockchains via Wormhole.",
+        "Using the price updates on another chain requires verifying the signatures of the Wormhole guardians.",
+        "The usual process is to check the signatures for two thirds of the total number of guardians, but this can be cumbersome on Solana because of the transaction size limits,",
+        "so we also allow for partial verification.",
+        "",
+        "This enum represents how much a price update has been verified:",
+        "- If `Full`, we have verified the signatures for two thirds of the current guardians.",
+        "- If `Partial`, only `num_signatures` guardian signatures have been checked.",
+        "",
+        "# Warning",
+        "Using partially verified price updates is dangerous, as it lowers the threshold of guardians that need to collude to produce a malicious price update."
       ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "waitingForPlayers"
+            "name": "partial",
+            "fields": [
+              {
+                "name": "numSignatures",
+                "type": "u8"
+              }
+            ]
           },
           {
-            "name": "inProgress"
+            "name": "full"
+          }
+        ]
+      }
+    },
+    {
+      "name": "winCondition",
+      "docs": [
+        "How a team session's winner is determined, which the reported result must",
+        "agree with"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "serverDecision"
           },
           {
-            "name": "completed"
+            "name": "mostKills"
+          },
+          {
+            "name": "firstToScore",
+            "fields": [
+              "u32"
+            ]
+          },
+          {
+            "name": "objectiveCapture"
           }
         ]
       }
     },
     {
-      "name": "team",
+      "name": "winnerDeclaration",
       "docs": [
-        "Represents a team in the game"
+        "Result the game server reports for a session, telling wins, forfeits and",
+        "draws apart explicitly"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "players",
+            "name": "result",
             "type": {
-              "array": [
-                "pubkey",
-                5
-              ]
+              "defined": {
+                "name": "matchResult"
+              }
             }
           },
           {
-            "name": "totalBet",
-            "type": "u64"
+            "name": "team",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "winningsDistributed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "string"
           },
           {
-            "name": "playerSpawns",
-            "type": {
-              "array": [
-                "u16",
-                5
-              ]
-            }
+            "name": "player",
+            "type": "pubkey"
           },
           {
-            "name": "playerKills",
-            "type": {
-              "array": [
-                "u16",
-                5
-              ]
-            }
+            "name": "amount",
+            "type": "u64"
           }
         ]
       }
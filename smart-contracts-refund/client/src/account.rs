@@ -0,0 +1,109 @@
+//! Fetching and decoding of program accounts. The crate does not pick an RPC
+//! client; callers implement [`AccountSource`] over the one they use.
+
+use std::fmt;
+
+use anchor_lang::prelude::*;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use wager_program::state::{GameSession, GAME_SESSION_VERSION};
+
+use crate::pda;
+
+/// Errors raised while fetching or decoding accounts
+#[derive(Debug)]
+pub enum ClientError {
+    /// No account exists at the address
+    AccountNotFound(Pubkey),
+    /// The account is not owned by the wager program
+    InvalidOwner(Pubkey),
+    /// The account data does not decode as the expected type
+    InvalidAccountData(Pubkey),
+    /// The account source failed to fetch the account
+    Source(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::AccountNotFound(address) => write!(f, "account {} not found", address),
+            ClientError::InvalidOwner(address) => {
+                write!(f, "account {} is not owned by the wager program", address)
+            }
+            ClientError::InvalidAccountData(address) => {
+                write!(f, "account {} has unexpected data", address)
+            }
+            ClientError::Source(message) => write!(f, "failed to fetch account: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Raw account as returned by an RPC node
+pub struct RawAccount {
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+/// Source of on-chain accounts, implemented over the caller's RPC client
+pub trait AccountSource {
+    /// Fetches the account at `address`, `None` when it does not exist
+    fn get_account(&self, address: &Pubkey)
+        -> std::result::Result<Option<RawAccount>, ClientError>;
+
+    /// Fetches and decodes a Borsh account of the wager program
+    fn fetch<T: AccountDeserialize>(
+        &self,
+        address: &Pubkey,
+    ) -> std::result::Result<T, ClientError> {
+        let account = self.load(address)?;
+        decode_account(address, &account.data)
+    }
+
+    /// Fetches and decodes the zero-copy session with `session_id`
+    fn fetch_game_session(
+        &self,
+        session_id: &str,
+    ) -> std::result::Result<GameSession, ClientError> {
+        let address = pda::game_session(session_id).0;
+        let account = self.load(&address)?;
+        decode_game_session(&address, &account.data)
+    }
+
+    /// Fetches an account, requiring it exists and is owned by the program
+    fn load(&self, address: &Pubkey) -> std::result::Result<RawAccount, ClientError> {
+        let account = self
+            .get_account(address)?
+            .ok_or(ClientError::AccountNotFound(*address))?;
+        if account.owner != crate::ID {
+            return Err(ClientError::InvalidOwner(*address));
+        }
+        Ok(account)
+    }
+}
+
+/// Decodes a Borsh account, checking its discriminator
+pub fn decode_account<T: AccountDeserialize>(
+    address: &Pubkey,
+    data: &[u8],
+) -> std::result::Result<T, ClientError> {
+    T::try_deserialize(&mut &data[..]).map_err(|_| ClientError::InvalidAccountData(*address))
+}
+
+/// Decodes a zero-copy game session, checking its discriminator and layout
+pub fn decode_game_session(
+    address: &Pubkey,
+    data: &[u8],
+) -> std::result::Result<GameSession, ClientError> {
+    let discriminator = GameSession::DISCRIMINATOR;
+    let body = data
+        .strip_prefix(&discriminator[..])
+        .and_then(|body| body.get(..std::mem::size_of::<GameSession>()))
+        .ok_or(ClientError::InvalidAccountData(*address))?;
+    let game_session: GameSession = bytemuck::try_pod_read_unaligned(body)
+        .map_err(|_| ClientError::InvalidAccountData(*address))?;
+    if game_session.version != GAME_SESSION_VERSION {
+        return Err(ClientError::InvalidAccountData(*address));
+    }
+    Ok(game_session)
+}
@@ -0,0 +1,441 @@
+//! Builders for the instructions backend services send most, filling in every
+//! PDA and the remaining accounts the program expects. Optional accounts the
+//! builders leave out can be supplied through [`crate::build_instruction`].
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::address_lookup_table::{
+    self, instruction::derive_lookup_table_address,
+};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::{self, instructions as instructions_sysvar};
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+use wager_program::instructions::distribution_payees;
+use wager_program::state::{GameSession, JoinCredential, WinnerDeclaration, MAX_DISTRIBUTION_PAGE};
+use wager_program::{accounts, instruction};
+
+use crate::{build_instruction, pda};
+
+/// Account a session pays `owner` through: the wallet itself for native SOL
+/// sessions, otherwise its associated token account
+pub fn payout_destination(
+    game_session: &GameSession,
+    owner: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    if game_session.is_native() {
+        *owner
+    } else {
+        get_associated_token_address_with_program_id(owner, &game_session.mint, token_program)
+    }
+}
+
+/// Creates a session, along with its match log and metadata when the
+/// arguments ask for them. The config's treasury collects the creation fee.
+pub fn create_game_session(
+    game_server: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    args: instruction::CreateGameSession,
+) -> Instruction {
+    let session_id = &args.session_id;
+    let accounts = accounts::CreateGameSession {
+        game_server: *game_server,
+        config: pda::config().0,
+        treasury: pda::treasury().0,
+        game_session: pda::game_session(session_id).0,
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, token_program),
+        match_log: (args.log_capacity > 0).then(|| pda::match_log(session_id).0),
+        session_metadata: args
+            .metadata
+            .is_some()
+            .then(|| pda::session_metadata(session_id).0),
+        lobby_index: pda::lobby_index(mint, args.bet_amount, args.game_mode).0,
+        mint: *mint,
+        registered_mint: pda::registered_mint(mint).0,
+        price_feed: None,
+        price_update: None,
+        instructions: None,
+        token_program: *token_program,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+        rent: sysvar::rent::ID,
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// Permits `mint` as a wager token; zero limits fall back to the config's
+pub fn register_mint(authority: &Pubkey, mint: &Pubkey, min_bet: u64, max_bet: u64) -> Instruction {
+    let accounts = accounts::RegisterMint {
+        authority: *authority,
+        config: pda::config().0,
+        mint: *mint,
+        registered_mint: pda::registered_mint(mint).0,
+        system_program: system_program::ID,
+    };
+    build_instruction(
+        accounts,
+        instruction::RegisterMint { min_bet, max_bet },
+        Vec::new(),
+    )
+}
+
+/// Joins `user` to `team` of the session, paying the bet from their
+/// associated token account. A referrer is recorded on the player's first
+/// referred join; pass-gated sessions need the player's pass token account.
+#[allow(clippy::too_many_arguments)]
+pub fn join_user(
+    user: &Pubkey,
+    session_id: &str,
+    game_session: &GameSession,
+    token_program: &Pubkey,
+    team: u8,
+    referrer: Option<Pubkey>,
+    credential: Option<JoinCredential>,
+    pass_token_account: Option<Pubkey>,
+    stake: Option<u64>,
+) -> Result<Instruction> {
+    let mint = &game_session.mint;
+    let game_session_key = pda::game_session(session_id).0;
+    let lobby_index = pda::lobby_index(mint, game_session.session_bet, game_session.game_mode()?).0;
+    let accounts = accounts::JoinUser {
+        user: *user,
+        game_server: game_session.authority,
+        config: pda::config().0,
+        game_session: game_session_key,
+        user_token_account: get_associated_token_address_with_program_id(user, mint, token_program),
+        vault: pda::vault(session_id).0,
+        player_stats: pda::player_stats(user).0,
+        bet_receipt: pda::bet_receipt(&game_session_key, user).0,
+        referral: referrer.map(|_| pda::referral(user).0),
+        referral_credit: referrer.map(|_| pda::referral_credit(&game_session_key, user).0),
+        lobby_index: Some(lobby_index),
+        pass_token_account,
+        active_exposure: Some(pda::active_exposure(user).0),
+        player_ban: pda::player_ban(user).0,
+        session_kick: pda::session_kick(&game_session_key, user).0,
+        instructions: None,
+        vault_token_account: pda::vault_token_account(session_id, mint, token_program),
+        mint: *mint,
+        token_program: *token_program,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+    };
+    let args = instruction::JoinUser {
+        session_id: session_id.to_string(),
+        team,
+        referrer,
+        credential,
+        stake,
+    };
+    Ok(build_instruction(accounts, args, Vec::new()))
+}
+
+/// Seats `user` with their bet pledged from their associated token account
+/// rather than escrowed, see `collect_pledges`
+pub fn join_user_delegated(
+    user: &Pubkey,
+    session_id: &str,
+    game_session: &GameSession,
+    token_program: &Pubkey,
+    team: u8,
+    credential: Option<JoinCredential>,
+    pass_token_account: Option<Pubkey>,
+) -> Instruction {
+    let mint = &game_session.mint;
+    let accounts = accounts::JoinUserDelegated {
+        user: *user,
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        user_token_account: get_associated_token_address_with_program_id(user, mint, token_program),
+        vault: pda::vault(session_id).0,
+        player_stats: pda::player_stats(user).0,
+        bet_receipt: pda::bet_receipt(&pda::game_session(session_id).0, user).0,
+        pass_token_account,
+        active_exposure: Some(pda::active_exposure(user).0),
+        player_ban: pda::player_ban(user).0,
+        session_kick: pda::session_kick(&pda::game_session(session_id).0, user).0,
+        instructions: None,
+        mint: *mint,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    let args = instruction::JoinUserDelegated {
+        session_id: session_id.to_string(),
+        team,
+        credential,
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// Pulls every pledged bet into escrow, assuming each was pledged from the
+/// player's associated token account
+pub fn collect_pledges(
+    game_server: &Pubkey,
+    session_id: &str,
+    game_session: &GameSession,
+    token_program: &Pubkey,
+) -> Result<Instruction> {
+    let mint = &game_session.mint;
+    let mut remaining_accounts = Vec::new();
+    for player in game_session.pledged_players() {
+        remaining_accounts.push(AccountMeta::new(
+            get_associated_token_address_with_program_id(&player, mint, token_program),
+            false,
+        ));
+        remaining_accounts.push(AccountMeta::new(pda::player_stats(&player).0, false));
+    }
+
+    let accounts = accounts::CollectPledges {
+        game_server: *game_server,
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, token_program),
+        lobby_index: Some(
+            pda::lobby_index(mint, game_session.session_bet, game_session.game_mode()?).0,
+        ),
+        mint: *mint,
+        token_program: *token_program,
+        associated_token_program: associated_token::ID,
+    };
+    let args = instruction::CollectPledges {
+        session_id: session_id.to_string(),
+    };
+    Ok(build_instruction(accounts, args, remaining_accounts))
+}
+
+/// Posts a status line to the session's announcement board
+pub fn post_announcement(game_server: &Pubkey, session_id: &str, text: &str) -> Instruction {
+    let accounts = accounts::PostAnnouncement {
+        game_server: *game_server,
+        game_session: pda::game_session(session_id).0,
+        announcements: pda::announcements(session_id).0,
+        system_program: system_program::ID,
+    };
+    let args = instruction::PostAnnouncement {
+        session_id: session_id.to_string(),
+        text: text.to_string(),
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// Records a signed kill report. The transaction must carry the ed25519
+/// signature check of `wager_program::utils::kill_report_message` over the
+/// same report directly before this instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn record_kill(
+    game_server: &Pubkey,
+    session_id: &str,
+    game_session: &GameSession,
+    killer_team: u8,
+    killer: Pubkey,
+    victim_team: u8,
+    victim: Pubkey,
+    assists: [Pubkey; wager_program::state::MAX_KILL_ASSISTS],
+    round: u64,
+    sequence: u64,
+) -> Instruction {
+    let accounts = accounts::RecordKill {
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        game_server: *game_server,
+        killer_stats: pda::player_stats(&killer).0,
+        victim_stats: pda::player_stats(&victim).0,
+        match_log: (game_session.log_capacity > 0).then(|| pda::match_log(session_id).0),
+        instructions: instructions_sysvar::ID,
+    };
+    let args = instruction::RecordKill {
+        session_id: session_id.to_string(),
+        killer_team,
+        killer,
+        victim_team,
+        victim,
+        assists,
+        round,
+        sequence,
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// Creates the session's settlement lookup table, returning the instruction
+/// and the table's address
+pub fn create_settlement_table(
+    game_server: &Pubkey,
+    session_id: &str,
+    recent_slot: u64,
+) -> (Instruction, Pubkey) {
+    let vault = pda::vault(session_id).0;
+    let lookup_table = derive_lookup_table_address(&vault, recent_slot).0;
+    let accounts = accounts::CreateSettlementTable {
+        game_server: *game_server,
+        game_session: pda::game_session(session_id).0,
+        vault,
+        lookup_table,
+        address_lookup_table_program: address_lookup_table::program::ID,
+        system_program: system_program::ID,
+    };
+    let args = instruction::CreateSettlementTable {
+        session_id: session_id.to_string(),
+        recent_slot,
+    };
+    (build_instruction(accounts, args, Vec::new()), lookup_table)
+}
+
+/// Adds the settlement accounts of the players from `first_player` on to the
+/// session's lookup table, `MAX_TABLE_EXTEND_PLAYERS` at a time
+pub fn extend_settlement_table(
+    game_server: &Pubkey,
+    session_id: &str,
+    game_session: &GameSession,
+    first_player: u8,
+) -> Instruction {
+    let accounts = accounts::ExtendSettlementTable {
+        game_server: *game_server,
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        vault: pda::vault(session_id).0,
+        lookup_table: game_session.settlement_table,
+        mint: game_session.mint,
+        address_lookup_table_program: address_lookup_table::program::ID,
+        system_program: system_program::ID,
+    };
+    let args = instruction::ExtendSettlementTable {
+        session_id: session_id.to_string(),
+        first_player,
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// Deactivates the settled session's lookup table, or closes it once it was
+/// deactivated and the cooldown passed
+pub fn close_settlement_table(
+    game_server: &Pubkey,
+    session_id: &str,
+    game_session: &GameSession,
+) -> Instruction {
+    let accounts = accounts::CloseSettlementTable {
+        game_server: *game_server,
+        game_session: pda::game_session(session_id).0,
+        vault: pda::vault(session_id).0,
+        lookup_table: game_session.settlement_table,
+        address_lookup_table_program: address_lookup_table::program::ID,
+    };
+    let args = instruction::CloseSettlementTable {
+        session_id: session_id.to_string(),
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// Pays the next page of the session's winners on `declaration`, starting at
+/// its distribution cursor. The page completing the list also carries the fee
+/// split destinations. Sessions settled by a single server need no approval.
+pub fn distribute_winnings(
+    caller: &Pubkey,
+    session_id: &str,
+    game_session: &GameSession,
+    token_program: &Pubkey,
+    declaration: WinnerDeclaration,
+    approval: bool,
+) -> Result<Instruction> {
+    let mint = &game_session.mint;
+    let game_session_key = pda::game_session(session_id).0;
+    let (payees, _, _) = distribution_payees(game_session, declaration.team)?;
+
+    let cursor = game_session.distribution_cursor as usize;
+    let end = payees.len().min(cursor + MAX_DISTRIBUTION_PAGE);
+    let mut remaining_accounts = Vec::new();
+    for (player, _) in &payees[cursor..end] {
+        remaining_accounts.push(AccountMeta::new_readonly(*player, false));
+        remaining_accounts.push(AccountMeta::new(
+            payout_destination(game_session, player, token_program),
+            false,
+        ));
+        remaining_accounts.push(AccountMeta::new(pda::player_stats(player).0, false));
+        remaining_accounts.push(AccountMeta::new(
+            pda::bet_receipt(&game_session_key, player).0,
+            false,
+        ));
+    }
+    if end == payees.len() {
+        for share in game_session.fee_split() {
+            remaining_accounts.push(AccountMeta::new(
+                payout_destination(game_session, &share.recipient, token_program),
+                false,
+            ));
+        }
+    }
+
+    let accounts = accounts::DistributeWinnings {
+        caller: *caller,
+        config: pda::config().0,
+        game_session: game_session_key,
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, token_program),
+        treasury: pda::treasury().0,
+        treasury_token_account: pda::treasury_token_account(mint, token_program),
+        approval: approval.then(|| {
+            pda::approval(
+                &game_session_key,
+                wager_program::state::ApprovalKind::DistributeWinnings,
+            )
+            .0
+        }),
+        jackpot: None,
+        jackpot_token_account: None,
+        mint: *mint,
+        memo_program: Some(anchor_spl::memo::ID),
+        unclaimed_payout: Some(pda::unclaimed_payout(&game_session_key).0),
+        token_program: *token_program,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+    };
+    let args = instruction::DistributeWinnings {
+        session_id: session_id.to_string(),
+        declaration,
+    };
+    Ok(build_instruction(accounts, args, remaining_accounts))
+}
+
+/// Refunds every depositor of the session. Sessions whose refund policy
+/// forfeits the creator's bond need the creator's token account.
+pub fn refund_wager(
+    game_server: &Pubkey,
+    session_id: &str,
+    game_session: &GameSession,
+    token_program: &Pubkey,
+    creator_token_account: Option<Pubkey>,
+) -> Result<Instruction> {
+    let mint = &game_session.mint;
+    let game_session_key = pda::game_session(session_id).0;
+    let mut remaining_accounts = Vec::new();
+    for (player, _) in game_session.player_deposits()? {
+        remaining_accounts.push(AccountMeta::new_readonly(player, false));
+        remaining_accounts.push(AccountMeta::new(
+            payout_destination(game_session, &player, token_program),
+            false,
+        ));
+        remaining_accounts.push(AccountMeta::new(
+            pda::bet_receipt(&game_session_key, &player).0,
+            false,
+        ));
+    }
+
+    let accounts = accounts::RefundWager {
+        game_server: *game_server,
+        game_session: game_session_key,
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, token_program),
+        creator_token_account,
+        mint: *mint,
+        memo_program: Some(anchor_spl::memo::ID),
+        token_program: *token_program,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+    };
+    let args = instruction::RefundWager {
+        session_id: session_id.to_string(),
+    };
+    Ok(build_instruction(accounts, args, remaining_accounts))
+}
@@ -0,0 +1,19 @@
+//! Decoding of the events the program emits, which `emit!` writes to the
+//! transaction logs as base64 `Program data:` lines.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+/// Event payload carried by a log line, discriminator included
+pub fn event_data(log: &str) -> Option<Vec<u8>> {
+    STANDARD.decode(log.strip_prefix(PROGRAM_DATA)?).ok()
+}
+
+/// Decodes an event payload as `E`, `None` when it is some other event
+pub fn decode_event<E: Discriminator + AnchorDeserialize>(data: &[u8]) -> Option<E> {
+    let body = data.strip_prefix(&E::DISCRIMINATOR[..])?;
+    E::deserialize(&mut &body[..]).ok()
+}
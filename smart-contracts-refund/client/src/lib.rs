@@ -0,0 +1,42 @@
+//! Rust client for the wager program. Backend services build instructions,
+//! derive PDAs and decode accounts through this crate instead of assembling
+//! account lists and instruction data by hand.
+//!
+//! Every instruction has a typed builder through [`build_instruction`] and the
+//! program's generated [`accounts`] and [`instruction`] modules; the most used
+//! ones also have builders in [`builder`] that fill in the PDAs themselves.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::InstructionData;
+
+pub mod account;
+pub mod builder;
+pub mod event;
+pub mod pda;
+#[cfg(feature = "rpc")]
+pub mod rpc;
+#[cfg(feature = "rpc")]
+pub mod settlement;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+pub use account::{decode_account, decode_game_session, AccountSource, ClientError};
+pub use event::decode_event;
+pub use wager_program::{accounts, events, instruction, state, ID};
+
+/// Builds an instruction of the wager program from its generated accounts and
+/// instruction data, followed by any remaining accounts it consumes
+pub fn build_instruction(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut metas = accounts.to_account_metas(None);
+    metas.extend(remaining_accounts);
+    Instruction {
+        program_id: ID,
+        accounts: metas,
+        data: data.data(),
+    }
+}
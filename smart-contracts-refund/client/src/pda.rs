@@ -0,0 +1,176 @@
+//! Program derived addresses of every account the wager program owns. Each
+//! helper returns the address together with its bump.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use wager_program::state::{ApprovalKind, GameMode};
+use wager_program::ID;
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &ID)
+}
+
+pub fn config() -> (Pubkey, u8) {
+    find(&[b"config"])
+}
+
+pub fn treasury() -> (Pubkey, u8) {
+    find(&[b"treasury"])
+}
+
+pub fn game_session(session_id: &str) -> (Pubkey, u8) {
+    find(&[b"game_session", session_id.as_bytes()])
+}
+
+pub fn vault(session_id: &str) -> (Pubkey, u8) {
+    find(&[b"vault", session_id.as_bytes()])
+}
+
+pub fn match_log(session_id: &str) -> (Pubkey, u8) {
+    find(&[b"match_log", session_id.as_bytes()])
+}
+
+pub fn session_metadata(session_id: &str) -> (Pubkey, u8) {
+    find(&[b"session_metadata", session_id.as_bytes()])
+}
+
+pub fn announcements(session_id: &str) -> (Pubkey, u8) {
+    find(&[b"announcements", session_id.as_bytes()])
+}
+
+pub fn spectator_pool(session_id: &str) -> (Pubkey, u8) {
+    find(&[b"spectator_pool", session_id.as_bytes()])
+}
+
+pub fn spectator_bet(session_id: &str, bettor: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"spectator_bet", session_id.as_bytes(), bettor.as_ref()])
+}
+
+pub fn match_result(session_id: &str, created_at: i64) -> (Pubkey, u8) {
+    find(&[
+        b"match_result",
+        session_id.as_bytes(),
+        &created_at.to_le_bytes(),
+    ])
+}
+
+pub fn player_stats(player: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"player_stats", player.as_ref()])
+}
+
+pub fn player_ban(player: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"player_ban", player.as_ref()])
+}
+
+pub fn active_exposure(player: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"active_exposure", player.as_ref()])
+}
+
+pub fn referral(user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"referral", user.as_ref()])
+}
+
+pub fn bet_receipt(game_session: &Pubkey, player: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"bet_receipt", game_session.as_ref(), player.as_ref()])
+}
+
+pub fn session_kick(game_session: &Pubkey, player: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"session_kick", game_session.as_ref(), player.as_ref()])
+}
+
+pub fn referral_credit(game_session: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"referral_credit", game_session.as_ref(), user.as_ref()])
+}
+
+pub fn approval(game_session: &Pubkey, kind: ApprovalKind) -> (Pubkey, u8) {
+    find(&[b"approval", game_session.as_ref(), &[kind as u8]])
+}
+
+pub fn unclaimed_payout(game_session: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"unclaimed_payout", game_session.as_ref()])
+}
+
+pub fn trophy(game_session: &Pubkey, player: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"trophy", game_session.as_ref(), player.as_ref()])
+}
+
+pub fn trophy_authority() -> (Pubkey, u8) {
+    find(&[b"trophy_authority"])
+}
+
+pub fn rewards() -> (Pubkey, u8) {
+    find(&[b"rewards"])
+}
+
+/// Discovery index of open lobbies sharing a mint, bet and game mode
+pub fn lobby_index(mint: &Pubkey, bet_amount: u64, game_mode: GameMode) -> (Pubkey, u8) {
+    find(&[
+        b"lobby_index",
+        mint.as_ref(),
+        &bet_amount.to_le_bytes(),
+        &[game_mode as u8],
+    ])
+}
+
+pub fn jackpot(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"jackpot", mint.as_ref()])
+}
+
+pub fn price_feed(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"price_feed", mint.as_ref()])
+}
+
+pub fn registered_mint(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"registered_mint", mint.as_ref()])
+}
+
+pub fn server_bond(operator: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"server_bond", operator.as_ref()])
+}
+
+pub fn dispute_votes(game_session: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"dispute_votes", game_session.as_ref()])
+}
+
+pub fn result_commitment(game_session: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"result_commitment", game_session.as_ref()])
+}
+
+pub fn session_template(authority: &Pubkey, template_id: u64) -> (Pubkey, u8) {
+    find(&[
+        b"session_template",
+        authority.as_ref(),
+        &template_id.to_le_bytes(),
+    ])
+}
+
+pub fn recurring_match(authority: &Pubkey, prefix: &str) -> (Pubkey, u8) {
+    find(&[b"recurring_match", authority.as_ref(), prefix.as_bytes()])
+}
+
+pub fn tournament(tournament_id: &str) -> (Pubkey, u8) {
+    find(&[b"tournament", tournament_id.as_bytes()])
+}
+
+pub fn tournament_vault(tournament_id: &str) -> (Pubkey, u8) {
+    find(&[b"tournament_vault", tournament_id.as_bytes()])
+}
+
+pub fn bracket_match(tournament_id: &str, round: u8, match_index: u8) -> (Pubkey, u8) {
+    find(&[
+        b"bracket_match",
+        tournament_id.as_bytes(),
+        &[round],
+        &[match_index],
+    ])
+}
+
+/// Escrow token account the session's vault owns
+pub fn vault_token_account(session_id: &str, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(&vault(session_id).0, mint, token_program)
+}
+
+/// Token account the treasury collects fees in
+pub fn treasury_token_account(mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(&treasury().0, mint, token_program)
+}
@@ -0,0 +1,238 @@
+//! Minimal JSON-RPC client for the workspace's tools. They talk to a local
+//! validator, which serves plain HTTP, so requests go over a TCP stream
+//! without pulling in an HTTP stack.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::account::RawAccount;
+use crate::{AccountSource, ClientError};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::Transaction;
+
+/// How long a transaction may take to confirm before giving up
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Delay between confirmation polls
+pub const POLL_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Transaction signature listed for an address
+pub struct SignatureInfo {
+    pub signature: Signature,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub failed: bool, // Whether the transaction failed, leaving no state behind
+}
+
+pub struct RpcClient {
+    host: String, // host:port of the validator's RPC endpoint
+}
+
+impl RpcClient {
+    /// Connects to an `http://host:port` endpoint
+    pub fn new(url: &str) -> Result<Self, ClientError> {
+        let host = url
+            .strip_prefix("http://")
+            .ok_or_else(|| ClientError::Source(format!("unsupported RPC url {}", url)))?
+            .trim_end_matches('/');
+        Ok(Self {
+            host: host.to_string(),
+        })
+    }
+
+    /// Sends one JSON-RPC request, returning its `result`
+    fn call(&self, method: &str, params: Value) -> Result<Value, ClientError> {
+        let body =
+            json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params}).to_string();
+        let request = format!(
+            "POST / HTTP/1.0\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            self.host,
+            body.len(),
+            body
+        );
+
+        let mut stream = TcpStream::connect(&self.host).map_err(source_error)?;
+        stream.write_all(request.as_bytes()).map_err(source_error)?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).map_err(source_error)?;
+
+        let (_, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| ClientError::Source("malformed RPC response".to_string()))?;
+        let mut reply: Value = serde_json::from_str(body).map_err(source_error)?;
+        if let Some(error) = reply.get("error") {
+            return Err(ClientError::Source(format!("{} failed: {}", method, error)));
+        }
+        Ok(reply["result"].take())
+    }
+
+    pub fn latest_blockhash(&self) -> Result<Hash, ClientError> {
+        Ok(self.latest_blockhash_with_height()?.0)
+    }
+
+    /// Latest blockhash with the last block height it can land in
+    pub fn latest_blockhash_with_height(&self) -> Result<(Hash, u64), ClientError> {
+        let result = self.call("getLatestBlockhash", json!([{"commitment": "confirmed"}]))?;
+        let value = &result["value"];
+        let blockhash = Hash::from_str(value["blockhash"].as_str().unwrap_or_default())
+            .map_err(source_error)?;
+        Ok((
+            blockhash,
+            value["lastValidBlockHeight"].as_u64().unwrap_or_default(),
+        ))
+    }
+
+    pub fn block_height(&self) -> Result<u64, ClientError> {
+        let result = self.call("getBlockHeight", json!([{"commitment": "confirmed"}]))?;
+        result
+            .as_u64()
+            .ok_or_else(|| ClientError::Source("malformed block height".to_string()))
+    }
+
+    /// Sends a signed transaction without waiting for it
+    pub fn send_transaction(&self, transaction: &Transaction) -> Result<Signature, ClientError> {
+        let wire = STANDARD.encode(bincode::serialize(transaction).map_err(source_error)?);
+        let result = self.call(
+            "sendTransaction",
+            json!([wire, {"encoding": "base64", "preflightCommitment": "confirmed"}]),
+        )?;
+        Signature::from_str(result.as_str().unwrap_or_default()).map_err(source_error)
+    }
+
+    /// Sends a signed transaction and waits for it to confirm
+    pub fn send_and_confirm(&self, transaction: &Transaction) -> Result<Signature, ClientError> {
+        let signature = self.send_transaction(transaction)?;
+        self.confirm(&signature)?;
+        Ok(signature)
+    }
+
+    /// Confirmation state of a sent transaction, `None` while it is pending
+    pub fn confirmation(
+        &self,
+        signature: &Signature,
+    ) -> Result<Option<Result<(), String>>, ClientError> {
+        let result = self.call("getSignatureStatuses", json!([[signature.to_string()]]))?;
+        let status = &result["value"][0];
+        if status.is_null() {
+            return Ok(None);
+        }
+        if !status["err"].is_null() {
+            return Ok(Some(Err(status["err"].to_string())));
+        }
+        Ok(matches!(
+            status["confirmationStatus"].as_str(),
+            Some("confirmed" | "finalized")
+        )
+        .then_some(Ok(())))
+    }
+
+    /// Airdrops lamports to `address` and waits for them to land
+    pub fn airdrop(&self, address: &Pubkey, lamports: u64) -> Result<(), ClientError> {
+        let result = self.call("requestAirdrop", json!([address.to_string(), lamports]))?;
+        let signature =
+            Signature::from_str(result.as_str().unwrap_or_default()).map_err(source_error)?;
+        self.confirm(&signature)
+    }
+
+    /// Confirmed signatures of transactions touching `address`, newest
+    /// first, starting below `before` and stopping at `until` when given
+    pub fn signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<&Signature>,
+        until: Option<&Signature>,
+        limit: usize,
+    ) -> Result<Vec<SignatureInfo>, ClientError> {
+        let mut config = json!({"commitment": "confirmed", "limit": limit});
+        if let Some(before) = before {
+            config["before"] = json!(before.to_string());
+        }
+        if let Some(until) = until {
+            config["until"] = json!(until.to_string());
+        }
+        let result = self.call(
+            "getSignaturesForAddress",
+            json!([address.to_string(), config]),
+        )?;
+        let mut signatures = Vec::new();
+        for entry in result.as_array().into_iter().flatten() {
+            signatures.push(SignatureInfo {
+                signature: Signature::from_str(entry["signature"].as_str().unwrap_or_default())
+                    .map_err(source_error)?,
+                slot: entry["slot"].as_u64().unwrap_or_default(),
+                block_time: entry["blockTime"].as_i64(),
+                failed: !entry["err"].is_null(),
+            });
+        }
+        Ok(signatures)
+    }
+
+    /// Log messages of a confirmed transaction
+    pub fn transaction_logs(&self, signature: &Signature) -> Result<Vec<String>, ClientError> {
+        let result = self.call(
+            "getTransaction",
+            json!([signature.to_string(), {
+                "encoding": "json",
+                "commitment": "confirmed",
+                "maxSupportedTransactionVersion": 0
+            }]),
+        )?;
+        Ok(result["meta"]["logMessages"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|log| log.as_str().map(str::to_string))
+            .collect())
+    }
+
+    fn confirm(&self, signature: &Signature) -> Result<(), ClientError> {
+        let started = Instant::now();
+        while started.elapsed() < CONFIRM_TIMEOUT {
+            match self.confirmation(signature)? {
+                Some(Ok(())) => return Ok(()),
+                Some(Err(error)) => {
+                    return Err(ClientError::Source(format!(
+                        "transaction {} failed: {}",
+                        signature, error
+                    )))
+                }
+                None => thread::sleep(POLL_INTERVAL),
+            }
+        }
+        Err(ClientError::Source(format!(
+            "transaction {} was not confirmed",
+            signature
+        )))
+    }
+}
+
+impl AccountSource for RpcClient {
+    fn get_account(&self, address: &Pubkey) -> Result<Option<RawAccount>, ClientError> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), {"encoding": "base64", "commitment": "confirmed"}]),
+        )?;
+        let account = &result["value"];
+        if account.is_null() {
+            return Ok(None);
+        }
+        let owner = Pubkey::from_str(account["owner"].as_str().unwrap_or_default())
+            .map_err(source_error)?;
+        let data = STANDARD
+            .decode(account["data"][0].as_str().unwrap_or_default())
+            .map_err(source_error)?;
+        Ok(Some(RawAccount { owner, data }))
+    }
+}
+
+fn source_error(error: impl std::fmt::Display) -> ClientError {
+    ClientError::Source(error.to_string())
+}
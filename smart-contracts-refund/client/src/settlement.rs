@@ -0,0 +1,185 @@
+//! Settlement submitter: pays out every page of a session's result, with
+//! compute budget and priority fee instructions attached. Each page is built
+//! from the session's on-chain distribution cursor, so a page that landed
+//! despite an expired blockhash or a lost response is never paid twice, and
+//! a settlement interrupted midway resumes where it stopped.
+
+use std::thread;
+
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+use wager_program::state::{GameSession, SessionStatus, WinnerDeclaration, DISTRIBUTION_CU_BUDGET};
+
+use crate::rpc::{RpcClient, POLL_INTERVAL};
+use crate::{builder, AccountSource, ClientError};
+
+/// Sends of a single page before giving up on it
+pub const DEFAULT_MAX_ATTEMPTS: usize = 5;
+
+/// Outcome of one send of a page
+enum Attempt {
+    Confirmed(Signature),
+    Failed(String),
+    Expired,
+}
+
+/// Submits `distribute_winnings` pages until a session completes
+pub struct SettlementSubmitter<'a> {
+    rpc: &'a RpcClient,
+    caller: &'a Keypair,
+    token_program: Pubkey,
+    compute_unit_limit: u32,
+    priority_fee: u64, // Micro-lamports paid per compute unit
+    max_attempts: usize,
+}
+
+impl<'a> SettlementSubmitter<'a> {
+    /// Submitter signing as `caller`, a member of the game server signing set
+    pub fn new(rpc: &'a RpcClient, caller: &'a Keypair, token_program: Pubkey) -> Self {
+        Self {
+            rpc,
+            caller,
+            token_program,
+            compute_unit_limit: DISTRIBUTION_CU_BUDGET as u32,
+            priority_fee: 0,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+
+    pub fn with_priority_fee(mut self, micro_lamports: u64) -> Self {
+        self.priority_fee = micro_lamports;
+        self
+    }
+
+    pub fn with_compute_unit_limit(mut self, units: u32) -> Self {
+        self.compute_unit_limit = units;
+        self
+    }
+
+    pub fn with_max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Pays every remaining page of the session's result, returning the
+    /// signatures of the pages this call landed. A session already completed
+    /// returns no signatures.
+    pub fn distribute(
+        &self,
+        session_id: &str,
+        declaration: WinnerDeclaration,
+        approval: bool,
+    ) -> Result<Vec<Signature>, ClientError> {
+        let mut signatures = Vec::new();
+        loop {
+            let game_session = self.rpc.fetch_game_session(session_id)?;
+            match session_status(&game_session)? {
+                SessionStatus::Completed => return Ok(signatures),
+                SessionStatus::InProgress | SessionStatus::Settling => {}
+                status => {
+                    return Err(ClientError::Source(format!(
+                        "session {} cannot settle from status {}",
+                        session_id, status as u8
+                    )))
+                }
+            }
+            if let Some(signature) =
+                self.submit_page(session_id, &game_session, declaration, approval)?
+            {
+                signatures.push(signature);
+            }
+        }
+    }
+
+    /// Sends the page following the session's cursor until it lands, or
+    /// until the session shows it already has. Returns the signature when
+    /// this call's send landed it.
+    fn submit_page(
+        &self,
+        session_id: &str,
+        game_session: &GameSession,
+        declaration: WinnerDeclaration,
+        approval: bool,
+    ) -> Result<Option<Signature>, ClientError> {
+        let distribute = builder::distribute_winnings(
+            &self.caller.pubkey(),
+            session_id,
+            game_session,
+            &self.token_program,
+            declaration,
+            approval,
+        )
+        .map_err(|error| ClientError::Source(error.to_string()))?;
+        let instructions = [
+            ComputeBudgetInstruction::set_compute_unit_limit(self.compute_unit_limit),
+            ComputeBudgetInstruction::set_compute_unit_price(self.priority_fee),
+            distribute,
+        ];
+
+        let mut last_error = String::new();
+        for _ in 0..self.max_attempts {
+            let (blockhash, last_valid_block_height) = self.rpc.latest_blockhash_with_height()?;
+            let transaction = Transaction::new_signed_with_payer(
+                &instructions,
+                Some(&self.caller.pubkey()),
+                &[self.caller],
+                blockhash,
+            );
+            match self.send(&transaction, last_valid_block_height)? {
+                Attempt::Confirmed(signature) => return Ok(Some(signature)),
+                Attempt::Failed(error) => last_error = error,
+                Attempt::Expired => last_error = "blockhash expired".to_string(),
+            }
+
+            // Another submitter, or an earlier send, may have paid the page
+            let current = self.rpc.fetch_game_session(session_id)?;
+            if current.distribution_cursor != game_session.distribution_cursor
+                || current.status != game_session.status
+            {
+                return Ok(None);
+            }
+        }
+        Err(ClientError::Source(format!(
+            "distribution of session {} failed after {} attempts: {}",
+            session_id, self.max_attempts, last_error
+        )))
+    }
+
+    /// Sends a transaction and waits until it confirms, fails, or its
+    /// blockhash can no longer land it
+    fn send(
+        &self,
+        transaction: &Transaction,
+        last_valid_block_height: u64,
+    ) -> Result<Attempt, ClientError> {
+        let signature = match self.rpc.send_transaction(transaction) {
+            Ok(signature) => signature,
+            // Preflight rejections carry the program error
+            Err(ClientError::Source(error)) => return Ok(Attempt::Failed(error)),
+            Err(error) => return Err(error),
+        };
+        loop {
+            match self.rpc.confirmation(&signature)? {
+                Some(Ok(())) => return Ok(Attempt::Confirmed(signature)),
+                Some(Err(error)) => return Ok(Attempt::Failed(error)),
+                None if self.rpc.block_height()? > last_valid_block_height => {
+                    // A last look, the transaction may have landed meanwhile
+                    return Ok(match self.rpc.confirmation(&signature)? {
+                        Some(Ok(())) => Attempt::Confirmed(signature),
+                        Some(Err(error)) => Attempt::Failed(error),
+                        None => Attempt::Expired,
+                    });
+                }
+                None => thread::sleep(POLL_INTERVAL),
+            }
+        }
+    }
+}
+
+fn session_status(game_session: &GameSession) -> Result<SessionStatus, ClientError> {
+    game_session
+        .status()
+        .map_err(|error| ClientError::Source(error.to_string()))
+}
@@ -0,0 +1,364 @@
+//! In-memory simulation of a session's life for testing payout math.
+//!
+//! [`GameSimulator`] drives a session through random joins, prize top-ups,
+//! kills, spawn purchases and a final settlement or refund using the
+//! program's own state transitions and payee calculations. The token
+//! transfers the instructions would make are mirrored on the vault ledger and
+//! a simulated escrow balance, so every step can assert funds are conserved.
+//! Actions the program rejects leave the session untouched, as a failed
+//! transaction would. `tests/program_simulation.rs` plays the same kind of
+//! sessions through real transactions on a test validator.
+
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::SUCCESS;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use wager_program::instructions::{apply_kill, distribution_payees};
+use wager_program::state::*;
+use wager_program::validation::{safe_math, validation};
+
+/// Timestamp the simulated clock reports
+pub const SIMULATION_TIME: i64 = 1_700_000_000;
+
+/// Most actions a single simulation takes before it must settle
+pub const MAX_SIMULATION_STEPS: usize = 512;
+
+static INSTALL_STUBS: Once = Once::new();
+
+/// Syscalls the program makes off-chain: a fixed clock and silenced logs
+struct SimulatorStubs;
+
+impl SyscallStubs for SimulatorStubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: SIMULATION_TIME,
+            ..Clock::default()
+        };
+        // SAFETY: the runtime passes a pointer to an uninitialized Clock
+        unsafe { std::ptr::write(var_addr as *mut Clock, clock) };
+        SUCCESS
+    }
+}
+
+/// Small deterministic generator, so a failing seed can be replayed
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..bound`, `bound` being non-zero
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+/// How a simulated session ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The result was paid out to the winners
+    Settled,
+    /// The program refused to settle because payouts exceeded the escrow
+    SettlementRejected,
+    /// The lobby never started and every deposit was returned
+    Refunded,
+}
+
+/// Session driven through random play, see the module documentation
+pub struct GameSimulator {
+    pub session: Box<GameSession>,
+    pub vault: Vault,
+    pub escrow: u64, // Tokens the vault's token account would hold
+    rng: SplitMix64,
+    slot: u64,
+    sponsors: Vec<Pubkey>,
+}
+
+impl GameSimulator {
+    /// Creates a session of `game_mode` with random terms drawn from `seed`
+    pub fn new(seed: u64, game_mode: GameMode, bet: u64) -> Result<Self> {
+        INSTALL_STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(SimulatorStubs));
+        });
+
+        let mut rng = SplitMix64(seed);
+        let config = ProgramConfig {
+            fee_bps: rng.below(1_001) as u16,
+            session_timeout_secs: 3_600,
+            ..ProgramConfig::default()
+        };
+        let team_count = if game_mode.is_free_for_all() { 0 } else { 2 };
+        // Team winner-takes-all sessions sometimes take stakes up to 4x the bet
+        let max_stake = if team_count > 0 && !game_mode.is_pay_to_spawn() && rng.below(2) == 0 {
+            bet.saturating_mul(1 + rng.below(4))
+        } else {
+            0
+        };
+        let terms = SessionTerms {
+            game_mode,
+            team_count,
+            spawn_earnings_bps: rng.below(10_001) as u16,
+            log_capacity: 0,
+            team_a_capacity: 0,
+            team_b_capacity: 0,
+            payout_schedule: PayoutSchedule::WinnerTeamEqual,
+            max_kills_per_player: 0,
+            max_match_kills: 0,
+            series_length: 0,
+            refund_policy: RefundPolicy::FullAnytimeBeforeStart,
+            win_condition: WinCondition::ServerDecision,
+            assist_weight_bps: rng.below(5_001) as u16,
+            min_ready_players: 0,
+            max_stake,
+        };
+        validation::validate_session_terms(&terms, None, false, SIMULATION_TIME)?;
+
+        let mut session: Box<GameSession> = bytemuck::zeroed_box();
+        session.initialize(
+            &format!("sim-{}", seed),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            bet,
+            game_mode,
+            team_count,
+            &config,
+            SIMULATION_TIME,
+        )?;
+        session.apply_terms(&terms)?;
+
+        Ok(Self {
+            session,
+            vault: Vault {
+                game_session: Pubkey::new_unique(),
+                total_deposited: 0,
+                total_paid_out: 0,
+                total_refunded: 0,
+                total_fees: 0,
+            },
+            escrow: 0,
+            rng,
+            slot: 0,
+            sponsors: Vec::new(),
+        })
+    }
+
+    /// Runs random actions until the match has been played out, then settles
+    /// or refunds it, checking funds are conserved after every step
+    pub fn run(&mut self) -> Result<Outcome> {
+        for _ in 0..MAX_SIMULATION_STEPS {
+            if self.rng.below(16) == 0 {
+                break;
+            }
+            let snapshot = *self.session;
+            let vault = self.vault.clone();
+            let escrow = self.escrow;
+            if self.step().is_err() {
+                // A rejected transaction leaves no trace
+                *self.session = snapshot;
+                self.vault = vault;
+                self.escrow = escrow;
+            }
+            self.check_conservation()?;
+        }
+
+        let outcome = if self.session.status()? == SessionStatus::InProgress {
+            self.settle()?
+        } else {
+            self.refund()?
+        };
+        self.check_conservation()?;
+        Ok(outcome)
+    }
+
+    /// Takes one random action a player or the game server could send
+    fn step(&mut self) -> Result<()> {
+        match self.session.status()? {
+            SessionStatus::Created | SessionStatus::Filling => match self.rng.below(8) {
+                0 => self.sponsor(),
+                _ => self.join(),
+            },
+            SessionStatus::InProgress => match self.rng.below(4) {
+                0 if self.session.is_pay_to_spawn() => self.buy_spawns(),
+                _ => self.kill(),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.vault.record_deposit(amount)?;
+        self.escrow = safe_math::safe_add(self.escrow, amount)?;
+        Ok(())
+    }
+
+    fn release(&mut self, amount: u64) -> Result<()> {
+        self.escrow = safe_math::safe_subtract(self.escrow, amount)?;
+        Ok(())
+    }
+
+    /// Seats a new player on a random team
+    fn join(&mut self) -> Result<()> {
+        let team = match self.session.team_count() {
+            0 => 0,
+            teams => self.rng.below(teams as u64) as u8,
+        };
+        let bet = self.session.session_bet;
+        let stake = match self.session.max_stake {
+            0 => bet,
+            max_stake => bet + self.rng.below(max_stake - bet + 1),
+        };
+        self.session
+            .add_staked_player(team, Pubkey::new_unique(), stake)?;
+        self.deposit(stake)
+    }
+
+    /// Tops up the prize pool from one of a few sponsors
+    fn sponsor(&mut self) -> Result<()> {
+        if self.sponsors.is_empty()
+            || (self.sponsors.len() < MAX_PRIZE_SPONSORS && self.rng.below(2) == 0)
+        {
+            self.sponsors.push(Pubkey::new_unique());
+        }
+        let sponsor = self.sponsors[self.rng.below(self.sponsors.len() as u64) as usize];
+        let amount = 1 + self.rng.below(self.session.session_bet.max(1));
+        self.session.add_sponsored_prize(sponsor, amount)?;
+        self.deposit(amount)
+    }
+
+    /// Records a kill between two random players, with a random assist
+    fn kill(&mut self) -> Result<()> {
+        let players = self.seated_players()?;
+        let (killer_team, killer) = players[self.rng.below(players.len() as u64) as usize];
+        let (victim_team, victim) = players[self.rng.below(players.len() as u64) as usize];
+        let mut assists = [Pubkey::default(); MAX_KILL_ASSISTS];
+        let (assist_team, assist) = players[self.rng.below(players.len() as u64) as usize];
+        if assist_team == killer_team && assist != killer {
+            assists[0] = assist;
+        }
+
+        self.slot += 1;
+        let session_id = self.session.session_id();
+        apply_kill(
+            &mut self.session,
+            None,
+            &session_id,
+            KillRecord {
+                killer_team,
+                killer,
+                victim_team,
+                victim,
+                assists,
+            },
+            self.slot,
+        )
+    }
+
+    /// Sells a random player one purchase of spawns
+    fn buy_spawns(&mut self) -> Result<()> {
+        let players = self.seated_players()?;
+        let (team, player) = players[self.rng.below(players.len() as u64) as usize];
+        let index = self.session.get_player_index(team, player)?;
+        self.session.add_spawns(team, index, SPAWNS_PER_PURCHASE)?;
+        self.deposit(self.session.session_bet)
+    }
+
+    /// Declares a winner and pays every payee as `distribute_winnings` would
+    fn settle(&mut self) -> Result<Outcome> {
+        let winning_team = match self.session.last_team_standing() {
+            Some(team) => team,
+            None if self.session.is_free_for_all() => 0,
+            None => self.rng.below(self.session.team_count() as u64) as u8,
+        };
+        self.session.record_result(&WinnerDeclaration {
+            result: MatchResult::Win,
+            team: winning_team,
+        })?;
+        self.session.begin_settlement(SIMULATION_TIME)?;
+
+        let (payees, fee, claimable) = distribution_payees(&self.session, winning_team)?;
+        let owed = payees
+            .iter()
+            .chain(&claimable)
+            .map(|(_, amount)| *amount)
+            .chain([fee]);
+        if validation::validate_payout_invariant(owed, self.escrow).is_err() {
+            return Ok(Outcome::SettlementRejected);
+        }
+
+        for (_, amount) in payees.iter().chain(&claimable) {
+            self.vault.record_payout(*amount)?;
+            self.release(*amount)?;
+        }
+        self.vault.record_fee(fee)?;
+        self.release(fee)?;
+        self.session.transition_to(SessionStatus::Completed)?;
+        Ok(Outcome::Settled)
+    }
+
+    /// Returns every deposit of a lobby that never started
+    fn refund(&mut self) -> Result<Outcome> {
+        self.session.begin_settlement(SIMULATION_TIME)?;
+        let refunds = self
+            .session
+            .player_deposits()?
+            .into_iter()
+            .map(|(_, amount)| amount)
+            .chain(
+                self.session
+                    .prize_sponsors()
+                    .into_iter()
+                    .map(|entry| entry.amount),
+            )
+            .collect::<Vec<_>>();
+        for amount in refunds {
+            self.vault.record_refund(amount)?;
+            self.release(amount)?;
+        }
+        self.session.clear_sponsored_prize();
+        self.session.transition_to(SessionStatus::Refunded)?;
+        Ok(Outcome::Refunded)
+    }
+
+    /// Every seated player with their team, free-for-all entrants on team 0
+    fn seated_players(&self) -> Result<Vec<(u8, Pubkey)>> {
+        let mut players = Vec::new();
+        for player in self.session.get_all_players() {
+            if player == Pubkey::default() {
+                continue;
+            }
+            let team = if self.session.is_free_for_all() {
+                0
+            } else {
+                self.session.get_player_slot(player)?.0
+            };
+            players.push((team, player));
+        }
+        require!(
+            !players.is_empty(),
+            wager_program::errors::WagerError::NoWinnersFound
+        );
+        Ok(players)
+    }
+
+    /// Checks the vault ledger matches the escrow: nothing was paid out
+    /// that was not deposited, and nothing deposited went unaccounted for
+    pub fn check_conservation(&self) -> Result<()> {
+        self.vault.check_invariant(self.escrow)?;
+        require_eq!(
+            self.vault.remaining()?,
+            self.escrow,
+            wager_program::errors::WagerError::VaultInvariantViolated
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+//! Committed results: a reveal must match the committed declaration and
+//! salt, land in a later slot and before the session's timeout refunds open.
+//! A result that is never revealed leaves the bets to those refunds.
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{accounts, build_instruction, instruction, pda};
+
+const DISPUTE_WINDOW_SECS: i64 = 3_600;
+const SALT: [u8; 32] = [7; 32];
+
+const WINNER: WinnerDeclaration = WinnerDeclaration {
+    result: MatchResult::Win,
+    team: 0,
+};
+
+async fn start(players: &[Keypair], session_id: &str) -> Validator {
+    let mut validator = Validator::start_with_config(players, BET, |params| {
+        params.commit_reveal_results = true;
+        params.dispute_window_secs = DISPUTE_WINDOW_SECS;
+    })
+    .await;
+    validator
+        .start_match(session_id, GameMode::WinnerTakesAllOneVsOne, players)
+        .await;
+    validator
+}
+
+#[tokio::test]
+async fn reveal_must_match_the_commitment() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let session_id = "revealed";
+    let mut validator = start(&players, session_id).await;
+
+    // Neither a direct submission nor a reveal without a commitment records
+    // a result
+    let submit = submit_result(&validator, session_id, WINNER);
+    let err = validator.send(&[submit], &[]).await.unwrap_err();
+    assert!(err.contains("Error Code: ResultCommitRequired."), "{}", err);
+    let reveal = reveal_result(&validator, session_id, WINNER, SALT);
+    let err = validator.send(&[reveal], &[]).await.unwrap_err();
+    assert!(
+        err.contains("Error Code: AccountNotInitialized."),
+        "{}",
+        err
+    );
+
+    // A reveal sharing the commitment's transaction shares its slot
+    let commit = commit_result(&validator, session_id, WINNER, SALT);
+    let reveal = reveal_result(&validator, session_id, WINNER, SALT);
+    let err = validator
+        .send(&[commit.clone(), reveal], &[])
+        .await
+        .unwrap_err();
+    assert!(err.contains("Error Code: RevealTooEarly."), "{}", err);
+    validator.send(&[commit], &[]).await.unwrap();
+    validator.next_slot().await;
+
+    // Another team or another salt doesn't open the commitment
+    let loser = WinnerDeclaration { team: 1, ..WINNER };
+    for (declaration, salt) in [(loser, SALT), (WINNER, [8; 32])] {
+        let reveal = reveal_result(&validator, session_id, declaration, salt);
+        let err = validator.send(&[reveal], &[]).await.unwrap_err();
+        assert!(
+            err.contains("Error Code: ResultCommitmentMismatch."),
+            "{}",
+            err
+        );
+    }
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.status().unwrap() == SessionStatus::InProgress);
+
+    // The committed result opens the dispute window and frees the commitment
+    let reveal = reveal_result(&validator, session_id, WINNER, SALT);
+    validator.send(&[reveal], &[]).await.unwrap();
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.status().unwrap() == SessionStatus::ResultSubmitted);
+    assert_eq!(game_session.winning_team, WINNER.team);
+    let game_session_key = pda::game_session(session_id).0;
+    let commitment = pda::result_commitment(&game_session_key).0;
+    assert!(validator
+        .banks()
+        .get_account(commitment)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn unrevealed_result_leaves_the_bets_to_timeout_refunds() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let session_id = "unrevealed";
+    let mut validator = start(&players, session_id).await;
+    let commit = commit_result(&validator, session_id, WINNER, SALT);
+    validator.send(&[commit], &[]).await.unwrap();
+
+    // Once the session expires the committed result can't be revealed
+    validator.next_slot().await;
+    validator.advance_clock(86_401).await;
+    let reveal = reveal_result(&validator, session_id, WINNER, SALT);
+    let err = validator.send(&[reveal], &[]).await.unwrap_err();
+    assert!(err.contains("Error Code: OperationExpired."), "{}", err);
+
+    let game_session = validator.game_session(session_id).await;
+    for player in &players {
+        let refund = claim_timeout_refund(session_id, &game_session, &player.pubkey());
+        validator.send(&[refund], &[player]).await.unwrap();
+        let token_account = get_associated_token_address(&player.pubkey(), &validator.mint);
+        assert_eq!(validator.token_balance(token_account).await, BET);
+    }
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.status().unwrap() == SessionStatus::Refunded);
+}
+
+fn submit_result(
+    validator: &Validator,
+    session_id: &str,
+    declaration: WinnerDeclaration,
+) -> Instruction {
+    let accounts = accounts::SubmitResult {
+        game_server: validator.game_server.pubkey(),
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        approval: None,
+    };
+    let args = instruction::SubmitResult {
+        session_id: session_id.to_string(),
+        declaration,
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+fn commit_result(
+    validator: &Validator,
+    session_id: &str,
+    declaration: WinnerDeclaration,
+    salt: [u8; 32],
+) -> Instruction {
+    let game_session_key = pda::game_session(session_id).0;
+    let accounts = accounts::CommitResult {
+        game_server: validator.game_server.pubkey(),
+        config: pda::config().0,
+        game_session: game_session_key,
+        result_commitment: pda::result_commitment(&game_session_key).0,
+        system_program: anchor_lang::system_program::ID,
+    };
+    let args = instruction::CommitResult {
+        session_id: session_id.to_string(),
+        hash: ResultCommitment::digest(&game_session_key, &declaration, &salt),
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+fn reveal_result(
+    validator: &Validator,
+    session_id: &str,
+    declaration: WinnerDeclaration,
+    salt: [u8; 32],
+) -> Instruction {
+    let game_session_key = pda::game_session(session_id).0;
+    let accounts = accounts::RevealResult {
+        game_server: validator.game_server.pubkey(),
+        config: pda::config().0,
+        game_session: game_session_key,
+        result_commitment: pda::result_commitment(&game_session_key).0,
+        committer: validator.game_server.pubkey(),
+    };
+    let args = instruction::RevealResult {
+        session_id: session_id.to_string(),
+        declaration,
+        salt,
+    };
+    build_instruction(accounts, args, Vec::new())
+}
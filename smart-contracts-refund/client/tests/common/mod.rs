@@ -0,0 +1,663 @@
+//! Fixtures shared by the test validator suites: the accounts a session
+//! needs before its first instruction, and a [`Validator`] running the
+//! native build of the program for suites that don't measure compute.
+
+// Each suite uses its own subset of the fixtures
+#![allow(dead_code)]
+
+use std::sync::Once;
+
+use anchor_lang::solana_program::account_info::AccountInfo;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token::{
+    self,
+    solana_program::{program_option::COption, program_pack::Pack},
+};
+use solana_program_test::{processor, BanksClient, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::ed25519_instruction::new_ed25519_instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use wager_client::state::*;
+use wager_client::{
+    accounts, build_instruction, builder, decode_game_session, instruction, pda, ID,
+};
+use wager_program::utils::kill_report_message;
+
+pub const BET: u64 = 1_000_000;
+pub const LAMPORTS: u64 = 10_000_000_000;
+
+/// Test validator running the program natively, so suites run without the
+/// SBF build. Native programs aren't metered, see [`install_compute_stub`].
+pub fn native_program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::new("wager_program", ID, processor!(process_instruction));
+    program_test.prefer_bpf(false);
+    program_test
+}
+
+/// Native entry point. The program's entry ties the accounts to the lifetime
+/// of their slice, which the test validator's processor signature doesn't,
+/// so the account infos are copied into a slice that lives long enough.
+/// They share their lamports and data with the originals.
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(accounts.to_vec().into_boxed_slice());
+    wager_program::entry(program_id, accounts, data)
+}
+
+/// Test validator running the native program, with the config, a registered
+/// mint and every player funded with SOL and tokens. The game server is the
+/// config authority, only signing set key and kill report signer.
+pub struct Validator {
+    pub context: ProgramTestContext,
+    pub game_server: Keypair,
+    pub mint: Pubkey,
+}
+
+impl Validator {
+    pub async fn start(players: &[Keypair], tokens: u64) -> Self {
+        Self::start_with_config(players, tokens, |_| {}).await
+    }
+
+    /// Starts the validator with the default config as changed by `configure`
+    pub async fn start_with_config(
+        players: &[Keypair],
+        tokens: u64,
+        configure: impl FnOnce(&mut ConfigParams),
+    ) -> Self {
+        let game_server = Keypair::new();
+        let mint = Pubkey::new_unique();
+        let mut params = config_params(&game_server.pubkey());
+        configure(&mut params);
+
+        let mut program_test = native_program_test();
+        program_test.add_account(game_server.pubkey(), system_account());
+        program_test.add_account(
+            pda::config().0,
+            config_account_with(&game_server.pubkey(), &params),
+        );
+        program_test.add_account(mint, mint_account(tokens * players.len() as u64));
+        program_test.add_account(
+            get_associated_token_address(&pda::treasury().0, &mint),
+            token_account(&mint, &pda::treasury().0, 0),
+        );
+        for player in players {
+            program_test.add_account(player.pubkey(), system_account());
+            program_test.add_account(
+                get_associated_token_address(&player.pubkey(), &mint),
+                token_account(&mint, &player.pubkey(), tokens),
+            );
+        }
+
+        let context = program_test.start_with_context().await;
+        install_compute_stub();
+        let mut validator = Self {
+            context,
+            game_server,
+            mint,
+        };
+        let register = builder::register_mint(&validator.game_server.pubkey(), &mint, 0, 0);
+        validator.send(&[register], &[]).await.unwrap();
+        validator
+    }
+
+    /// Sends the instructions paid for by the game server, returning the
+    /// error and program logs of a failed transaction
+    pub async fn send(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), String> {
+        let blockhash = self.banks().get_latest_blockhash().await.unwrap();
+        let mut all_signers = vec![&self.game_server];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.game_server.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        let outcome = self
+            .banks()
+            .process_transaction_with_metadata(transaction)
+            .await
+            .map_err(|err| err.to_string())?;
+        match outcome.result {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                let logs = outcome
+                    .metadata
+                    .map(|metadata| metadata.log_messages.join("\n"))
+                    .unwrap_or_default();
+                Err(format!("{}\n{}", err, logs))
+            }
+        }
+    }
+
+    pub fn banks(&mut self) -> &mut BanksClient {
+        &mut self.context.banks_client
+    }
+
+    /// Moves the validator on to the next slot
+    pub async fn next_slot(&mut self) {
+        let slot = self.banks().get_root_slot().await.unwrap();
+        self.context.warp_to_slot(slot + 1).unwrap();
+    }
+
+    pub async fn game_session(&mut self, session_id: &str) -> GameSession {
+        let address = pda::game_session(session_id).0;
+        let account = self.banks().get_account(address).await.unwrap().unwrap();
+        decode_game_session(&address, &account.data).unwrap()
+    }
+
+    /// Moves the validator's clock on by `secs`, e.g. past a session's expiry
+    pub async fn advance_clock(&mut self, secs: i64) {
+        let mut clock: Clock = self.banks().get_sysvar().await.unwrap();
+        clock.unix_timestamp += secs;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn vault(&mut self, session_id: &str) -> Vault {
+        self.account(pda::vault(session_id).0).await.unwrap()
+    }
+
+    /// Anchor account at `address`, `None` if it doesn't exist
+    pub async fn account<T: AccountDeserialize>(&mut self, address: Pubkey) -> Option<T> {
+        let account = self.banks().get_account(address).await.unwrap()?;
+        Some(T::try_deserialize(&mut &account.data[..]).unwrap())
+    }
+
+    /// Creates a token session of `game_mode` with the default settings
+    pub async fn create_session(&mut self, session_id: &str, game_mode: GameMode) {
+        let create = builder::create_game_session(
+            &self.game_server.pubkey(),
+            &self.mint,
+            &spl_token::ID,
+            session_args(session_id, game_mode),
+        );
+        self.send(&[create], &[]).await.unwrap();
+    }
+
+    /// Seats `player` on `team` with the session bet
+    pub async fn join(
+        &mut self,
+        session_id: &str,
+        player: &Keypair,
+        team: u8,
+    ) -> Result<(), String> {
+        let game_session = self.game_session(session_id).await;
+        let join = builder::join_user(
+            &player.pubkey(),
+            session_id,
+            &game_session,
+            &spl_token::ID,
+            team,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        self.send(&[join], &[player]).await
+    }
+
+    /// Creates a session of `game_mode` and seats `players` team by team.
+    /// Every pair of opposite players then trades a kill, so no one sits the
+    /// match out and the result pays out without review.
+    pub async fn start_match(
+        &mut self,
+        session_id: &str,
+        game_mode: GameMode,
+        players: &[Keypair],
+    ) {
+        self.create_session(session_id, game_mode).await;
+        let players_per_team = game_mode.players_per_team();
+        for (index, player) in players.iter().enumerate() {
+            self.join(session_id, player, (index / players_per_team) as u8)
+                .await
+                .unwrap();
+        }
+        let (team_a, team_b) = players.split_at(players_per_team);
+        for (player_a, player_b) in team_a.iter().zip(team_b) {
+            self.record_kill(session_id, player_a.pubkey(), player_b.pubkey())
+                .await
+                .unwrap();
+            self.record_kill(session_id, player_b.pubkey(), player_a.pubkey())
+                .await
+                .unwrap();
+        }
+    }
+
+    /// Reports a kill without assists as the next round and sequence of the
+    /// session, then moves on a slot so kills stay under the per-slot limit
+    pub async fn record_kill(
+        &mut self,
+        session_id: &str,
+        killer: Pubkey,
+        victim: Pubkey,
+    ) -> Result<(), String> {
+        let game_session = self.game_session(session_id).await;
+        let killer_team = game_session.get_player_slot(killer).unwrap().0;
+        let victim_team = game_session.get_player_slot(victim).unwrap().0;
+        let assists = [Pubkey::default(); MAX_KILL_ASSISTS];
+        let round = game_session.last_kill_round + 1;
+        let sequence = game_session.report_sequence + 1;
+        let message = kill_report_message(session_id, &killer, &victim, &assists, round, sequence);
+        let signer = ed25519_dalek::Keypair::from_bytes(&self.game_server.to_bytes()).unwrap();
+        let record_kill = builder::record_kill(
+            &self.game_server.pubkey(),
+            session_id,
+            &game_session,
+            killer_team,
+            killer,
+            victim_team,
+            victim,
+            assists,
+            round,
+            sequence,
+        );
+        let sent = self
+            .send(
+                &[new_ed25519_instruction(&signer, &message), record_kill],
+                &[],
+            )
+            .await;
+        self.next_slot().await;
+        sent
+    }
+
+    pub async fn token_balance(&mut self, address: Pubkey) -> u64 {
+        self.banks()
+            .get_packed_account_data::<spl_token::state::Account>(address)
+            .await
+            .map(|account| account.amount)
+            .unwrap_or(0)
+    }
+}
+
+/// Sells `user` one purchase of spawns
+pub fn pay_to_spawn(session_id: &str, game_session: &GameSession, user: &Pubkey) -> Instruction {
+    let args = instruction::PayToSpawn {
+        session_id: session_id.to_string(),
+        team: game_session.get_player_slot(*user).unwrap().0,
+    };
+    let accounts = pay_to_spawn_accounts(session_id, game_session, user);
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// Sells `user` `count` spawns at the session's spawn price
+pub fn purchase_spawns(
+    session_id: &str,
+    game_session: &GameSession,
+    user: &Pubkey,
+    count: u16,
+) -> Instruction {
+    let args = instruction::PurchaseSpawns {
+        session_id: session_id.to_string(),
+        team: game_session.get_player_slot(*user).unwrap().0,
+        count,
+    };
+    let accounts = pay_to_spawn_accounts(session_id, game_session, user);
+    build_instruction(accounts, args, Vec::new())
+}
+
+fn pay_to_spawn_accounts(
+    session_id: &str,
+    game_session: &GameSession,
+    user: &Pubkey,
+) -> accounts::PayToSpawn {
+    let mint = &game_session.mint;
+    accounts::PayToSpawn {
+        user: *user,
+        game_server: game_session.authority,
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        user_token_account: get_associated_token_address(user, mint),
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, &spl_token::ID),
+        player_stats: pda::player_stats(user).0,
+        player_ban: pda::player_ban(user).0,
+        mint: *mint,
+        token_program: spl_token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: anchor_lang::system_program::ID,
+    }
+}
+
+/// Tops up `user`'s spawn budget by `amount` worth of base bets
+pub fn top_up(
+    session_id: &str,
+    game_session: &GameSession,
+    user: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let mint = &game_session.mint;
+    let accounts = accounts::TopUp {
+        user: *user,
+        game_server: game_session.authority,
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        user_token_account: get_associated_token_address(user, mint),
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, &spl_token::ID),
+        player_stats: pda::player_stats(user).0,
+        mint: *mint,
+        token_program: spl_token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: anchor_lang::system_program::ID,
+    };
+    let args = instruction::TopUp {
+        session_id: session_id.to_string(),
+        team: game_session.get_player_slot(*user).unwrap().0,
+        amount,
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// Reclaims `player`'s deposit from an expired session
+pub fn claim_timeout_refund(
+    session_id: &str,
+    game_session: &GameSession,
+    player: &Pubkey,
+) -> Instruction {
+    let mint = &game_session.mint;
+    let game_session_key = pda::game_session(session_id).0;
+    let accounts = accounts::ClaimTimeoutRefund {
+        player: *player,
+        game_session: game_session_key,
+        player_token_account: Some(get_associated_token_address(player, mint)),
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, &spl_token::ID),
+        active_exposure: Some(pda::active_exposure(player).0),
+        bet_receipt: pda::bet_receipt(&game_session_key, player).0,
+        mint: *mint,
+        token_program: spl_token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+    };
+    let args = instruction::ClaimTimeoutRefund {
+        session_id: session_id.to_string(),
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// Tears down a settled session, returning its rent to the creator
+pub fn close_session(session_id: &str, game_session: &GameSession) -> Instruction {
+    let mint = &game_session.mint;
+    let accounts = accounts::CloseSession {
+        game_server: game_session.authority,
+        config: pda::config().0,
+        treasury: pda::treasury().0,
+        game_session: pda::game_session(session_id).0,
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, &spl_token::ID),
+        session_metadata: None,
+        announcements: None,
+        match_result: None,
+        mint: *mint,
+        token_program: spl_token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: anchor_lang::system_program::ID,
+    };
+    let args = instruction::CloseSession {
+        session_id: session_id.to_string(),
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+static INSTALL_COMPUTE_STUB: Once = Once::new();
+
+/// Reports a full compute budget to the native program, whose syscall stubs
+/// otherwise report none left and stop paged distributions before their
+/// first payee. Call after starting the test validator, which installs the
+/// stubs this wraps.
+pub fn install_compute_stub() {
+    INSTALL_COMPUTE_STUB.call_once(|| {
+        let inner = set_syscall_stubs(Box::new(NoStubs));
+        set_syscall_stubs(Box::new(ComputeStub(inner)));
+    });
+}
+
+/// Placeholder holding the stubs slot while they are wrapped
+struct NoStubs;
+
+impl SyscallStubs for NoStubs {}
+
+/// Test validator stubs with compute reported as unlimited
+struct ComputeStub(Box<dyn SyscallStubs>);
+
+impl SyscallStubs for ComputeStub {
+    fn sol_log(&self, message: &str) {
+        self.0.sol_log(message)
+    }
+
+    fn sol_log_compute_units(&self) {
+        self.0.sol_log_compute_units()
+    }
+
+    fn sol_remaining_compute_units(&self) -> u64 {
+        DISTRIBUTION_CU_BUDGET
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        self.0
+            .sol_invoke_signed(instruction, account_infos, signers_seeds)
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_clock_sysvar(var_addr)
+    }
+
+    fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_epoch_schedule_sysvar(var_addr)
+    }
+
+    fn sol_get_fees_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_fees_sysvar(var_addr)
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_rent_sysvar(var_addr)
+    }
+
+    fn sol_get_epoch_rewards_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_epoch_rewards_sysvar(var_addr)
+    }
+
+    fn sol_get_last_restart_slot(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_last_restart_slot(var_addr)
+    }
+
+    unsafe fn sol_memcpy(&self, dst: *mut u8, src: *const u8, n: usize) {
+        self.0.sol_memcpy(dst, src, n)
+    }
+
+    unsafe fn sol_memmove(&self, dst: *mut u8, src: *const u8, n: usize) {
+        self.0.sol_memmove(dst, src, n)
+    }
+
+    unsafe fn sol_memcmp(&self, s1: *const u8, s2: *const u8, n: usize, result: *mut i32) {
+        self.0.sol_memcmp(s1, s2, n, result)
+    }
+
+    unsafe fn sol_memset(&self, s: *mut u8, c: u8, n: usize) {
+        self.0.sol_memset(s, c, n)
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        self.0.sol_get_return_data()
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        self.0.sol_set_return_data(data)
+    }
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        self.0.sol_log_data(fields)
+    }
+
+    fn sol_get_processed_sibling_instruction(&self, index: usize) -> Option<Instruction> {
+        self.0.sol_get_processed_sibling_instruction(index)
+    }
+
+    fn sol_get_stack_height(&self) -> u64 {
+        self.0.sol_get_stack_height()
+    }
+}
+
+pub fn session_args(
+    session_id: &str,
+    game_mode: GameMode,
+) -> wager_client::instruction::CreateGameSession {
+    wager_client::instruction::CreateGameSession {
+        session_id: session_id.to_string(),
+        bet_amount: BET,
+        game_mode,
+        team_count: if game_mode.is_free_for_all() { 0 } else { 2 },
+        spawn_earnings_bps: 0,
+        log_capacity: 0,
+        team_a_capacity: 0,
+        team_b_capacity: 0,
+        payout_schedule: PayoutSchedule::WinnerTeamEqual,
+        metadata: None,
+        access: SessionAccess::Open,
+        max_kills_per_player: 0,
+        max_match_kills: 0,
+        series_length: 0,
+        min_rating: 0,
+        max_rating: 0,
+        refund_policy: RefundPolicy::FullAnytimeBeforeStart,
+        requires_pass: None,
+        join_window: None,
+        win_condition: WinCondition::ServerDecision,
+        freeroll: false,
+        assist_weight_bps: 0,
+        min_ready_players: 0,
+        max_stake: 0,
+    }
+}
+
+pub fn system_account() -> Account {
+    Account::new(LAMPORTS, 0, &solana_sdk::system_program::ID)
+}
+
+/// Config as `initialize_config` would write it, which the test validator
+/// can't run without an upgradeable deployment to check the authority against
+pub fn config_account(game_server: &Pubkey) -> Account {
+    config_account_with(game_server, &config_params(game_server))
+}
+
+/// Config the suites run under: the game server is the authority, only
+/// signing set key and kill report signer, with a 5% rake to the treasury
+pub fn config_params(game_server: &Pubkey) -> ConfigParams {
+    ConfigParams {
+        min_bet: 1,
+        max_bet: u64::MAX / 1_000,
+        session_timeout_secs: 86_400,
+        kill_report_signer: *game_server,
+        fee_bps: 500,
+        cancellation_fee_bps: 0,
+        usd_min_bet: 0,
+        usd_max_bet: 0,
+        max_price_age_secs: 0,
+        arbiter: Pubkey::default(),
+        dispute_window_secs: 0,
+        game_server_keys: vec![*game_server],
+        game_server_threshold: 1,
+        referral_bps: 0,
+        max_top_up_multiple: 1,
+        map_pool_size: 0,
+        jackpot_bps: 0,
+        jackpot_interval: 0,
+        rewards_mint: Pubkey::default(),
+        streak_milestone: 0,
+        streak_bonus: 0,
+        elo_k_factor: 0,
+        creator_penalty_bps: 0,
+        trophy_uri: String::new(),
+        cpi_callers: Vec::new(),
+        creation_fee_lamports: 0,
+        max_exposure: 0,
+        fee_split: Vec::new(),
+        draw_fee_bps: 0,
+        forfeit_timeout_secs: 0,
+        forfeit_penalty_bps: 0,
+        freeroll_sponsors: Vec::new(),
+        yield_programs: Vec::new(),
+        yield_treasury_bps: 0,
+        clock_skew_secs: 0,
+        bond_slash_bps: 0,
+        arbiters: Vec::new(),
+        commit_reveal_results: false,
+    }
+}
+
+/// Config account holding `params`, see [`config_account`]
+pub fn config_account_with(game_server: &Pubkey, params: &ConfigParams) -> Account {
+    let mut data = vec![0; ProgramConfig::LEN];
+    data[..8].copy_from_slice(&ProgramConfig::DISCRIMINATOR);
+    let mut config = ProgramConfig::try_deserialize(&mut &data[..]).unwrap();
+    config.authority = *game_server;
+    config.bump = pda::config().1;
+    config.apply(params);
+
+    let mut data = Vec::with_capacity(ProgramConfig::LEN);
+    config.try_serialize(&mut data).unwrap();
+    data.resize(ProgramConfig::LEN, 0);
+    program_account(data, ID)
+}
+
+pub fn mint_account(supply: u64) -> Account {
+    let mint = spl_token::state::Mint {
+        mint_authority: COption::None,
+        supply,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    mint.pack_into_slice(&mut data);
+    program_account(data, spl_token::ID)
+}
+
+pub fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let account = spl_token::state::Account {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        delegate: COption::None,
+        state: spl_token::state::AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    };
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    account.pack_into_slice(&mut data);
+    program_account(data, spl_token::ID)
+}
+
+/// Rent-exempt account holding `data`
+pub fn program_account(data: Vec<u8>, owner: Pubkey) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
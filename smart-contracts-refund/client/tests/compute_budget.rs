@@ -0,0 +1,274 @@
+//! Compute unit benchmarks: creates, joins, kills in and distributes sessions
+//! of growing rosters on the SBF build of the program, printing the units
+//! each instruction consumed and failing once one exceeds its ceiling.
+//!
+//! The native build doesn't meter compute, so the benchmarks need
+//! `target/deploy/wager_program.so` from `anchor build`, or the directory in
+//! `SBF_OUT_DIR`. They are ignored by a plain `cargo test` and run in CI
+//! after the program build with
+//!
+//! ```text
+//! cargo test -p wager-client --test compute_budget -- --ignored
+//! ```
+//!
+//! failing if the SBF build is missing rather than passing unmeasured.
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use solana_program_test::{find_file, tokio, BanksClient, ProgramTest};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::ed25519_instruction::new_ed25519_instruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use wager_client::state::*;
+use wager_client::{builder, decode_game_session, pda, ID};
+use wager_program::utils::kill_report_message;
+
+/// Ceilings the instructions must stay under. Lower them as optimizations
+/// land so a regression fails here before it fails on a full lobby.
+const CREATE_CU_CEILING: u64 = 120_000;
+const JOIN_CU_CEILING: u64 = 100_000;
+const KILL_CU_CEILING: u64 = 40_000;
+const DISTRIBUTE_CU_CEILING: u64 = DISTRIBUTION_CU_BUDGET;
+
+/// Rosters benchmarked, smallest to largest
+const GAME_MODES: [GameMode; 3] = [
+    GameMode::WinnerTakesAllOneVsOne,
+    GameMode::WinnerTakesAllThreeVsThree,
+    GameMode::WinnerTakesAllFiveVsFive,
+];
+
+/// Units each benchmarked instruction consumed for one roster
+struct Usage {
+    create: u64,
+    join: u64, // Most expensive join, the one filling the lobby
+    kill: u64, // Most expensive kill report
+    distribute: u64,
+}
+
+struct Bench {
+    banks: BanksClient,
+    game_server: Keypair,
+    mint: Pubkey,
+}
+
+impl Bench {
+    /// Starts a test validator with the config, a registered mint and every
+    /// player funded with SOL and one bet, the game server being the config
+    /// authority, only signing set key and kill report signer
+    async fn start(players: &[Keypair]) -> Self {
+        let game_server = Keypair::new();
+        let mint = Pubkey::new_unique();
+
+        let mut program_test = ProgramTest::new("wager_program", ID, None);
+        program_test.prefer_bpf(true);
+        program_test.add_account(game_server.pubkey(), system_account());
+        program_test.add_account(pda::config().0, config_account(&game_server.pubkey()));
+        program_test.add_account(mint, mint_account(BET * players.len() as u64));
+        program_test.add_account(
+            get_associated_token_address(&pda::treasury().0, &mint),
+            token_account(&mint, &pda::treasury().0, 0),
+        );
+        for player in players {
+            program_test.add_account(player.pubkey(), system_account());
+            program_test.add_account(
+                get_associated_token_address(&player.pubkey(), &mint),
+                token_account(&mint, &player.pubkey(), BET),
+            );
+        }
+
+        let (banks, _, _) = program_test.start().await;
+        let mut bench = Self {
+            banks,
+            game_server,
+            mint,
+        };
+        let register = builder::register_mint(&bench.game_server.pubkey(), &mint, 0, 0);
+        bench.run(&[register], &[]).await;
+        bench
+    }
+
+    /// Sends the instructions paid for by the game server, returning the
+    /// compute units the transaction consumed
+    async fn run(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> u64 {
+        let mut all = vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000)];
+        all.extend_from_slice(instructions);
+        let mut all_signers = vec![&self.game_server];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            &all,
+            Some(&self.game_server.pubkey()),
+            &all_signers,
+            self.banks.get_latest_blockhash().await.unwrap(),
+        );
+
+        let outcome = self
+            .banks
+            .process_transaction_with_metadata(transaction)
+            .await
+            .unwrap();
+        let metadata = outcome.metadata.unwrap();
+        if let Err(err) = outcome.result {
+            panic!("{}\n{}", err, metadata.log_messages.join("\n"));
+        }
+        metadata.compute_units_consumed
+    }
+
+    async fn game_session(&mut self, session_id: &str) -> GameSession {
+        let address = pda::game_session(session_id).0;
+        let account = self.banks.get_account(address).await.unwrap().unwrap();
+        decode_game_session(&address, &account.data).unwrap()
+    }
+
+    /// Plays a session of `game_mode` through, team 0 winning
+    async fn play(&mut self, game_mode: GameMode, players: &[Keypair]) -> Usage {
+        let session_id = format!("bench-{}", game_mode as u8);
+        let game_server = self.game_server.pubkey();
+
+        let create = builder::create_game_session(
+            &game_server,
+            &self.mint,
+            &spl_token::ID,
+            session_args(&session_id, game_mode),
+        );
+        let create = self.run(&[create], &[]).await;
+
+        let players_per_team = game_mode.players_per_team();
+        let mut join = 0;
+        for (index, player) in players.iter().enumerate() {
+            let game_session = self.game_session(&session_id).await;
+            let instruction = builder::join_user(
+                &player.pubkey(),
+                &session_id,
+                &game_session,
+                &spl_token::ID,
+                (index / players_per_team) as u8,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+            join = join.max(self.run(&[instruction], &[player]).await);
+        }
+
+        // Every pair of opposite players trades a kill, so no one sits the
+        // match out and the result pays out without review
+        let signer = ed25519_dalek::Keypair::from_bytes(&self.game_server.to_bytes()).unwrap();
+        let assists = [Pubkey::default(); MAX_KILL_ASSISTS];
+        let mut kill = 0;
+        for (index, killer) in players[..players_per_team].iter().enumerate() {
+            let killer = killer.pubkey();
+            let victim = players[players_per_team + index].pubkey();
+            let (round, sequence) = (index as u64 + 1, index as u64 + 1);
+            let message =
+                kill_report_message(&session_id, &killer, &victim, &assists, round, sequence);
+            let game_session = self.game_session(&session_id).await;
+            let record_kill = builder::record_kill(
+                &game_server,
+                &session_id,
+                &game_session,
+                0,
+                killer,
+                1,
+                victim,
+                assists,
+                round,
+                sequence,
+            );
+            let used = self
+                .run(
+                    &[new_ed25519_instruction(&signer, &message), record_kill],
+                    &[],
+                )
+                .await;
+            kill = kill.max(used);
+        }
+
+        let game_session = self.game_session(&session_id).await;
+        let distribute = builder::distribute_winnings(
+            &game_server,
+            &session_id,
+            &game_session,
+            &spl_token::ID,
+            WinnerDeclaration {
+                result: MatchResult::Win,
+                team: 0,
+            },
+            false,
+        )
+        .unwrap();
+        let distribute = self.run(&[distribute], &[]).await;
+
+        Usage {
+            create,
+            join,
+            kill,
+            distribute,
+        }
+    }
+}
+
+#[test]
+#[ignore = "needs the SBF build: anchor build, then cargo test -p wager-client --test compute_budget -- --ignored"]
+fn instructions_stay_within_compute_ceilings() {
+    assert!(
+        sbf_build_available(),
+        "wager_program.so not found in SBF_OUT_DIR, run anchor build first"
+    );
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    for game_mode in GAME_MODES {
+        let players: Vec<Keypair> = (0..2 * game_mode.players_per_team())
+            .map(|_| Keypair::new())
+            .collect();
+        let usage = runtime.block_on(async {
+            let mut bench = Bench::start(&players).await;
+            bench.play(game_mode, &players).await
+        });
+
+        println!(
+            "{:>2} players: create {:>7} join {:>7} kill {:>7} distribute {:>7}",
+            players.len(),
+            usage.create,
+            usage.join,
+            usage.kill,
+            usage.distribute
+        );
+        for (name, used, ceiling) in [
+            ("create_game_session", usage.create, CREATE_CU_CEILING),
+            ("join_user", usage.join, JOIN_CU_CEILING),
+            ("record_kill", usage.kill, KILL_CU_CEILING),
+            (
+                "distribute_winnings",
+                usage.distribute,
+                DISTRIBUTE_CU_CEILING,
+            ),
+        ] {
+            assert!(
+                used <= ceiling,
+                "{} with {} players used {} CU, over its {} CU ceiling",
+                name,
+                players.len(),
+                used,
+                ceiling
+            );
+        }
+    }
+}
+
+/// Points the program loader at the SBF build, returning whether there is one
+fn sbf_build_available() -> bool {
+    if std::env::var_os("SBF_OUT_DIR").is_none() && std::env::var_os("BPF_OUT_DIR").is_none() {
+        std::env::set_var(
+            "SBF_OUT_DIR",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../target/deploy"),
+        );
+    }
+    find_file("wager_program.so").is_some()
+}
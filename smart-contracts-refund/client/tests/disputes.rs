@@ -0,0 +1,214 @@
+//! Disputed results: an arbiter overturning the game server's result slashes
+//! the operator's bond to the players it wronged, once per session, and a
+//! council corrects a result once a strict majority of its members agree.
+
+mod common;
+
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{accounts, build_instruction, instruction, pda};
+
+const DISPUTE_WINDOW_SECS: i64 = 3_600;
+const BOND_SLASH_BPS: u16 = 5_000;
+const BOND: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn overturned_result_slashes_the_bond_to_the_wronged_team_once() {
+    let players: Vec<Keypair> = (0..6).map(|_| Keypair::new()).collect();
+    let arbiter = Keypair::new();
+    let mut validator = Validator::start_with_config(&players, BET, |params| {
+        params.arbiter = arbiter.pubkey();
+        params.dispute_window_secs = DISPUTE_WINDOW_SECS;
+        params.bond_slash_bps = BOND_SLASH_BPS;
+    })
+    .await;
+    let post_bond = post_bond(&validator, BOND);
+    validator.send(&[post_bond], &[]).await.unwrap();
+    let session_id = "slashed";
+    validator
+        .start_match(session_id, GameMode::WinnerTakesAllThreeVsThree, &players)
+        .await;
+
+    // The game server reports team 0, the arbiter finds team 1 won
+    let submit = submit_result(&validator, session_id, 0);
+    validator.send(&[submit], &[]).await.unwrap();
+    let mut lamports = Vec::new();
+    for player in &players {
+        lamports.push(
+            validator
+                .banks()
+                .get_balance(player.pubkey())
+                .await
+                .unwrap(),
+        );
+    }
+    let game_session = validator.game_session(session_id).await;
+    let overturn = override_result(session_id, &game_session, &arbiter, 1);
+    validator.send(&[overturn], &[&arbiter]).await.unwrap();
+
+    // Half the bond is split across the wronged team, the reported winners
+    // get nothing
+    let share = BOND * BOND_SLASH_BPS as u64 / 10_000 / 3;
+    for (index, player) in players.iter().enumerate() {
+        let balance = validator
+            .banks()
+            .get_balance(player.pubkey())
+            .await
+            .unwrap();
+        let expected = if index < 3 { 0 } else { share };
+        assert_eq!(balance - lamports[index], expected);
+    }
+    let bond_key = pda::server_bond(&validator.game_server.pubkey()).0;
+    let bond: ServerBond = validator.account(bond_key).await.unwrap();
+    assert_eq!(bond.amount, BOND - 3 * share);
+    assert_eq!(bond.total_slashed, 3 * share);
+
+    // Overturning the session again can't slash the bond a second time
+    let game_session = validator.game_session(session_id).await;
+    let overturn = override_result(session_id, &game_session, &arbiter, 0);
+    let err = validator.send(&[overturn], &[&arbiter]).await.unwrap_err();
+    assert!(err.contains("Error Code: BondAlreadySlashed."), "{}", err);
+    let bond: ServerBond = validator.account(bond_key).await.unwrap();
+    assert_eq!(bond.total_slashed, 3 * share);
+}
+
+#[tokio::test]
+async fn council_majority_of_distinct_members_corrects_the_result() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let council: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+    let members = council.iter().map(|member| member.pubkey()).collect();
+    let mut validator = Validator::start_with_config(&players, BET, |params| {
+        params.arbiters = members;
+        params.dispute_window_secs = DISPUTE_WINDOW_SECS;
+    })
+    .await;
+    let outsider = Keypair::new();
+    for arbiter in council.iter().chain([&outsider]) {
+        validator
+            .context
+            .set_account(&arbiter.pubkey(), &system_account().into());
+    }
+    let session_id = "council";
+    validator
+        .start_match(session_id, GameMode::WinnerTakesAllOneVsOne, &players)
+        .await;
+    let submit = submit_result(&validator, session_id, 0);
+    validator.send(&[submit], &[]).await.unwrap();
+    let game_session = validator.game_session(session_id).await;
+
+    // A key off the council has no vote
+    let vote = cast_dispute_vote(session_id, &game_session, &outsider, 1);
+    let err = validator.send(&[vote], &[&outsider]).await.unwrap_err();
+    assert!(err.contains("Error Code: NotOnArbiterCouncil."), "{}", err);
+
+    // A member voting twice is still one vote short of the majority of two
+    for _ in 0..2 {
+        let vote = cast_dispute_vote(session_id, &game_session, &council[0], 1);
+        validator.send(&[vote], &[&council[0]]).await.unwrap();
+        validator.next_slot().await;
+    }
+    let game_session_key = pda::game_session(session_id).0;
+    let dispute_votes: DisputeVotes = validator
+        .account(pda::dispute_votes(&game_session_key).0)
+        .await
+        .unwrap();
+    assert_eq!(dispute_votes.votes.len(), 1);
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.status().unwrap() == SessionStatus::ResultSubmitted);
+    assert_eq!(game_session.winning_team, 0);
+    let dispute_deadline = game_session.dispute_deadline;
+
+    // A second member makes the majority, correcting the result and closing
+    // the window
+    let vote = cast_dispute_vote(session_id, &game_session, &council[1], 1);
+    validator.send(&[vote], &[&council[1]]).await.unwrap();
+    let game_session = validator.game_session(session_id).await;
+    assert_eq!(game_session.winning_team, 1);
+    assert!(game_session.match_result().unwrap() == MatchResult::Win);
+    assert!(game_session.dispute_deadline < dispute_deadline);
+}
+
+fn post_bond(validator: &Validator, amount: u64) -> Instruction {
+    let operator = validator.game_server.pubkey();
+    let accounts = accounts::PostBond {
+        operator,
+        server_bond: pda::server_bond(&operator).0,
+        system_program: anchor_lang::system_program::ID,
+    };
+    build_instruction(accounts, instruction::PostBond { amount }, Vec::new())
+}
+
+fn submit_result(validator: &Validator, session_id: &str, team: u8) -> Instruction {
+    let accounts = accounts::SubmitResult {
+        game_server: validator.game_server.pubkey(),
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        approval: None,
+    };
+    let args = instruction::SubmitResult {
+        session_id: session_id.to_string(),
+        declaration: WinnerDeclaration {
+            result: MatchResult::Win,
+            team,
+        },
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+/// The arbiter's corrected result, with the wallets of `team`'s seated
+/// players to take any slashed bond
+fn override_result(
+    session_id: &str,
+    game_session: &GameSession,
+    arbiter: &Keypair,
+    team: u8,
+) -> Instruction {
+    let accounts = accounts::OverrideResult {
+        arbiter: arbiter.pubkey(),
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        approval: None,
+        server_bond: pda::server_bond(&game_session.authority).0,
+    };
+    let args = instruction::OverrideResult {
+        session_id: session_id.to_string(),
+        winning_team: team,
+    };
+    build_instruction(accounts, args, team_wallets(game_session, team))
+}
+
+fn team_wallets(game_session: &GameSession, team: u8) -> Vec<AccountMeta> {
+    game_session
+        .team(team)
+        .unwrap()
+        .players
+        .iter()
+        .filter(|player| **player != Default::default())
+        .map(|player| AccountMeta::new(*player, false))
+        .collect()
+}
+
+fn cast_dispute_vote(
+    session_id: &str,
+    game_session: &GameSession,
+    arbiter: &Keypair,
+    team: u8,
+) -> Instruction {
+    let game_session_key = pda::game_session(session_id).0;
+    let accounts = accounts::CastDisputeVote {
+        arbiter: arbiter.pubkey(),
+        config: pda::config().0,
+        game_session: game_session_key,
+        dispute_votes: pda::dispute_votes(&game_session_key).0,
+        server_bond: pda::server_bond(&game_session.authority).0,
+        system_program: anchor_lang::system_program::ID,
+    };
+    let args = instruction::CastDisputeVote {
+        session_id: session_id.to_string(),
+        winning_team: team,
+    };
+    build_instruction(accounts, args, team_wallets(game_session, team))
+}
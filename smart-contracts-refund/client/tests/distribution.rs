@@ -0,0 +1,254 @@
+//! Paged distributions pay every winner exactly once: pages are taken in
+//! order from the session's cursor, a page can't be replayed or skipped
+//! ahead, and each payee's accounts must all belong to that payee. A payee
+//! whose token account can't take the transfer is paid once they claim it.
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{accounts, build_instruction, builder, instruction, pda};
+use wager_program::instructions::distribution_payees;
+
+/// Remaining accounts per payee: wallet, payout destination, statistics and
+/// bet receipt
+const PAYEE_ACCOUNTS: usize = 4;
+
+const WINNER: WinnerDeclaration = WinnerDeclaration {
+    result: MatchResult::Win,
+    team: 0,
+};
+
+/// Distribution of the next `len` payees from the session's cursor, the
+/// whole rest of the list when `len` covers it
+fn page(
+    validator: &Validator,
+    session_id: &str,
+    game_session: &GameSession,
+    len: usize,
+) -> Instruction {
+    let mut distribute = builder::distribute_winnings(
+        &validator.game_server.pubkey(),
+        session_id,
+        game_session,
+        &spl_token::ID,
+        WINNER,
+        false,
+    )
+    .unwrap();
+    let (payees, _, _) = distribution_payees(game_session, WINNER.team).unwrap();
+    let owed = payees.len() - game_session.distribution_cursor as usize;
+    if len < owed {
+        let payee_accounts = PAYEE_ACCOUNTS * owed + game_session.fee_split().len();
+        let fixed = distribute.accounts.len() - payee_accounts;
+        distribute.accounts.truncate(fixed + PAYEE_ACCOUNTS * len);
+    }
+    distribute
+}
+
+/// Offset of the first payee account of a page built by [`page`]
+fn first_payee_account(distribute: &Instruction, len: usize) -> usize {
+    distribute.accounts.len() - PAYEE_ACCOUNTS * len
+}
+
+async fn balances(validator: &mut Validator, players: &[Keypair]) -> Vec<u64> {
+    let mut balances = Vec::new();
+    for player in players {
+        let token_account = get_associated_token_address(&player.pubkey(), &validator.mint);
+        balances.push(validator.token_balance(token_account).await);
+    }
+    balances
+}
+
+#[tokio::test]
+async fn pages_pay_every_winner_once_in_order() {
+    let players: Vec<Keypair> = (0..10).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start(&players, BET).await;
+    let session_id = "paged";
+    validator
+        .start_match(session_id, GameMode::WinnerTakesAllFiveVsFive, &players)
+        .await;
+    let game_session = validator.game_session(session_id).await;
+    let (payees, _, _) = distribution_payees(&game_session, WINNER.team).unwrap();
+    assert_eq!(payees.len(), 5);
+
+    // Pages of two, three calls in all
+    let first = page(&validator, session_id, &game_session, 2);
+    validator
+        .send(std::slice::from_ref(&first), &[])
+        .await
+        .unwrap();
+    let game_session = validator.game_session(session_id).await;
+    assert_eq!(game_session.distribution_cursor, 2);
+    assert!(game_session.status().unwrap() == SessionStatus::Settling);
+
+    // Replaying the paid page is rejected before anyone is paid again
+    validator.next_slot().await;
+    let paid = balances(&mut validator, &players).await;
+    let err = validator.send(&[first], &[]).await.unwrap_err();
+    assert!(err.contains("Error Code: InvalidPlayer."), "{}", err);
+
+    // So is a page skipping ahead of the cursor
+    let mut skipped = page(&validator, session_id, &game_session, 3);
+    let start = first_payee_account(&skipped, 3);
+    skipped.accounts.drain(start..start + PAYEE_ACCOUNTS);
+    let err = validator.send(&[skipped], &[]).await.unwrap_err();
+    assert!(err.contains("Error Code: InvalidPlayer."), "{}", err);
+    assert_eq!(balances(&mut validator, &players).await, paid);
+
+    let second = page(&validator, session_id, &game_session, 2);
+    validator.send(&[second], &[]).await.unwrap();
+    let game_session = validator.game_session(session_id).await;
+    assert_eq!(game_session.distribution_cursor, 4);
+    let last = page(&validator, session_id, &game_session, 2);
+    validator.send(&[last], &[]).await.unwrap();
+    let game_session = validator.game_session(session_id).await;
+    assert_eq!(game_session.distribution_cursor, 5);
+    assert!(game_session.status().unwrap() == SessionStatus::Completed);
+
+    // Every winner was paid their share once, the losers nothing
+    let balances = balances(&mut validator, &players).await;
+    for (player, amount) in &payees {
+        let index = players.iter().position(|p| p.pubkey() == *player).unwrap();
+        assert_eq!(balances[index], *amount);
+    }
+    assert!(balances[5..].iter().all(|balance| *balance == 0));
+    let vault = validator.vault(session_id).await;
+    assert_eq!(
+        vault.total_paid_out,
+        payees.iter().map(|(_, amount)| amount).sum::<u64>()
+    );
+    assert_eq!(
+        vault.total_paid_out + vault.total_fees,
+        vault.total_deposited
+    );
+}
+
+#[tokio::test]
+async fn payee_accounts_must_all_belong_to_the_payee() {
+    let players: Vec<Keypair> = (0..10).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start(&players, BET).await;
+    let session_id = "paged-accounts";
+    validator
+        .start_match(session_id, GameMode::WinnerTakesAllFiveVsFive, &players)
+        .await;
+    let game_session = validator.game_session(session_id).await;
+
+    // Each of the first payee's accounts in turn swapped for the second's
+    for (position, error) in [
+        (0, "InvalidPlayer"),
+        (1, "InvalidPlayerTokenAccount"),
+        (2, "InvalidPlayerStats"),
+        (3, "BetReceiptMismatch"),
+    ] {
+        let mut distribute = page(&validator, session_id, &game_session, 2);
+        let start = first_payee_account(&distribute, 2);
+        distribute
+            .accounts
+            .swap(start + position, start + PAYEE_ACCOUNTS + position);
+        let err = validator.send(&[distribute], &[]).await.unwrap_err();
+        let code = format!("Error Code: {}.", error);
+        assert!(err.contains(&code), "account {}: {}", position, err);
+    }
+
+    // Nothing was paid and the session still settles from its first payee
+    assert!(balances(&mut validator, &players)
+        .await
+        .iter()
+        .all(|balance| *balance == 0));
+    let distribute = page(&validator, session_id, &game_session, 5);
+    validator.send(&[distribute], &[]).await.unwrap();
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.status().unwrap() == SessionStatus::Completed);
+}
+
+#[tokio::test]
+async fn closed_token_account_payout_is_held_and_claimed_once() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start(&players, BET).await;
+    let session_id = "unclaimed";
+    validator
+        .start_match(session_id, GameMode::WinnerTakesAllOneVsOne, &players)
+        .await;
+
+    // The winner closes their emptied token account before the payout
+    let winner = &players[0];
+    let winner_token_account = get_associated_token_address(&winner.pubkey(), &validator.mint);
+    let close = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &winner_token_account,
+        &winner.pubkey(),
+        &winner.pubkey(),
+        &[],
+    )
+    .unwrap();
+    validator.send(&[close], &[winner]).await.unwrap();
+
+    // The distribution completes, holding the payout back in the vault
+    let game_session = validator.game_session(session_id).await;
+    let (payees, _, _) = distribution_payees(&game_session, WINNER.team).unwrap();
+    let payout = payees[0].1;
+    let distribute = page(&validator, session_id, &game_session, 1);
+    validator.send(&[distribute], &[]).await.unwrap();
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.status().unwrap() == SessionStatus::Completed);
+    let game_session_key = pda::game_session(session_id).0;
+    let unclaimed_payout_key = pda::unclaimed_payout(&game_session_key).0;
+    let unclaimed_payout: UnclaimedPayout = validator.account(unclaimed_payout_key).await.unwrap();
+    assert_eq!(unclaimed_payout.entries.len(), 1);
+    assert_eq!(unclaimed_payout.entries[0].player, winner.pubkey());
+    assert_eq!(unclaimed_payout.entries[0].amount, payout);
+    let vault_token_account = pda::vault_token_account(session_id, &validator.mint, &spl_token::ID);
+    assert_eq!(validator.token_balance(vault_token_account).await, payout);
+
+    // Claimed to a fresh token account, once
+    let destination = Pubkey::new_unique();
+    let account = token_account(&validator.mint, &winner.pubkey(), 0);
+    validator.context.set_account(&destination, &account.into());
+    let claim = claim_unclaimed(session_id, &game_session, &winner.pubkey(), &destination);
+    validator.send(std::slice::from_ref(&claim), &[winner]).await.unwrap();
+    assert_eq!(validator.token_balance(destination).await, payout);
+    assert_eq!(validator.token_balance(vault_token_account).await, 0);
+    let vault = validator.vault(session_id).await;
+    assert_eq!(vault.total_paid_out, payout);
+    assert_eq!(
+        vault.total_paid_out + vault.total_fees,
+        vault.total_deposited
+    );
+
+    validator.next_slot().await;
+    let err = validator.send(&[claim], &[winner]).await.unwrap_err();
+    assert!(err.contains("Error Code: NothingToClaim."), "{}", err);
+    assert_eq!(validator.token_balance(destination).await, payout);
+}
+
+fn claim_unclaimed(
+    session_id: &str,
+    game_session: &GameSession,
+    player: &Pubkey,
+    destination: &Pubkey,
+) -> Instruction {
+    let mint = &game_session.mint;
+    let game_session_key = pda::game_session(session_id).0;
+    let accounts = accounts::ClaimUnclaimed {
+        player: *player,
+        config: pda::config().0,
+        game_session: game_session_key,
+        unclaimed_payout: pda::unclaimed_payout(&game_session_key).0,
+        player_token_account: *destination,
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, &spl_token::ID),
+        mint: *mint,
+        token_program: spl_token::ID,
+    };
+    let args = instruction::ClaimUnclaimed {
+        session_id: session_id.to_string(),
+    };
+    build_instruction(accounts, args, Vec::new())
+}
@@ -0,0 +1,192 @@
+//! Players out of a session stay out: an eliminated player can't buy spawns,
+//! top up or score, and a kicked player can't take a seat again by joining or
+//! substituting, however many kicks the session has seen since.
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{accounts, build_instruction, instruction, pda};
+
+/// Players kicked before the first one tries to rejoin, more than the
+/// session's old kick list held
+const KICKS: usize = 9;
+
+#[tokio::test]
+async fn eliminated_player_cannot_buy_spawns_or_score() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start(&players, 4 * BET).await;
+    let session_id = "eliminated";
+    validator
+        .create_session(session_id, GameMode::PayToSpawnOneVsOne)
+        .await;
+    for (team, player) in players.iter().enumerate() {
+        validator
+            .join(session_id, player, team as u8)
+            .await
+            .unwrap();
+    }
+
+    // Every spawn and then the player themselves
+    let (killer, victim) = (&players[0], &players[1]);
+    for _ in 0..=STARTING_SPAWNS {
+        validator
+            .record_kill(session_id, killer.pubkey(), victim.pubkey())
+            .await
+            .unwrap();
+    }
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.is_eliminated(1, 0));
+
+    let spawn = pay_to_spawn(session_id, &game_session, &victim.pubkey());
+    let err = validator.send(&[spawn], &[victim]).await.unwrap_err();
+    assert!(err.contains("PlayerEliminated"), "{}", err);
+
+    // The top-up allowance is open to players still in the match
+    let killer_top_up = top_up(session_id, &game_session, &killer.pubkey(), BET);
+    validator.send(&[killer_top_up], &[killer]).await.unwrap();
+    let victim_top_up = top_up(session_id, &game_session, &victim.pubkey(), BET);
+    let err = validator
+        .send(&[victim_top_up], &[victim])
+        .await
+        .unwrap_err();
+    assert!(err.contains("PlayerEliminated"), "{}", err);
+
+    let err = validator
+        .record_kill(session_id, victim.pubkey(), killer.pubkey())
+        .await
+        .unwrap_err();
+    assert!(err.contains("PlayerEliminated"), "{}", err);
+    let game_session = validator.game_session(session_id).await;
+    assert_eq!(game_session.team(1).unwrap().slots[0].kills, 0);
+}
+
+#[tokio::test]
+async fn kicked_player_cannot_rejoin_or_substitute() {
+    let players: Vec<Keypair> = (0..KICKS + 2).map(|_| Keypair::new()).collect();
+    let (captain, teammate, kicked) = (&players[0], &players[1], &players[2..]);
+    let mut validator = Validator::start(&players, BET).await;
+    let session_id = "kicked";
+    validator
+        .create_session(session_id, GameMode::WinnerTakesAllThreeVsThree)
+        .await;
+    validator.join(session_id, captain, 0).await.unwrap();
+    validator.join(session_id, teammate, 0).await.unwrap();
+
+    for player in kicked {
+        validator.join(session_id, player, 0).await.unwrap();
+        let game_session = validator.game_session(session_id).await;
+        let kick = kick_player(
+            session_id,
+            &game_session,
+            &captain.pubkey(),
+            &player.pubkey(),
+        );
+        validator.send(&[kick], &[captain]).await.unwrap();
+    }
+
+    // The first kick is as binding as the last
+    let first = &kicked[0];
+    let err = validator.join(session_id, first, 0).await.unwrap_err();
+    assert!(err.contains("PlayerEliminated"), "{}", err);
+    let err = validator.join(session_id, first, 1).await.unwrap_err();
+    assert!(err.contains("PlayerEliminated"), "{}", err);
+
+    let game_session = validator.game_session(session_id).await;
+    let substitute = substitute_player(
+        session_id,
+        &game_session,
+        &teammate.pubkey(),
+        &first.pubkey(),
+    );
+    let err = validator
+        .send(&[substitute], &[teammate, first])
+        .await
+        .unwrap_err();
+    assert!(err.contains("PlayerEliminated"), "{}", err);
+
+    // The marker bars the player until the session has settled
+    let game_session_key = pda::game_session(session_id).0;
+    let close = close_session_kick(&game_session_key, &captain.pubkey(), &first.pubkey());
+    let err = validator.send(&[close], &[captain]).await.unwrap_err();
+    assert!(err.contains("SessionKickInUse"), "{}", err);
+}
+
+fn kick_player(
+    session_id: &str,
+    game_session: &GameSession,
+    captain: &Pubkey,
+    player: &Pubkey,
+) -> Instruction {
+    let mint = &game_session.mint;
+    let game_session_key = pda::game_session(session_id).0;
+    let accounts = accounts::KickPlayer {
+        captain: *captain,
+        config: pda::config().0,
+        game_session: game_session_key,
+        player: *player,
+        player_token_account: Some(get_associated_token_address(player, mint)),
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, &spl_token::ID),
+        player_stats: pda::player_stats(player).0,
+        bet_receipt: pda::bet_receipt(&game_session_key, player).0,
+        session_kick: pda::session_kick(&game_session_key, player).0,
+        active_exposure: Some(pda::active_exposure(player).0),
+        mint: *mint,
+        token_program: spl_token::ID,
+        system_program: anchor_lang::system_program::ID,
+    };
+    let args = instruction::KickPlayer {
+        session_id: session_id.to_string(),
+        team: game_session.get_player_slot(*player).unwrap().0,
+        player: *player,
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+fn substitute_player(
+    session_id: &str,
+    game_session: &GameSession,
+    player: &Pubkey,
+    replacement: &Pubkey,
+) -> Instruction {
+    let mint = &game_session.mint;
+    let game_session_key = pda::game_session(session_id).0;
+    let accounts = accounts::SubstitutePlayer {
+        player: *player,
+        replacement: *replacement,
+        config: pda::config().0,
+        game_session: game_session_key,
+        player_token_account: Some(get_associated_token_address(player, mint)),
+        replacement_token_account: Some(get_associated_token_address(replacement, mint)),
+        player_stats: pda::player_stats(player).0,
+        replacement_stats: pda::player_stats(replacement).0,
+        bet_receipt: pda::bet_receipt(&game_session_key, player).0,
+        replacement_receipt: pda::bet_receipt(&game_session_key, replacement).0,
+        replacement_pass_account: None,
+        replacement_player_ban: pda::player_ban(replacement).0,
+        replacement_session_kick: pda::session_kick(&game_session_key, replacement).0,
+        mint: *mint,
+        token_program: spl_token::ID,
+        system_program: anchor_lang::system_program::ID,
+    };
+    let args = instruction::SubstitutePlayer {
+        session_id: session_id.to_string(),
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+fn close_session_kick(game_session: &Pubkey, captain: &Pubkey, player: &Pubkey) -> Instruction {
+    let accounts = accounts::CloseSessionKick {
+        captain: *captain,
+        game_session: *game_session,
+        session_kick: pda::session_kick(game_session, player).0,
+    };
+    build_instruction(accounts, instruction::CloseSessionKick {}, Vec::new())
+}
@@ -0,0 +1,137 @@
+//! The house rake is routed in full: the jackpot's cut and every fee split
+//! share add up to exactly the rake, the rounding dust going to the first
+//! recipient, with nothing left in escrow.
+
+mod common;
+
+use anchor_lang::ToAccountMetas;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{accounts, build_instruction, builder, instruction, pda};
+use wager_program::instructions::distribution_payees;
+
+/// Rake and jackpot cut chosen so neither divides the pot evenly
+const FEE_BPS: u16 = 333;
+const JACKPOT_BPS: u16 = 1_234;
+const SPLIT_BPS: [u16; 3] = [3_333, 3_333, 3_334];
+
+#[tokio::test]
+async fn fee_shares_and_jackpot_cut_sum_to_the_rake() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let recipients: Vec<Pubkey> = SPLIT_BPS.iter().map(|_| Pubkey::new_unique()).collect();
+    let fee_split: Vec<FeeShare> = recipients
+        .iter()
+        .zip(SPLIT_BPS)
+        .map(|(recipient, bps)| FeeShare {
+            recipient: *recipient,
+            bps,
+        })
+        .collect();
+    let mut validator = Validator::start_with_config(&players, BET, |params| {
+        params.fee_bps = FEE_BPS;
+        params.jackpot_bps = JACKPOT_BPS;
+        params.fee_split = fee_split;
+    })
+    .await;
+    let mint = validator.mint;
+    for recipient in &recipients {
+        let account = token_account(&mint, recipient, 0);
+        let address = get_associated_token_address(recipient, &mint);
+        validator.context.set_account(&address, &account.into());
+    }
+    let (jackpot, _) = pda::jackpot(&mint);
+    let jackpot_token_account = get_associated_token_address(&jackpot, &mint);
+    let initialize_jackpot = build_instruction(
+        accounts::InitializeJackpot {
+            authority: validator.game_server.pubkey(),
+            config: pda::config().0,
+            mint,
+            jackpot,
+            jackpot_token_account,
+            token_program: spl_token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::InitializeJackpot {},
+        Vec::new(),
+    );
+    validator.send(&[initialize_jackpot], &[]).await.unwrap();
+
+    let session_id = "fee-split";
+    validator
+        .start_match(session_id, GameMode::WinnerTakesAllOneVsOne, &players)
+        .await;
+    let game_session = validator.game_session(session_id).await;
+    let declaration = WinnerDeclaration {
+        result: MatchResult::Win,
+        team: 0,
+    };
+    let (_, fee, _) = distribution_payees(&game_session, declaration.team).unwrap();
+    assert_ne!(fee * JACKPOT_BPS as u64 % 10_000, 0);
+
+    // The builder leaves the jackpot out, so its fixed accounts are redone
+    // with the jackpot in
+    let mut distribute = builder::distribute_winnings(
+        &validator.game_server.pubkey(),
+        session_id,
+        &game_session,
+        &spl_token::ID,
+        declaration,
+        false,
+    )
+    .unwrap();
+    let game_session_key = pda::game_session(session_id).0;
+    let fixed = accounts::DistributeWinnings {
+        caller: validator.game_server.pubkey(),
+        config: pda::config().0,
+        game_session: game_session_key,
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, &mint, &spl_token::ID),
+        treasury: pda::treasury().0,
+        treasury_token_account: pda::treasury_token_account(&mint, &spl_token::ID),
+        approval: None,
+        jackpot: Some(jackpot),
+        jackpot_token_account: Some(jackpot_token_account),
+        mint,
+        memo_program: Some(anchor_spl::memo::ID),
+        unclaimed_payout: Some(pda::unclaimed_payout(&game_session_key).0),
+        token_program: spl_token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: anchor_lang::system_program::ID,
+    }
+    .to_account_metas(None);
+    distribute.accounts.splice(..fixed.len(), fixed);
+    validator.send(&[distribute], &[]).await.unwrap();
+
+    let jackpot_cut = validator.token_balance(jackpot_token_account).await;
+    assert_eq!(jackpot_cut, fee * JACKPOT_BPS as u64 / 10_000);
+    let jackpot_account: Jackpot = validator.account(jackpot).await.unwrap();
+    assert_eq!(jackpot_account.balance, jackpot_cut);
+    let split = fee - jackpot_cut;
+    let mut shares = Vec::new();
+    for recipient in &recipients {
+        let address = get_associated_token_address(recipient, &mint);
+        shares.push(validator.token_balance(address).await);
+    }
+    for (share, bps) in shares.iter().zip(SPLIT_BPS).skip(1) {
+        assert_eq!(*share, split * bps as u64 / 10_000);
+    }
+    assert!(shares[0] > split * SPLIT_BPS[0] as u64 / 10_000);
+    assert_eq!(jackpot_cut + shares.iter().sum::<u64>(), fee);
+
+    let treasury_token_account = pda::treasury_token_account(&mint, &spl_token::ID);
+    assert_eq!(validator.token_balance(treasury_token_account).await, 0);
+    let vault = validator.vault(session_id).await;
+    assert_eq!(vault.total_fees, fee);
+    assert_eq!(
+        vault.total_paid_out + vault.total_fees,
+        vault.total_deposited
+    );
+    let vault_token_account = pda::vault_token_account(session_id, &mint, &spl_token::ID);
+    assert_eq!(validator.token_balance(vault_token_account).await, 0);
+}
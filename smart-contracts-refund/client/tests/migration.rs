@@ -0,0 +1,117 @@
+//! Sessions of the last append-only layout are converted on migration: each
+//! slot's counters and bitmask bits land in its `PlayerSlot`, and a counter
+//! too large for a byte fails the migration rather than wrap.
+
+mod common;
+
+use anchor_lang::Discriminator;
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{accounts, build_instruction, instruction, pda, ID};
+
+/// A 1v1 session in progress at `APPEND_ONLY_SESSION_VERSION`: the first
+/// player killed the second twice and reported ready, the second was
+/// eliminated
+fn legacy_session(session_id: &str, players: [Pubkey; 2]) -> Box<GameSessionV42> {
+    let mut session: Box<GameSessionV42> = bytemuck::zeroed_box();
+    session.version = APPEND_ONLY_SESSION_VERSION;
+    session.session_id_len = session_id.len() as u8;
+    session.session_id[..session_id.len()].copy_from_slice(session_id.as_bytes());
+    session.game_mode = GameMode::WinnerTakesAllOneVsOne as u8;
+    session.status = SessionStatus::InProgress as u8;
+    session.team_count = 2;
+    session.session_bet = BET;
+    for (team, player) in players.into_iter().enumerate() {
+        session.teams[team].players[0] = player;
+        session.teams[team].total_bet = BET;
+        session.ratings[team][0] = 1_500;
+    }
+    session.teams[0].player_kills[0] = 2;
+    session.teams[0].player_spawns[0] = STARTING_SPAWNS as u16;
+    session.kill_victims[0][0] = V42_PLAYERS_PER_TEAM as u8 + 1;
+    session.ready[0] = 1;
+    session.eliminated[1] = 1;
+    session.match_kills = 2;
+    session
+}
+
+async fn store(validator: &mut Validator, session_id: &str, session: &GameSessionV42) {
+    let mut data = GameSession::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(session));
+    let address = pda::game_session(session_id).0;
+    validator
+        .context
+        .set_account(&address, &program_account(data, ID).into());
+}
+
+#[tokio::test]
+async fn legacy_counters_move_into_roster_slots() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start(&players, BET).await;
+    let session_id = "migrated";
+    let keys = [players[0].pubkey(), players[1].pubkey()];
+    store(
+        &mut validator,
+        session_id,
+        &legacy_session(session_id, keys),
+    )
+    .await;
+
+    let migrate = migrate_session(&validator, session_id);
+    validator.send(&[migrate], &[]).await.unwrap();
+    let game_session = validator.game_session(session_id).await;
+    assert_eq!(game_session.version, GAME_SESSION_VERSION);
+    assert!(game_session.status().unwrap() == SessionStatus::InProgress);
+    assert_eq!(game_session.match_kills, 2);
+    let killer = game_session.teams[0].slots[0];
+    assert_eq!(game_session.teams[0].players[0], keys[0]);
+    assert_eq!((killer.kills, killer.spawns), (2, STARTING_SPAWNS));
+    assert_eq!(killer.rating, 1_500);
+    assert!(killer.has(SLOT_READY) && !killer.has(SLOT_ELIMINATED));
+    // The victim is renumbered across the wider rosters
+    assert_eq!(killer.first_victim as usize, MAX_PLAYERS_PER_TEAM + 1);
+    let victim = game_session.teams[1].slots[0];
+    assert_eq!(game_session.teams[1].players[0], keys[1]);
+    assert!(victim.has(SLOT_ELIMINATED) && !victim.has(SLOT_READY));
+
+    // A converted session is already current
+    validator.next_slot().await;
+    let migrate = migrate_session(&validator, session_id);
+    let err = validator.send(&[migrate], &[]).await.unwrap_err();
+    assert!(
+        err.contains("Error Code: SessionAlreadyMigrated."),
+        "{}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn counter_too_large_for_a_slot_fails_the_migration() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start(&players, BET).await;
+    let session_id = "overflowing";
+    let keys = [players[0].pubkey(), players[1].pubkey()];
+    let mut session = legacy_session(session_id, keys);
+    session.teams[0].player_kills[0] = u8::MAX as u16 + 1;
+    store(&mut validator, session_id, &session).await;
+
+    let migrate = migrate_session(&validator, session_id);
+    let err = validator.send(&[migrate], &[]).await.unwrap_err();
+    assert!(err.contains("Error Code: CounterOverflow."), "{}", err);
+}
+
+fn migrate_session(validator: &Validator, session_id: &str) -> Instruction {
+    let accounts = accounts::MigrateSession {
+        payer: validator.game_server.pubkey(),
+        game_session: pda::game_session(session_id).0,
+        system_program: anchor_lang::system_program::ID,
+    };
+    let args = instruction::MigrateSession {
+        session_id: session_id.to_string(),
+    };
+    build_instruction(accounts, args, Vec::new())
+}
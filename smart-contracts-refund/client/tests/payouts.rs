@@ -0,0 +1,43 @@
+//! Property tests of payout math: random sessions of every game mode must
+//! never pay out more than was deposited, and sessions without pay-to-spawn
+//! must release exactly what they escrowed.
+
+use wager_client::state::GameMode;
+use wager_client::test_utils::{GameSimulator, Outcome};
+
+const SEEDS: u64 = 256;
+
+const GAME_MODES: [GameMode; 8] = [
+    GameMode::WinnerTakesAllOneVsOne,
+    GameMode::WinnerTakesAllThreeVsThree,
+    GameMode::WinnerTakesAllFiveVsFive,
+    GameMode::PayToSpawnOneVsOne,
+    GameMode::PayToSpawnThreeVsThree,
+    GameMode::PayToSpawnFiveVsFive,
+    GameMode::FreeForAllFourPlayers,
+    GameMode::FreeForAllEightPlayers,
+];
+
+#[test]
+fn random_sessions_conserve_funds() {
+    for game_mode in GAME_MODES {
+        for seed in 0..SEEDS {
+            let bet = 1 + seed * 7_919 % 1_000_000_000;
+            let mut simulator = GameSimulator::new(seed, game_mode, bet).unwrap();
+            let outcome = simulator
+                .run()
+                .unwrap_or_else(|err| panic!("mode {} seed {}: {}", game_mode as u8, seed, err));
+
+            let vault = &simulator.vault;
+            let released = vault.total_paid_out + vault.total_refunded + vault.total_fees;
+            assert!(released <= vault.total_deposited);
+            if outcome != Outcome::SettlementRejected && !game_mode.is_pay_to_spawn() {
+                assert_eq!(
+                    simulator.escrow, 0,
+                    "mode {} seed {} left funds in escrow",
+                    game_mode as u8, seed
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Program addresses passed where no seeds constraint covers them must be
+//! the canonical ones: an account ground from any other bump of the same
+//! seeds is rejected with `InvalidPdaBump`.
+
+mod common;
+
+use anchor_lang::{AccountDeserialize, AccountSerialize};
+use anchor_spl::token::spl_token;
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{builder, pda, ID};
+
+#[tokio::test]
+async fn payee_statistics_under_a_non_canonical_bump_are_rejected() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start(&players, BET).await;
+    let session_id = "non-canonical-stats";
+    validator
+        .create_session(session_id, GameMode::WinnerTakesAllOneVsOne)
+        .await;
+    for (team, player) in players.iter().enumerate() {
+        validator
+            .join(session_id, player, team as u8)
+            .await
+            .unwrap();
+    }
+    // Both players trade a kill, so the result pays out without review
+    let (winner, loser) = (players[0].pubkey(), players[1].pubkey());
+    validator
+        .record_kill(session_id, winner, loser)
+        .await
+        .unwrap();
+    validator
+        .record_kill(session_id, loser, winner)
+        .await
+        .unwrap();
+
+    // Copy the winner's statistics to the next valid bump below the
+    // canonical one, recording that bump as their own
+    let (stats, canonical_bump) = pda::player_stats(&winner);
+    let mut forged = validator.banks().get_account(stats).await.unwrap().unwrap();
+    let (forged_stats, forged_bump) = (0..canonical_bump)
+        .rev()
+        .find_map(|bump| {
+            let seeds: &[&[u8]] = &[b"player_stats", winner.as_ref(), &[bump]];
+            Pubkey::create_program_address(seeds, &ID)
+                .ok()
+                .map(|address| (address, bump))
+        })
+        .unwrap();
+    let mut player_stats = PlayerStats::try_deserialize(&mut &forged.data[..]).unwrap();
+    assert_eq!(player_stats.bump, canonical_bump);
+    player_stats.bump = forged_bump;
+    let mut data = Vec::with_capacity(forged.data.len());
+    player_stats.try_serialize(&mut data).unwrap();
+    data.resize(forged.data.len(), 0);
+    forged.data = data;
+    validator.context.set_account(&forged_stats, &forged.into());
+
+    let game_session = validator.game_session(session_id).await;
+    let declaration = WinnerDeclaration {
+        result: MatchResult::Win,
+        team: 0,
+    };
+    let mut distribute = builder::distribute_winnings(
+        &validator.game_server.pubkey(),
+        session_id,
+        &game_session,
+        &spl_token::ID,
+        declaration,
+        false,
+    )
+    .unwrap();
+    let stats_meta = distribute
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == stats)
+        .unwrap();
+    stats_meta.pubkey = forged_stats;
+    let err = validator.send(&[distribute], &[]).await.unwrap_err();
+    assert!(err.contains("InvalidPdaBump"), "{}", err);
+
+    // The canonical statistics settle the same page
+    let distribute = builder::distribute_winnings(
+        &validator.game_server.pubkey(),
+        session_id,
+        &game_session,
+        &spl_token::ID,
+        declaration,
+        false,
+    )
+    .unwrap();
+    validator.send(&[distribute], &[]).await.unwrap();
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.status().unwrap() == SessionStatus::Completed);
+}
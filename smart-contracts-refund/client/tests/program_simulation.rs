@@ -0,0 +1,352 @@
+//! Random sessions played through real transactions. Where `GameSimulator`
+//! calls the program's state transitions directly, this suite sends the
+//! same kinds of joins, leaves and kills, then a settlement or refund, to a
+//! test validator running the native build of the program. After every
+//! transaction the vault's token balance must match its ledger, and every
+//! session must end with nothing left in escrow and its receipts consumed.
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{accounts, build_instruction, builder, instruction, pda};
+use wager_program::instructions::distribution_payees;
+
+/// Sessions played per game mode
+const SEEDS: u64 = 6;
+
+/// Most transactions a session sends before it must settle
+const MAX_STEPS: usize = 48;
+
+const GAME_MODES: [GameMode; 3] = [
+    GameMode::WinnerTakesAllOneVsOne,
+    GameMode::WinnerTakesAllThreeVsThree,
+    GameMode::WinnerTakesAllFiveVsFive,
+];
+
+/// Small deterministic generator, so a failing seed can be replayed
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..bound`, `bound` being non-zero
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+/// Session driven through random transactions, see the module documentation
+struct Simulation<'a> {
+    validator: &'a mut Validator,
+    players: &'a [Keypair],
+    session_id: String,
+    rng: SplitMix64,
+    payees: Vec<Pubkey>, // Players the settlement paid
+}
+
+impl Simulation<'_> {
+    /// Sends random transactions until the match has been played out, then
+    /// settles or refunds it
+    async fn run(&mut self, game_mode: GameMode) {
+        self.validator
+            .create_session(&self.session_id, game_mode)
+            .await;
+
+        for _ in 0..MAX_STEPS {
+            // Lobbies are sometimes abandoned and matches cut short
+            if self.rng.below(16) == 0 {
+                break;
+            }
+            // Rejected transactions are part of the game, as long as they
+            // leave the escrow untouched
+            let _ = self.step().await;
+            self.check_conservation().await;
+        }
+
+        let game_session = self.validator.game_session(&self.session_id).await;
+        let seated = seated_players(&game_session);
+        match game_session.status().unwrap() {
+            SessionStatus::InProgress => self.settle().await,
+            _ if !seated.is_empty() => self.refund().await,
+            _ => {}
+        }
+        self.check_conservation().await;
+
+        let escrowed = self.escrowed().await;
+        assert_eq!(
+            escrowed, 0,
+            "session {} left funds in escrow",
+            self.session_id
+        );
+        let game_session = self.validator.game_session(&self.session_id).await;
+        let game_session_key = pda::game_session(&self.session_id).0;
+        for player in seated {
+            let receipt: BetReceipt = self
+                .validator
+                .account(pda::bet_receipt(&game_session_key, &player).0)
+                .await
+                .unwrap();
+            assert!(
+                receipt.status == self.expected_receipt_status(&game_session, player),
+                "session {} left player {} with the wrong receipt status",
+                self.session_id,
+                player
+            );
+        }
+    }
+
+    /// Sends one random transaction a player or the game server could send
+    async fn step(&mut self) -> Result<(), String> {
+        let game_session = self.validator.game_session(&self.session_id).await;
+        match game_session.status().unwrap() {
+            SessionStatus::Created | SessionStatus::Filling => match self.rng.below(8) {
+                0 => self.leave(&game_session).await,
+                _ => self.join(&game_session).await,
+            },
+            SessionStatus::InProgress => self.kill(&game_session).await,
+            _ => Ok(()),
+        }
+    }
+
+    /// Seats a random unseated player on a random team with an open seat
+    async fn join(&mut self, game_session: &GameSession) -> Result<(), String> {
+        let unseated: Vec<&Keypair> = self
+            .players
+            .iter()
+            .filter(|player| !game_session.has_player(player.pubkey()))
+            .collect();
+        let open_teams: Vec<u8> = (0..game_session.team_count() as u8)
+            .filter(|team| {
+                let capacity = game_session.team_capacity(*team as usize).unwrap();
+                game_session.team(*team).unwrap().players[..capacity].contains(&Pubkey::default())
+            })
+            .collect();
+        let player = unseated[self.rng.below(unseated.len() as u64) as usize];
+        let team = open_teams[self.rng.below(open_teams.len() as u64) as usize];
+        self.validator.join(&self.session_id, player, team).await
+    }
+
+    /// Withdraws a random seated player's bet before the match starts
+    async fn leave(&mut self, game_session: &GameSession) -> Result<(), String> {
+        let seated: Vec<&Keypair> = self
+            .players
+            .iter()
+            .filter(|player| game_session.has_player(player.pubkey()))
+            .collect();
+        if seated.is_empty() {
+            return Ok(());
+        }
+        let player = seated[self.rng.below(seated.len() as u64) as usize];
+        let leave = leave_session(&self.session_id, game_session, &player.pubkey());
+        self.validator.send(&[leave], &[player]).await
+    }
+
+    /// Reports a kill of a random opponent by a random seated player
+    async fn kill(&mut self, game_session: &GameSession) -> Result<(), String> {
+        let seated = seated_players(game_session);
+        let killer = seated[self.rng.below(seated.len() as u64) as usize];
+        let killer_team = game_session.get_player_slot(killer).unwrap().0;
+        let opponents: Vec<Pubkey> = seated
+            .into_iter()
+            .filter(|player| game_session.get_player_slot(*player).unwrap().0 != killer_team)
+            .collect();
+        let victim = opponents[self.rng.below(opponents.len() as u64) as usize];
+        self.validator
+            .record_kill(&self.session_id, killer, victim)
+            .await
+    }
+
+    /// Declares a winner and pays every page of payees. A match flagged for
+    /// suspicious play can't pay out instantly, so it is called off instead.
+    async fn settle(&mut self) {
+        let game_session = self.validator.game_session(&self.session_id).await;
+        let team = game_session
+            .last_team_standing()
+            .unwrap_or_else(|| self.rng.below(game_session.team_count() as u64) as u8);
+        let declaration = WinnerDeclaration {
+            result: MatchResult::Win,
+            team,
+        };
+        loop {
+            let game_session = self.validator.game_session(&self.session_id).await;
+            if game_session.status().unwrap() == SessionStatus::Completed {
+                let (payees, _, _) = distribution_payees(&game_session, team).unwrap();
+                self.payees = payees.into_iter().map(|(player, _)| player).collect();
+                break;
+            }
+            let distribute = builder::distribute_winnings(
+                &self.validator.game_server.pubkey(),
+                &self.session_id,
+                &game_session,
+                &spl_token::ID,
+                declaration,
+                false,
+            )
+            .unwrap();
+            match self.validator.send(&[distribute], &[]).await {
+                Err(err) if err.contains("AuditReviewRequired") => return self.abort().await,
+                result => result.unwrap(),
+            }
+            self.check_conservation().await;
+        }
+    }
+
+    /// Returns every deposit of a lobby that never started
+    async fn refund(&mut self) {
+        let game_session = self.validator.game_session(&self.session_id).await;
+        let refund = builder::refund_wager(
+            &self.validator.game_server.pubkey(),
+            &self.session_id,
+            &game_session,
+            &spl_token::ID,
+            None,
+        )
+        .unwrap();
+        self.validator.send(&[refund], &[]).await.unwrap();
+    }
+
+    /// Refunds a match that started but can't be settled
+    async fn abort(&mut self) {
+        let game_session = self.validator.game_session(&self.session_id).await;
+        let abort = abort_match(
+            &self.validator.game_server.pubkey(),
+            &self.session_id,
+            &game_session,
+        );
+        self.validator.send(&[abort], &[]).await.unwrap();
+    }
+
+    /// Status a seated player's receipt ends the session in: consumed by the
+    /// refund or payout, or left open for a loser to close
+    fn expected_receipt_status(&self, game_session: &GameSession, player: Pubkey) -> ReceiptStatus {
+        match game_session.status().unwrap() {
+            SessionStatus::Refunded => ReceiptStatus::Refunded,
+            SessionStatus::Completed if self.payees.contains(&player) => ReceiptStatus::PaidOut,
+            _ => ReceiptStatus::Open,
+        }
+    }
+
+    async fn escrowed(&mut self) -> u64 {
+        let vault_token_account =
+            pda::vault_token_account(&self.session_id, &self.validator.mint, &spl_token::ID);
+        self.validator.token_balance(vault_token_account).await
+    }
+
+    /// Checks the vault ledger matches its token balance: nothing was paid
+    /// out that was not deposited, and nothing deposited went unaccounted for
+    async fn check_conservation(&mut self) {
+        let vault: Vault = self
+            .validator
+            .account(pda::vault(&self.session_id).0)
+            .await
+            .unwrap();
+        let escrowed = self.escrowed().await;
+        vault.check_invariant(escrowed).unwrap();
+        assert_eq!(
+            vault.remaining().unwrap(),
+            escrowed,
+            "session {} ledger drifted from its escrow",
+            self.session_id
+        );
+    }
+}
+
+fn seated_players(game_session: &GameSession) -> Vec<Pubkey> {
+    game_session
+        .get_all_players()
+        .into_iter()
+        .filter(|player| *player != Pubkey::default())
+        .collect()
+}
+
+fn leave_session(session_id: &str, game_session: &GameSession, player: &Pubkey) -> Instruction {
+    let mint = &game_session.mint;
+    let game_session_key = pda::game_session(session_id).0;
+    let accounts = accounts::LeaveSession {
+        player: *player,
+        config: pda::config().0,
+        game_session: game_session_key,
+        player_token_account: Some(get_associated_token_address(player, mint)),
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, &spl_token::ID),
+        treasury: pda::treasury().0,
+        treasury_token_account: pda::treasury_token_account(mint, &spl_token::ID),
+        player_stats: pda::player_stats(player).0,
+        bet_receipt: pda::bet_receipt(&game_session_key, player).0,
+        active_exposure: Some(pda::active_exposure(player).0),
+        mint: *mint,
+        token_program: spl_token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+    };
+    let args = instruction::LeaveSession {
+        session_id: session_id.to_string(),
+    };
+    build_instruction(accounts, args, Vec::new())
+}
+
+fn abort_match(game_server: &Pubkey, session_id: &str, game_session: &GameSession) -> Instruction {
+    let mint = &game_session.mint;
+    let game_session_key = pda::game_session(session_id).0;
+    let mut remaining_accounts = Vec::new();
+    for player in seated_players(game_session) {
+        remaining_accounts.push(AccountMeta::new_readonly(player, false));
+        remaining_accounts.push(AccountMeta::new(
+            get_associated_token_address(&player, mint),
+            false,
+        ));
+        remaining_accounts.push(AccountMeta::new(
+            pda::bet_receipt(&game_session_key, &player).0,
+            false,
+        ));
+    }
+    let accounts = accounts::AbortMatch {
+        game_server: *game_server,
+        game_session: game_session_key,
+        vault: pda::vault(session_id).0,
+        vault_token_account: pda::vault_token_account(session_id, mint, &spl_token::ID),
+        mint: *mint,
+        token_program: spl_token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+    };
+    let args = instruction::AbortMatch {
+        session_id: session_id.to_string(),
+        reason: 0,
+    };
+    build_instruction(accounts, args, remaining_accounts)
+}
+
+#[test]
+fn random_sessions_conserve_funds_on_chain() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    for game_mode in GAME_MODES {
+        let players: Vec<Keypair> = (0..2 * game_mode.players_per_team() + 2)
+            .map(|_| Keypair::new())
+            .collect();
+        runtime.block_on(async {
+            let mut validator = Validator::start(&players, BET * SEEDS).await;
+            for seed in 0..SEEDS {
+                let mut simulation = Simulation {
+                    validator: &mut validator,
+                    players: &players,
+                    session_id: format!("sim-{}-{}", game_mode as u8, seed),
+                    rng: SplitMix64(seed),
+                    payees: Vec::new(),
+                };
+                simulation.run(game_mode).await;
+            }
+        });
+    }
+}
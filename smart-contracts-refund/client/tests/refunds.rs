@@ -0,0 +1,128 @@
+//! Refunds return exactly what each player put in, purchased spawns
+//! included, leaving nothing stranded in escrow once a session is refunded.
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{builder, pda};
+
+#[tokio::test]
+async fn refunded_pay_to_spawn_session_returns_spawns_and_closes() {
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start(&players, 4 * BET).await;
+    let session_id = "refund-spawns";
+    validator
+        .create_session(session_id, GameMode::PayToSpawnOneVsOne)
+        .await;
+    for (team, player) in players.iter().enumerate() {
+        validator
+            .join(session_id, player, team as u8)
+            .await
+            .unwrap();
+    }
+
+    // The match started, so spawns can be bought before the game server
+    // calls it off
+    let buyer = &players[1];
+    let game_session = validator.game_session(session_id).await;
+    let spawn = pay_to_spawn(session_id, &game_session, &buyer.pubkey());
+    validator.send(&[spawn], &[buyer]).await.unwrap();
+
+    let game_session = validator.game_session(session_id).await;
+    let refund = builder::refund_wager(
+        &validator.game_server.pubkey(),
+        session_id,
+        &game_session,
+        &spl_token::ID,
+        None,
+    )
+    .unwrap();
+    validator.send(&[refund], &[]).await.unwrap();
+
+    for player in &players {
+        let token_account = get_associated_token_address(&player.pubkey(), &validator.mint);
+        assert_eq!(validator.token_balance(token_account).await, 4 * BET);
+    }
+    let vault = validator.vault(session_id).await;
+    assert_eq!(vault.total_deposited, 3 * BET);
+    assert_eq!(vault.total_refunded, vault.total_deposited);
+    let vault_token_account = pda::vault_token_account(session_id, &validator.mint, &spl_token::ID);
+    assert_eq!(validator.token_balance(vault_token_account).await, 0);
+
+    // With the escrow empty the session can be torn down after its cool-down
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.status().unwrap() == SessionStatus::Refunded);
+    validator.advance_clock(SESSION_CLOSE_COOLDOWN_SECS).await;
+    let close = close_session(session_id, &game_session);
+    validator.send(&[close], &[]).await.unwrap();
+    let closed = validator
+        .banks()
+        .get_account(pda::game_session(session_id).0)
+        .await
+        .unwrap();
+    assert!(closed.is_none());
+}
+
+#[tokio::test]
+async fn repeated_small_spawn_purchases_refund_what_was_paid() {
+    // At a bet of 15 a single spawn would cost 1.5 tokens, so only even
+    // counts are for sale
+    const SMALL_BET: u64 = 15;
+    const TOKENS: u64 = 100;
+    let players: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start(&players, TOKENS).await;
+    let session_id = "refund-small-spawns";
+    let mut args = session_args(session_id, GameMode::PayToSpawnOneVsOne);
+    args.bet_amount = SMALL_BET;
+    let create = builder::create_game_session(
+        &validator.game_server.pubkey(),
+        &validator.mint,
+        &spl_token::ID,
+        args,
+    );
+    validator.send(&[create], &[]).await.unwrap();
+    for (team, player) in players.iter().enumerate() {
+        validator
+            .join(session_id, player, team as u8)
+            .await
+            .unwrap();
+    }
+
+    let buyer = &players[1];
+    let game_session = validator.game_session(session_id).await;
+    let single = purchase_spawns(session_id, &game_session, &buyer.pubkey(), 1);
+    let err = validator.send(&[single], &[buyer]).await.unwrap_err();
+    assert!(err.contains("InvalidSpawns"), "{}", err);
+
+    // Five purchases of two spawns at 3 tokens each
+    for _ in 0..5 {
+        let purchase = purchase_spawns(session_id, &game_session, &buyer.pubkey(), 2);
+        validator.send(&[purchase], &[buyer]).await.unwrap();
+        validator.next_slot().await;
+    }
+    let buyer_account = get_associated_token_address(&buyer.pubkey(), &validator.mint);
+    assert_eq!(
+        validator.token_balance(buyer_account).await,
+        TOKENS - SMALL_BET - 15
+    );
+
+    // Once the session expires each player reclaims exactly what they paid
+    validator.advance_clock(86_400 + 1).await;
+    for player in &players {
+        let game_session = validator.game_session(session_id).await;
+        let refund = claim_timeout_refund(session_id, &game_session, &player.pubkey());
+        validator.send(&[refund], &[player]).await.unwrap();
+        let token_account = get_associated_token_address(&player.pubkey(), &validator.mint);
+        assert_eq!(validator.token_balance(token_account).await, TOKENS);
+    }
+    let vault = validator.vault(session_id).await;
+    assert_eq!(vault.total_deposited, 2 * SMALL_BET + 15);
+    assert_eq!(vault.total_refunded, vault.total_deposited);
+    let vault_token_account = pda::vault_token_account(session_id, &validator.mint, &spl_token::ID);
+    assert_eq!(validator.token_balance(vault_token_account).await, 0);
+}
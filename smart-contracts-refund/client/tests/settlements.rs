@@ -0,0 +1,167 @@
+//! Results other than a plain win settle to the amounts their rules give:
+//! a draw returns every stake less the draw rake, and a forfeit pays the
+//! forfeiting team's penalty to its opponents and refunds the rest. The
+//! vault ledger must reach zero with nothing left in escrow.
+
+mod common;
+
+use anchor_lang::InstructionData;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::*;
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signer};
+use wager_client::state::*;
+use wager_client::{accounts, build_instruction, builder, instruction, pda};
+
+const DRAW_FEE_BPS: u16 = 250;
+const FORFEIT_PENALTY_BPS: u16 = 2_000;
+
+async fn balance(validator: &mut Validator, player: &Keypair) -> u64 {
+    let token_account = get_associated_token_address(&player.pubkey(), &validator.mint);
+    validator.token_balance(token_account).await
+}
+
+/// Checks the session's ledger released everything deposited and its escrow
+/// is empty, returning the ledger
+async fn settled_vault(validator: &mut Validator, session_id: &str) -> Vault {
+    let vault = validator.vault(session_id).await;
+    assert_eq!(vault.remaining().unwrap(), 0);
+    let vault_token_account = pda::vault_token_account(session_id, &validator.mint, &spl_token::ID);
+    assert_eq!(validator.token_balance(vault_token_account).await, 0);
+    vault
+}
+
+/// Distributes page after page until the session completes
+async fn settle(validator: &mut Validator, session_id: &str, declaration: WinnerDeclaration) {
+    loop {
+        let game_session = validator.game_session(session_id).await;
+        if game_session.status().unwrap() == SessionStatus::Completed {
+            return;
+        }
+        let distribute = builder::distribute_winnings(
+            &validator.game_server.pubkey(),
+            session_id,
+            &game_session,
+            &spl_token::ID,
+            declaration,
+            false,
+        )
+        .unwrap();
+        validator.send(&[distribute], &[]).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn draw_returns_every_stake_less_the_draw_rake() {
+    let players: Vec<Keypair> = (0..6).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start_with_config(&players, BET, |params| {
+        params.draw_fee_bps = DRAW_FEE_BPS;
+    })
+    .await;
+    let session_id = "draw";
+    validator
+        .start_match(session_id, GameMode::WinnerTakesAllThreeVsThree, &players)
+        .await;
+
+    // The first page is built before the session records the draw, so the
+    // rest of the payees follow on the next page
+    let declaration = WinnerDeclaration {
+        result: MatchResult::Draw,
+        team: 0,
+    };
+    settle(&mut validator, session_id, declaration).await;
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.is_draw());
+
+    // Both teams get their bets back less the rake, which the treasury takes
+    let player_fee = BET * DRAW_FEE_BPS as u64 / 10_000;
+    for player in &players {
+        assert_eq!(balance(&mut validator, player).await, BET - player_fee);
+    }
+    let treasury_token_account = pda::treasury_token_account(&validator.mint, &spl_token::ID);
+    assert_eq!(
+        validator.token_balance(treasury_token_account).await,
+        6 * player_fee
+    );
+    let vault = settled_vault(&mut validator, session_id).await;
+    assert_eq!(vault.total_deposited, 6 * BET);
+    assert_eq!(vault.total_paid_out, 6 * (BET - player_fee));
+    assert_eq!(vault.total_fees, 6 * player_fee);
+}
+
+#[tokio::test]
+async fn forfeit_pays_the_penalty_to_the_opponents() {
+    let players: Vec<Keypair> = (0..6).map(|_| Keypair::new()).collect();
+    let mut validator = Validator::start_with_config(&players, BET, |params| {
+        params.forfeit_penalty_bps = FORFEIT_PENALTY_BPS;
+    })
+    .await;
+    let session_id = "forfeit";
+    validator
+        .start_match(session_id, GameMode::WinnerTakesAllThreeVsThree, &players)
+        .await;
+
+    // Team 1 forfeits, and with no dispute window the result is final as
+    // soon as the clock moves on
+    let forfeit = declare_forfeit(&validator, session_id, 1);
+    validator.send(&[forfeit], &[]).await.unwrap();
+    validator.advance_clock(1).await;
+    let declaration = WinnerDeclaration {
+        result: MatchResult::Forfeit,
+        team: 0,
+    };
+    // Finalizing takes the same accounts as an immediate distribution
+    loop {
+        let game_session = validator.game_session(session_id).await;
+        if game_session.status().unwrap() == SessionStatus::Completed {
+            break;
+        }
+        let mut finalize = builder::distribute_winnings(
+            &validator.game_server.pubkey(),
+            session_id,
+            &game_session,
+            &spl_token::ID,
+            declaration,
+            false,
+        )
+        .unwrap();
+        finalize.data = instruction::FinalizeDistribution {
+            session_id: session_id.to_string(),
+        }
+        .data();
+        validator.send(&[finalize], &[]).await.unwrap();
+    }
+
+    // The winners split their own bets and the penalty, less the rake
+    let game_session = validator.game_session(session_id).await;
+    assert!(game_session.match_result().unwrap() == MatchResult::Forfeit);
+    let penalty = BET * FORFEIT_PENALTY_BPS as u64 / 10_000;
+    let pot = 3 * BET + 3 * penalty;
+    let fee = pot * game_session.fee_bps as u64 / 10_000;
+    for player in &players[..3] {
+        assert_eq!(balance(&mut validator, player).await, (pot - fee) / 3);
+    }
+    for player in &players[3..] {
+        assert_eq!(balance(&mut validator, player).await, BET - penalty);
+    }
+    let vault = settled_vault(&mut validator, session_id).await;
+    assert_eq!(vault.total_deposited, 6 * BET);
+    assert_eq!(vault.total_fees, fee);
+    assert_eq!(vault.total_paid_out, 6 * BET - fee);
+}
+
+fn declare_forfeit(validator: &Validator, session_id: &str, forfeiting_team: u8) -> Instruction {
+    let accounts = accounts::DeclareForfeit {
+        caller: validator.game_server.pubkey(),
+        config: pda::config().0,
+        game_session: pda::game_session(session_id).0,
+        approval: None,
+    };
+    let args = instruction::DeclareForfeit {
+        session_id: session_id.to_string(),
+        forfeiting_team,
+    };
+    build_instruction(accounts, args, Vec::new())
+}
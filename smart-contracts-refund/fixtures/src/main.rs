@@ -0,0 +1,356 @@
+//! Creates sessions in every lifecycle state on a local validator, so
+//! frontend and backend work can run against realistic program state.
+//!
+//! The keypair must be the program's upgrade authority when the config does
+//! not exist yet, and must hold tokens of `--mint` in its associated token
+//! account, as `scripts/localnet-spl-token.sh` sets up. Every session is a
+//! 1v1 winner-takes-all lobby between freshly funded players.
+//!
+//! ```text
+//! gen-fixtures --mint <MINT> [--url http://127.0.0.1:8899]
+//!     [--keypair ~/.config/solana/id.json] [--filling 2] [--in-progress 2]
+//!     [--completed 2] [--bet 10] [--prefix fx]
+//! ```
+
+use std::env;
+use std::error::Error;
+use std::process;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{
+    get_associated_token_address, spl_associated_token_account::instruction as ata_instruction,
+};
+use anchor_spl::token::spl_token;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use wager_client::rpc::RpcClient;
+use wager_client::settlement::SettlementSubmitter;
+use wager_client::state::*;
+use wager_client::{accounts, build_instruction, builder, instruction, pda, AccountSource, ID};
+
+/// Lamports airdropped to each fixture player for fees and rent
+const PLAYER_LAMPORTS: u64 = 1_000_000_000;
+
+type FixtureResult<T> = Result<T, Box<dyn Error>>;
+
+struct Options {
+    url: String,
+    keypair: String,
+    mint: Pubkey,
+    filling: usize,
+    in_progress: usize,
+    completed: usize,
+    bet: u64,
+    prefix: String,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> FixtureResult<Self> {
+        let mut url = "http://127.0.0.1:8899".to_string();
+        let mut keypair = format!("{}/.config/solana/id.json", env::var("HOME")?);
+        let mut mint = None;
+        let (mut filling, mut in_progress, mut completed) = (2, 2, 2);
+        let mut bet = 10;
+        let mut prefix = "fx".to_string();
+
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {}", flag))?;
+            match flag.as_str() {
+                "--url" => url = value,
+                "--keypair" => keypair = value,
+                "--mint" => mint = Some(Pubkey::from_str(&value)?),
+                "--filling" => filling = value.parse()?,
+                "--in-progress" => in_progress = value.parse()?,
+                "--completed" => completed = value.parse()?,
+                "--bet" => bet = value.parse()?,
+                "--prefix" => prefix = value,
+                _ => return Err(format!("unknown flag {}", flag).into()),
+            }
+        }
+
+        Ok(Self {
+            url,
+            keypair,
+            mint: mint.ok_or("--mint is required")?,
+            filling,
+            in_progress,
+            completed,
+            bet,
+            prefix,
+        })
+    }
+}
+
+/// Lifecycle state a fixture session is left in
+#[derive(Clone, Copy)]
+enum Stage {
+    Filling,
+    InProgress,
+    Completed,
+}
+
+impl Stage {
+    fn label(self) -> &'static str {
+        match self {
+            Stage::Filling => "filling",
+            Stage::InProgress => "in-progress",
+            Stage::Completed => "completed",
+        }
+    }
+}
+
+struct Fixtures {
+    rpc: RpcClient,
+    payer: Keypair,
+    mint: Pubkey,
+    bet: u64,
+}
+
+impl Fixtures {
+    fn send(&self, instructions: &[Instruction], signers: &[&Keypair]) -> FixtureResult<()> {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&signers[0].pubkey()),
+            signers,
+            self.rpc.latest_blockhash()?,
+        );
+        self.rpc.send_and_confirm(&transaction)?;
+        Ok(())
+    }
+
+    /// Initializes the config with the payer as its only game server, unless
+    /// it already exists
+    fn ensure_config(&self) -> FixtureResult<()> {
+        let config = pda::config().0;
+        if self.rpc.get_account(&config)?.is_some() {
+            return Ok(());
+        }
+
+        let payer = self.payer.pubkey();
+        let params = ConfigParams {
+            min_bet: 1,
+            max_bet: u64::MAX / 1_000,
+            session_timeout_secs: 86_400,
+            kill_report_signer: payer,
+            fee_bps: 500,
+            cancellation_fee_bps: 0,
+            usd_min_bet: 0,
+            usd_max_bet: 0,
+            max_price_age_secs: 0,
+            arbiter: Pubkey::default(),
+            dispute_window_secs: 0,
+            game_server_keys: vec![payer],
+            game_server_threshold: 1,
+            referral_bps: 0,
+            max_top_up_multiple: 0,
+            map_pool_size: 0,
+            jackpot_bps: 0,
+            jackpot_interval: 0,
+            rewards_mint: Pubkey::default(),
+            streak_milestone: 0,
+            streak_bonus: 0,
+            elo_k_factor: 0,
+            creator_penalty_bps: 0,
+            trophy_uri: String::new(),
+            cpi_callers: Vec::new(),
+            creation_fee_lamports: 0,
+            max_exposure: 0,
+            fee_split: Vec::new(),
+            draw_fee_bps: 0,
+            forfeit_timeout_secs: 0,
+            forfeit_penalty_bps: 0,
+            freeroll_sponsors: Vec::new(),
+            yield_programs: Vec::new(),
+            yield_treasury_bps: 0,
+            clock_skew_secs: 0,
+            bond_slash_bps: 0,
+            arbiters: Vec::new(),
+            commit_reveal_results: false,
+        };
+        let accounts = accounts::InitializeConfig {
+            authority: payer,
+            config,
+            program: ID,
+            program_data: Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID)
+                .0,
+            system_program: system_program::ID,
+        };
+        let initialize = build_instruction(
+            accounts,
+            instruction::InitializeConfig { params },
+            Vec::new(),
+        );
+        self.send(&[initialize], &[&self.payer])?;
+        println!("Initialized config {}", config);
+        Ok(())
+    }
+
+    /// Registers the mint as a wager token with the config's bet limits
+    fn ensure_mint_registered(&self) -> FixtureResult<()> {
+        let registered_mint = pda::registered_mint(&self.mint).0;
+        if self.rpc.get_account(&registered_mint)?.is_some() {
+            return Ok(());
+        }
+
+        let register = builder::register_mint(&self.payer.pubkey(), &self.mint, 0, 0);
+        self.send(&[register], &[&self.payer])?;
+        println!("Registered mint {}", self.mint);
+        Ok(())
+    }
+
+    /// Creates the treasury's fee token account for the mint
+    fn ensure_treasury_account(&self) -> FixtureResult<()> {
+        let create = ata_instruction::create_associated_token_account_idempotent(
+            &self.payer.pubkey(),
+            &pda::treasury().0,
+            &self.mint,
+            &spl_token::ID,
+        );
+        self.send(&[create], &[&self.payer])
+    }
+
+    /// New player with SOL for fees and one bet's worth of tokens
+    fn fund_player(&self) -> FixtureResult<Keypair> {
+        let player = Keypair::new();
+        self.rpc.airdrop(&player.pubkey(), PLAYER_LAMPORTS)?;
+
+        let create = ata_instruction::create_associated_token_account_idempotent(
+            &self.payer.pubkey(),
+            &player.pubkey(),
+            &self.mint,
+            &spl_token::ID,
+        );
+        let transfer = spl_token::instruction::transfer(
+            &spl_token::ID,
+            &get_associated_token_address(&self.payer.pubkey(), &self.mint),
+            &get_associated_token_address(&player.pubkey(), &self.mint),
+            &self.payer.pubkey(),
+            &[],
+            self.bet,
+        )?;
+        self.send(&[create, transfer], &[&self.payer])?;
+        Ok(player)
+    }
+
+    /// Creates a session and walks it to `stage`
+    fn create(&self, session_id: &str, stage: Stage) -> FixtureResult<()> {
+        let args = instruction::CreateGameSession {
+            session_id: session_id.to_string(),
+            bet_amount: self.bet,
+            game_mode: GameMode::WinnerTakesAllOneVsOne,
+            team_count: 2,
+            spawn_earnings_bps: 0,
+            log_capacity: 0,
+            team_a_capacity: 0,
+            team_b_capacity: 0,
+            payout_schedule: PayoutSchedule::WinnerTeamEqual,
+            metadata: Some(SessionMetadataParams {
+                game_name: "Fixture".to_string(),
+                map_id: "arena".to_string(),
+                region: "local".to_string(),
+                custom: format!("{{\"stage\":\"{}\"}}", stage.label()),
+            }),
+            access: SessionAccess::Open,
+            max_kills_per_player: 0,
+            max_match_kills: 0,
+            series_length: 0,
+            min_rating: 0,
+            max_rating: 0,
+            refund_policy: RefundPolicy::FullAnytimeBeforeStart,
+            requires_pass: None,
+            join_window: None,
+            win_condition: WinCondition::ServerDecision,
+            freeroll: false,
+            assist_weight_bps: 0,
+            min_ready_players: 0,
+            max_stake: 0,
+        };
+        let create =
+            builder::create_game_session(&self.payer.pubkey(), &self.mint, &spl_token::ID, args);
+        self.send(&[create], &[&self.payer])?;
+
+        let teams: &[u8] = match stage {
+            Stage::Filling => &[0],
+            Stage::InProgress | Stage::Completed => &[0, 1],
+        };
+        for team in teams {
+            let player = self.fund_player()?;
+            let game_session = self.rpc.fetch_game_session(session_id)?;
+            let join = builder::join_user(
+                &player.pubkey(),
+                session_id,
+                &game_session,
+                &spl_token::ID,
+                *team,
+                None,
+                None,
+                None,
+                None,
+            )?;
+            self.send(&[join], &[&player])?;
+        }
+
+        if let Stage::Completed = stage {
+            SettlementSubmitter::new(&self.rpc, &self.payer, spl_token::ID).distribute(
+                session_id,
+                WinnerDeclaration {
+                    result: MatchResult::Win,
+                    team: 0,
+                },
+                false,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn run() -> FixtureResult<()> {
+    let options = Options::parse(env::args().skip(1))?;
+    let fixtures = Fixtures {
+        rpc: RpcClient::new(&options.url)?,
+        payer: read_keypair_file(&options.keypair)?,
+        mint: options.mint,
+        bet: options.bet,
+    };
+
+    let mint = fixtures
+        .rpc
+        .get_account(&fixtures.mint)?
+        .ok_or("mint not found")?;
+    if mint.owner != spl_token::ID {
+        return Err("fixtures only support SPL Token mints".into());
+    }
+    fixtures.ensure_config()?;
+    fixtures.ensure_mint_registered()?;
+    fixtures.ensure_treasury_account()?;
+
+    // Session ids are unique per run so fixtures can be generated repeatedly
+    let run_id = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() % 1_000_000;
+    let stages = [
+        (Stage::Filling, options.filling),
+        (Stage::InProgress, options.in_progress),
+        (Stage::Completed, options.completed),
+    ];
+    for (stage, count) in stages {
+        for index in 0..count {
+            let session_id = format!("{}-{}-{}-{}", options.prefix, run_id, stage.label(), index);
+            fixtures.create(&session_id, stage)?;
+            println!("{} {}", stage.label(), session_id);
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,353 @@
+//! Follows the wager program's transactions and turns the events they emit
+//! into session lifecycle rows, written as SQL to stdout for SQLite or
+//! Postgres:
+//!
+//! ```text
+//! wager-indexer --schema | sqlite3 wagers.db
+//! wager-indexer --url http://127.0.0.1:8899 --cursor .indexer-cursor | sqlite3 wagers.db
+//! ```
+//!
+//! Transactions are read oldest first through `getSignaturesForAddress`, and
+//! the newest indexed signature is kept in the cursor file so a restarted
+//! indexer picks up where it stopped. Every transaction's rows are written as
+//! one SQL transaction, and re-indexing a transaction is a no-op.
+
+mod sql;
+
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::process;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use anchor_lang::prelude::Pubkey;
+use solana_sdk::signature::Signature;
+use wager_client::event::event_data;
+use wager_client::events::*;
+use wager_client::rpc::{RpcClient, SignatureInfo};
+use wager_client::state::SessionStatus;
+use wager_client::{decode_event, ID};
+
+/// Signatures requested per page of history
+const PAGE_SIZE: usize = 1_000;
+
+type IndexerResult<T> = Result<T, Box<dyn Error>>;
+
+struct Options {
+    url: String,
+    cursor: Option<String>,
+    poll_secs: u64,
+    once: bool,
+    schema: bool,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> IndexerResult<Self> {
+        let mut options = Self {
+            url: "http://127.0.0.1:8899".to_string(),
+            cursor: None,
+            poll_secs: 5,
+            once: false,
+            schema: false,
+        };
+        while let Some(flag) = args.next() {
+            match flag.as_str() {
+                "--once" => options.once = true,
+                "--schema" => options.schema = true,
+                "--url" | "--cursor" | "--poll-secs" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("missing value for {}", flag))?;
+                    match flag.as_str() {
+                        "--url" => options.url = value,
+                        "--cursor" => options.cursor = Some(value),
+                        _ => options.poll_secs = value.parse()?,
+                    }
+                }
+                _ => return Err(format!("unknown flag {}", flag).into()),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Where an event was emitted
+struct Origin<'a> {
+    transaction: &'a SignatureInfo,
+    index: usize,
+}
+
+/// Event history row, with the session update it implies
+struct Row {
+    session_id: String,
+    event: &'static str,
+    player: Option<Pubkey>,
+    amount: Option<u64>,
+    update: Option<String>,
+}
+
+impl Row {
+    fn new(session_id: String, event: &'static str) -> Self {
+        Self {
+            session_id,
+            event,
+            player: None,
+            amount: None,
+            update: None,
+        }
+    }
+
+    fn player(mut self, player: Pubkey) -> Self {
+        self.player = Some(player);
+        self
+    }
+
+    fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Columns of the session to set along with its `updated_slot`
+    fn update(mut self, assignments: String) -> Self {
+        self.update = Some(assignments);
+        self
+    }
+
+    fn status(self, status: SessionStatus) -> Self {
+        let assignment = format!("status = {}", sql::text(sql::status(status)));
+        self.update(assignment)
+    }
+
+    fn write(&self, origin: &Origin, out: &mut impl Write) -> io::Result<()> {
+        let transaction = origin.transaction;
+        writeln!(
+            out,
+            "INSERT INTO session_events (signature, event_index, slot, block_time, session_id, event, player, amount) \
+             VALUES ({}, {}, {}, {}, {}, {}, {}, {}) ON CONFLICT DO NOTHING;",
+            sql::text(&transaction.signature.to_string()),
+            origin.index,
+            transaction.slot,
+            sql::optional(transaction.block_time, |time| time.to_string()),
+            sql::text(&self.session_id),
+            sql::text(self.event),
+            sql::optional(self.player.as_ref(), sql::key),
+            sql::optional(self.amount, |amount| amount.to_string()),
+        )?;
+        // Updates never move a session back to an older transaction's state
+        if let Some(update) = &self.update {
+            writeln!(
+                out,
+                "UPDATE sessions SET {}, updated_slot = {} WHERE session_id = {} AND updated_slot <= {};",
+                update,
+                transaction.slot,
+                sql::text(&self.session_id),
+                transaction.slot,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Row of a session lifecycle event, `None` for any other event
+fn lifecycle_row(data: &[u8]) -> Option<Row> {
+    if let Some(event) = decode_event::<PlayerJoined>(data) {
+        return Some(
+            Row::new(event.session_id, "player_joined")
+                .player(event.player)
+                .amount(event.amount)
+                .update(
+                    "players = players + 1, \
+                     status = CASE WHEN status = 'created' THEN 'filling' ELSE status END"
+                        .to_string(),
+                ),
+        );
+    }
+    if let Some(event) = decode_event::<PlayerLeft>(data) {
+        return Some(
+            Row::new(event.session_id, "player_left")
+                .player(event.player)
+                .amount(event.refund)
+                .update("players = players - 1".to_string()),
+        );
+    }
+    if let Some(event) = decode_event::<PlayerKicked>(data) {
+        return Some(
+            Row::new(event.session_id, "player_kicked")
+                .player(event.player)
+                .amount(event.refund)
+                .update("players = players - 1".to_string()),
+        );
+    }
+    if let Some(event) = decode_event::<SessionStarted>(data) {
+        return Some(Row::new(event.session_id, "started").status(SessionStatus::InProgress));
+    }
+    if let Some(event) = decode_event::<SessionLocked>(data) {
+        let status = if event.started {
+            SessionStatus::InProgress
+        } else {
+            SessionStatus::Refundable
+        };
+        return Some(Row::new(event.session_id, "locked").status(status));
+    }
+    if let Some(event) = decode_event::<ResultSubmitted>(data) {
+        return Some(
+            Row::new(event.session_id, "result_submitted").status(SessionStatus::ResultSubmitted),
+        );
+    }
+    if let Some(event) = decode_event::<WinningsDistributed>(data) {
+        return Some(
+            Row::new(event.session_id, "winnings_paid")
+                .player(event.player)
+                .amount(event.amount),
+        );
+    }
+    if let Some(event) = decode_event::<SessionSettled>(data) {
+        let assignments = format!(
+            "status = 'completed', total_paid_out = {}",
+            event.total_paid_out
+        );
+        return Some(
+            Row::new(event.session_id, "settled")
+                .amount(event.total_paid_out)
+                .update(assignments),
+        );
+    }
+    if let Some(event) = decode_event::<RefundIssued>(data) {
+        return Some(
+            Row::new(event.session_id, "refund_paid")
+                .player(event.player)
+                .amount(event.amount)
+                .status(SessionStatus::Refunded),
+        );
+    }
+    if let Some(event) = decode_event::<MatchAborted>(data) {
+        return Some(
+            Row::new(event.session_id, "aborted")
+                .amount(event.refunded)
+                .status(SessionStatus::Refunded),
+        );
+    }
+    if let Some(event) = decode_event::<SettlementRecovered>(data) {
+        return Some(Row::new(event.session_id, "settlement_recovered").status(event.status));
+    }
+    if let Some(event) = decode_event::<MatchResultRecorded>(data) {
+        return Some(Row::new(event.session_id, "result_recorded").player(event.winner));
+    }
+    None
+}
+
+/// Writes the rows of one decoded event
+fn index_event(data: &[u8], origin: &Origin, out: &mut impl Write) -> io::Result<()> {
+    if let Some(event) = decode_event::<SessionCreated>(data) {
+        let slot = origin.transaction.slot;
+        writeln!(
+            out,
+            "INSERT INTO sessions (session_id, authority, mint, bet_amount, game_mode, team_count, status, created_slot, updated_slot) \
+             VALUES ({}, {}, {}, {}, {}, {}, 'created', {}, {}) ON CONFLICT DO NOTHING;",
+            sql::text(&event.session_id),
+            sql::key(&event.authority),
+            sql::key(&event.mint),
+            event.bet_amount,
+            event.game_mode as u8,
+            event.team_count,
+            slot,
+            slot,
+        )?;
+        return Row::new(event.session_id, "created").write(origin, out);
+    }
+    match lifecycle_row(data) {
+        Some(row) => row.write(origin, out),
+        None => Ok(()),
+    }
+}
+
+/// Writes every lifecycle row of a transaction as one SQL transaction
+fn index_transaction(
+    rpc: &RpcClient,
+    transaction: &SignatureInfo,
+    out: &mut impl Write,
+) -> IndexerResult<()> {
+    if transaction.failed {
+        return Ok(());
+    }
+    let events: Vec<Vec<u8>> = rpc
+        .transaction_logs(&transaction.signature)?
+        .iter()
+        .filter_map(|log| event_data(log))
+        .collect();
+    if events.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out, "BEGIN;")?;
+    for (index, data) in events.iter().enumerate() {
+        index_event(data, &Origin { transaction, index }, out)?;
+    }
+    writeln!(out, "COMMIT;")?;
+    Ok(())
+}
+
+/// Transactions after `cursor`, oldest first
+fn new_transactions(
+    rpc: &RpcClient,
+    cursor: Option<&Signature>,
+) -> IndexerResult<Vec<SignatureInfo>> {
+    let mut transactions = Vec::new();
+    loop {
+        let before = transactions
+            .last()
+            .map(|info: &SignatureInfo| info.signature);
+        let page = rpc.signatures_for_address(&ID, before.as_ref(), cursor, PAGE_SIZE)?;
+        let done = page.len() < PAGE_SIZE;
+        transactions.extend(page);
+        if done {
+            break;
+        }
+    }
+    transactions.reverse();
+    Ok(transactions)
+}
+
+fn run() -> IndexerResult<()> {
+    let options = Options::parse(env::args().skip(1))?;
+    let mut out = io::stdout().lock();
+    if options.schema {
+        write!(out, "{}", sql::SCHEMA)?;
+        return Ok(());
+    }
+
+    let rpc = RpcClient::new(&options.url)?;
+    let mut cursor = match &options.cursor {
+        Some(path) => match fs::read_to_string(path) {
+            Ok(saved) => Some(Signature::from_str(saved.trim())?),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+            Err(error) => return Err(error.into()),
+        },
+        None => None,
+    };
+
+    loop {
+        for transaction in new_transactions(&rpc, cursor.as_ref())? {
+            index_transaction(&rpc, &transaction, &mut out)?;
+            out.flush()?;
+            cursor = Some(transaction.signature);
+            if let Some(path) = &options.cursor {
+                fs::write(path, transaction.signature.to_string())?;
+            }
+        }
+        if options.once {
+            return Ok(());
+        }
+        thread::sleep(Duration::from_secs(options.poll_secs));
+    }
+}
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
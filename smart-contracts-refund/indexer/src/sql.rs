@@ -0,0 +1,60 @@
+//! SQL the indexer writes. Statements stick to the subset SQLite and
+//! Postgres share, so the output can be piped into either.
+
+use anchor_lang::prelude::Pubkey;
+use wager_client::state::SessionStatus;
+
+pub const SCHEMA: &str = "\
+CREATE TABLE IF NOT EXISTS sessions (
+    session_id TEXT PRIMARY KEY,
+    authority TEXT NOT NULL,
+    mint TEXT NOT NULL,
+    bet_amount NUMERIC(20, 0) NOT NULL,
+    game_mode SMALLINT NOT NULL,
+    team_count SMALLINT NOT NULL,
+    status TEXT NOT NULL,
+    players INTEGER NOT NULL DEFAULT 0,
+    total_paid_out NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    created_slot BIGINT NOT NULL,
+    updated_slot BIGINT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS session_events (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot BIGINT NOT NULL,
+    block_time BIGINT,
+    session_id TEXT NOT NULL,
+    event TEXT NOT NULL,
+    player TEXT,
+    amount NUMERIC(20, 0),
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS session_events_session ON session_events (session_id, slot);
+";
+
+/// Quotes a string literal
+pub fn text(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+pub fn key(value: &Pubkey) -> String {
+    text(&value.to_string())
+}
+
+pub fn optional<T>(value: Option<T>, render: impl Fn(T) -> String) -> String {
+    value.map_or_else(|| "NULL".to_string(), render)
+}
+
+/// Name stored for a session status
+pub fn status(status: SessionStatus) -> &'static str {
+    match status {
+        SessionStatus::Created => "created",
+        SessionStatus::Filling => "filling",
+        SessionStatus::InProgress => "in_progress",
+        SessionStatus::Settling => "settling",
+        SessionStatus::Completed => "completed",
+        SessionStatus::Refunded => "refunded",
+        SessionStatus::ResultSubmitted => "result_submitted",
+        SessionStatus::Refundable => "refundable",
+    }
+}
@@ -5,7 +5,7 @@ pub enum WagerError {
     #[msg("Game session is not in the correct state")]
     InvalidGameState,
 
-    #[msg("Invalid team selection. Team must be 0 or 1")]
+    #[msg("Invalid team selection for this session")]
     InvalidTeamSelection,
 
     #[msg("Team is already full")]
@@ -125,4 +125,575 @@ pub enum WagerError {
 
     #[msg("Session ID contains invalid characters")]
     InvalidSessionIdFormat,
+
+    #[msg("Invalid number of teams for a game session")]
+    InvalidTeamCount,
+
+    #[msg("Minimum bet must be positive and not exceed maximum bet")]
+    InvalidBetLimits,
+
+    #[msg("Vault accounting invariant violated")]
+    VaultInvariantViolated,
+
+    #[msg("Session timeout must be positive")]
+    InvalidSessionTimeout,
+
+    #[msg("Game session has not expired yet")]
+    SessionNotExpired,
+
+    #[msg("Spawn earnings rate cannot exceed 10000 basis points")]
+    InvalidSpawnEarningsBps,
+
+    #[msg("Native SOL sessions must be joined with join_user_sol")]
+    NativeSolSession,
+
+    #[msg("Session is not denominated in native SOL")]
+    NotNativeSolSession,
+
+    #[msg("Pay-to-spawn is not supported for native SOL sessions")]
+    UnsupportedNativeGameMode,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Invalid game session status transition")]
+    InvalidStatusTransition,
+
+    #[msg("Settlement is not stale enough to recover")]
+    SettlementNotStale,
+
+    #[msg("Kill report signer must be a valid public key")]
+    InvalidKillReportSigner,
+
+    #[msg("Kill report must be preceded by an ed25519 signature verification")]
+    MissingKillReportSignature,
+
+    #[msg("Kill report signature does not match the registered signer or report")]
+    InvalidKillReportSignature,
+
+    #[msg("Kill report round has already been recorded")]
+    StaleKillReport,
+
+    #[msg("Player stats account does not belong to the player")]
+    InvalidPlayerStats,
+
+    #[msg("Protocol fee exceeds the maximum allowed rate")]
+    InvalidFeeBps,
+
+    #[msg("Withdrawal amount must be positive")]
+    InvalidWithdrawAmount,
+
+    #[msg("Game session account version is not supported")]
+    UnsupportedSessionVersion,
+
+    #[msg("Game session account is already at the current version")]
+    SessionAlreadyMigrated,
+
+    #[msg("Tournament size must be a power of two within the supported range")]
+    InvalidTournamentSize,
+
+    #[msg("Tournament is not in the correct state")]
+    InvalidTournamentState,
+
+    #[msg("Tournament has no open entry slots")]
+    TournamentFull,
+
+    #[msg("Bracket match does not belong to this tournament position")]
+    InvalidBracketMatch,
+
+    #[msg("Bracket match already has a winner")]
+    MatchAlreadyDecided,
+
+    #[msg("Bracket match is still waiting for its teams")]
+    MatchNotReady,
+
+    #[msg("Spectator betting is only available for winner-takes-all team sessions")]
+    SpectatorPoolUnsupported,
+
+    #[msg("Spectator betting is closed for this session")]
+    SpectatorBettingClosed,
+
+    #[msg("Players cannot bet as spectators on their own session")]
+    PlayerCannotSpectate,
+
+    #[msg("Spectator stakes can only be added to the team already backed")]
+    SpectatorTeamMismatch,
+
+    #[msg("Spectator payout already claimed")]
+    SpectatorBetAlreadyClaimed,
+
+    #[msg("Game session has not been settled yet")]
+    SessionNotSettled,
+
+    #[msg("Oracle price is invalid, stale, or for the wrong feed")]
+    InvalidOraclePrice,
+
+    #[msg("USD bet bounds require the mint's price feed and a price update")]
+    MissingPriceFeed,
+
+    #[msg("Session cannot be closed before the cool-down elapses")]
+    SessionCloseCooldown,
+
+    #[msg("Vault still holds funds")]
+    VaultNotEmpty,
+
+    #[msg("Distribution is partially paid and must be resumed")]
+    DistributionInProgress,
+
+    #[msg("Match log capacity exceeds the maximum allowed")]
+    InvalidLogCapacity,
+
+    #[msg("Session keeps a match log that must be supplied")]
+    MissingMatchLog,
+
+    #[msg("Dispute window must be non-negative and requires an arbiter")]
+    InvalidDisputeWindow,
+
+    #[msg("Session results must be submitted and finalized after the dispute window")]
+    DisputeWindowRequired,
+
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+
+    #[msg("Only the arbiter can override a submitted result")]
+    UnauthorizedArbiter,
+
+    #[msg("Only winner-takes-all team results can be overridden")]
+    ResultNotOverridable,
+
+    #[msg("Game server keys must be distinct, non-empty and meet the threshold")]
+    InvalidGameServerKeys,
+
+    #[msg("Signer is not in the game server signing set")]
+    UnauthorizedGameServer,
+
+    #[msg("Not enough game server approvals for this operation")]
+    InsufficientApprovals,
+
+    #[msg("Approval does not match this session, operation or result")]
+    ApprovalMismatch,
+
+    #[msg("Referral cut cannot exceed 10000 basis points")]
+    InvalidReferralBps,
+
+    #[msg("Referrer must be another valid account, supplied with its referral accounts")]
+    InvalidReferrer,
+
+    #[msg("Team capacities must be 1..=10 and only apply to two-team sessions")]
+    InvalidTeamCapacity,
+
+    #[msg("Payout schedule shares must fit the winning team and sum to 10000 basis points")]
+    InvalidPayoutSchedule,
+
+    #[msg("Session metadata is empty, too long or contains control characters")]
+    InvalidSessionMetadata,
+
+    #[msg("Matchmaking queues only support fixed-bet SPL mints without transfer fees")]
+    QueueUnsupported,
+
+    #[msg("Player is already waiting in this queue")]
+    PlayerAlreadyQueued,
+
+    #[msg("Matchmaking queue is full")]
+    QueueFull,
+
+    #[msg("Player is not waiting in this queue")]
+    PlayerNotQueued,
+
+    #[msg("Not enough queued players to fill a session")]
+    NotEnoughQueuedPlayers,
+
+    #[msg("Replacement must be a valid account not already in the session")]
+    InvalidSubstitute,
+
+    #[msg("Player already holds a slot in this session")]
+    PlayerAlreadyJoined,
+
+    #[msg("Private session requires a valid join code or allowlist proof")]
+    InvalidJoinCredential,
+
+    #[msg("Too many kills recorded for this session in the current slot")]
+    KillRateExceeded,
+
+    #[msg("Kill exceeds the session's per-player or per-match cap")]
+    KillCapExceeded,
+
+    #[msg("Computed payouts exceed the escrowed balance")]
+    PayoutExceedsEscrow,
+
+    #[msg("Earnings for this slot have already been claimed")]
+    EarningsAlreadyClaimed,
+
+    #[msg("Player has no earnings to claim")]
+    NothingToClaim,
+
+    #[msg("Series length must be odd and at most 7, for winner-takes-all team sessions")]
+    InvalidSeriesLength,
+
+    #[msg("No team has won the series yet")]
+    SeriesNotDecided,
+
+    #[msg("Series has already been decided")]
+    SeriesAlreadyDecided,
+
+    #[msg("Top-up must be a positive multiple of the session bet")]
+    InvalidTopUpAmount,
+
+    #[msg("Top-up exceeds the session's allowance for this player")]
+    TopUpLimitExceeded,
+
+    #[msg("Player has been eliminated from the match")]
+    PlayerEliminated,
+
+    #[msg("VRF randomness is not in the correct state for this session")]
+    InvalidRandomnessState,
+
+    #[msg("VRF randomness has not been fulfilled yet")]
+    RandomnessNotFulfilled,
+
+    #[msg("Match setup is waiting on VRF randomness")]
+    RandomnessPending,
+
+    #[msg("Jackpot and referral cuts together cannot exceed 10000 basis points")]
+    InvalidJackpotBps,
+
+    #[msg("Jackpot accounts for the session mint must be supplied")]
+    InvalidJackpot,
+
+    #[msg("Session has not won the jackpot")]
+    JackpotNotWon,
+
+    #[msg("Win streak was broken by a session that was not won")]
+    StreakBroken,
+
+    #[msg("Streak bonuses are not configured")]
+    StreakBonusUnavailable,
+
+    #[msg("Player stats account is already at the current layout")]
+    PlayerStatsAlreadyMigrated,
+
+    #[msg("Maximum rating must be zero or at least the minimum rating")]
+    InvalidRatingBounds,
+
+    #[msg("Player rating is outside the lobby's rating bounds")]
+    RatingOutOfRange,
+
+    #[msg("Rating change for this session has already been applied")]
+    RatingAlreadyApplied,
+
+    #[msg("Lobby index is full and must be pruned")]
+    LobbyIndexFull,
+
+    #[msg("Creator penalty cannot exceed 10000 basis points")]
+    InvalidCreatorPenaltyBps,
+
+    #[msg("Creator token account is required to pay the forfeit penalty")]
+    InvalidCreatorTokenAccount,
+
+    #[msg("Account was not allocated the size of its type")]
+    InvalidAccountSize,
+
+    #[msg("Account does not hold enough lamports to be rent-exempt")]
+    AccountNotRentExempt,
+
+    #[msg("Trophy base URI is too long")]
+    InvalidTrophyUri,
+
+    #[msg("Trophies are not configured")]
+    TrophiesUnavailable,
+
+    #[msg("Only members of the session's winning team can receive a trophy")]
+    NotSessionWinner,
+
+    #[msg("Session requires holding its season pass")]
+    MissingSeasonPass,
+
+    #[msg("Player is banned from joining sessions")]
+    PlayerIsBanned,
+
+    #[msg("CPI callers must be distinct, non-empty and within the maximum")]
+    InvalidCpiCallers,
+
+    #[msg("Calling program is not allowed to invoke sessions by CPI")]
+    UnauthorizedCpiCaller,
+
+    #[msg("Kill report sequence number has already been recorded")]
+    DuplicateKillReport,
+
+    #[msg("Kill report sequence number skips ahead of the next expected one")]
+    KillReportOutOfOrder,
+
+    #[msg("Kill batch must hold between one and the maximum number of kills")]
+    InvalidKillBatch,
+
+    #[msg("Only the team captain can manage the team")]
+    NotTeamCaptain,
+
+    #[msg("Withdrawal would spend creation fees still held for their sessions")]
+    CreationFeesReserved,
+
+    #[msg("Payee's token account is closed or frozen and the unclaimed payout account was not supplied")]
+    MissingUnclaimedPayout,
+
+    #[msg("Unclaimed payout account is full")]
+    UnclaimedPayoutsFull,
+
+    #[msg("Not enough compute left to pay a single payee")]
+    DistributionComputeExhausted,
+
+    #[msg("Account is not the canonical program address for its seeds")]
+    InvalidPdaBump,
+
+    #[msg("Join deadline must be in the future with player minimums the lobby can seat")]
+    InvalidJoinWindow,
+
+    #[msg("Session's join deadline has passed")]
+    JoinDeadlinePassed,
+
+    #[msg("Session has no join deadline or it has not passed yet")]
+    JoinDeadlineNotReached,
+
+    #[msg("Join would exceed the wallet's cap on bets open across sessions")]
+    ExposureCapExceeded,
+
+    #[msg("Wallet already has the maximum number of open sessions tracked")]
+    TooManyOpenSessions,
+
+    #[msg("Active exposure account is required while an exposure cap is set")]
+    MissingActiveExposure,
+
+    #[msg("Session still holds the player's bet")]
+    ExposureStillActive,
+
+    #[msg("Fee split needs up to four distinct recipients with shares summing to 10000 bps")]
+    InvalidFeeSplit,
+
+    #[msg("Fee recipient account does not match the session's fee split")]
+    InvalidFeeRecipient,
+
+    #[msg("Win condition is not supported by this game mode")]
+    InvalidWinCondition,
+
+    #[msg("Reported winner contradicts the session's win condition")]
+    ResultContradictsWinCondition,
+
+    #[msg("A team has already won the match on score")]
+    ScoreAlreadyDecided,
+
+    #[msg("Score must increase, and an objective capture is submitted as 1")]
+    InvalidScore,
+
+    #[msg("Declared result is not valid for this session")]
+    InvalidWinnerDeclaration,
+
+    #[msg("Match cannot be declared a draw, a team is ahead under its win condition")]
+    DrawNotPossible,
+
+    #[msg("Only two-team winner-takes-all sessions can be forfeited")]
+    ForfeitNotSupported,
+
+    #[msg("Team has not been inactive for the forfeit timeout, or its opponent is inactive too")]
+    TeamNotInactive,
+
+    #[msg("Invalid forfeit timeout or penalty")]
+    InvalidForfeitTerms,
+
+    #[msg("Session was flagged for suspicious play, its result must go through a reviewed dispute window")]
+    AuditReviewRequired,
+
+    #[msg("Config rake no longer matches the session template")]
+    TemplateOutdated,
+
+    #[msg("Session template does not belong to this schedule")]
+    InvalidSessionTemplate,
+
+    #[msg("Invalid recurring match interval or join window")]
+    InvalidRecurringSchedule,
+
+    #[msg("Next instance of the recurring match is not due yet")]
+    RecurringMatchNotDue,
+
+    #[msg("Recurring match has spawned all of its instances")]
+    RecurringMatchFinished,
+
+    #[msg("Freeroll sponsors must be distinct, non-default and within the limit")]
+    InvalidFreerollSponsors,
+
+    #[msg("Only the config authority or a whitelisted sponsor can run freerolls")]
+    UnauthorizedFreerollSponsor,
+
+    #[msg("Freerolls take no bet, no spawn payments and refund in full")]
+    InvalidFreeroll,
+
+    #[msg("Sponsor token account is required for an SPL freeroll prize")]
+    MissingSponsorTokenAccount,
+
+    #[msg("Prize deposits must be positive")]
+    InvalidPrizeAmount,
+
+    #[msg("Session does not take sponsored prizes")]
+    PrizeSponsorshipUnavailable,
+
+    #[msg("Session already has the maximum number of prize sponsors")]
+    PrizeSponsorLimitReached,
+
+    #[msg("Assist weight lets a kill's assists take more than the kill")]
+    InvalidAssistWeight,
+
+    #[msg("Session does not require players to report ready")]
+    ReadinessNotRequired,
+
+    #[msg("Completed sessions need their result record before closing")]
+    MissingMatchResult,
+
+    #[msg("Pledged bets are collected once the lobby is full or its join deadline has passed")]
+    PledgesNotDue,
+
+    #[msg("Session has no pledged bets to collect")]
+    NoOutstandingPledges,
+
+    #[msg("Only the session authority can collect pledged bets")]
+    UnauthorizedPledgeCollection,
+
+    #[msg("Yield programs must be distinct, non-default and within the limit, with a treasury share of at most 100%")]
+    InvalidYieldPrograms,
+
+    #[msg("Yield program is not whitelisted or differs from the one holding parked funds")]
+    YieldProgramNotAllowed,
+
+    #[msg("Yield program moved a different amount than requested")]
+    InvalidYieldTransfer,
+
+    #[msg("Yield program returned less than the parked principal")]
+    YieldPrincipalShortfall,
+
+    #[msg("Parked tournament funds must be withdrawn before payout")]
+    YieldFundsParked,
+
+    #[msg("Stake must be between the session bet and its maximum stake")]
+    InvalidStake,
+
+    #[msg("Maximum stake must be at least the bet and within the config's maximum, in winner-takes-all team sessions")]
+    InvalidStakeRange,
+
+    #[msg("Recorded team stakes do not reconcile with the seats or the vault")]
+    StakeMismatch,
+
+    #[msg("Announcement must be 1 to 64 bytes of text without control characters")]
+    InvalidAnnouncement,
+
+    #[msg("Session announcement board is full")]
+    AnnouncementsFull,
+
+    #[msg("Only the session authority can post announcements")]
+    UnauthorizedAnnouncement,
+
+    #[msg("Clock skew tolerance exceeds the maximum or closes the dispute window")]
+    InvalidClockSkew,
+
+    #[msg("Operation's deadline has passed")]
+    OperationExpired,
+
+    #[msg("Operation is not open until its deadline")]
+    OperationPremature,
+
+    #[msg("Signer is not the config's pending authority")]
+    NotPendingAuthority,
+
+    #[msg("Kill, spawn or assist counter is full")]
+    CounterOverflow,
+
+    #[msg("Player joined with a bet receipt, which the refund must close")]
+    MissingBetReceipt,
+
+    #[msg("Bet receipt does not match the player's seat")]
+    BetReceiptMismatch,
+
+    #[msg("Bet receipt is still backing a seat in an unsettled session")]
+    BetReceiptInUse,
+
+    #[msg("Bond slash must not exceed 10000 basis points")]
+    InvalidBondSlashBps,
+
+    #[msg("Bond amount must be greater than zero")]
+    InvalidBondAmount,
+
+    #[msg("Bond holds fewer lamports than requested")]
+    InsufficientBond,
+
+    #[msg("No bond withdrawal is pending or it is still locked")]
+    BondWithdrawalLocked,
+
+    #[msg("Arbiter council must hold distinct non-default keys, at most MAX_ARBITERS")]
+    InvalidArbiterCouncil,
+
+    #[msg("Signer doesn't sit on the arbiter council")]
+    NotOnArbiterCouncil,
+
+    #[msg("Results must be committed and revealed while the config requires it")]
+    ResultCommitRequired,
+
+    #[msg("Revealed result and salt don't match the commitment")]
+    ResultCommitmentMismatch,
+
+    #[msg("Result can't be revealed in the slot it was committed")]
+    RevealTooEarly,
+
+    #[msg("Token transfer moved a different amount than intended")]
+    TransferAmountMismatch,
+
+    #[msg("Lookup table address doesn't match the vault's table for the slot")]
+    InvalidLookupTable,
+
+    #[msg("Disputes are decided by the arbiter council")]
+    CouncilDecidesDisputes,
+
+    #[msg("Result commitment is missing signing set approvals")]
+    ResultCommitmentPending,
+
+    #[msg("Result commitment already reached the threshold")]
+    ResultAlreadyCommitted,
+
+    #[msg("Session's settlement lookup table is still open")]
+    SettlementTableOpen,
+
+    #[msg("Bet receipt was already refunded or paid out")]
+    BetReceiptClaimed,
+
+    #[msg("Kick marker can't be closed before its session settles")]
+    SessionKickInUse,
+
+    #[msg("Session's result was already overturned against the operator's bond")]
+    BondAlreadySlashed,
+}
+
+// Context helpers: log the offending value before failing, so a failed
+// settlement's logs show which amount, player or team it tripped on. Build
+// the error lazily, e.g. with `ok_or_else`, so nothing is logged on success.
+
+/// Logs the amount a failure concerns and returns the error
+pub fn err_with_amount(error: WagerError, amount: u64) -> Error {
+    msg!("{}: amount {}", error, amount);
+    error.into()
+}
+
+/// Logs the player a failure concerns and returns the error
+pub fn err_with_player(error: WagerError, player: &Pubkey) -> Error {
+    msg!("{}: player {}", error, player);
+    error.into()
+}
+
+/// Logs the team a failure concerns and returns the error
+pub fn err_with_team(error: WagerError, team: u8) -> Error {
+    msg!("{}: team {}", error, team);
+    error.into()
+}
+
+/// Logs the deadline a timing failure concerns and the clock it was checked at
+pub fn err_with_deadline(error: WagerError, deadline: i64, now: i64) -> Error {
+    msg!("{}: deadline {}, now {}", error, deadline, now);
+    error.into()
 }
@@ -0,0 +1,651 @@
+//! Events emitted on every game session state transition
+use crate::state::{ApprovalKind, GameMode, PrizeSponsor, SessionStatus};
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PauseUpdated {
+    pub authority: Pubkey,
+    pub paused: bool,
+}
+
+#[event]
+pub struct SessionCreated {
+    pub session_id: String,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub bet_amount: u64,
+    pub game_mode: GameMode,
+    pub team_count: u8,
+}
+
+#[event]
+pub struct PlayerJoined {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub team: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PlayerLeft {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub refund: u64,
+    pub fee: u64,
+}
+
+#[event]
+pub struct PlayerSubstituted {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub replacement: Pubkey,
+    pub team: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PlayerQueued {
+    pub queue: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PlayerDequeued {
+    pub queue: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MatchFormed {
+    pub queue: Pubkey,
+    pub session_id: String,
+    pub players: Vec<Pubkey>,
+}
+
+#[event]
+pub struct SessionStarted {
+    pub session_id: String,
+}
+
+#[event]
+pub struct KillRecorded {
+    pub session_id: String,
+    pub killer: Pubkey,
+    pub killer_team: u8,
+    pub victim: Pubkey,
+    pub victim_team: u8,
+    pub assists: Vec<Pubkey>,
+}
+
+#[event]
+pub struct SpawnPurchased {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub team: u8,
+    pub amount: u64,
+    pub spawns: u16,
+}
+
+#[event]
+pub struct PlayerEliminated {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub team: u8,
+}
+
+#[event]
+pub struct SpawnsToppedUp {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub team: u8,
+    pub amount: u64,
+    pub spawns: u16,
+    pub total_top_up: u64,
+}
+
+#[event]
+pub struct RoundRecorded {
+    pub session_id: String,
+    pub round: u8,
+    pub winning_team: u8,
+    pub round_wins: u8,
+}
+
+#[event]
+pub struct ResultSubmitted {
+    pub session_id: String,
+    pub winning_team: u8,
+    pub draw: bool,
+    pub dispute_deadline: i64,
+}
+
+#[event]
+pub struct ResultOverridden {
+    pub session_id: String,
+    pub arbiter: Pubkey,
+    pub previous_team: u8,
+    pub winning_team: u8,
+}
+
+#[event]
+pub struct OperationApproved {
+    pub session_id: String,
+    pub kind: ApprovalKind,
+    pub winning_team: u8,
+    pub approver: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ReferralRecorded {
+    pub referee: Pubkey,
+    pub referrer: Pubkey,
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub session_id: String,
+    pub referrer: Pubkey,
+    pub referee: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EarningsClaimed {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WinningsDistributed {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RefundIssued {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SettlementRecovered {
+    pub session_id: String,
+    pub status: SessionStatus,
+}
+
+#[event]
+pub struct FeesCollected {
+    pub session_id: String,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TournamentCreated {
+    pub tournament_id: String,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub entry_fee: u64,
+    pub team_count: u8,
+}
+
+#[event]
+pub struct TournamentTeamRegistered {
+    pub tournament_id: String,
+    pub captain: Pubkey,
+    pub seed: u8,
+}
+
+#[event]
+pub struct MatchResultReported {
+    pub tournament_id: String,
+    pub round: u8,
+    pub match_index: u8,
+    pub winner: Pubkey,
+}
+
+#[event]
+pub struct TournamentCompleted {
+    pub tournament_id: String,
+    pub champion: Pubkey,
+    pub prize: u64,
+}
+
+#[event]
+pub struct SpectatorBetPlaced {
+    pub session_id: String,
+    pub bettor: Pubkey,
+    pub team: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SpectatorPayoutClaimed {
+    pub session_id: String,
+    pub bettor: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RandomnessRequested {
+    pub session_id: String,
+    pub seed: [u8; 32],
+}
+
+#[event]
+pub struct RandomnessRevealed {
+    pub session_id: String,
+    pub randomness: [u8; 64],
+    pub map_index: u8,
+    pub starting_team: u8,
+}
+
+#[event]
+pub struct JackpotFunded {
+    pub session_id: String,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
+
+#[event]
+pub struct JackpotWon {
+    pub session_id: String,
+    pub mint: Pubkey,
+    pub prize: u64,
+}
+
+#[event]
+pub struct JackpotPaid {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct StreakBonusClaimed {
+    pub player: Pubkey,
+    pub win_streak: u64,
+    pub bonuses: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RatingUpdated {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub old_rating: u64,
+    pub new_rating: u64,
+}
+
+#[event]
+pub struct CreatorPenaltyPaid {
+    pub session_id: String,
+    pub creator: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MatchAborted {
+    pub session_id: String,
+    pub reason: u8,
+    pub refunded: u64,
+    pub spawns_returned: bool,
+}
+
+#[event]
+pub struct SessionAuthorityDelegated {
+    pub session_id: String,
+    pub reporter: Pubkey,
+}
+
+#[event]
+pub struct TrophyMinted {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub mint: Pubkey,
+    pub score: String,
+}
+
+#[event]
+pub struct PlayerBanUpdated {
+    pub authority: Pubkey,
+    pub player: Pubkey,
+    pub banned: bool,
+    pub reason: u8,
+}
+
+#[event]
+pub struct PlayerKicked {
+    pub session_id: String,
+    pub team: u8,
+    pub captain: Pubkey,
+    pub player: Pubkey,
+    pub refund: u64,
+}
+
+#[event]
+pub struct CaptaincyTransferred {
+    pub session_id: String,
+    pub team: u8,
+    pub previous_captain: Pubkey,
+    pub captain: Pubkey,
+}
+
+#[event]
+pub struct CreationFeeSettled {
+    pub session_id: String,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+}
+
+#[event]
+pub struct PayoutWithheld {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct UnclaimedPayoutClaimed {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SessionLocked {
+    pub session_id: String,
+    pub players: u8,
+    pub started: bool,
+}
+
+#[event]
+pub struct ExposureReleased {
+    pub player: Pubkey,
+    pub game_session: Pubkey,
+    pub amount: u64,
+    pub total: u64,
+}
+
+#[event]
+pub struct FeeShareRouted {
+    pub session_id: String,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ScoreSubmitted {
+    pub session_id: String,
+    pub team: u8,
+    pub score: u32,
+    pub decided: bool,
+}
+
+#[event]
+pub struct ForfeitDeclared {
+    pub session_id: String,
+    pub forfeiting_team: u8,
+    pub winning_team: u8,
+    pub inactivity: bool,
+    pub penalty_bps: u16,
+    pub dispute_deadline: i64,
+}
+
+#[event]
+pub struct SessionFlagged {
+    pub session_id: String,
+    pub audit_flags: u8,
+}
+
+#[event]
+pub struct SessionTemplateCreated {
+    pub template: Pubkey,
+    pub authority: Pubkey,
+    pub template_id: u64,
+    pub mint: Pubkey,
+    pub bet_amount: u64,
+    pub game_mode: GameMode,
+    pub fee_bps: u16,
+}
+
+#[event]
+pub struct SessionCreatedFromTemplate {
+    pub session_id: String,
+    pub template: Pubkey,
+    pub sessions_created: u64,
+}
+
+#[event]
+pub struct RecurringMatchCreated {
+    pub recurring_match: Pubkey,
+    pub template: Pubkey,
+    pub prefix: String,
+    pub interval_secs: i64,
+    pub first_start: i64,
+}
+
+#[event]
+pub struct RecurringInstanceSpawned {
+    pub recurring_match: Pubkey,
+    pub session_id: String,
+    pub instance: u32,
+    pub next_start: i64,
+    pub reimbursed: u64,
+}
+
+#[event]
+pub struct FreerollFunded {
+    pub session_id: String,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub from_treasury: bool,
+    pub prize_pool: u64,
+}
+
+#[event]
+pub struct FreerollPrizeReclaimed {
+    pub session_id: String,
+    pub sponsor_prize: u64,
+    pub treasury_prize: u64,
+}
+
+#[event]
+pub struct PrizeAdded {
+    pub session_id: String,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub sponsored_amount: u64,
+}
+
+#[event]
+pub struct SponsoredPrizeReclaimed {
+    pub session_id: String,
+    pub sponsors: Vec<PrizeSponsor>,
+}
+
+#[event]
+pub struct SessionSettled {
+    pub session_id: String,
+    pub outcome: String,
+    pub total_paid_out: u64,
+    pub sponsored_amount: u64,
+    pub sponsors: Vec<PrizeSponsor>,
+}
+
+#[event]
+pub struct PlayerReadied {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub ready: bool,
+    pub ready_count: u8,
+}
+
+#[event]
+pub struct MatchResultRecorded {
+    pub session_id: String,
+    pub match_result: Pubkey,
+    pub winning_team: u8,
+    pub winner: Pubkey,
+}
+
+#[event]
+pub struct BetPledged {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub team: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PledgeCollected {
+    pub session_id: String,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PledgeDefaulted {
+    pub session_id: String,
+    pub player: Pubkey,
+}
+
+#[event]
+pub struct TournamentFundsParked {
+    pub tournament_id: String,
+    pub yield_program: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TournamentFundsWithdrawn {
+    pub tournament_id: String,
+    pub yield_program: Pubkey,
+    pub principal: u64,
+    pub earned: u64,
+}
+
+#[event]
+pub struct AnnouncementPosted {
+    pub session_id: String,
+    pub index: u8,
+    pub text: String,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityAccepted {
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct MintRegistered {
+    pub mint: Pubkey,
+    pub min_bet: u64,
+    pub max_bet: u64,
+}
+
+#[event]
+pub struct MintDeregistered {
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct BondPosted {
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub total: u64,
+}
+
+#[event]
+pub struct BondWithdrawalRequested {
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+}
+
+#[event]
+pub struct BondWithdrawn {
+    pub operator: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BondSlashed {
+    pub session_id: String,
+    pub operator: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DisputeVoteCast {
+    pub session_id: String,
+    pub arbiter: Pubkey,
+    pub winning_team: u8,
+    pub support: u8,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub session_id: String,
+    pub previous_team: u8,
+    pub winning_team: u8,
+    pub votes: u8,
+}
+
+#[event]
+pub struct ResultCommitted {
+    pub session_id: String,
+    pub game_server: Pubkey,
+    pub hash: [u8; 32],
+    pub approvals: u8,
+    pub committed: bool,
+}
+
+#[event]
+pub struct SettlementTableCreated {
+    pub session_id: String,
+    pub lookup_table: Pubkey,
+}
+
+#[event]
+pub struct SettlementTableExtended {
+    pub session_id: String,
+    pub lookup_table: Pubkey,
+    pub addresses: u8,
+}
+
+#[event]
+pub struct SettlementTableDeactivated {
+    pub session_id: String,
+    pub lookup_table: Pubkey,
+}
+
+#[event]
+pub struct SettlementTableClosed {
+    pub session_id: String,
+    pub lookup_table: Pubkey,
+}
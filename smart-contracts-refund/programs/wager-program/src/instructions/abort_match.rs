@@ -0,0 +1,149 @@
+use crate::{errors::WagerError, events::*, state::*, utils::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Aborts a match that crashed mid-game and returns the escrow to its players.
+/// Under a pro-rata refund policy every player gets back their share of what
+/// they deposited, spawn purchases included; otherwise spawn purchases are
+/// burned into the pot, which is then refunded in proportion to base bets.
+/// Remaining accounts are (player, payout destination, bet receipt) triplets.
+pub fn abort_match_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AbortMatch<'info>>,
+    session_id: String,
+    reason: u8,
+) -> Result<()> {
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    // Only a match that actually started can crash mid-game
+    require!(
+        game_session.status()? == SessionStatus::InProgress,
+        WagerError::GameNotInProgress
+    );
+    game_session.begin_settlement(Clock::get()?.unix_timestamp)?;
+    msg!("Aborting session {} with reason {}", session_id, reason);
+
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 3 == 0,
+        WagerError::InvalidRemainingAccounts
+    );
+
+    let returns_spawns = game_session.refund_policy()? == RefundPolicy::ProRataOnAbort;
+    let deposits = game_session.player_deposits()?;
+    let weights: Vec<u64> = deposits
+        .iter()
+        .map(|(player, deposit)| {
+            if returns_spawns {
+                *deposit
+            } else {
+                game_session.stake_of(*player)
+            }
+        })
+        .collect();
+    // A sponsored prize is returned to its sponsors, not split among players
+    let escrowed =
+        safe_math::safe_subtract(ctx.accounts.vault.remaining()?, game_session.prize_pool()?)?;
+    let refunds = safe_math::pro_rata_split(escrowed, &weights)?;
+
+    for ((player, _), refund) in deposits.into_iter().zip(refunds) {
+        let player_index = ctx
+            .remaining_accounts
+            .iter()
+            .step_by(3)
+            .position(|acc| acc.key() == player)
+            .ok_or(WagerError::InvalidPlayer)?;
+        let player_account = &ctx.remaining_accounts[player_index * 3];
+        let destination = &ctx.remaining_accounts[player_index * 3 + 1];
+        settle_bet_receipt(
+            &ctx.remaining_accounts[player_index * 3 + 2],
+            ctx.accounts.game_session.key(),
+            player,
+            ReceiptStatus::Refunded,
+        )?;
+
+        validate_payout_destination(
+            &game_session,
+            player_account,
+            destination,
+            WagerError::InvalidPlayerTokenAccount,
+        )?;
+
+        if refund > 0 {
+            release_from_vault(
+                &game_session,
+                &ctx.accounts.vault,
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                destination,
+                &session_id,
+                refund,
+            )?;
+            ctx.accounts.vault.record_refund(refund)?;
+        }
+
+        emit!(RefundIssued {
+            session_id: session_id.clone(),
+            player,
+            amount: refund,
+        });
+    }
+
+    // Verify vault accounting against the live escrow balance
+    let balance = escrow_balance(
+        &game_session,
+        &ctx.accounts.vault,
+        &mut ctx.accounts.vault_token_account,
+    )?;
+    ctx.accounts.vault.check_invariant(balance)?;
+
+    game_session.transition_to(SessionStatus::Refunded)?;
+
+    emit!(MatchAborted {
+        session_id,
+        reason,
+        refunded: escrowed,
+        spawns_returned: returns_spawns,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct AbortMatch<'info> {
+    /// The game server authority that created the session
+    pub game_server: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == game_server.key() @ WagerError::UnauthorizedDistribution,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,30 @@
+use crate::{errors::WagerError, events::AuthorityAccepted, state::*};
+use anchor_lang::prelude::*;
+
+/// Completes a config authority handover, signed by the proposed authority
+pub fn accept_authority_handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_authority = config.authority;
+    config.authority = config.pending_authority;
+    config.pending_authority = Pubkey::default();
+
+    emit!(AuthorityAccepted {
+        previous_authority,
+        authority: config.authority,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub pending_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.pending_authority != Pubkey::default() @ WagerError::NotPendingAuthority,
+        has_one = pending_authority @ WagerError::NotPendingAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
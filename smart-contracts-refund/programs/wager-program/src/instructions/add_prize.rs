@@ -0,0 +1,109 @@
+use crate::{
+    errors::WagerError, events::PrizeAdded, state::*, utils::transfer_spl_tokens, validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Lets any wallet add to a session's prize before the match starts. The
+/// deposit grows the pot the winners split without changing the per-player
+/// bet, and is returned to the sponsor if the session is refunded.
+pub fn add_prize_handler(ctx: Context<AddPrize>, session_id: String, amount: u64) -> Result<()> {
+    validation::validate_session_id(&session_id)?;
+    require!(amount > 0, WagerError::InvalidPrizeAmount);
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    game_session.add_sponsored_prize(ctx.accounts.sponsor.key(), amount)?;
+
+    if game_session.is_native() {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.sponsor.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    } else {
+        let sponsor_token_account = ctx
+            .accounts
+            .sponsor_token_account
+            .as_ref()
+            .ok_or(error!(WagerError::MissingSponsorTokenAccount))?;
+        transfer_spl_tokens(
+            sponsor_token_account,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.sponsor,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+    }
+    ctx.accounts.vault.record_deposit(amount)?;
+
+    emit!(PrizeAdded {
+        session_id,
+        sponsor: ctx.accounts.sponsor.key(),
+        amount,
+        sponsored_amount: game_session.sponsored_amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct AddPrize<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Sponsor's token account, required for SPL sessions
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sponsor,
+        token::token_program = token_program,
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
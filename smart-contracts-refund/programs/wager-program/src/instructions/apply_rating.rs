@@ -0,0 +1,60 @@
+use crate::{errors::WagerError, events::RatingUpdated, state::*};
+use anchor_lang::prelude::*;
+
+/// Applies a participant's Elo rating change for a completed session.
+/// Winners are rated as they are paid by `distribute_winnings`; every other
+/// participant is rated through this permissionless crank, once per session.
+pub fn apply_rating_handler(
+    ctx: Context<ApplyRating>,
+    session_id: String,
+    player: Pubkey,
+) -> Result<()> {
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    require!(
+        game_session.status()? == SessionStatus::Completed,
+        WagerError::GameNotCompleted
+    );
+
+    let rating_change =
+        game_session.take_rating_change(player, ctx.accounts.config.elo_k_factor)?;
+    let player_stats = &mut ctx.accounts.player_stats;
+    let old_rating = player_stats.rating();
+    let new_rating = player_stats.apply_rating_change(rating_change);
+
+    emit!(RatingUpdated {
+        session_id,
+        player,
+        old_rating,
+        new_rating,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String, player: Pubkey)]
+pub struct ApplyRating<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Lifetime statistics of the rated player
+    #[account(
+        mut,
+        seeds = [b"player_stats", player.as_ref()],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+}
@@ -0,0 +1,71 @@
+use crate::{errors::WagerError, events::OperationApproved, state::*};
+use anchor_lang::prelude::*;
+
+/// Records one signing set key's approval of a sensitive operation on a
+/// session, letting the threshold be gathered across separate transactions
+pub fn approve_operation_handler(
+    ctx: Context<ApproveOperation>,
+    session_id: String,
+    kind: ApprovalKind,
+    winning_team: u8,
+) -> Result<()> {
+    let approver = ctx.accounts.game_server.key();
+    require!(
+        ctx.accounts.config.is_game_server(&approver),
+        WagerError::UnauthorizedGameServer
+    );
+
+    let approval = &mut ctx.accounts.approval;
+    if approval.game_session == Pubkey::default() {
+        approval.game_session = ctx.accounts.game_session.key();
+        approval.kind = kind;
+        approval.bump = ctx.bumps.approval;
+    }
+
+    // Keys rotated out of the signing set no longer count towards the threshold
+    let config = &ctx.accounts.config;
+    approval.approvers.retain(|key| config.is_game_server(key));
+    approval.approve(approver, winning_team);
+
+    emit!(OperationApproved {
+        session_id,
+        kind,
+        winning_team,
+        approver,
+        approvals: approval.approvers.len() as u8,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String, kind: ApprovalKind)]
+pub struct ApproveOperation<'info> {
+    /// Signing set key approving the operation, pays for the approval account
+    #[account(mut)]
+    pub game_server: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        init_if_needed,
+        payer = game_server,
+        space = Approval::LEN,
+        seeds = [b"approval", game_session.key().as_ref(), &[kind as u8]],
+        bump
+    )]
+    pub approval: Account<'info, Approval>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,78 @@
+use crate::{errors::WagerError, events::PlayerBanUpdated, state::*};
+use anchor_lang::prelude::*;
+
+/// Excludes a player flagged by the anti-cheat backend from joining sessions
+/// and buying spawns, without touching slots they already hold
+pub fn ban_player_handler(ctx: Context<BanPlayer>, player: Pubkey, reason: u8) -> Result<()> {
+    let player_ban = &mut ctx.accounts.player_ban;
+    player_ban.player = player;
+    player_ban.banned_at = Clock::get()?.unix_timestamp;
+    player_ban.reason = reason;
+    player_ban.bump = ctx.bumps.player_ban;
+
+    emit!(PlayerBanUpdated {
+        authority: ctx.accounts.authority.key(),
+        player,
+        banned: true,
+        reason,
+    });
+    Ok(())
+}
+
+/// Lifts a ban by closing the player's ban marker
+pub fn unban_player_handler(ctx: Context<UnbanPlayer>, player: Pubkey) -> Result<()> {
+    emit!(PlayerBanUpdated {
+        authority: ctx.accounts.authority.key(),
+        player,
+        banned: false,
+        reason: ctx.accounts.player_ban.reason,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct BanPlayer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PlayerBan::LEN,
+        seeds = [b"player_ban", player.as_ref()],
+        bump
+    )]
+    pub player_ban: Account<'info, PlayerBan>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct UnbanPlayer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"player_ban", player.as_ref()],
+        bump = player_ban.bump,
+    )]
+    pub player_ban: Account<'info, PlayerBan>,
+}
@@ -0,0 +1,129 @@
+use crate::{
+    errors::{err_with_team, WagerError},
+    events::{DisputeResolved, DisputeVoteCast},
+    state::*,
+    utils::*,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+
+/// Records a council member's vote on the winner of a winner-takes-all
+/// session while its dispute window is open. Once a strict majority of the
+/// council backs one team, that team's win replaces the submitted result and
+/// the dispute window closes, so the session can settle right away. Members
+/// may change their vote until then; only votes of keys still on the council
+/// count.
+///
+/// An overturning majority slashes the reporting operator's bond as
+/// `override_result` does, with the same remaining accounts.
+pub fn cast_dispute_vote_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CastDisputeVote<'info>>,
+    _session_id: String,
+    winning_team: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    require!(
+        game_session.status()? == SessionStatus::ResultSubmitted,
+        WagerError::InvalidGameState
+    );
+    validation::validate_before_deadline(
+        now,
+        game_session.dispute_deadline,
+        game_session.clock_skew_secs,
+    )?;
+    require!(
+        game_session.supports_spectators(),
+        WagerError::ResultNotOverridable
+    );
+    validation::validate_team_number(winning_team, game_session.team_count())
+        .map_err(|_| err_with_team(WagerError::InvalidWinningTeam, winning_team))?;
+
+    let config = &ctx.accounts.config;
+    let arbiter = ctx.accounts.arbiter.key();
+    let dispute_votes = &mut ctx.accounts.dispute_votes;
+    dispute_votes.game_session = game_session_key;
+    dispute_votes.bump = ctx.bumps.dispute_votes;
+    dispute_votes.cast(arbiter, winning_team);
+    let support = dispute_votes.support(winning_team, &config.arbiters);
+
+    emit!(DisputeVoteCast {
+        session_id: game_session.session_id(),
+        arbiter,
+        winning_team,
+        support: support as u8,
+    });
+    if support < config.council_majority() {
+        return Ok(());
+    }
+
+    let previous_team = game_session.winning_team;
+    let overturned = game_session.correct_result(winning_team);
+    // The council's decision is final: close the window past its skew grace
+    game_session.dispute_deadline = now.saturating_sub(game_session.clock_skew_secs);
+
+    emit!(DisputeResolved {
+        session_id: game_session.session_id(),
+        previous_team,
+        winning_team,
+        votes: support as u8,
+    });
+
+    if overturned {
+        slash_bond(
+            &ctx.accounts.server_bond.to_account_info(),
+            &mut game_session,
+            winning_team,
+            config.bond_slash_bps,
+            ctx.remaining_accounts,
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct CastDisputeVote<'info> {
+    /// Council member casting the vote, paying for the tally on the first vote
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+        constraint = config.arbiters.contains(&arbiter.key()) @ WagerError::NotOnArbiterCouncil,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Council votes cast on the session's result
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = DisputeVotes::LEN,
+        seeds = [b"dispute_votes", game_session.key().as_ref()],
+        bump
+    )]
+    pub dispute_votes: Account<'info, DisputeVotes>,
+
+    /// CHECK: Bond PDA of the operator that reported the disputed result,
+    /// slashed on an overturn if the operator posted one
+    #[account(
+        mut,
+        seeds = [b"server_bond", game_session.load()?.authority.as_ref()],
+        bump,
+    )]
+    pub server_bond: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,109 @@
+use crate::{
+    errors::WagerError, events::EarningsClaimed, instructions::pay_to_spawn_earnings, state::*,
+    utils::*, validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Pays a player their own pay-to-spawn earnings once the session has settled
+pub fn claim_earnings_handler(ctx: Context<ClaimEarnings>, session_id: String) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    require!(
+        game_session.is_pay_to_spawn() && game_session.status()? == SessionStatus::Completed,
+        WagerError::InvalidGameState
+    );
+
+    // Marking the slot claimed prevents a second payout
+    let player = ctx.accounts.player.key();
+    let (team, index) = game_session.get_player_slot(player)?;
+    game_session.claim_earnings(team, index)?;
+
+    let (earnings, _) = pay_to_spawn_earnings(&game_session, player)?;
+    require!(earnings > 0, WagerError::NothingToClaim);
+    msg!("Earnings claimed by {}: {}", player, earnings);
+
+    let escrowed = escrow_balance(
+        &game_session,
+        &ctx.accounts.vault,
+        &mut ctx.accounts.vault_token_account,
+    )?;
+    validation::validate_payout_invariant([earnings], escrowed)?;
+
+    release_from_vault(
+        &game_session,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.player_token_account.to_account_info(),
+        &session_id,
+        earnings,
+    )?;
+    ctx.accounts.vault.record_payout(earnings)?;
+    ctx.accounts.player_stats.record_winnings(earnings)?;
+
+    emit!(EarningsClaimed {
+        session_id,
+        player,
+        amount: earnings,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct ClaimEarnings<'info> {
+    /// Player claiming their own earnings
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        mut,
+        constraint = player_token_account.owner == player.key() @ WagerError::InvalidPlayerTokenAccount,
+        constraint = player_token_account.mint == game_session.load()?.mint @ WagerError::InvalidTokenMint,
+    )]
+    pub player_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Lifetime statistics of the claiming player
+    #[account(
+        mut,
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,102 @@
+use crate::{
+    errors::WagerError, events::ReferralRewardsClaimed, state::*, utils::transfer_from_escrow,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Pays a referrer their cut of the rake on a referee's bet once the session
+/// settled. Refunded sessions and referees who left collected no rake, so their
+/// credits close without a payout.
+pub fn claim_referral_rewards_handler(
+    ctx: Context<ClaimReferralRewards>,
+    session_id: String,
+    referee: Pubkey,
+) -> Result<()> {
+    let game_session = ctx.accounts.game_session.load()?;
+    let status = game_session.status()?;
+    require!(status.is_terminal(), WagerError::SessionNotSettled);
+
+    let amount = if status == SessionStatus::Completed && game_session.has_player(referee) {
+        ctx.accounts.referral_credit.amount
+    } else {
+        0
+    };
+    msg!("Referral reward for {}: {}", ctx.accounts.referrer.key(), amount);
+
+    // Rewards are paid from the rake collected into the treasury
+    if amount > 0 {
+        transfer_from_escrow(
+            &ctx.accounts.treasury_token_account.to_account_info(),
+            &ctx.accounts.referrer_token_account.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+            &[&[b"treasury", &[ctx.bumps.treasury]]],
+        )?;
+    }
+
+    emit!(ReferralRewardsClaimed {
+        session_id,
+        referrer: ctx.accounts.referrer.key(),
+        referee,
+        amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String, referee: Pubkey)]
+pub struct ClaimReferralRewards<'info> {
+    pub referrer: Signer<'info>,
+
+    /// Referee who paid the credit's rent, refunded when it closes
+    #[account(mut, constraint = referee_wallet.key() == referee @ WagerError::InvalidReferrer)]
+    pub referee_wallet: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        mut,
+        seeds = [b"referral_credit", game_session.key().as_ref(), referee.as_ref()],
+        bump = referral_credit.bump,
+        has_one = referrer @ WagerError::InvalidReferrer,
+        close = referee_wallet,
+    )]
+    pub referral_credit: Account<'info, ReferralCredit>,
+
+    /// Treasury PDA that owns the fee token accounts
+    #[account(
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = referrer_token_account.owner == referrer.key() @ WagerError::InvalidPlayerTokenAccount,
+        constraint = referrer_token_account.mint == game_session.load()?.mint @ WagerError::InvalidTokenMint,
+    )]
+    pub referrer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
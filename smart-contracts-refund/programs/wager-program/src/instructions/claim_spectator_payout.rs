@@ -0,0 +1,101 @@
+use crate::{
+    errors::WagerError, events::SpectatorPayoutClaimed, state::*, utils::transfer_from_escrow,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn claim_spectator_payout_handler(
+    ctx: Context<ClaimSpectatorPayout>,
+    session_id: String,
+) -> Result<()> {
+    let game_session = ctx.accounts.game_session.load()?;
+
+    // Settles on the result recorded by distribute_winnings, or refunds
+    // stakes of refunded and drawn sessions
+    let winning_team = match game_session.status()? {
+        SessionStatus::Completed if game_session.is_draw() => None,
+        SessionStatus::Completed => Some(
+            game_session
+                .winning_team()
+                .ok_or(error!(WagerError::SessionNotSettled))?,
+        ),
+        SessionStatus::Refunded => None,
+        _ => return Err(error!(WagerError::SessionNotSettled)),
+    };
+
+    let bet = &mut ctx.accounts.spectator_bet;
+    require!(!bet.claimed, WagerError::SpectatorBetAlreadyClaimed);
+    bet.claimed = true;
+
+    let pool = &ctx.accounts.spectator_pool;
+    let payout = pool.payout(bet, winning_team)?;
+    msg!("Spectator payout for {}: {}", bet.bettor, payout);
+
+    if payout > 0 {
+        transfer_from_escrow(
+            &ctx.accounts.pool_token_account.to_account_info(),
+            &ctx.accounts.bettor_token_account.to_account_info(),
+            &pool.to_account_info(),
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            payout,
+            &[&[b"spectator_pool", session_id.as_bytes(), &[pool.bump]]],
+        )?;
+    }
+
+    emit!(SpectatorPayoutClaimed {
+        session_id,
+        bettor: bet.bettor,
+        amount: payout,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct ClaimSpectatorPayout<'info> {
+    pub bettor: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        seeds = [b"spectator_pool", session_id.as_bytes()],
+        bump = spectator_pool.bump,
+    )]
+    pub spectator_pool: Account<'info, SpectatorPool>,
+
+    #[account(
+        mut,
+        seeds = [b"spectator_bet", session_id.as_bytes(), bettor.key().as_ref()],
+        bump = spectator_bet.bump,
+    )]
+    pub spectator_bet: Account<'info, SpectatorBet>,
+
+    #[account(
+        mut,
+        constraint = bettor_token_account.owner == bettor.key() @ WagerError::InvalidPlayerTokenAccount,
+        constraint = bettor_token_account.mint == game_session.load()?.mint @ WagerError::InvalidTokenMint,
+    )]
+    pub bettor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = spectator_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
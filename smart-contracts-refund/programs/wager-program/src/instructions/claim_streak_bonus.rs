@@ -0,0 +1,90 @@
+use crate::{
+    errors::WagerError, events::StreakBonusClaimed, state::*, utils::transfer_from_escrow,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Pays a player the bonuses for the streak milestones they reached, from the
+/// rewards vault funded by the operator. Bonuses are forfeited once a session
+/// joined after the last win breaks the streak.
+pub fn claim_streak_bonus_handler(ctx: Context<ClaimStreakBonus>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(
+        config.streak_milestone > 0 && config.streak_bonus > 0,
+        WagerError::StreakBonusUnavailable
+    );
+
+    let player_stats = &mut ctx.accounts.player_stats;
+    let bonuses = player_stats.take_bonuses()?;
+    let amount = safe_math::safe_multiply(bonuses, config.streak_bonus)?;
+    msg!("Streak bonus for {}: {}", player_stats.player, amount);
+
+    transfer_from_escrow(
+        &ctx.accounts.rewards_token_account.to_account_info(),
+        &ctx.accounts.player_token_account.to_account_info(),
+        &ctx.accounts.rewards.to_account_info(),
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount,
+        &[&[b"rewards", &[ctx.bumps.rewards]]],
+    )?;
+
+    emit!(StreakBonusClaimed {
+        player: player_stats.player,
+        win_streak: player_stats.win_streak,
+        bonuses,
+        amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimStreakBonus<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Lifetime statistics holding the player's streak
+    #[account(
+        mut,
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Rewards vault PDA that owns the bonus token account
+    #[account(
+        seeds = [b"rewards"],
+        bump,
+    )]
+    pub rewards: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = rewards,
+        associated_token::token_program = token_program,
+    )]
+    pub rewards_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = player_token_account.owner == player.key() @ WagerError::InvalidPlayerTokenAccount,
+        constraint = player_token_account.mint == mint.key() @ WagerError::InvalidTokenMint,
+    )]
+    pub player_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mint streak bonuses are paid in
+    #[account(address = config.rewards_mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,167 @@
+use crate::{errors::WagerError, events::RefundIssued, state::*, utils::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn claim_timeout_refund_handler(
+    ctx: Context<ClaimTimeoutRefund>,
+    session_id: String,
+) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let clock = Clock::get()?;
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    // Settled sessions have nothing left to reclaim, and a submitted result
+    // can be finalized by anyone
+    let status = game_session.status()?;
+    require!(
+        !status.is_terminal() && status != SessionStatus::ResultSubmitted,
+        WagerError::InvalidGameState
+    );
+
+    // Only open once the session has expired, or once it was locked short
+    // of its minimum players
+    if status != SessionStatus::Refundable {
+        validation::validate_deadline_reached(
+            clock.unix_timestamp,
+            game_session.session_expiry,
+            game_session.clock_skew_secs,
+        )?;
+    }
+
+    // Removing the player from the roster prevents a second claim. The
+    // refund covers the spawns they bought as well as their bet, and a
+    // pledged bet never left the player's account.
+    let player = ctx.accounts.player.key();
+    let refund = game_session.deposit_of(player)?;
+    let receipted = open_bet_receipt(
+        &ctx.accounts.bet_receipt,
+        player,
+        game_session.stake_of(player),
+    )?
+    .is_some();
+    game_session.remove_player(player)?;
+    msg!("Timeout refund for player {}: {}", player, refund);
+
+    // Native SOL sessions refund to the player wallet itself
+    let destination = if game_session.is_native() {
+        ctx.accounts.player.to_account_info()
+    } else {
+        let player_token_account = ctx
+            .accounts
+            .player_token_account
+            .as_ref()
+            .ok_or(error!(WagerError::InvalidPlayerTokenAccount))?;
+        require!(
+            player_token_account.owner == player,
+            WagerError::InvalidPlayerTokenAccount
+        );
+        require!(
+            player_token_account.mint == game_session.mint,
+            WagerError::InvalidTokenMint
+        );
+        player_token_account.to_account_info()
+    };
+
+    // Transfer funds from vault back to the player
+    if refund > 0 {
+        release_from_vault(
+            &game_session,
+            &ctx.accounts.vault,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &destination,
+            &session_id,
+            refund,
+        )?;
+        ctx.accounts.vault.record_refund(refund)?;
+    }
+    release_exposure(
+        ctx.accounts.active_exposure.as_mut(),
+        ctx.accounts.game_session.key(),
+    )?;
+
+    emit!(RefundIssued {
+        session_id: session_id.clone(),
+        player,
+        amount: refund,
+    });
+
+    // Once everyone has reclaimed their bet the session is finished
+    if game_session.is_empty() {
+        game_session.transition_to(SessionStatus::Refunded)?;
+    }
+
+    // The receipt backed the seat's only claim
+    if receipted {
+        redeem_bet_receipt(
+            &ctx.accounts.bet_receipt,
+            &ctx.accounts.player.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct ClaimTimeoutRefund<'info> {
+    /// Player reclaiming their own bet
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Player's token account, omitted for native SOL sessions
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Open bets of the refunded player, released for this session if passed
+    #[account(
+        mut,
+        seeds = [b"active_exposure", player.key().as_ref()],
+        bump = active_exposure.bump,
+    )]
+    pub active_exposure: Option<Account<'info, ActiveExposure>>,
+
+    /// CHECK: Receipt PDA for the player's bet, closed by the refund if they
+    /// joined with one
+    #[account(
+        mut,
+        seeds = [b"bet_receipt", game_session.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub bet_receipt: UncheckedAccount<'info>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
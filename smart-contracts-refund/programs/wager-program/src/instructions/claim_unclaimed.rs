@@ -0,0 +1,94 @@
+use crate::{
+    errors::WagerError, events::UnclaimedPayoutClaimed, state::*, utils::*, validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Pays a payout withheld during distribution because the player's token
+/// account was closed or frozen, once they supply a usable token account
+pub fn claim_unclaimed_handler(ctx: Context<ClaimUnclaimed>, session_id: String) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let game_session = ctx.accounts.game_session.load()?;
+    let player = ctx.accounts.player.key();
+
+    let amount = ctx.accounts.unclaimed_payout.take(player)?;
+
+    release_from_vault(
+        &game_session,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.player_token_account.to_account_info(),
+        &session_id,
+        amount,
+    )?;
+    ctx.accounts.vault.record_payout(amount)?;
+
+    emit!(UnclaimedPayoutClaimed {
+        session_id,
+        player,
+        amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct ClaimUnclaimed<'info> {
+    /// Payee of the withheld payout
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        mut,
+        seeds = [b"unclaimed_payout", game_session.key().as_ref()],
+        bump = unclaimed_payout.bump,
+    )]
+    pub unclaimed_payout: Account<'info, UnclaimedPayout>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = player,
+        token::token_program = token_program,
+    )]
+    pub player_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
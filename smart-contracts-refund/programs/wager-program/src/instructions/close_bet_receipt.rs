@@ -0,0 +1,60 @@
+use crate::{errors::WagerError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Closes a bet receipt that no longer backs a seat, returning its rent to
+/// the player: once the session has settled or been closed, or after the
+/// player lost their seat without a refund, as when a pledge defaults.
+/// Settlement pays every seat at once, marking the receipts of refunded and
+/// paid players, so receipts outlive it until their player reclaims them
+/// here.
+pub fn close_bet_receipt_handler(ctx: Context<CloseBetReceipt>) -> Result<()> {
+    // A closed session has nothing left the receipt could claim
+    let game_session_info = &ctx.accounts.game_session;
+    if game_session_info.data_is_empty() {
+        return Ok(());
+    }
+
+    // Read the session in place, as `AccountLoader` would, without tying
+    // it to the account's lifetime
+    require_keys_eq!(
+        *game_session_info.owner,
+        crate::ID,
+        WagerError::BetReceiptMismatch
+    );
+    let data = game_session_info.try_borrow_data()?;
+    require!(
+        data.len() >= GameSession::LEN && data[..8] == GameSession::DISCRIMINATOR,
+        WagerError::UnsupportedSessionVersion
+    );
+    let game_session: &GameSession = bytemuck::from_bytes(&data[8..GameSession::LEN]);
+    require!(
+        game_session.version == GAME_SESSION_VERSION,
+        WagerError::UnsupportedSessionVersion
+    );
+    require!(
+        game_session.status()?.is_terminal() || !game_session.has_player(ctx.accounts.player.key()),
+        WagerError::BetReceiptInUse
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseBetReceipt<'info> {
+    /// Player the receipt was issued to, who receives its rent
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// CHECK: Session the receipt was issued by, which may already be closed
+    #[account(address = bet_receipt.game_session @ WagerError::BetReceiptMismatch)]
+    pub game_session: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = player,
+        has_one = player @ WagerError::BetReceiptMismatch,
+        seeds = [b"bet_receipt", game_session.key().as_ref(), player.key().as_ref()],
+        bump = bet_receipt.bump,
+    )]
+    pub bet_receipt: Account<'info, BetReceipt>,
+}
@@ -0,0 +1,188 @@
+use crate::{errors::WagerError, events::CreationFeeSettled, state::*, utils::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+pub fn close_session_handler(ctx: Context<CloseSession>, session_id: String) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_session = ctx.accounts.game_session.load()?;
+
+    // Only settled sessions can be torn down
+    require!(
+        game_session.status()?.is_terminal(),
+        WagerError::InvalidGameState
+    );
+
+    // Completed sessions keep a permanent result record, which leaves
+    // indexers nothing to wait for; other sessions leave time for them
+    // before removing the accounts
+    if game_session.status()? == SessionStatus::Completed {
+        require!(
+            ctx.accounts.match_result.is_some(),
+            WagerError::MissingMatchResult
+        );
+    } else {
+        let closable_at = game_session
+            .settled_at
+            .checked_add(SESSION_CLOSE_COOLDOWN_SECS)
+            .ok_or(error!(WagerError::ArithmeticOverflow))?;
+        require!(
+            clock.unix_timestamp >= closable_at,
+            WagerError::SessionCloseCooldown
+        );
+    }
+
+    // The vault must still sign for the lookup table's close
+    require!(
+        game_session.settlement_table == Pubkey::default(),
+        WagerError::SettlementTableOpen
+    );
+
+    // Never close a vault that still escrows funds
+    let escrowed = escrow_balance(
+        &game_session,
+        &ctx.accounts.vault,
+        &mut ctx.accounts.vault_token_account,
+    )?;
+    require!(escrowed == 0, WagerError::VaultNotEmpty);
+
+    // Token-2022 accounts holding withheld transfer fees can't be closed
+    harvest_withheld_fees(
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault_token_account,
+    )?;
+
+    // Close the escrow token account, the session and vault close on exit
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.vault_token_account.to_account_info(),
+            destination: ctx.accounts.game_server.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        &[&[b"vault", session_id.as_bytes(), &[game_session.vault_bump]]],
+    ))?;
+
+    // Completed sessions get their creation fee back, abandoned ones forfeit
+    // it to the treasury
+    let creation_fee = game_session.creation_fee;
+    if creation_fee > 0 {
+        let config = &mut ctx.accounts.config;
+        config.held_creation_fees =
+            safe_math::safe_subtract(config.held_creation_fees, creation_fee)?;
+        let refunded = game_session.status()? == SessionStatus::Completed;
+        if refunded {
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.treasury.to_account_info(),
+                        to: ctx.accounts.game_server.to_account_info(),
+                    },
+                    &[&[b"treasury", &[ctx.bumps.treasury]]],
+                ),
+                creation_fee,
+            )?;
+        }
+        emit!(CreationFeeSettled {
+            session_id: session_id.clone(),
+            creator: game_session.authority,
+            amount: creation_fee,
+            refunded,
+        });
+    }
+
+    msg!("Closed game session: {}", session_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct CloseSession<'info> {
+    /// Session creator, receives the reclaimed rent
+    #[account(mut)]
+    pub game_server: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Treasury PDA holding the session's creation fee
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = game_server,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == game_server.key() @ WagerError::InvalidAuthority,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        mut,
+        close = game_server,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Discovery details, closed with the session when it has them
+    #[account(
+        mut,
+        close = game_server,
+        seeds = [b"session_metadata", session_id.as_bytes()],
+        bump = session_metadata.bump,
+    )]
+    pub session_metadata: Option<Account<'info, SessionMetadata>>,
+
+    /// Announcement board, closed with the session when it has one
+    #[account(
+        mut,
+        close = game_server,
+        seeds = [b"announcements", session_id.as_bytes()],
+        bump = announcements.bump,
+    )]
+    pub announcements: Option<Account<'info, Announcements>>,
+
+    /// Permanent result record, required to close a completed session
+    #[account(
+        seeds = [
+            b"match_result",
+            session_id.as_bytes(),
+            &game_session.load()?.created_at.to_le_bytes(),
+        ],
+        bump = match_result.bump,
+    )]
+    pub match_result: Option<Account<'info, MatchResultRecord>>,
+
+    /// Wager token of the session
+    #[account(
+        mut,
+        address = game_session.load()?.mint @ WagerError::InvalidMint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,56 @@
+use crate::{errors::WagerError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Closes a kick marker once its session has settled or been closed,
+/// returning its rent to the captain who kicked the player. The session
+/// takes no more seats by then, so the marker has nothing left to bar.
+pub fn close_session_kick_handler(ctx: Context<CloseSessionKick>) -> Result<()> {
+    let game_session_info = &ctx.accounts.game_session;
+    if game_session_info.data_is_empty() {
+        return Ok(());
+    }
+
+    // Read the session in place, as `AccountLoader` would, without tying
+    // it to the account's lifetime
+    require_keys_eq!(
+        *game_session_info.owner,
+        crate::ID,
+        WagerError::SessionKickInUse
+    );
+    let data = game_session_info.try_borrow_data()?;
+    require!(
+        data.len() >= GameSession::LEN && data[..8] == GameSession::DISCRIMINATOR,
+        WagerError::UnsupportedSessionVersion
+    );
+    let game_session: &GameSession = bytemuck::from_bytes(&data[8..GameSession::LEN]);
+    require!(
+        game_session.version == GAME_SESSION_VERSION,
+        WagerError::UnsupportedSessionVersion
+    );
+    require!(
+        game_session.status()?.is_terminal(),
+        WagerError::SessionKickInUse
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseSessionKick<'info> {
+    /// Captain who kicked the player, who receives the marker's rent
+    #[account(mut)]
+    pub captain: Signer<'info>,
+
+    /// CHECK: Session the player was kicked from, which may already be closed
+    #[account(address = session_kick.game_session @ WagerError::SessionKickInUse)]
+    pub game_session: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = captain,
+        has_one = captain @ WagerError::NotTeamCaptain,
+        seeds = [b"session_kick", game_session.key().as_ref(), session_kick.player.as_ref()],
+        bump = session_kick.bump,
+    )]
+    pub session_kick: Account<'info, SessionKick>,
+}
@@ -0,0 +1,107 @@
+use crate::{
+    errors::WagerError,
+    events::{SettlementTableClosed, SettlementTableDeactivated},
+    state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    address_lookup_table::{
+        self,
+        instruction::{close_lookup_table, deactivate_lookup_table},
+        state::AddressLookupTable,
+    },
+    program::invoke_signed,
+};
+
+/// Winds down a settled session's lookup table in two calls: the first
+/// deactivates it, the second closes it once the lookup table program's
+/// deactivation cooldown passed, returning its rent to the game server.
+/// `close_session` waits for the table to be closed so the rent isn't
+/// stranded with the vault.
+pub fn close_settlement_table_handler(
+    ctx: Context<CloseSettlementTable>,
+    session_id: String,
+) -> Result<()> {
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    require!(
+        game_session.status()?.is_terminal(),
+        WagerError::InvalidGameState
+    );
+
+    let lookup_table = ctx.accounts.lookup_table.key();
+    let active = {
+        let data = ctx.accounts.lookup_table.try_borrow_data()?;
+        let table = AddressLookupTable::deserialize(&data)
+            .map_err(|_| error!(WagerError::InvalidLookupTable))?;
+        table.meta.deactivation_slot == u64::MAX
+    };
+
+    let vault = ctx.accounts.vault.key();
+    let signer_seeds: &[&[u8]] = &[b"vault", session_id.as_bytes(), &[game_session.vault_bump]];
+    if active {
+        invoke_signed(
+            &deactivate_lookup_table(lookup_table, vault),
+            &[
+                ctx.accounts.lookup_table.to_account_info(),
+                ctx.accounts.vault.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+        emit!(SettlementTableDeactivated {
+            session_id,
+            lookup_table,
+        });
+    } else {
+        invoke_signed(
+            &close_lookup_table(lookup_table, vault, ctx.accounts.game_server.key()),
+            &[
+                ctx.accounts.lookup_table.to_account_info(),
+                ctx.accounts.vault.to_account_info(),
+                ctx.accounts.game_server.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+        game_session.settlement_table = Pubkey::default();
+        emit!(SettlementTableClosed {
+            session_id,
+            lookup_table,
+        });
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct CloseSettlementTable<'info> {
+    /// The game server authority that created the session, receiving the table's rent
+    #[account(mut)]
+    pub game_server: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == game_server.key() @ WagerError::InvalidAuthority,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Session vault, the table's authority
+    #[account(
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Lookup table the session opened, owned by the lookup table program
+    #[account(
+        mut,
+        address = game_session.load()?.settlement_table @ WagerError::InvalidLookupTable,
+        owner = address_lookup_table::program::ID @ WagerError::InvalidLookupTable,
+    )]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// CHECK: Address lookup table program
+    #[account(address = address_lookup_table::program::ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+}
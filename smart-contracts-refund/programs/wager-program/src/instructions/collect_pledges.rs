@@ -0,0 +1,161 @@
+use crate::{
+    errors::*,
+    events::{PledgeCollected, PledgeDefaulted, SessionStarted},
+    state::*,
+    utils::*,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Pulls every pledged bet into escrow once the lobby is full or its join
+/// deadline has passed. Players whose approval was revoked or whose balance
+/// no longer covers the bet lose their seat instead, reopening it. Remaining
+/// accounts are (token account, player stats) pairs in the order of
+/// `pledged_players`.
+pub fn collect_pledges_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CollectPledges<'info>>,
+    session_id: String,
+) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    require!(
+        game_session.status()? == SessionStatus::Filling,
+        WagerError::InvalidGameState
+    );
+    let deadline_passed = game_session.join_deadline_reached(Clock::get()?.unix_timestamp);
+    require!(
+        game_session.check_all_filled()? || deadline_passed,
+        WagerError::PledgesNotDue
+    );
+
+    let pledged = game_session.pledged_players();
+    require!(!pledged.is_empty(), WagerError::NoOutstandingPledges);
+    require!(
+        ctx.remaining_accounts.len() == 2 * pledged.len(),
+        WagerError::InvalidRemainingAccounts
+    );
+
+    let session_bet = game_session.session_bet;
+    let pledge = gross_up_transfer_fee(&ctx.accounts.mint, session_bet)?;
+    let vault_key = ctx.accounts.vault.key();
+    for (index, player) in pledged.iter().enumerate() {
+        let token_info = &ctx.remaining_accounts[index * 2];
+        let stats_info = &ctx.remaining_accounts[index * 2 + 1];
+
+        // A closed or foreign account can't be collected from either
+        let collectable = InterfaceAccount::<TokenAccount>::try_from(token_info)
+            .map(|token_account| {
+                token_account.owner == *player
+                    && token_account.mint == game_session.mint
+                    && token_account.delegate == COption::Some(vault_key)
+                    && token_account.delegated_amount >= pledge
+                    && token_account.amount >= pledge
+                    && !token_account.is_frozen()
+            })
+            .unwrap_or(false);
+
+        if collectable {
+            transfer_from_escrow(
+                token_info,
+                &ctx.accounts.vault_token_account.to_account_info(),
+                &ctx.accounts.vault.to_account_info(),
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                pledge,
+                &[&[b"vault", session_id.as_bytes(), &[game_session.vault_bump]]],
+            )?;
+            ctx.accounts.vault.record_deposit(session_bet)?;
+            game_session.collect_pledge(*player)?;
+            emit!(PledgeCollected {
+                session_id: session_id.clone(),
+                player: *player,
+                amount: session_bet,
+            });
+        } else {
+            game_session.remove_player(*player)?;
+            update_player_stats(stats_info, player, |stats| stats.revert_game(session_bet))?;
+            emit!(PledgeDefaulted {
+                session_id: session_id.clone(),
+                player: *player,
+            });
+        }
+    }
+
+    // A fully paid lobby starts, one with defaulters reopens their seats
+    let next = game_session.resting_status()?;
+    game_session.transition_to(next)?;
+    if next == SessionStatus::InProgress {
+        // Full lobbies drop out of the discovery index
+        if let Some(lobby_index) = &mut ctx.accounts.lobby_index {
+            lobby_index.remove(game_session_key);
+        }
+        emit!(SessionStarted { session_id });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct CollectPledges<'info> {
+    pub game_server: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == game_server.key() @ WagerError::UnauthorizedPledgeCollection,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA approved as delegate over the pledged bets
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Discovery index listing the session, updated once the lobby starts
+    #[account(
+        mut,
+        seeds = [
+            b"lobby_index",
+            game_session.load()?.mint.as_ref(),
+            &game_session.load()?.session_bet.to_le_bytes(),
+            &[game_session.load()?.game_mode],
+        ],
+        bump = lobby_index.bump,
+    )]
+    pub lobby_index: Option<Account<'info, LobbyIndex>>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
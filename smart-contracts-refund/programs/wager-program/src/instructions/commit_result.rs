@@ -0,0 +1,87 @@
+use crate::{errors::WagerError, events::ResultCommitted, state::*};
+use anchor_lang::prelude::*;
+
+/// Commits a signing set key to an in-progress session's result ahead of
+/// revealing it with `reveal_result`. The hash is
+/// `ResultCommitment::digest` of the session, the declaration and a secret
+/// salt. The commitment only binds once the config's threshold of keys
+/// committed to the same hash; until then a key proposing another hash
+/// restarts the collection, so no single key can lock in a result.
+pub fn commit_result_handler(
+    ctx: Context<CommitResult>,
+    _session_id: String,
+    hash: [u8; 32],
+) -> Result<()> {
+    let game_session = ctx.accounts.game_session.load()?;
+    require!(
+        game_session.status()? == SessionStatus::InProgress,
+        WagerError::GameNotInProgress
+    );
+
+    let config = &ctx.accounts.config;
+    let game_server = ctx.accounts.game_server.key();
+    let result_commitment = &mut ctx.accounts.result_commitment;
+    if result_commitment.game_session == Pubkey::default() {
+        result_commitment.game_session = ctx.accounts.game_session.key();
+        result_commitment.committer = game_server;
+        result_commitment.bump = ctx.bumps.result_commitment;
+    }
+    require!(
+        !result_commitment.is_committed(),
+        WagerError::ResultAlreadyCommitted
+    );
+
+    // Keys rotated out of the signing set no longer count towards the threshold
+    result_commitment
+        .approvers
+        .retain(|key| config.is_game_server(key));
+    result_commitment.approve(game_server, hash);
+    let approvals = config.approval_count(&game_server, &result_commitment.approvers);
+    if approvals >= config.game_server_threshold as usize {
+        result_commitment.committed_slot = Clock::get()?.slot;
+    }
+
+    emit!(ResultCommitted {
+        session_id: game_session.session_id(),
+        game_server,
+        hash,
+        approvals: approvals as u8,
+        committed: result_commitment.is_committed(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct CommitResult<'info> {
+    /// A key of the game server signing set, paying for the commitment on
+    /// the first proposal
+    #[account(mut)]
+    pub game_server: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+        constraint = config.is_game_server(&game_server.key()) @ WagerError::UnauthorizedGameServer,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        init_if_needed,
+        payer = game_server,
+        space = ResultCommitment::LEN,
+        seeds = [b"result_commitment", game_session.key().as_ref()],
+        bump
+    )]
+    pub result_commitment: Account<'info, ResultCommitment>,
+
+    pub system_program: Program<'info, System>,
+}
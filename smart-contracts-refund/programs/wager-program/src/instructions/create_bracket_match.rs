@@ -0,0 +1,66 @@
+use crate::{errors::WagerError, state::*, validation::*};
+use anchor_lang::prelude::*;
+
+pub fn create_bracket_match_handler(
+    ctx: Context<CreateBracketMatch>,
+    _tournament_id: String,
+    round: u8,
+    match_index: u8,
+    session_id: String,
+) -> Result<()> {
+    validation::validate_session_id(&session_id)?;
+
+    let tournament = &ctx.accounts.tournament;
+    require!(
+        tournament.status == TournamentStatus::InProgress,
+        WagerError::InvalidTournamentState
+    );
+    require!(
+        round < tournament.round_count()
+            && (match_index as usize) < tournament.matches_in_round(round),
+        WagerError::InvalidBracketMatch
+    );
+
+    // Opening matches pair entrants by seed, later rounds fill in as teams advance
+    let teams = if round == 0 {
+        [match_index * 2, match_index * 2 + 1]
+    } else {
+        [NO_ENTRANT, NO_ENTRANT]
+    };
+
+    let bracket_match = &mut ctx.accounts.bracket_match;
+    bracket_match.tournament = tournament.key();
+    bracket_match.round = round;
+    bracket_match.match_index = match_index;
+    bracket_match.session_id = session_id;
+    bracket_match.teams = teams;
+    bracket_match.winner = NO_ENTRANT;
+    bracket_match.bump = ctx.bumps.bracket_match;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_tournament_id: String, round: u8, match_index: u8)]
+pub struct CreateBracketMatch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"tournament", _tournament_id.as_bytes()],
+        bump = tournament.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = BracketMatch::LEN,
+        seeds = [b"bracket_match", _tournament_id.as_bytes(), &[round], &[match_index]],
+        bump
+    )]
+    pub bracket_match: Account<'info, BracketMatch>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,253 @@
+use crate::errors::WagerError;
+use crate::events::{SessionCreated, SessionCreatedFromTemplate};
+use crate::instructions::create_game_session::charge_creation_fee;
+use crate::state::*;
+use crate::utils::validate_bet_usd_bounds;
+use crate::validation::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+/// Launches a public lobby on one of the operator's session templates. The
+/// session records the template it was launched from, so players can check
+/// its terms against the advertised template.
+pub fn create_from_template_handler(
+    ctx: Context<CreateFromTemplate>,
+    session_id: String,
+) -> Result<()> {
+    validation::validate_cpi_caller(
+        &ctx.accounts.config.cpi_callers,
+        ctx.accounts.instructions.as_deref(),
+    )?;
+
+    let template_key = ctx.accounts.session_template.key();
+    let template = &ctx.accounts.session_template;
+    let terms = template.terms.clone();
+    let bet_amount = template.bet_amount;
+
+    // Sessions only launch on the rake the template advertises
+    let config = &ctx.accounts.config;
+    require!(
+        template.fee_bps == config.fee_bps,
+        WagerError::TemplateOutdated
+    );
+    let (min_bet, max_bet) = ctx.accounts.registered_mint.bet_limits(config);
+    validation::validate_bet_amount(bet_amount, min_bet, max_bet)?;
+    validation::validate_stake_range(bet_amount, terms.max_stake, max_bet)?;
+
+    // The match log is created exactly when the session keeps one
+    require!(
+        ctx.accounts.match_log.is_some() == (terms.log_capacity > 0),
+        WagerError::MissingMatchLog
+    );
+
+    // Optionally bound the bet in USD using the mint's oracle price
+    let clock = Clock::get()?;
+    validate_bet_usd_bounds(
+        config,
+        &ctx.accounts.mint,
+        ctx.accounts.price_feed.as_ref(),
+        ctx.accounts.price_update.as_ref(),
+        bet_amount,
+        &clock,
+    )?;
+
+    // Every account created for the session must stay rent-exempt at its
+    // final size. The session is checked before loading it holds its data
+    // borrowed.
+    validation::validate_rent_exempt(
+        &ctx.accounts.game_session.to_account_info(),
+        GameSession::LEN,
+    )?;
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_init()?;
+
+    game_session.initialize(
+        &session_id,
+        ctx.accounts.game_server.key(),
+        ctx.accounts.mint.key(),
+        bet_amount,
+        terms.game_mode,
+        terms.team_count,
+        config,
+        clock.unix_timestamp,
+    )?;
+    game_session.apply_terms(&terms)?;
+    game_session.set_bumps(
+        &game_session_key,
+        &ctx.accounts.vault.key(),
+        ctx.bumps.game_session,
+        ctx.bumps.vault,
+    )?;
+    game_session.template = template_key;
+
+    if let Some(match_log) = &mut ctx.accounts.match_log {
+        match_log.game_session = game_session_key;
+        match_log.capacity = terms.log_capacity;
+        match_log.bump = ctx
+            .bumps
+            .match_log
+            .ok_or(error!(WagerError::MissingMatchLog))?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.game_session = game_session_key;
+
+    game_session.creation_fee = charge_creation_fee(
+        &mut ctx.accounts.config,
+        &ctx.accounts.game_server,
+        &ctx.accounts.treasury,
+        &ctx.accounts.system_program,
+    )?;
+
+    validation::validate_rent_exempt(&ctx.accounts.vault.to_account_info(), Vault::LEN)?;
+
+    let lobby_index = &mut ctx.accounts.lobby_index;
+    lobby_index.init_if_new(
+        ctx.accounts.mint.key(),
+        bet_amount,
+        terms.game_mode,
+        ctx.bumps.lobby_index,
+    );
+    lobby_index.add(game_session_key)?;
+
+    let template = &mut ctx.accounts.session_template;
+    template.sessions_created = safe_math::safe_add(template.sessions_created, 1)?;
+
+    emit!(SessionCreated {
+        session_id: session_id.clone(),
+        authority: game_session.authority,
+        mint: game_session.mint,
+        bet_amount,
+        game_mode: terms.game_mode,
+        team_count: terms.team_count,
+    });
+    emit!(SessionCreatedFromTemplate {
+        session_id,
+        template: template_key,
+        sessions_created: template.sessions_created,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct CreateFromTemplate<'info> {
+    /// Operator that created the template
+    #[account(mut)]
+    pub game_server: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Treasury PDA holding the creation fee
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"session_template",
+            game_server.key().as_ref(),
+            &session_template.template_id.to_le_bytes(),
+        ],
+        bump = session_template.bump,
+    )]
+    pub session_template: Box<Account<'info, SessionTemplate>>,
+
+    #[account(
+        init,
+        payer = game_server,
+        space = GameSession::LEN,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA that owns the escrow token account
+    #[account(
+        init,
+        payer = game_server,
+        space = Vault::LEN,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = game_server,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Ring buffer of recent kills, created when the template keeps a match log
+    #[account(
+        init,
+        payer = game_server,
+        space = MatchLog::space(session_template.terms.log_capacity),
+        seeds = [b"match_log", session_id.as_bytes()],
+        bump
+    )]
+    pub match_log: Option<Account<'info, MatchLog>>,
+
+    /// Discovery index of open lobbies with the template's mint, bet and mode
+    #[account(
+        init_if_needed,
+        payer = game_server,
+        space = LobbyIndex::LEN,
+        seeds = [
+            b"lobby_index",
+            mint.key().as_ref(),
+            &session_template.bet_amount.to_le_bytes(),
+            &[session_template.terms.game_mode as u8],
+        ],
+        bump
+    )]
+    pub lobby_index: Box<Account<'info, LobbyIndex>>,
+
+    /// Wager token of the template
+    #[account(address = session_template.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Registry entry permitting the mint as a wager token
+    #[account(
+        seeds = [b"registered_mint", mint.key().as_ref()],
+        bump = registered_mint.bump,
+    )]
+    pub registered_mint: Account<'info, RegisteredMint>,
+
+    /// Price feed registered for the mint, required when USD bounds are enforced
+    #[account(
+        seeds = [b"price_feed", mint.key().as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    /// Pyth price update for the registered feed
+    pub price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    /// CHECK: Instructions sysvar identifying the calling program, required
+    /// for CPI when the config restricts callers
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
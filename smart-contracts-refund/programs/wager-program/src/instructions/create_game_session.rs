@@ -1,30 +1,211 @@
 use crate::errors::WagerError;
+use crate::events::SessionCreated;
 use crate::state::*;
-use crate::TOKEN_ID;
+use crate::utils::validate_bet_usd_bounds;
+use crate::validation::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::system_program;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_game_session_handler(
     ctx: Context<CreateGameSession>,
     session_id: String,
     bet_amount: u64,
     game_mode: GameMode,
+    team_count: u8,
+    spawn_earnings_bps: u16,
+    log_capacity: u16,
+    team_a_capacity: u8,
+    team_b_capacity: u8,
+    payout_schedule: PayoutSchedule,
+    metadata: Option<SessionMetadataParams>,
+    access: SessionAccess,
+    max_kills_per_player: u16,
+    max_match_kills: u16,
+    series_length: u8,
+    min_rating: u16,
+    max_rating: u16,
+    refund_policy: RefundPolicy,
+    requires_pass: Option<Pubkey>,
+    join_window: Option<JoinWindow>,
+    win_condition: WinCondition,
+    freeroll: bool,
+    assist_weight_bps: u16,
+    min_ready_players: u8,
+    max_stake: u64,
 ) -> Result<()> {
+    validation::validate_cpi_caller(
+        &ctx.accounts.config.cpi_callers,
+        ctx.accounts.instructions.as_deref(),
+    )?;
+    validation::validate_rating_bounds(min_rating, max_rating)?;
+
+    // Metadata is created exactly when the operator supplies it
+    require!(
+        ctx.accounts.session_metadata.is_some() == metadata.is_some(),
+        WagerError::InvalidSessionMetadata
+    );
+    if let Some(metadata) = &metadata {
+        validation::validate_session_metadata(metadata)?;
+    }
+
+    // The match log is created exactly when the session keeps one
+    require!(
+        ctx.accounts.match_log.is_some() == (log_capacity > 0),
+        WagerError::MissingMatchLog
+    );
+
+    let terms = SessionTerms {
+        game_mode,
+        team_count,
+        spawn_earnings_bps,
+        log_capacity,
+        team_a_capacity,
+        team_b_capacity,
+        payout_schedule,
+        max_kills_per_player,
+        max_match_kills,
+        series_length,
+        refund_policy,
+        win_condition,
+        assist_weight_bps,
+        min_ready_players,
+        max_stake,
+    };
     let clock = Clock::get()?;
-    let game_session = &mut ctx.accounts.game_session;
+    validation::validate_session_terms(
+        &terms,
+        join_window.as_ref(),
+        ctx.accounts.mint.key() == native_mint::ID,
+        clock.unix_timestamp,
+    )?;
+
+    let config = &ctx.accounts.config;
+    if freeroll {
+        // Freerolls are free to enter, their prize is funded separately
+        require!(
+            config.is_freeroll_sponsor(&ctx.accounts.game_server.key()),
+            WagerError::UnauthorizedFreerollSponsor
+        );
+        validation::validate_freeroll_terms(bet_amount, &terms)?;
+    } else {
+        let (min_bet, max_bet) = ctx.accounts.registered_mint.bet_limits(config);
+        validation::validate_bet_amount(bet_amount, min_bet, max_bet)?;
+        validation::validate_stake_range(bet_amount, max_stake, max_bet)?;
+
+        // Optionally bound the bet in USD using the mint's oracle price
+        validate_bet_usd_bounds(
+            config,
+            &ctx.accounts.mint,
+            ctx.accounts.price_feed.as_ref(),
+            ctx.accounts.price_update.as_ref(),
+            bet_amount,
+            &clock,
+        )?;
+    }
+
+    // Every account created for the session must stay rent-exempt at its
+    // final size. The session is checked before loading it holds its data
+    // borrowed.
+    validation::validate_rent_exempt(
+        &ctx.accounts.game_session.to_account_info(),
+        GameSession::LEN,
+    )?;
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_init()?;
+
+    game_session.initialize(
+        &session_id,
+        ctx.accounts.game_server.key(),
+        ctx.accounts.mint.key(),
+        bet_amount,
+        game_mode,
+        team_count,
+        config,
+        clock.unix_timestamp,
+    )?;
+    game_session.apply_terms(&terms)?;
+    game_session.set_bumps(
+        &game_session_key,
+        &ctx.accounts.vault.key(),
+        ctx.bumps.game_session,
+        ctx.bumps.vault,
+    )?;
+    game_session.set_access(access);
+    game_session.min_rating = min_rating;
+    game_session.max_rating = max_rating;
+    game_session.set_required_pass(requires_pass);
+    game_session.set_join_window(join_window);
+    if freeroll {
+        game_session.set_freeroll();
+    }
+
+    if let Some(match_log) = &mut ctx.accounts.match_log {
+        match_log.game_session = game_session_key;
+        match_log.capacity = log_capacity;
+        match_log.bump = ctx
+            .bumps
+            .match_log
+            .ok_or(error!(WagerError::MissingMatchLog))?;
+    }
+
+    if let (Some(session_metadata), Some(metadata)) = (&mut ctx.accounts.session_metadata, metadata)
+    {
+        session_metadata.game_session = game_session_key;
+        session_metadata.game_name = metadata.game_name;
+        session_metadata.map_id = metadata.map_id;
+        session_metadata.region = metadata.region;
+        session_metadata.custom = metadata.custom;
+        session_metadata.bump = ctx
+            .bumps
+            .session_metadata
+            .ok_or(error!(WagerError::InvalidSessionMetadata))?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.game_session = game_session_key;
+
+    game_session.creation_fee = charge_creation_fee(
+        &mut ctx.accounts.config,
+        &ctx.accounts.game_server,
+        &ctx.accounts.treasury,
+        &ctx.accounts.system_program,
+    )?;
 
-    game_session.session_id = session_id;
-    game_session.authority = ctx.accounts.game_server.key();
-    game_session.session_bet = bet_amount;
-    game_session.game_mode = game_mode;
-    game_session.status = GameStatus::WaitingForPlayers;
-    game_session.created_at = clock.unix_timestamp;
-    game_session.bump = ctx.bumps.game_session;
-    game_session.vault_bump = ctx.bumps.vault;
+    validation::validate_rent_exempt(&ctx.accounts.vault.to_account_info(), Vault::LEN)?;
+    if let Some(session_metadata) = &ctx.accounts.session_metadata {
+        validation::validate_rent_exempt(
+            &session_metadata.to_account_info(),
+            SessionMetadata::LEN,
+        )?;
+    }
+
+    let lobby_index = &mut ctx.accounts.lobby_index;
+    lobby_index.init_if_new(
+        ctx.accounts.mint.key(),
+        bet_amount,
+        game_mode,
+        ctx.bumps.lobby_index,
+    );
+    lobby_index.add(game_session_key)?;
+
+    emit!(SessionCreated {
+        session_id,
+        authority: game_session.authority,
+        mint: game_session.mint,
+        bet_amount,
+        game_mode,
+        team_count,
+    });
 
     // Log all the accounts
-    msg!("Game session: {}", game_session.key());
+    msg!("Game session: {}", game_session_key);
     msg!("Vault: {}", ctx.accounts.vault.key());
     msg!(
         "Vault token account: {}",
@@ -33,46 +214,145 @@ pub fn create_game_session_handler(
     Ok(())
 }
 
+/// Moves the config's creation fee from the creator into the treasury,
+/// returning the lamports charged. The fee sits in the treasury until the
+/// session completes, and is forfeited if the lobby is abandoned.
+pub fn charge_creation_fee<'info>(
+    config: &mut Account<'info, ProgramConfig>,
+    creator: &Signer<'info>,
+    treasury: &SystemAccount<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    let creation_fee = config.creation_fee_lamports;
+    if creation_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: creator.to_account_info(),
+                    to: treasury.to_account_info(),
+                },
+            ),
+            creation_fee,
+        )?;
+        config.held_creation_fees = safe_math::safe_add(config.held_creation_fees, creation_fee)?;
+    }
+    Ok(creation_fee)
+}
+
 #[derive(Accounts)]
-#[instruction(session_id: String)]
+#[instruction(
+    session_id: String,
+    bet_amount: u64,
+    game_mode: GameMode,
+    _team_count: u8,
+    _spawn_earnings_bps: u16,
+    log_capacity: u16,
+)]
 pub struct CreateGameSession<'info> {
     #[account(mut)]
     pub game_server: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Treasury PDA holding the creation fee
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
     #[account(
         init,
         payer = game_server,
-        space = 8 + 4 + 10 + 32 + 8 + 1 + (2 * (32 * 5 + 16 * 5 + 16 * 5 + 8)) + 1 + 8 + 1 + 1 + 1,
+        space = GameSession::LEN,
         seeds = [b"game_session", session_id.as_bytes()],
         bump
     )]
-    pub game_session: Account<'info, GameSession>,
+    pub game_session: AccountLoader<'info, GameSession>,
 
-    /// CHECK: This is safe as it's just used to store SOL
+    /// Vault PDA that owns the escrow token account
     #[account(
         init,
         payer = game_server,
-        space = 0,
+        space = Vault::LEN,
         seeds = [b"vault", session_id.as_bytes()],
         bump
     )]
-    pub vault: AccountInfo<'info>,
+    pub vault: Account<'info, Vault>,
 
     #[account(
         init,
         payer = game_server,
         associated_token::mint = mint,
         associated_token::authority = vault,
+        associated_token::token_program = token_program,
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// Ring buffer of recent kills, created when `log_capacity` is non-zero
     #[account(
-        mut,
-        address = TOKEN_ID @ WagerError::InvalidMint
+        init,
+        payer = game_server,
+        space = MatchLog::space(log_capacity),
+        seeds = [b"match_log", session_id.as_bytes()],
+        bump
     )]
-    pub mint: Account<'info, anchor_spl::token::Mint>,
+    pub match_log: Option<Account<'info, MatchLog>>,
+
+    /// Discovery details, created when metadata is supplied
+    #[account(
+        init,
+        payer = game_server,
+        space = SessionMetadata::LEN,
+        seeds = [b"session_metadata", session_id.as_bytes()],
+        bump
+    )]
+    pub session_metadata: Option<Account<'info, SessionMetadata>>,
+
+    /// Discovery index of open lobbies with the session's mint, bet and mode
+    #[account(
+        init_if_needed,
+        payer = game_server,
+        space = LobbyIndex::LEN,
+        seeds = [b"lobby_index", mint.key().as_ref(), &bet_amount.to_le_bytes(), &[game_mode as u8]],
+        bump
+    )]
+    pub lobby_index: Account<'info, LobbyIndex>,
+
+    /// Wager token for this session
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Registry entry permitting the mint as a wager token
+    #[account(
+        seeds = [b"registered_mint", mint.key().as_ref()],
+        bump = registered_mint.bump,
+    )]
+    pub registered_mint: Account<'info, RegisteredMint>,
+
+    /// Price feed registered for the mint, required when USD bounds are enforced
+    #[account(
+        seeds = [b"price_feed", mint.key().as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    /// Pyth price update for the registered feed
+    pub price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    /// CHECK: Instructions sysvar identifying the calling program, required
+    /// for CPI when the config restricts callers
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
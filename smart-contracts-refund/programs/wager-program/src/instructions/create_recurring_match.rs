@@ -0,0 +1,96 @@
+use crate::{errors::WagerError, events::RecurringMatchCreated, state::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+/// Schedules a session to launch from one of the operator's templates every
+/// `interval_secs`, starting at `first_start`. Instances are spawned by
+/// anyone with `spawn_next_instance`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_recurring_match_handler(
+    ctx: Context<CreateRecurringMatch>,
+    prefix: String,
+    interval_secs: i64,
+    first_start: i64,
+    join_secs: i64,
+    min_players: u8,
+    max_instances: u32,
+) -> Result<()> {
+    // Instance ids must stay valid session ids
+    validation::validate_session_id(&prefix)?;
+    require!(
+        prefix.len() <= MAX_RECURRING_PREFIX_LEN,
+        WagerError::SessionIdTooLong
+    );
+    require!(interval_secs > 0, WagerError::InvalidRecurringSchedule);
+    require!(
+        (0..=interval_secs).contains(&join_secs),
+        WagerError::InvalidRecurringSchedule
+    );
+
+    // Instances must launch on valid terms, checked against an instance
+    // spawned now
+    let now = Clock::get()?.unix_timestamp;
+    let template = &ctx.accounts.session_template;
+    let recurring_match = &mut ctx.accounts.recurring_match;
+    recurring_match.join_secs = join_secs;
+    recurring_match.min_players = min_players;
+    validation::validate_session_terms(
+        &template.terms,
+        recurring_match.join_window(now)?.as_ref(),
+        template.mint == native_mint::ID,
+        now,
+    )?;
+
+    recurring_match.authority = ctx.accounts.authority.key();
+    recurring_match.template = template.key();
+    recurring_match.prefix = prefix;
+    recurring_match.interval_secs = interval_secs;
+    recurring_match.next_start = first_start;
+    recurring_match.max_instances = max_instances;
+    recurring_match.bump = ctx.bumps.recurring_match;
+
+    emit!(RecurringMatchCreated {
+        recurring_match: recurring_match.key(),
+        template: recurring_match.template,
+        prefix: recurring_match.prefix.clone(),
+        interval_secs,
+        first_start,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(prefix: String)]
+pub struct CreateRecurringMatch<'info> {
+    /// Operator owning the template
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [
+            b"session_template",
+            authority.key().as_ref(),
+            &session_template.template_id.to_le_bytes(),
+        ],
+        bump = session_template.bump,
+    )]
+    pub session_template: Account<'info, SessionTemplate>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RecurringMatch::LEN,
+        seeds = [b"recurring_match", authority.key().as_ref(), prefix.as_bytes()],
+        bump
+    )]
+    pub recurring_match: Account<'info, RecurringMatch>,
+
+    pub system_program: Program<'info, System>,
+}
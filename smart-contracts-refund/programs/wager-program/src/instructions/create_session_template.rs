@@ -0,0 +1,81 @@
+use crate::{errors::WagerError, events::SessionTemplateCreated, state::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::Mint;
+
+/// Saves a game configuration an operator launches lobbies from with a
+/// single argument. Templates are immutable once created, and record the
+/// config's current rake so sessions only launch on the advertised fees.
+pub fn create_session_template_handler(
+    ctx: Context<CreateSessionTemplate>,
+    template_id: u64,
+    bet_amount: u64,
+    terms: SessionTerms,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let (min_bet, max_bet) = ctx.accounts.registered_mint.bet_limits(config);
+    validation::validate_bet_amount(bet_amount, min_bet, max_bet)?;
+    validation::validate_stake_range(bet_amount, terms.max_stake, max_bet)?;
+    validation::validate_session_terms(
+        &terms,
+        None,
+        ctx.accounts.mint.key() == native_mint::ID,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let template = &mut ctx.accounts.session_template;
+    template.authority = ctx.accounts.authority.key();
+    template.template_id = template_id;
+    template.mint = ctx.accounts.mint.key();
+    template.bet_amount = bet_amount;
+    template.fee_bps = config.fee_bps;
+    template.terms = terms;
+    template.bump = ctx.bumps.session_template;
+
+    emit!(SessionTemplateCreated {
+        template: template.key(),
+        authority: template.authority,
+        template_id,
+        mint: template.mint,
+        bet_amount,
+        game_mode: template.terms.game_mode,
+        fee_bps: template.fee_bps,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct CreateSessionTemplate<'info> {
+    /// Operator launching sessions from the template
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SessionTemplate::LEN,
+        seeds = [b"session_template", authority.key().as_ref(), &template_id.to_le_bytes()],
+        bump
+    )]
+    pub session_template: Account<'info, SessionTemplate>,
+
+    /// Wager token of the template's sessions
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Registry entry permitting the mint as a wager token
+    #[account(
+        seeds = [b"registered_mint", mint.key().as_ref()],
+        bump = registered_mint.bump,
+    )]
+    pub registered_mint: Account<'info, RegisteredMint>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,93 @@
+use crate::{errors::WagerError, events::SettlementTableCreated, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    address_lookup_table::{self, instruction::create_lookup_table_signed},
+    program::invoke_signed,
+};
+
+/// Creates an address lookup table for a started session, owned by the
+/// session vault, so settlements paying 10+ winners fit in one v0
+/// transaction. `recent_slot` is a recent slot the table address derives
+/// from; `extend_settlement_table` then fills the table and
+/// `close_settlement_table` returns its rent once the session is settled. A
+/// session has one table open at a time.
+pub fn create_settlement_table_handler(
+    ctx: Context<CreateSettlementTable>,
+    session_id: String,
+    recent_slot: u64,
+) -> Result<()> {
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    // The roster is final once the session is under way
+    require!(
+        game_session.status()? == SessionStatus::InProgress,
+        WagerError::GameNotInProgress
+    );
+    require!(
+        game_session.settlement_table == Pubkey::default(),
+        WagerError::SettlementTableOpen
+    );
+
+    let (instruction, lookup_table) = create_lookup_table_signed(
+        ctx.accounts.vault.key(),
+        ctx.accounts.game_server.key(),
+        recent_slot,
+    );
+    require_keys_eq!(
+        lookup_table,
+        ctx.accounts.lookup_table.key(),
+        WagerError::InvalidLookupTable
+    );
+    invoke_signed(
+        &instruction,
+        &[
+            ctx.accounts.lookup_table.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.game_server.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.address_lookup_table_program.to_account_info(),
+        ],
+        &[&[b"vault", session_id.as_bytes(), &[game_session.vault_bump]]],
+    )?;
+    game_session.settlement_table = lookup_table;
+
+    emit!(SettlementTableCreated {
+        session_id,
+        lookup_table,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct CreateSettlementTable<'info> {
+    /// The game server authority that created the session, paying the table's rent
+    #[account(mut)]
+    pub game_server: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == game_server.key() @ WagerError::UnauthorizedDistribution,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Session vault, the table's authority
+    #[account(
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Table created by the lookup table program at the address the
+    /// vault and `recent_slot` derive
+    #[account(mut)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// CHECK: Address lookup table program
+    #[account(address = address_lookup_table::program::ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
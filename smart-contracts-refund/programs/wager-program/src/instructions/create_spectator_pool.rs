@@ -0,0 +1,67 @@
+use crate::{errors::WagerError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn create_spectator_pool_handler(
+    ctx: Context<CreateSpectatorPool>,
+    _session_id: String,
+) -> Result<()> {
+    let game_session = ctx.accounts.game_session.load()?;
+    require!(
+        game_session.supports_spectators(),
+        WagerError::SpectatorPoolUnsupported
+    );
+    require!(
+        game_session.status()?.is_accepting_players(),
+        WagerError::SpectatorBettingClosed
+    );
+
+    let pool = &mut ctx.accounts.spectator_pool;
+    pool.game_session = ctx.accounts.game_session.key();
+    pool.team_totals = vec![0; game_session.team_count()];
+    pool.total_staked = 0;
+    pool.bump = ctx.bumps.spectator_pool;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct CreateSpectatorPool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = SpectatorPool::space(game_session.load()?.team_count()),
+        seeds = [b"spectator_pool", _session_id.as_bytes()],
+        bump
+    )]
+    pub spectator_pool: Account<'info, SpectatorPool>,
+
+    /// Escrow for spectator stakes, owned by the pool PDA
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = spectator_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
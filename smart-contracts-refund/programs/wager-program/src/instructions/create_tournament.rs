@@ -0,0 +1,104 @@
+use crate::{errors::WagerError, events::TournamentCreated, state::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn create_tournament_handler(
+    ctx: Context<CreateTournament>,
+    tournament_id: String,
+    entry_fee: u64,
+    team_count: u8,
+) -> Result<()> {
+    validation::validate_session_id(&tournament_id)?;
+    validation::validate_tournament_size(team_count)?;
+
+    let config = &ctx.accounts.config;
+    let (min_bet, max_bet) = ctx.accounts.registered_mint.bet_limits(config);
+    validation::validate_bet_amount(entry_fee, min_bet, max_bet)?;
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.tournament_id = tournament_id.clone();
+    tournament.authority = ctx.accounts.authority.key();
+    tournament.mint = ctx.accounts.mint.key();
+    tournament.entry_fee = entry_fee;
+    tournament.fee_bps = config.fee_bps;
+    tournament.team_count = team_count;
+    tournament.entrants = Vec::with_capacity(team_count as usize);
+    tournament.champion = Pubkey::default();
+    tournament.status = TournamentStatus::Registering;
+    tournament.bump = ctx.bumps.tournament;
+    tournament.vault_bump = ctx.bumps.vault;
+    tournament.yield_program = Pubkey::default();
+    tournament.parked_amount = 0;
+    tournament.accrued_yield = 0;
+    tournament.yield_treasury_bps = config.yield_treasury_bps;
+
+    ctx.accounts.vault.game_session = tournament.key();
+    validation::validate_rent_exempt(&ctx.accounts.vault.to_account_info(), Vault::LEN)?;
+
+    emit!(TournamentCreated {
+        tournament_id,
+        authority: tournament.authority,
+        mint: tournament.mint,
+        entry_fee,
+        team_count,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: String, entry_fee: u64, team_count: u8)]
+pub struct CreateTournament<'info> {
+    /// Operator that will report match results
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Tournament::space(team_count),
+        seeds = [b"tournament", tournament_id.as_bytes()],
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Vault PDA that owns the entry fee escrow token account
+    #[account(
+        init,
+        payer = authority,
+        space = Vault::LEN,
+        seeds = [b"tournament_vault", tournament_id.as_bytes()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token entry fees are paid in
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Registry entry permitting the mint as a wager token
+    #[account(
+        seeds = [b"registered_mint", mint.key().as_ref()],
+        bump = registered_mint.bump,
+    )]
+    pub registered_mint: Account<'info, RegisteredMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,108 @@
+use crate::{
+    errors::WagerError,
+    events::{ForfeitDeclared, SessionFlagged},
+    state::*,
+    utils::*,
+};
+use anchor_lang::prelude::*;
+
+/// Settles a two-team match in favour of the team whose opponents forfeited
+/// and opens the dispute window, after which anyone can finalize the
+/// distribution. The game server signing set can declare a forfeit at any
+/// point of the match, with the config's threshold of approvals; anyone else
+/// can once a team has been inactive for the session's forfeit timeout while
+/// its opponent kept playing. The forfeiting team gets back its bets less the
+/// session's forfeit penalty, which goes to the winners.
+pub fn declare_forfeit_handler(
+    ctx: Context<DeclareForfeit>,
+    _session_id: String,
+    forfeiting_team: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    require!(
+        game_session.status()? == SessionStatus::InProgress,
+        WagerError::GameNotInProgress
+    );
+    let declaration = WinnerDeclaration {
+        result: MatchResult::Forfeit,
+        team: game_session.forfeit_winner(forfeiting_team)?,
+    };
+
+    let config = &ctx.accounts.config;
+    let caller = ctx.accounts.caller.key();
+    let inactivity = !config.is_game_server(&caller);
+    if inactivity {
+        game_session.check_forfeit_inactivity(forfeiting_team, now)?;
+    } else {
+        let approvals = game_server_approvals(
+            config,
+            &caller,
+            ctx.accounts.approval.as_ref(),
+            game_session_key,
+            ApprovalKind::SubmitResult,
+            declaration.result_code(),
+        )?;
+        require!(
+            approvals >= config.game_server_threshold as usize,
+            WagerError::InsufficientApprovals
+        );
+    }
+
+    let audit_flags = game_session.audit_flags;
+    game_session.record_result(&declaration)?;
+    if game_session.audit_flags != audit_flags {
+        emit!(SessionFlagged {
+            session_id: game_session.session_id(),
+            audit_flags: game_session.audit_flags,
+        });
+    }
+
+    game_session.dispute_deadline = now
+        .checked_add(game_session.result_dispute_window())
+        .ok_or(error!(WagerError::ArithmeticOverflow))?;
+    game_session.transition_to(SessionStatus::ResultSubmitted)?;
+
+    emit!(ForfeitDeclared {
+        session_id: game_session.session_id(),
+        forfeiting_team,
+        winning_team: declaration.team,
+        inactivity,
+        penalty_bps: game_session.forfeit_penalty_bps,
+        dispute_deadline: game_session.dispute_deadline,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct DeclareForfeit<'info> {
+    /// A key of the game server signing set, or anyone once a team went
+    /// inactive
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Signing set approvals for a server-declared forfeit, needed when the
+    /// threshold exceeds the caller's own signature
+    #[account(
+        seeds = [b"approval", game_session.key().as_ref(), &[ApprovalKind::SubmitResult as u8]],
+        bump = approval.bump,
+    )]
+    pub approval: Option<Account<'info, Approval>>,
+}
@@ -0,0 +1,52 @@
+use crate::{errors::WagerError, events::SessionAuthorityDelegated, state::*};
+use anchor_lang::prelude::*;
+
+/// Delegates reporting for a single session to its own key, e.g. the regional
+/// server hosting the match, so a leaked key only reaches the sessions it was
+/// delegated. Delegating the default key revokes the delegation.
+pub fn delegate_session_authority_handler(
+    ctx: Context<DelegateSessionAuthority>,
+    _session_id: String,
+    reporter: Pubkey,
+) -> Result<()> {
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    require!(
+        !game_session.status()?.is_terminal(),
+        WagerError::InvalidGameState
+    );
+    require!(
+        reporter != game_session.authority,
+        WagerError::InvalidAuthority
+    );
+    game_session.reporter = reporter;
+
+    emit!(SessionAuthorityDelegated {
+        session_id: game_session.session_id(),
+        reporter,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct DelegateSessionAuthority<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == authority.key() @ WagerError::InvalidAuthority,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Game server authority that created the session
+    pub authority: Signer<'info>,
+}
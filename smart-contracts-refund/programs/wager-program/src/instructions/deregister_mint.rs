@@ -0,0 +1,32 @@
+use crate::{errors::WagerError, events::MintDeregistered, state::*};
+use anchor_lang::prelude::*;
+
+/// Withdraws a mint's permission as a wager token. Sessions already running
+/// in it play out and settle as usual; only new lobbies are refused.
+pub fn deregister_mint_handler(ctx: Context<DeregisterMint>) -> Result<()> {
+    emit!(MintDeregistered {
+        mint: ctx.accounts.registered_mint.mint,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeregisterMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"registered_mint", registered_mint.mint.as_ref()],
+        bump = registered_mint.bump,
+    )]
+    pub registered_mint: Account<'info, RegisteredMint>,
+}
@@ -0,0 +1,142 @@
+use crate::{errors::WagerError, events::*, state::*, utils::transfer_from_escrow, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn distribute_tournament_prize_handler(
+    ctx: Context<DistributeTournamentPrize>,
+    tournament_id: String,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    require!(
+        tournament.status == TournamentStatus::InProgress
+            && tournament.champion != Pubkey::default(),
+        WagerError::InvalidTournamentState
+    );
+    require!(tournament.parked_amount == 0, WagerError::YieldFundsParked);
+
+    // The champion takes the whole pot less the house rake, and shares any
+    // yield earned on parked escrow with the treasury
+    let total_pot = tournament.total_pot()?;
+    let treasury_yield =
+        safe_math::apply_bps(tournament.accrued_yield, tournament.yield_treasury_bps)?;
+    let champion_yield = safe_math::safe_subtract(tournament.accrued_yield, treasury_yield)?;
+    let rake = safe_math::apply_bps(total_pot, tournament.fee_bps)?;
+    let fee = safe_math::safe_add(rake, treasury_yield)?;
+    let prize = safe_math::safe_add(safe_math::safe_subtract(total_pot, rake)?, champion_yield)?;
+    msg!("Tournament pot {} pays champion {}", total_pot, prize);
+
+    // Mark the tournament completed before moving funds
+    tournament.status = TournamentStatus::Completed;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"tournament_vault",
+        tournament_id.as_bytes(),
+        &[tournament.vault_bump],
+    ]];
+
+    for (destination, amount) in [
+        (ctx.accounts.champion_token_account.to_account_info(), prize),
+        (ctx.accounts.treasury_token_account.to_account_info(), fee),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        transfer_from_escrow(
+            &ctx.accounts.vault_token_account.to_account_info(),
+            &destination,
+            &ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+            signer_seeds,
+        )?;
+    }
+    ctx.accounts.vault.record_payout(prize)?;
+    ctx.accounts.vault.record_fee(fee)?;
+
+    // Verify vault accounting against the live escrow balance
+    ctx.accounts.vault_token_account.reload()?;
+    ctx.accounts
+        .vault
+        .check_invariant(ctx.accounts.vault_token_account.amount)?;
+
+    if fee > 0 {
+        emit!(FeesCollected {
+            session_id: tournament_id.clone(),
+            amount: fee,
+        });
+    }
+    emit!(TournamentCompleted {
+        tournament_id,
+        champion: tournament.champion,
+        prize,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: String)]
+pub struct DistributeTournamentPrize<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_bytes()],
+        bump = tournament.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Vault PDA that holds the entry fees and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament_id.as_bytes()],
+        bump = tournament.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = tournament.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = champion_token_account.owner == tournament.champion @ WagerError::InvalidWinnerTokenAccount,
+        constraint = champion_token_account.mint == tournament.mint @ WagerError::InvalidTokenMint,
+    )]
+    pub champion_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury PDA that owns the fee token accounts
+    #[account(
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = tournament.mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the tournament
+    #[account(address = tournament.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
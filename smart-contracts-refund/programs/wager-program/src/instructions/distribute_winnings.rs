@@ -1,274 +1,875 @@
-use crate::{errors::WagerError, state::*, validation::*, TOKEN_ID};
+use crate::{errors::*, events::*, state::*, utils::*, validation::*};
 use anchor_lang::prelude::*;
-use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Token, TokenAccount};
-
-// Import reentrancy protection macros
-use crate::{reentrancy_guard, release_reentrancy_guard};
-
-pub fn distribute_pay_spawn_earnings<'info>(
+use anchor_lang::solana_program::compute_units::sol_remaining_compute_units;
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Settles a session immediately on the game server's result and pays the
+/// first page of winners. The result needs the config's threshold of signing
+/// set approvals; sessions with a dispute window must instead go through
+/// `submit_result` and `finalize_distribution`, as must every session while
+/// the config requires committed results.
+pub fn distribute_winnings_handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, DistributeWinnings<'info>>,
     session_id: String,
+    declaration: WinnerDeclaration,
 ) -> Result<()> {
     // Input validation
     validation::validate_session_id(&session_id)?;
-    
-    let game_session = &mut ctx.accounts.game_session;
-    
-    // Reentrancy protection
-    reentrancy_guard!(game_session);
-    
-    // Double-check authority
-    require!(
-        game_session.authority == ctx.accounts.game_server.key(),
-        WagerError::UnauthorizedDistribution
-    );
-    msg!("Starting distribution for session: {}", session_id);
-
-    let players = game_session.get_all_players();
-    let session_bet = game_session.session_bet;
-    let vault_bump = game_session.vault_bump;
-    
-    msg!("Number of players: {}", players.len());
-    msg!(
-        "Number of remaining accounts: {}",
-        ctx.remaining_accounts.len()
-    );
 
-    // Validate remaining accounts count
-    validation::validate_remaining_accounts_count(ctx.remaining_accounts.len(), 20)?;
-    
-    // We need at least one player and their token account
-    require!(
-        !ctx.remaining_accounts.is_empty(),
-        WagerError::InvalidRemainingAccounts
-    );
+    let now = Clock::get()?.unix_timestamp;
+    let game_session_key = ctx.accounts.game_session.key();
+    {
+        let mut game_session = ctx.accounts.game_session.load_mut()?;
 
-    // Make sure remaining accounts are in pairs
-    require!(
-        ctx.remaining_accounts.len() % 2 == 0,
-        WagerError::InvalidRemainingAccounts
-    );
+        // Only the game server signing set settles sessions
+        let config = &ctx.accounts.config;
+        let caller = ctx.accounts.caller.key();
+        require!(
+            config.is_game_server(&caller),
+            WagerError::UnauthorizedDistribution
+        );
 
-    for player in players {
-        // Skip players with no kills/spawns
-        let kills_and_spawns = game_session.get_kills_and_spawns(player)?;
-        if kills_and_spawns == 0 {
-            continue;
+        if game_session.distribution_cursor == 0 {
+            let approvals = game_server_approvals(
+                config,
+                &caller,
+                ctx.accounts.approval.as_ref(),
+                game_session_key,
+                ApprovalKind::DistributeWinnings,
+                declaration.result_code(),
+            )?;
+            require!(
+                approvals >= config.game_server_threshold as usize,
+                WagerError::InsufficientApprovals
+            );
+
+            // Disputable results only pay out once their window has passed
+            require!(
+                game_session.dispute_window_secs == 0,
+                WagerError::DisputeWindowRequired
+            );
+            require!(
+                !config.commit_reveal_results,
+                WagerError::ResultCommitRequired
+            );
+            // Only a running game can be settled; entering Settling rejects reentrancy
+            require!(
+                game_session.status()? == SessionStatus::InProgress,
+                WagerError::GameNotInProgress
+            );
+            // Recorded for spectator pools settling on the same result
+            game_session.record_result(&declaration)?;
+            // Suspicious play must wait out a reviewed dispute window
+            require!(!game_session.is_flagged(), WagerError::AuditReviewRequired);
+            game_session.begin_settlement(now)?;
+            msg!("Starting distribution for session: {}", session_id);
+        } else {
+            resume_distribution(&mut game_session, now)?;
+            if game_session.supports_spectators() && !game_session.matches_result(&declaration)? {
+                return Err(err_with_team(
+                    WagerError::InvalidWinningTeam,
+                    declaration.team,
+                ));
+            }
         }
+    }
 
-        // Safe arithmetic to prevent overflow
-        let earnings = safe_math::safe_earnings_calculation(kills_and_spawns, session_bet)?;
-        msg!("Earnings for player {}: {}", player, earnings);
+    pay_next_page(ctx, &session_id, declaration.team)
+}
 
-        // Find the player's account and token account in remaining_accounts
-        let player_index = ctx
-            .remaining_accounts
-            .iter()
-            .step_by(2) // Skip token accounts to only look at player accounts
-            .position(|acc| acc.key() == player)
-            .ok_or(WagerError::InvalidPlayer)?;
+/// Checks a partially paid distribution can continue on its recorded result
+pub fn resume_distribution(game_session: &mut GameSession, now: i64) -> Result<()> {
+    require!(
+        game_session.status()? == SessionStatus::Settling,
+        WagerError::GameNotInProgress
+    );
+    game_session.last_processed_at = now;
+    msg!(
+        "Resuming distribution for session {} at payee {}",
+        game_session.session_id(),
+        game_session.distribution_cursor
+    );
+    Ok(())
+}
 
-        // Get player and token account from remaining accounts
-        let player_account = &ctx.remaining_accounts[player_index * 2];
-        let player_token_account_info = &ctx.remaining_accounts[player_index * 2 + 1];
-        let player_token_account = Account::<TokenAccount>::try_from(player_token_account_info)?;
+/// Pays the next page of winners of a settling session. Every mode resolves to
+/// a deterministic list of payees; each call consumes `(player, destination,
+/// stats, bet receipt)` quadruplets for the payees following
+/// `distribution_cursor`, marking each receipt paid out, so nobody
+/// can be paid twice and the session completes once the cursor reaches the end
+/// of the list. The call completing the list appends one destination per
+/// recipient of the session's fee split after its quadruplets. Pay-to-spawn
+/// sessions have no pushed payees: settling only collects the rake and each
+/// player pulls their earnings with `claim_earnings`.
+pub fn pay_next_page<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeWinnings<'info>>,
+    session_id: &str,
+    winning_team: u8,
+) -> Result<()> {
+    let (fee, page_len) = {
+        let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+        // Every recorded stake must have reached the vault
+        if !game_session.is_free_for_all() {
+            require!(
+                game_session.reconcile_stakes()? <= ctx.accounts.vault.total_deposited,
+                WagerError::StakeMismatch
+            );
+        }
+        let (payees, fee, claimable) = distribution_payees(&game_session, winning_team)?;
+
+        // Validate remaining accounts hold whole quadruplets within the page
+        // limit, followed by the fee destinations on the completing call
+        let fee_accounts = game_session.fee_split_count as usize;
+        validation::validate_remaining_accounts_count(
+            ctx.remaining_accounts.len(),
+            PAYEE_ACCOUNTS * MAX_DISTRIBUTION_PAGE + fee_accounts,
+        )?;
 
-        // Verify player token account constraints
+        let cursor = game_session.distribution_cursor as usize;
+        let page_len = distribution_page_len(
+            ctx.remaining_accounts.len(),
+            cursor,
+            payees.len(),
+            fee_accounts,
+        )?;
         require!(
-            player_token_account.owner == player_account.key(),
-            WagerError::InvalidPlayerTokenAccount
+            cursor + page_len <= payees.len(),
+            WagerError::InvalidRemainingAccounts
         );
-
-        // Verify token account mint
+        // Every call must make progress until all payees are paid
         require!(
-            player_token_account.mint == TOKEN_ID,
-            WagerError::InvalidTokenMint
+            page_len > 0 || cursor == payees.len(),
+            WagerError::InvalidRemainingAccounts
         );
 
-        // Get vault balance before transfer
-        let vault_balance = ctx.accounts.vault_token_account.amount;
-        msg!("Vault balance before transfer: {}", vault_balance);
+        // Everything still owed must be covered before any transfer is made
+        let escrowed = escrow_balance(
+            &game_session,
+            &ctx.accounts.vault,
+            &mut ctx.accounts.vault_token_account,
+        )?;
+        validation::validate_payout_invariant(
+            payees[cursor..]
+                .iter()
+                .chain(&claimable)
+                .map(|(_, amount)| *amount)
+                .chain([fee]),
+            escrowed,
+        )?;
 
-        if earnings > 0 {
-            // Transfer tokens from vault to player
-            anchor_spl::token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    anchor_spl::token::Transfer {
-                        from: ctx.accounts.vault_token_account.to_account_info(),
-                        to: player_token_account_info.to_account_info(),
-                        authority: ctx.accounts.vault.to_account_info(),
-                    },
-                    &[&[
-                        b"vault",
-                        session_id.as_bytes(),
-                        &[vault_bump],
-                    ]],
-                ),
-                earnings,
+        if let Some(unclaimed_payout) = ctx.accounts.unclaimed_payout.as_mut() {
+            if unclaimed_payout.game_session == Pubkey::default() {
+                unclaimed_payout.game_session = ctx.accounts.game_session.key();
+                unclaimed_payout.bump = ctx
+                    .bumps
+                    .unclaimed_payout
+                    .ok_or(error!(WagerError::MissingUnclaimedPayout))?;
+            }
+        }
+
+        let mut paid = 0;
+        for (i, (player, amount)) in payees[cursor..cursor + page_len].iter().enumerate() {
+            // Stop short of the compute limit instead of failing the page;
+            // the rest of the page is paid by the next call
+            if sol_remaining_compute_units() < DISTRIBUTION_CU_PER_PAYEE {
+                require!(i > 0, WagerError::DistributionComputeExhausted);
+                msg!("Compute budget reached after {} payees", i);
+                break;
+            }
+
+            // Get player, payout destination, stats and bet receipt from
+            // remaining accounts
+            let payee_accounts = &ctx.remaining_accounts[i * PAYEE_ACCOUNTS..];
+            let player_account = &payee_accounts[0];
+            let player_token_account_info = &payee_accounts[1];
+            let player_stats_info = &payee_accounts[2];
+
+            // Payees must be supplied in order
+            if player_account.key() != *player {
+                return Err(err_with_player(WagerError::InvalidPlayer, player));
+            }
+            settle_bet_receipt(
+                &payee_accounts[3],
+                ctx.accounts.game_session.key(),
+                *player,
+                ReceiptStatus::PaidOut,
             )?;
+
+            msg!("Payee {} earns {}", player, amount);
+
+            // A closed or frozen token account would fail the whole page, so
+            // its payout stays in the vault for the payee to claim later
+            if payout_destination_unusable(
+                &game_session,
+                player_account,
+                player_token_account_info,
+                &ctx.accounts.token_program.key(),
+            )? {
+                ctx.accounts
+                    .unclaimed_payout
+                    .as_mut()
+                    .ok_or(error!(WagerError::MissingUnclaimedPayout))?
+                    .record(*player, *amount)?;
+                emit!(PayoutWithheld {
+                    session_id: session_id.to_string(),
+                    player: *player,
+                    amount: *amount,
+                });
+            } else {
+                // Verify payout destination constraints
+                validate_payout_destination(
+                    &game_session,
+                    player_account,
+                    player_token_account_info,
+                    WagerError::InvalidPlayerTokenAccount,
+                )?;
+
+                // Transfer funds from vault to player
+                release_from_vault(
+                    &game_session,
+                    &ctx.accounts.vault,
+                    &ctx.accounts.vault_token_account,
+                    &ctx.accounts.mint,
+                    &ctx.accounts.token_program,
+                    player_token_account_info,
+                    session_id,
+                    *amount,
+                )?;
+                ctx.accounts.vault.record_payout(*amount)?;
+            }
+            let rating_change =
+                game_session.take_rating_change(*player, ctx.accounts.config.elo_k_factor)?;
+            let (old_rating, new_rating) = record_player_winnings(
+                player_stats_info,
+                player,
+                *amount,
+                ctx.accounts.config.streak_milestone,
+                rating_change,
+                game_session.pays_as_winner(*player)?,
+            )?;
+            paid += 1;
+
+            emit!(WinningsDistributed {
+                session_id: session_id.to_string(),
+                player: *player,
+                amount: *amount,
+            });
+            emit!(RatingUpdated {
+                session_id: session_id.to_string(),
+                player: *player,
+                old_rating,
+                new_rating,
+            });
         }
-    }
+
+        let cursor = cursor + paid;
+        game_session.distribution_cursor = cursor as u16;
+        if cursor < payees.len() {
+            return Ok(());
+        }
+        (fee, page_len)
+    };
+
+    ctx.accounts.collect_fee(
+        session_id,
+        fee,
+        &ctx.remaining_accounts[PAYEE_ACCOUNTS * page_len..],
+    )?;
+
+    // Verify vault accounting against the live escrow balance
+    let escrowed = escrow_balance(
+        &*ctx.accounts.game_session.load()?,
+        &ctx.accounts.vault,
+        &mut ctx.accounts.vault_token_account,
+    )?;
+    ctx.accounts.vault.check_invariant(escrowed)?;
 
     // Mark session as completed
-    game_session.status = GameStatus::Completed;
-    
-    // Release reentrancy guard
-    release_reentrancy_guard!(game_session);
+    let outcome = {
+        let mut game_session = ctx.accounts.game_session.load_mut()?;
+        game_session.transition_to(SessionStatus::Completed)?;
+        if game_session.is_pay_to_spawn() {
+            "settled pay-to-spawn".to_string()
+        } else if game_session.is_free_for_all() {
+            let winner = game_session
+                .free_for_all_ranking()
+                .first()
+                .map(|entrant| entrant.player)
+                .unwrap_or_default();
+            format!("settled winner={}", winner)
+        } else if game_session.is_draw() {
+            "settled draw".to_string()
+        } else if game_session.match_result()? == MatchResult::Forfeit {
+            format!("settled forfeit winner=team{}", winning_team)
+        } else {
+            format!("settled winner=team{}", winning_team)
+        }
+    };
+    write_settlement_memo(
+        ctx.accounts.memo_program.as_ref(),
+        session_id,
+        &outcome,
+        ctx.accounts.vault.total_paid_out,
+    )?;
+
+    let game_session = ctx.accounts.game_session.load()?;
+    emit!(SessionSettled {
+        session_id: session_id.to_string(),
+        outcome,
+        total_paid_out: ctx.accounts.vault.total_paid_out,
+        sponsored_amount: game_session.sponsored_amount,
+        sponsors: game_session.prize_sponsors(),
+    });
 
     Ok(())
 }
 
-pub fn distribute_all_winnings_handler<'info>(
-    ctx: Context<'_, '_, 'info, 'info, DistributeWinnings<'info>>,
-    session_id: String,
-    winning_team: u8,
-) -> Result<()> {
-    // Input validation
-    validation::validate_session_id(&session_id)?;
-    validation::validate_team_number(winning_team)?;
-    
-    let game_session = &mut ctx.accounts.game_session;
-    
-    // Reentrancy protection
-    reentrancy_guard!(game_session);
-    
-    // Double-check authority
+/// Remaining accounts passed for each payee: wallet, payout destination,
+/// statistics and bet receipt
+const PAYEE_ACCOUNTS: usize = 4;
+
+/// Payees a distribution call pays given its remaining accounts: whole
+/// quadruplets, followed by `fee_accounts` fee destinations only when the page
+/// completes the list
+fn distribution_page_len(
+    accounts: usize,
+    cursor: usize,
+    payees: usize,
+    fee_accounts: usize,
+) -> Result<usize> {
+    if let Some(payee_accounts) = accounts.checked_sub(fee_accounts) {
+        if payee_accounts % PAYEE_ACCOUNTS == 0
+            && cursor + payee_accounts / PAYEE_ACCOUNTS == payees
+        {
+            return Ok(payee_accounts / PAYEE_ACCOUNTS);
+        }
+    }
     require!(
-        game_session.authority == ctx.accounts.game_server.key(),
-        WagerError::UnauthorizedDistribution
+        accounts % PAYEE_ACCOUNTS == 0,
+        WagerError::InvalidRemainingAccounts
     );
-    msg!("Starting distribution for session: {}", session_id);
-
-    // Validate winning team selection
+    let page_len = accounts / PAYEE_ACCOUNTS;
     require!(
-        winning_team == 0 || winning_team == 1,
-        WagerError::InvalidWinningTeam
+        fee_accounts == 0 || cursor + page_len < payees,
+        WagerError::InvalidRemainingAccounts
     );
+    Ok(page_len)
+}
 
-    let players_per_team = game_session.game_mode.players_per_team();
-    let session_bet = game_session.session_bet;
-    let vault_bump = game_session.vault_bump;
+/// Players paired with the amount each is paid
+type Payouts = Vec<(Pubkey, u64)>;
 
-    // Get the winning team
-    let winning_players = if winning_team == 0 {
-        &game_session.team_a.players[0..players_per_team]
+/// Payees of a session's result in payment order, the house rake withheld,
+/// and the pay-to-spawn earnings players pull themselves instead
+pub fn distribution_payees(
+    game_session: &GameSession,
+    winning_team: u8,
+) -> Result<(Payouts, u64, Payouts)> {
+    if game_session.is_pay_to_spawn() {
+        let (earnings, fee) = pay_to_spawn_payees(game_session)?;
+        Ok((Vec::new(), fee, earnings))
+    } else if game_session.is_free_for_all() {
+        let (payees, fee) = free_for_all_payees(game_session)?;
+        Ok((payees, fee, Vec::new()))
+    } else if game_session.is_draw() {
+        let (payees, fee) = draw_payees(game_session)?;
+        Ok((payees, fee, Vec::new()))
+    } else if game_session.match_result()? == MatchResult::Forfeit {
+        let (payees, fee) = forfeit_payees(game_session, winning_team)?;
+        Ok((payees, fee, Vec::new()))
     } else {
-        &game_session.team_b.players[0..players_per_team]
-    };
+        let (payees, fee) = winning_team_payees(game_session, winning_team)?;
+        Ok((payees, fee, Vec::new()))
+    }
+}
+
+/// Pay-to-spawn payees: every player with kills or spawns left, in roster
+/// order, with the house rake withheld from each player's earnings
+fn pay_to_spawn_payees(game_session: &GameSession) -> Result<(Vec<(Pubkey, u64)>, u64)> {
+    let mut payees = Vec::new();
+    let mut total_fees = 0u64;
 
-    for player in winning_players {
-        msg!("Winning player: {}", player);
+    for player in game_session.get_all_players() {
+        if player == Pubkey::default() {
+            continue;
+        }
+
+        let (earnings, fee) = pay_to_spawn_earnings(game_session, player)?;
+        total_fees = safe_math::safe_add(total_fees, fee)?;
+        if earnings > 0 {
+            payees.push((player, earnings));
+        }
     }
 
-    // Get winner account and token account from remaining accounts
+    Ok((payees, total_fees))
+}
+
+/// A pay-to-spawn player's earnings for their kills and spawns left, net of
+/// the house rake, and the rake withheld from them
+pub fn pay_to_spawn_earnings(game_session: &GameSession, player: Pubkey) -> Result<(u64, u64)> {
+    // Safe arithmetic to prevent overflow
+    let earnings = safe_math::safe_earnings_calculation(
+        game_session.earning_weight_bps(player)?,
+        game_session.session_bet,
+        game_session.spawn_earnings_bps,
+    )?;
+
+    // House rake is withheld from each player's earnings
+    let fee = safe_math::apply_bps(earnings, game_session.fee_bps)?;
+    Ok((safe_math::safe_subtract(earnings, fee)?, fee))
+}
+
+/// Free-for-all payees: the top finishers by kills, rounding dust to first place
+fn free_for_all_payees(game_session: &GameSession) -> Result<(Vec<(Pubkey, u64)>, u64)> {
+    let ranking = game_session.free_for_all_ranking();
     require!(
-        ctx.remaining_accounts.len() >= 2 * players_per_team,
-        WagerError::InvalidRemainingAccounts
+        ranking.len() >= FREE_FOR_ALL_PAYOUT_BPS.len(),
+        WagerError::NoWinnersFound
     );
 
-    for i in 0..players_per_team {
-        // Get winner and winner token account
-        let winner = &ctx.remaining_accounts[i * 2];
-        let winner_token_account_info = &ctx.remaining_accounts[i * 2 + 1];
-        let winner_token_account = Account::<TokenAccount>::try_from(winner_token_account_info)?;
+    // Total pot is every entrant's individual bet, plus a freeroll's prize
+    let total_pot = safe_math::safe_add(
+        safe_math::safe_multiply(game_session.session_bet, ranking.len() as u64)?,
+        game_session.prize_pool()?,
+    )?;
 
-        // Verify winner constraints
-        require!(
-            winner_token_account.owner == winner.key(),
-            WagerError::InvalidWinnerTokenAccount
-        );
+    // House rake comes off the top before the ranked split
+    let fee = safe_math::apply_bps(total_pot, game_session.fee_bps)?;
+    let prize_pool = safe_math::safe_subtract(total_pot, fee)?;
 
-        // Verify token account mint
-        require!(
-            winner_token_account.mint == TOKEN_ID,
-            WagerError::InvalidTokenMint
-        );
+    // Compute each ranked share, rounding dust goes to first place
+    let mut payouts = Vec::with_capacity(FREE_FOR_ALL_PAYOUT_BPS.len());
+    for share_bps in FREE_FOR_ALL_PAYOUT_BPS.iter() {
+        payouts.push(safe_math::apply_bps(prize_pool, *share_bps)?);
+    }
+    let mut runners_up_total = 0u64;
+    for payout in payouts.iter().skip(1) {
+        runners_up_total = safe_math::safe_add(runners_up_total, *payout)?;
+    }
+    payouts[0] = safe_math::safe_subtract(prize_pool, runners_up_total)?;
+
+    let payees = ranking
+        .iter()
+        .zip(payouts)
+        .filter(|(_, payout)| *payout > 0)
+        .map(|(entrant, payout)| (entrant.player, payout))
+        .collect();
+    Ok((payees, fee))
+}
 
-        // Verify winner is actually in the winning team
-        let winner_pubkey = winner.key();
-        require!(
-            winning_players
-                .iter()
-                .take(players_per_team)
-                .any(|&p| p == winner_pubkey),
-            WagerError::InvalidWinner
-        );
+/// Winner-takes-all payees: every player of the winning team splitting the
+/// pot. In uneven lobbies, and matches started short-handed, the whole pot
+/// is still shared across the seated winners, so a short-handed team that
+/// wins is paid in proportion to the handicap it overcame; rounding dust goes
+/// to the first winner.
+fn winning_team_payees(
+    game_session: &GameSession,
+    winning_team: u8,
+) -> Result<(Vec<(Pubkey, u64)>, u64)> {
+    // Total pot is every seat's stake, reconciled against the team totals
+    let total_pot = game_session.reconcile_stakes()?;
+    team_prize_payees(game_session, winning_team, total_pot)
+}
 
-        // Get vault balance before transfer
-        let vault_balance = ctx.accounts.vault_token_account.amount;
-        msg!("Vault balance before transfer: {}", vault_balance);
+/// Forfeit payees: the winning team splits its own bets and the forfeit
+/// penalty taken from each forfeiting player, who are then refunded the rest
+/// of their bet in roster order. The rake only comes off the winners' pot.
+fn forfeit_payees(
+    game_session: &GameSession,
+    winning_team: u8,
+) -> Result<(Vec<(Pubkey, u64)>, u64)> {
+    game_session.reconcile_stakes()?;
+    let mut total_pot = game_session.team(winning_team)?.total_bet;
+    let mut refunds = Vec::new();
+    for (team, roster) in game_session.active_teams().iter().enumerate() {
+        if team == winning_team as usize {
+            continue;
+        }
+        for (index, player) in roster.players.iter().enumerate() {
+            if *player == Pubkey::default() {
+                continue;
+            }
+            let stake = game_session.seat_stake(team, index);
+            let penalty = safe_math::apply_bps(stake, game_session.forfeit_penalty_bps)?;
+            total_pot = safe_math::safe_add(total_pot, penalty)?;
+            let refund = safe_math::safe_subtract(stake, penalty)?;
+            if refund > 0 {
+                refunds.push((*player, refund));
+            }
+        }
+    }
 
-        // Calculate total pot (sum of both teams' bets) with safe arithmetic
-        let players_per_team_u64 = players_per_team as u64;
-        let total_pot = safe_math::safe_multiply(
-            safe_math::safe_multiply(game_session.session_bet, players_per_team_u64)?,
-            2
-        )?;
-        msg!("Total pot calculated: {}", total_pot);
-
-        let winning_amount = safe_math::safe_multiply(game_session.session_bet, 2)?;
-        msg!("Winning amount calculated: {}", winning_amount);
-
-        // Transfer tokens from vault to winner
-        anchor_spl::token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token::Transfer {
-                    from: ctx.accounts.vault_token_account.to_account_info(),
-                    to: winner_token_account.to_account_info(),
-                    authority: ctx.accounts.vault.to_account_info(),
-                },
-                &[&[
-                    b"vault",
-                    session_id.as_bytes(),
-                    &[vault_bump],
-                ]],
-            ),
-            winning_amount,
-        )?;
+    let (mut payees, fee) = team_prize_payees(game_session, winning_team, total_pot)?;
+    payees.extend(refunds);
+    Ok((payees, fee))
+}
+
+/// Payees of the winning team splitting a pot, after the house rake. A
+/// freeroll's sponsored prize is added to the pot.
+fn team_prize_payees(
+    game_session: &GameSession,
+    winning_team: u8,
+    total_pot: u64,
+) -> Result<(Vec<(Pubkey, u64)>, u64)> {
+    let total_pot = safe_math::safe_add(total_pot, game_session.prize_pool()?)?;
+    let roster = game_session.team(winning_team)?;
+    let capacity = game_session.team_capacity(winning_team as usize)?;
+    let seated: Vec<usize> = (0..capacity)
+        .filter(|&index| roster.players[index] != Pubkey::default())
+        .collect();
+    require!(!seated.is_empty(), WagerError::NoWinnersFound);
+    let winners = seated.len();
+
+    // House rake comes off the top before the winners split the pot
+    let fee = safe_math::apply_bps(total_pot, game_session.fee_bps)?;
+    let prize_pool = safe_math::safe_subtract(total_pot, fee)?;
+
+    let shares = game_session.payout_shares();
+    if !shares.is_empty() {
+        return Ok((ranked_payees(roster, seated, shares, prize_pool)?, fee));
     }
 
-    // Mark session as completed
-    game_session.status = GameStatus::Completed;
-    
-    // Release reentrancy guard
-    release_reentrancy_guard!(game_session);
+    // Stake-weighted winners split the pot in proportion to their stakes,
+    // rounding dust going to the last winner
+    if game_session.is_stake_weighted() {
+        let stakes: Vec<u64> = seated
+            .iter()
+            .map(|&index| game_session.seat_stake(winning_team as usize, index))
+            .collect();
+        let payouts = safe_math::pro_rata_split(prize_pool, &stakes)?;
+        let payees = seated
+            .iter()
+            .zip(payouts)
+            .map(|(&index, payout)| (roster.players[index], payout))
+            .collect();
+        return Ok((payees, fee));
+    }
 
-    Ok(())
+    // Split the pot evenly across the winning team's players
+    let winning_amount = safe_math::safe_divide(prize_pool, winners as u64)?;
+    let dust = safe_math::safe_subtract(
+        prize_pool,
+        safe_math::safe_multiply(winning_amount, winners as u64)?,
+    )?;
+
+    let mut payees: Vec<(Pubkey, u64)> = seated
+        .iter()
+        .map(|&index| (roster.players[index], winning_amount))
+        .collect();
+    payees[0].1 = safe_math::safe_add(payees[0].1, dust)?;
+    Ok((payees, fee))
+}
+
+/// Draw payees: every seated player of every team, in roster order, gets
+/// their stake back less the draw rake
+fn draw_payees(game_session: &GameSession) -> Result<(Vec<(Pubkey, u64)>, u64)> {
+    let mut payees = Vec::new();
+    let mut fee = 0u64;
+    for player in game_session.get_all_players() {
+        if player == Pubkey::default() {
+            continue;
+        }
+        let stake = game_session.stake_of(player);
+        let player_fee = safe_math::apply_bps(stake, game_session.draw_fee_bps)?;
+        fee = safe_math::safe_add(fee, player_fee)?;
+        payees.push((player, safe_math::safe_subtract(stake, player_fee)?));
+    }
+    Ok((payees, fee))
+}
+
+/// Ranked payees of a payout schedule: the winning team's seated players
+/// ordered by kills (ties keep roster order) take the schedule's shares,
+/// rounding dust goes to the top fragger
+fn ranked_payees(
+    roster: &Team,
+    mut ranking: Vec<usize>,
+    shares: &[u16],
+    prize_pool: u64,
+) -> Result<Vec<(Pubkey, u64)>> {
+    ranking.sort_by_key(|&index| std::cmp::Reverse(roster.slots[index].kills));
+
+    let mut payouts = Vec::with_capacity(shares.len());
+    for share_bps in shares.iter() {
+        payouts.push(safe_math::apply_bps(prize_pool, *share_bps)?);
+    }
+    let mut others_total = 0u64;
+    for payout in payouts.iter().skip(1) {
+        others_total = safe_math::safe_add(others_total, *payout)?;
+    }
+    payouts[0] = safe_math::safe_subtract(prize_pool, others_total)?;
+
+    Ok(ranking
+        .iter()
+        .zip(payouts)
+        .filter(|(_, payout)| *payout > 0)
+        .map(|(index, payout)| (roster.players[*index], payout))
+        .collect())
 }
 
 #[derive(Accounts)]
 #[instruction(session_id: String)]
 pub struct DistributeWinnings<'info> {
-    /// The game server authority that created the session
-    pub game_server: Signer<'info>,
+    /// The session authority, or anyone finalizing a result whose dispute
+    /// window has passed; pays for the unclaimed payout account if needed
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
 
     #[account(
         mut,
         seeds = [b"game_session", session_id.as_bytes()],
-        bump = game_session.bump,
-        constraint = game_session.authority == game_server.key() @ WagerError::UnauthorizedDistribution,
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
     )]
-    pub game_session: Account<'info, GameSession>,
+    pub game_session: AccountLoader<'info, GameSession>,
 
-    /// CHECK: Vault PDA that holds the funds
+    /// Vault PDA that holds the funds and tracks deposits/payouts
     #[account(
         mut,
         seeds = [b"vault", session_id.as_bytes()],
-        bump = game_session.vault_bump,
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury PDA that owns the fee token accounts and holds native SOL fees
+    /// (must be pre-funded to rent exemption before collecting SOL fees)
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
     )]
-    pub vault: AccountInfo<'info>,
+    pub treasury: SystemAccount<'info>,
 
     #[account(
         mut,
-        associated_token::mint = TOKEN_ID,
-        associated_token::authority = vault
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Signing set approvals for an immediate settlement, needed when the
+    /// threshold exceeds the caller's own signature
+    #[account(
+        seeds = [b"approval", game_session.key().as_ref(), &[ApprovalKind::DistributeWinnings as u8]],
+        bump = approval.bump,
+    )]
+    pub approval: Option<Account<'info, Approval>>,
+
+    /// Jackpot of the session mint, required while the config routes a cut of
+    /// the rake into it
+    #[account(
+        mut,
+        seeds = [b"jackpot", game_session.load()?.mint.as_ref()],
+        bump = jackpot.bump,
+    )]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+
+    /// Jackpot token account for the mint, unused by native SOL sessions
+    #[account(mut)]
+    pub jackpot_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL Memo program, supplied to record a settlement summary
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    /// Holds payouts to closed or frozen token accounts, required when a
+    /// page contains one
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = UnclaimedPayout::LEN,
+        seeds = [b"unclaimed_payout", game_session.key().as_ref()],
+        bump
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub unclaimed_payout: Option<Box<Account<'info, UnclaimedPayout>>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
+
+impl<'info> DistributeWinnings<'info> {
+    /// Moves the withheld house rake from the vault into the treasury, or
+    /// across the session's fee split recipients, after routing the
+    /// jackpot's cut into the mint's jackpot
+    pub fn collect_fee(
+        &mut self,
+        session_id: &str,
+        amount: u64,
+        fee_destinations: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let jackpot_cut = safe_math::apply_bps(amount, self.config.jackpot_bps)?;
+        if jackpot_cut > 0 {
+            self.fund_jackpot(session_id, jackpot_cut)?;
+        }
+        let amount = safe_math::safe_subtract(amount, jackpot_cut)?;
+
+        let game_session = self.game_session.load()?;
+        let split = game_session.fee_split();
+        if split.is_empty() {
+            let destination = if game_session.is_native() {
+                self.treasury.to_account_info()
+            } else {
+                self.treasury_token_account.to_account_info()
+            };
+            release_from_vault(
+                &game_session,
+                &self.vault,
+                &self.vault_token_account,
+                &self.mint,
+                &self.token_program,
+                &destination,
+                session_id,
+                amount,
+            )?;
+            self.vault.record_fee(amount)?;
+        } else {
+            require!(
+                fee_destinations.len() == split.len(),
+                WagerError::InvalidRemainingAccounts
+            );
+
+            // Each recipient takes its share, rounding dust goes to the first
+            let mut shares = Vec::with_capacity(split.len());
+            for share in split.iter() {
+                shares.push(safe_math::apply_bps(amount, share.bps)?);
+            }
+            let others_total = shares[1..]
+                .iter()
+                .copied()
+                .try_fold(0u64, safe_math::safe_add)?;
+            shares[0] = safe_math::safe_subtract(amount, others_total)?;
+
+            for ((share, share_amount), destination) in
+                split.iter().zip(shares).zip(fee_destinations)
+            {
+                // Native SOL goes to the recipient wallet, tokens to its
+                // associated token account
+                let expected = if game_session.is_native() {
+                    share.recipient
+                } else {
+                    get_associated_token_address_with_program_id(
+                        &share.recipient,
+                        &game_session.mint,
+                        &self.token_program.key(),
+                    )
+                };
+                if destination.key() != expected {
+                    return Err(err_with_player(
+                        WagerError::InvalidFeeRecipient,
+                        &share.recipient,
+                    ));
+                }
+                if share_amount == 0 {
+                    continue;
+                }
+
+                release_from_vault(
+                    &game_session,
+                    &self.vault,
+                    &self.vault_token_account,
+                    &self.mint,
+                    &self.token_program,
+                    destination,
+                    session_id,
+                    share_amount,
+                )?;
+                self.vault.record_fee(share_amount)?;
+                emit!(FeeShareRouted {
+                    session_id: session_id.to_string(),
+                    recipient: share.recipient,
+                    amount: share_amount,
+                });
+            }
+        }
+
+        emit!(FeesCollected {
+            session_id: session_id.to_string(),
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Moves the jackpot's cut of the rake from the vault into the jackpot
+    /// and counts the session towards the next jackpot award
+    fn fund_jackpot(&mut self, session_id: &str, amount: u64) -> Result<()> {
+        let game_session = self.game_session.load()?;
+        let jackpot = self
+            .jackpot
+            .as_mut()
+            .ok_or(error!(WagerError::InvalidJackpot))?;
+        let destination = if game_session.is_native() {
+            jackpot.to_account_info()
+        } else {
+            let jackpot_token_account = self
+                .jackpot_token_account
+                .as_ref()
+                .ok_or(error!(WagerError::InvalidJackpot))?;
+            require_keys_eq!(
+                jackpot_token_account.key(),
+                get_associated_token_address_with_program_id(
+                    &jackpot.key(),
+                    &game_session.mint,
+                    &self.token_program.key(),
+                ),
+                WagerError::InvalidJackpot
+            );
+            jackpot_token_account.to_account_info()
+        };
+        release_from_vault(
+            &game_session,
+            &self.vault,
+            &self.vault_token_account,
+            &self.mint,
+            &self.token_program,
+            &destination,
+            session_id,
+            amount,
+        )?;
+        self.vault.record_fee(amount)?;
+
+        // Only sessions settled on a winning team can win the jackpot
+        let eligible_session = game_session.winning_team().map(|_| self.game_session.key());
+        let won = jackpot.contribute(amount, eligible_session, self.config.jackpot_interval)?;
+
+        emit!(JackpotFunded {
+            session_id: session_id.to_string(),
+            mint: game_session.mint,
+            amount,
+            balance: jackpot.balance,
+        });
+        if won {
+            emit!(JackpotWon {
+                session_id: session_id.to_string(),
+                mint: game_session.mint,
+                prize: jackpot.pending_prize,
+            });
+        }
+        Ok(())
+    }
+}
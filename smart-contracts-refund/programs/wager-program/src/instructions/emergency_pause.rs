@@ -0,0 +1,34 @@
+use crate::{errors::WagerError, events::PauseUpdated, state::*};
+use anchor_lang::prelude::*;
+
+pub fn emergency_pause_handler(ctx: Context<SetPause>) -> Result<()> {
+    set_paused(ctx, true)
+}
+
+pub fn unpause_handler(ctx: Context<SetPause>) -> Result<()> {
+    set_paused(ctx, false)
+}
+
+fn set_paused(ctx: Context<SetPause>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.paused = paused;
+
+    emit!(PauseUpdated {
+        authority: ctx.accounts.authority.key(),
+        paused,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,45 @@
+use crate::{errors::WagerError, instructions::distribution_payees, state::*};
+use anchor_lang::prelude::*;
+
+/// Read-only planner for a session's distribution: how many payees are left
+/// and how many `distribute_winnings` calls of `page_size` payees it takes to
+/// complete within the compute budget. Meant to be simulated by the game
+/// server, which reads the plan from the return data.
+pub fn estimate_distribution_chunks_handler(
+    ctx: Context<EstimateDistributionChunks>,
+    _session_id: String,
+    winning_team: u8,
+) -> Result<DistributionPlan> {
+    let game_session = ctx.accounts.game_session.load()?;
+    let status = game_session.status()?;
+
+    let (payees, _, _) = distribution_payees(&game_session, winning_team)?;
+    let paid = game_session.distribution_cursor as usize;
+    let page_size = MAX_DISTRIBUTION_PAGE;
+
+    // The last call also collects the fee, so a settling session with every
+    // payee paid still needs one more
+    let chunks = if status == SessionStatus::Completed {
+        0
+    } else {
+        payees.len().saturating_sub(paid).div_ceil(page_size).max(1)
+    };
+
+    Ok(DistributionPlan {
+        payees: payees.len() as u16,
+        paid: paid as u16,
+        page_size: page_size as u16,
+        chunks: chunks as u16,
+    })
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct EstimateDistributionChunks<'info> {
+    #[account(
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+}
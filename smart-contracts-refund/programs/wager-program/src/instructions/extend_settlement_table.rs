@@ -0,0 +1,165 @@
+use crate::{errors::WagerError, events::SettlementTableExtended, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    address_lookup_table::{self, instruction::extend_lookup_table},
+    program::invoke_signed,
+};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+use anchor_spl::memo;
+use anchor_spl::token_interface::Mint;
+
+/// Adds the settlement accounts of up to `MAX_TABLE_EXTEND_PLAYERS` players,
+/// in roster order from `first_player`, to the session's lookup table: each
+/// player wallet with its payout destination, statistics and bet receipt
+/// PDAs. The first
+/// page also adds every account `distribute_winnings` takes besides its
+/// signer: the session, its vault and token account, the mint, the config,
+/// treasury, jackpot and fee split destinations, and the programs. The
+/// lookup table program caps a table at 256 addresses.
+pub fn extend_settlement_table_handler(
+    ctx: Context<ExtendSettlementTable>,
+    session_id: String,
+    first_player: u8,
+) -> Result<()> {
+    let game_session = ctx.accounts.game_session.load()?;
+    require!(
+        game_session.status()? == SessionStatus::InProgress,
+        WagerError::GameNotInProgress
+    );
+
+    let mint = ctx.accounts.mint.key();
+    let token_program = *ctx.accounts.mint.to_account_info().owner;
+    // Native SOL sessions pay wallets themselves
+    let destination = |owner: &Pubkey| {
+        if game_session.is_native() {
+            *owner
+        } else {
+            get_associated_token_address_with_program_id(owner, &mint, &token_program)
+        }
+    };
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let vault = ctx.accounts.vault.key();
+    let mut addresses = Vec::new();
+    if first_player == 0 {
+        let treasury = Pubkey::find_program_address(&[b"treasury"], ctx.program_id).0;
+        let unclaimed_payout = Pubkey::find_program_address(
+            &[b"unclaimed_payout", game_session_key.as_ref()],
+            ctx.program_id,
+        )
+        .0;
+        addresses.extend([
+            game_session_key,
+            vault,
+            get_associated_token_address_with_program_id(&vault, &mint, &token_program),
+            mint,
+            ctx.accounts.config.key(),
+            treasury,
+            get_associated_token_address_with_program_id(&treasury, &mint, &token_program),
+            unclaimed_payout,
+            token_program,
+            associated_token::ID,
+            memo::ID,
+            ctx.accounts.system_program.key(),
+        ]);
+        if ctx.accounts.config.jackpot_bps > 0 {
+            let jackpot =
+                Pubkey::find_program_address(&[b"jackpot", mint.as_ref()], ctx.program_id).0;
+            addresses.push(jackpot);
+            if !game_session.is_native() {
+                addresses.push(destination(&jackpot));
+            }
+        }
+        for share in game_session.fee_split() {
+            addresses.push(destination(&share.recipient));
+        }
+    }
+    let players = game_session.get_all_players();
+    let first = (first_player as usize).min(players.len());
+    let last = (first + MAX_TABLE_EXTEND_PLAYERS).min(players.len());
+    for player in &players[first..last] {
+        addresses.push(*player);
+        if !game_session.is_native() {
+            addresses.push(destination(player));
+        }
+        addresses.push(
+            Pubkey::find_program_address(&[b"player_stats", player.as_ref()], ctx.program_id).0,
+        );
+        addresses.push(
+            Pubkey::find_program_address(
+                &[b"bet_receipt", game_session_key.as_ref(), player.as_ref()],
+                ctx.program_id,
+            )
+            .0,
+        );
+    }
+    require!(!addresses.is_empty(), WagerError::InvalidPlayer);
+    let count = addresses.len() as u8;
+
+    invoke_signed(
+        &extend_lookup_table(
+            ctx.accounts.lookup_table.key(),
+            vault,
+            Some(ctx.accounts.game_server.key()),
+            addresses,
+        ),
+        &[
+            ctx.accounts.lookup_table.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.game_server.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.address_lookup_table_program.to_account_info(),
+        ],
+        &[&[b"vault", session_id.as_bytes(), &[game_session.vault_bump]]],
+    )?;
+
+    emit!(SettlementTableExtended {
+        session_id,
+        lookup_table: ctx.accounts.lookup_table.key(),
+        addresses: count,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct ExtendSettlementTable<'info> {
+    /// The game server authority that created the session, paying the table's rent
+    #[account(mut)]
+    pub game_server: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == game_server.key() @ WagerError::UnauthorizedDistribution,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Session vault, the table's authority
+    #[account(
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Lookup table the session opened, which the lookup table
+    /// program checks against the vault's signature
+    #[account(
+        mut,
+        address = game_session.load()?.settlement_table @ WagerError::InvalidLookupTable,
+    )]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Address lookup table program
+    #[account(address = address_lookup_table::program::ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
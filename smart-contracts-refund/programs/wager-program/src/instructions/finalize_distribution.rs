@@ -0,0 +1,36 @@
+use crate::{errors::WagerError, instructions::distribute_winnings::*, state::*, validation::*};
+use anchor_lang::prelude::*;
+
+/// Pays out a submitted result once its dispute window has passed. Callable by
+/// anyone, paginated the same way as `distribute_winnings`.
+pub fn finalize_distribution_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeWinnings<'info>>,
+    session_id: String,
+) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let winning_team = {
+        let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+        if game_session.distribution_cursor == 0 {
+            require!(
+                game_session.status()? == SessionStatus::ResultSubmitted,
+                WagerError::InvalidGameState
+            );
+            validation::validate_deadline_reached(
+                now,
+                game_session.dispute_deadline,
+                game_session.clock_skew_secs,
+            )?;
+            game_session.begin_settlement(now)?;
+            msg!("Finalizing distribution for session: {}", session_id);
+        } else {
+            resume_distribution(&mut game_session, now)?;
+        }
+        game_session.winning_team
+    };
+
+    pay_next_page(ctx, &session_id, winning_team)
+}
@@ -0,0 +1,188 @@
+use crate::{
+    errors::WagerError,
+    events::*,
+    state::*,
+    utils::{gross_up_transfer_fee, transfer_from_escrow},
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Pops the oldest queued players into a new session, seating team players
+/// alternately, and moves their escrowed bets into its vault. Anyone may
+/// form a match once the queue holds enough players.
+pub fn form_match_handler(ctx: Context<FormMatch>, session_id: String) -> Result<()> {
+    validation::validate_session_id(&session_id)?;
+
+    let match_queue = &mut ctx.accounts.match_queue;
+    let needed = match_queue.players_per_match();
+    require!(
+        match_queue.players.len() >= needed,
+        WagerError::NotEnoughQueuedPlayers
+    );
+    let players: Vec<Pubkey> = match_queue.players.drain(..needed).collect();
+
+    let game_mode = match_queue.game_mode;
+    let bet_amount = match_queue.bet_amount;
+    let team_count = if game_mode.is_free_for_all() { 0 } else { 2 };
+    let total = safe_math::safe_multiply(bet_amount, needed as u64)?;
+
+    // A fee introduced while players waited would leave the session short
+    require!(
+        gross_up_transfer_fee(&ctx.accounts.mint, total)? == total,
+        WagerError::QueueUnsupported
+    );
+
+    let mint = match_queue.mint;
+    let mode_seed = [game_mode as u8];
+    let bet_bytes = bet_amount.to_le_bytes();
+    let bump = [match_queue.bump];
+    let seeds: &[&[u8]] = &[b"match_queue", mint.as_ref(), &bet_bytes, &mode_seed, &bump];
+    transfer_from_escrow(
+        &ctx.accounts.queue_token_account.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.match_queue.to_account_info(),
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        total,
+        &[seeds],
+    )?;
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_init()?;
+
+    // Queued sessions are run by the primary game server key
+    let config = &ctx.accounts.config;
+    let authority = *config
+        .game_server_keys
+        .first()
+        .ok_or(error!(WagerError::InvalidGameServerKeys))?;
+    game_session.initialize(
+        &session_id,
+        authority,
+        mint,
+        bet_amount,
+        game_mode,
+        team_count,
+        config,
+        Clock::get()?.unix_timestamp,
+    )?;
+    game_session.set_bumps(
+        &game_session_key,
+        &ctx.accounts.vault.key(),
+        ctx.bumps.game_session,
+        ctx.bumps.vault,
+    )?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.game_session = game_session_key;
+    vault.record_deposit(total)?;
+    validation::validate_rent_exempt(&vault.to_account_info(), Vault::LEN)?;
+
+    emit!(SessionCreated {
+        session_id: session_id.clone(),
+        authority,
+        mint,
+        bet_amount,
+        game_mode,
+        team_count,
+    });
+
+    for (index, player) in players.iter().enumerate() {
+        let team = if game_mode.is_free_for_all() {
+            0
+        } else {
+            (index % 2) as u8
+        };
+        game_session.add_player(team, *player)?;
+        emit!(PlayerJoined {
+            session_id: session_id.clone(),
+            player: *player,
+            team,
+            amount: bet_amount,
+        });
+    }
+
+    emit!(MatchFormed {
+        queue: ctx.accounts.match_queue.key(),
+        session_id: session_id.clone(),
+        players,
+    });
+    if game_session.status()? == SessionStatus::InProgress {
+        emit!(SessionStarted { session_id });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct FormMatch<'info> {
+    /// Anyone may form a match and pays for the session accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"match_queue",
+            match_queue.mint.as_ref(),
+            &match_queue.bet_amount.to_le_bytes(),
+            &[match_queue.game_mode as u8],
+        ],
+        bump = match_queue.bump,
+    )]
+    pub match_queue: Account<'info, MatchQueue>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = match_queue,
+        associated_token::token_program = token_program,
+    )]
+    pub queue_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = GameSession::LEN,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA that owns the escrow token account
+    #[account(
+        init,
+        payer = payer,
+        space = Vault::LEN,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = match_queue.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
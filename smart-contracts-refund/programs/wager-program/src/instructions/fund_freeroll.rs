@@ -0,0 +1,179 @@
+use crate::{
+    errors::WagerError,
+    events::FreerollFunded,
+    state::*,
+    utils::{gross_up_transfer_fee, transfer_from_escrow, transfer_spl_tokens},
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Adds to a freeroll's prize, either from the sponsor's own funds or, when
+/// the config authority runs the freeroll, from the treasury's fees. The
+/// prize can be topped up until the match ends, and is returned to whoever
+/// funded it if the session is refunded.
+pub fn fund_freeroll_handler(
+    ctx: Context<FundFreeroll>,
+    session_id: String,
+    amount: u64,
+    from_treasury: bool,
+) -> Result<()> {
+    validation::validate_session_id(&session_id)?;
+    require!(amount > 0, WagerError::InvalidPrizeAmount);
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    require!(
+        game_session.authority == ctx.accounts.sponsor.key(),
+        WagerError::UnauthorizedFreerollSponsor
+    );
+    game_session.fund_prize(amount, from_treasury)?;
+
+    let native = game_session.is_native();
+    if from_treasury {
+        let config = &ctx.accounts.config;
+        require!(
+            config.authority == ctx.accounts.sponsor.key(),
+            WagerError::UnauthorizedFreerollSponsor
+        );
+        let signer_seeds: &[&[&[u8]]] = &[&[b"treasury", &[ctx.bumps.treasury]]];
+        if native {
+            // Creation fees still refundable to session creators stay put
+            let available = ctx
+                .accounts
+                .treasury
+                .lamports()
+                .saturating_sub(config.held_creation_fees);
+            require!(amount <= available, WagerError::CreationFeesReserved);
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.treasury.to_account_info(),
+                        to: ctx.accounts.vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        } else {
+            transfer_from_escrow(
+                &ctx.accounts.treasury_token_account.to_account_info(),
+                &ctx.accounts.vault_token_account.to_account_info(),
+                &ctx.accounts.treasury.to_account_info(),
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                gross_up_transfer_fee(&ctx.accounts.mint, amount)?,
+                signer_seeds,
+            )?;
+        }
+    } else if native {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.sponsor.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    } else {
+        let sponsor_token_account = ctx
+            .accounts
+            .sponsor_token_account
+            .as_ref()
+            .ok_or(error!(WagerError::MissingSponsorTokenAccount))?;
+        transfer_spl_tokens(
+            sponsor_token_account,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.sponsor,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+    }
+    ctx.accounts.vault.record_deposit(amount)?;
+
+    emit!(FreerollFunded {
+        session_id,
+        funder: ctx.accounts.sponsor.key(),
+        amount,
+        from_treasury,
+        prize_pool: game_session.prize_pool()?,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct FundFreeroll<'info> {
+    /// Session authority that created the freeroll
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Sponsor's token account, required for sponsor deposits of SPL tokens
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sponsor,
+        token::token_program = token_program,
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury PDA that owns the fee token accounts and holds native SOL fees
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,46 @@
+use crate::{errors::WagerError, program::WagerProgram, state::*, validation::*};
+use anchor_lang::prelude::*;
+
+pub fn initialize_config_handler(
+    ctx: Context<InitializeConfig>,
+    params: ConfigParams,
+) -> Result<()> {
+    validation::validate_config_params(&params)?;
+
+    let config = &mut ctx.accounts.config;
+    config.authority = ctx.accounts.authority.key();
+    config.apply(&params);
+    config.paused = false;
+    config.bump = ctx.bumps.config;
+
+    msg!("Program config: {}", config.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// Must be the program's upgrade authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ProgramConfig::LEN,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ WagerError::InvalidAuthority
+    )]
+    pub program: Program<'info, WagerProgram>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ WagerError::InvalidAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
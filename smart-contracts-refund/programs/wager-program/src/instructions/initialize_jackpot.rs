@@ -0,0 +1,50 @@
+use crate::{errors::WagerError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn initialize_jackpot_handler(ctx: Context<InitializeJackpot>) -> Result<()> {
+    let jackpot = &mut ctx.accounts.jackpot;
+    jackpot.mint = ctx.accounts.mint.key();
+    jackpot.bump = ctx.bumps.jackpot;
+
+    msg!("Jackpot initialized for mint {}", jackpot.mint);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeJackpot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Jackpot::LEN,
+        seeds = [b"jackpot", mint.key().as_ref()],
+        bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = jackpot,
+        associated_token::token_program = token_program,
+    )]
+    pub jackpot_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,164 @@
+use crate::{errors::WagerError, events::*, state::*, utils::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+pub fn join_queue_handler(
+    ctx: Context<JoinQueue>,
+    bet_amount: u64,
+    game_mode: GameMode,
+) -> Result<()> {
+    // Queued sessions are formed without an operator to pick spawn rates,
+    // and their bets are escrowed in SPL token accounts
+    require!(
+        !game_mode.is_pay_to_spawn() && ctx.accounts.mint.key() != native_mint::ID,
+        WagerError::QueueUnsupported
+    );
+
+    // Moving bets on from the queue would shave off a second transfer fee
+    require!(
+        gross_up_transfer_fee(&ctx.accounts.mint, bet_amount)? == bet_amount,
+        WagerError::QueueUnsupported
+    );
+
+    let config = &ctx.accounts.config;
+    let (min_bet, max_bet) = ctx.accounts.registered_mint.bet_limits(config);
+    validation::validate_bet_amount(bet_amount, min_bet, max_bet)?;
+    validate_bet_usd_bounds(
+        config,
+        &ctx.accounts.mint,
+        ctx.accounts.price_feed.as_ref(),
+        ctx.accounts.price_update.as_ref(),
+        bet_amount,
+        &Clock::get()?,
+    )?;
+
+    validation::validate_not_banned(&ctx.accounts.player_ban)?;
+
+    let player = ctx.accounts.user.key();
+    let match_queue = &mut ctx.accounts.match_queue;
+    if match_queue.mint == Pubkey::default() {
+        match_queue.mint = ctx.accounts.mint.key();
+        match_queue.bet_amount = bet_amount;
+        match_queue.game_mode = game_mode;
+        match_queue.bump = ctx.bumps.match_queue;
+    }
+    require!(
+        !match_queue.players.contains(&player),
+        WagerError::PlayerAlreadyQueued
+    );
+    require!(
+        match_queue.players.len() < MAX_QUEUE_PLAYERS,
+        WagerError::QueueFull
+    );
+    match_queue.players.push(player);
+
+    // Escrow the bet with the queue until a session is formed
+    transfer_spl_tokens(
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.queue_token_account,
+        &ctx.accounts.user,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        bet_amount,
+    )?;
+
+    let player_stats = &mut ctx.accounts.player_stats;
+    player_stats.init_if_new(player, ctx.bumps.player_stats);
+    validation::validate_rent_exempt(&player_stats.to_account_info(), PlayerStats::LEN)?;
+    player_stats.record_game(bet_amount)?;
+
+    emit!(PlayerQueued {
+        queue: ctx.accounts.match_queue.key(),
+        player,
+        amount: bet_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(bet_amount: u64, game_mode: GameMode)]
+pub struct JoinQueue<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Queue for this bet and game mode, created by its first player
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = MatchQueue::LEN,
+        seeds = [
+            b"match_queue",
+            mint.key().as_ref(),
+            &bet_amount.to_le_bytes(),
+            &[game_mode as u8],
+        ],
+        bump
+    )]
+    pub match_queue: Account<'info, MatchQueue>,
+
+    /// Escrow of the queued bets, owned by the queue PDA
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = match_queue,
+        associated_token::token_program = token_program,
+    )]
+    pub queue_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key(),
+        constraint = user_token_account.mint == mint.key()
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Lifetime statistics of the queued player, created on first join
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PlayerStats::LEN,
+        seeds = [b"player_stats", user.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// CHECK: Ban marker PDA of the player, which must not exist
+    #[account(seeds = [b"player_ban", user.key().as_ref()], bump)]
+    pub player_ban: UncheckedAccount<'info>,
+
+    /// Wager token of the queue
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Registry entry permitting the mint as a wager token
+    #[account(
+        seeds = [b"registered_mint", mint.key().as_ref()],
+        bump = registered_mint.bump,
+    )]
+    pub registered_mint: Account<'info, RegisteredMint>,
+
+    /// Price feed registered for the mint, required when USD bounds are enforced
+    #[account(
+        seeds = [b"price_feed", mint.key().as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    /// Pyth price update for the registered feed
+    pub price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,63 +1,180 @@
-use crate::{errors::WagerError, state::*, validation::*, TOKEN_ID};
+use crate::{
+    errors::WagerError,
+    events::*,
+    state::*,
+    utils::{record_exposure, transfer_spl_tokens},
+    validation::*,
+};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-pub fn join_user_handler(ctx: Context<JoinUser>, session_id: String, team: u8) -> Result<()> {
+pub fn join_user_handler(
+    ctx: Context<JoinUser>,
+    session_id: String,
+    team: u8,
+    referrer: Option<Pubkey>,
+    credential: Option<JoinCredential>,
+    stake: Option<u64>,
+) -> Result<()> {
     // Input validation
     validation::validate_session_id(&session_id)?;
-    validation::validate_team_number(team)?;
-    
-    let game_session = &mut ctx.accounts.game_session;
 
-    // Validate game status
-    require!(
-        game_session.status == GameStatus::WaitingForPlayers,
-        WagerError::InvalidGameState
-    );
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    validation::validate_cpi_caller(
+        &ctx.accounts.config.cpi_callers,
+        ctx.accounts.instructions.as_deref(),
+    )?;
+    validation::validate_not_banned(&ctx.accounts.player_ban)?;
+    validation::validate_not_kicked(&ctx.accounts.session_kick)?;
+    validate_join(
+        &game_session,
+        team,
+        ctx.accounts.user.key(),
+        ctx.accounts.player_stats.rating(),
+        credential.as_ref(),
+        ctx.accounts.pass_token_account.as_deref(),
+    )?;
 
-    // Check if team is full already
-    let empty_index = game_session.get_player_empty_slot(team)?;
+    // Native SOL sessions escrow lamports through join_user_sol
+    require!(!game_session.is_native(), WagerError::NativeSolSession);
 
-    let session_bet = game_session.session_bet;
+    // Stake-weighted sessions let the player stake more than the bet
+    let stake = stake.unwrap_or(game_session.session_bet);
 
     // Transfer SPL tokens from user to vault using user's signature
-    anchor_spl::token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Transfer {
-                from: ctx.accounts.user_token_account.to_account_info(),
-                to: ctx.accounts.vault_token_account.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        ),
-        session_bet,
+    transfer_spl_tokens(
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.user,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        stake,
     )?;
+    ctx.accounts.vault.record_deposit(stake)?;
 
     let player = ctx.accounts.user.key();
+    game_session.add_staked_player(team, player, stake)?;
+    ctx.accounts
+        .bet_receipt
+        .issue(game_session_key, player, stake, ctx.bumps.bet_receipt)?;
+    game_session.record_rating(player, ctx.accounts.player_stats.rating())?;
+
+    let player_stats = &mut ctx.accounts.player_stats;
+    player_stats.init_if_new(player, ctx.bumps.player_stats);
+    validation::validate_rent_exempt(&player_stats.to_account_info(), PlayerStats::LEN)?;
+    player_stats.record_game(stake)?;
 
-    // Get reference to the selected team
-    let selected_team = if team == 0 {
-        &mut game_session.team_a
+    // Whale protection: the bet counts toward the wallet's open exposure
+    record_exposure(
+        ctx.accounts.active_exposure.as_deref_mut(),
+        ctx.bumps.active_exposure,
+        &ctx.accounts.config,
+        player,
+        game_session_key,
+        stake,
+    )?;
+
+    // Referred players earn their referrer a cut of the rake on this bet
+    if let Some(referral) = &mut ctx.accounts.referral {
+        if referral.referee == Pubkey::default() {
+            let referrer = referrer.ok_or(error!(WagerError::InvalidReferrer))?;
+            require!(
+                referrer != player && referrer != Pubkey::default(),
+                WagerError::InvalidReferrer
+            );
+            referral.referee = player;
+            referral.referrer = referrer;
+            referral.bump = ctx
+                .bumps
+                .referral
+                .ok_or(error!(WagerError::InvalidReferrer))?;
+            emit!(ReferralRecorded {
+                referee: player,
+                referrer,
+            });
+        }
+
+        let credit = ctx
+            .accounts
+            .referral_credit
+            .as_mut()
+            .ok_or(error!(WagerError::InvalidReferrer))?;
+        let rake = safe_math::apply_bps(stake, game_session.fee_bps)?;
+        credit.game_session = game_session_key;
+        credit.referee = player;
+        credit.referrer = referral.referrer;
+        credit.amount = safe_math::apply_bps(rake, ctx.accounts.config.referral_bps)?;
+        credit.bump = ctx
+            .bumps
+            .referral_credit
+            .ok_or(error!(WagerError::InvalidReferrer))?;
     } else {
-        &mut game_session.team_b
-    };
+        require!(referrer.is_none(), WagerError::InvalidReferrer);
+    }
 
-    // Race condition protection: verify slot is still empty
+    emit!(PlayerJoined {
+        session_id: session_id.clone(),
+        player,
+        team,
+        amount: stake,
+    });
+    if game_session.status()? == SessionStatus::InProgress {
+        // Full lobbies drop out of the discovery index
+        if let Some(lobby_index) = &mut ctx.accounts.lobby_index {
+            lobby_index.remove(game_session_key);
+        }
+        emit!(SessionStarted { session_id });
+    }
+
+    Ok(())
+}
+
+/// Shared checks for every join path: access control, season pass, rating
+/// bounds, team selection, game status, join deadline, capacity and that the
+/// player doesn't already hold a slot
+pub fn validate_join(
+    game_session: &GameSession,
+    team: u8,
+    player: Pubkey,
+    rating: u64,
+    credential: Option<&JoinCredential>,
+    pass_account: Option<&TokenAccount>,
+) -> Result<()> {
+    // Private sessions only admit players holding the join code or allowlisted
+    validation::validate_join_credential(game_session.access(), &player, credential)?;
+
+    // Ranked tiers only admit holders of the session's season pass
+    validation::validate_season_pass(game_session.required_pass(), &player, pass_account)?;
+
+    // Skill-gated lobbies only admit players within their rating bounds
+    game_session.check_rating(rating)?;
+
+    // Free-for-all entrants don't pick a team
+    if !game_session.is_free_for_all() {
+        validation::validate_team_number(team, game_session.team_count())?;
+    }
+
+    // Validate game status
     require!(
-        selected_team.players[empty_index] == Pubkey::default(),
-        WagerError::SlotAlreadyOccupied
+        game_session.status()?.is_accepting_players(),
+        WagerError::InvalidGameState
     );
+    game_session.check_join_open(Clock::get()?.unix_timestamp)?;
 
-    // Add player to the first available slot
-    selected_team.players[empty_index] = player.key();
-    selected_team.player_spawns[empty_index] = 10;
-    selected_team.player_kills[empty_index] = 0;
-
-    if game_session.check_all_filled()? {
-        game_session.status = GameStatus::InProgress;
+    // Check if team (or free-for-all lobby) is full already
+    if game_session.is_free_for_all() {
+        game_session.get_free_for_all_empty_slot()?;
+    } else {
+        game_session.get_player_empty_slot(team)?;
     }
 
+    let mut players = game_session.get_all_players();
+    players.push(player);
+    validation::validate_unique_players(&players)?;
+
     Ok(())
 }
 
@@ -70,41 +187,134 @@ pub struct JoinUser<'info> {
     /// CHECK: Game server authority
     pub game_server: AccountInfo<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         mut,
         seeds = [b"game_session", session_id.as_bytes()],
-        bump = game_session.bump,
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
     )]
-    pub game_session: Account<'info, GameSession>,
+    pub game_session: AccountLoader<'info, GameSession>,
 
     #[account(
         mut,
         constraint = user_token_account.owner == user.key(),
-        constraint = user_token_account.mint == TOKEN_ID
+        constraint = user_token_account.mint == game_session.load()?.mint
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// CHECK: Vault PDA that holds the funds
+    /// Vault PDA that holds the funds and tracks deposits/payouts
     #[account(
         mut,
         seeds = [b"vault", session_id.as_bytes()],
-        bump = game_session.vault_bump,
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Lifetime statistics of the joining player, created on first join
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PlayerStats::LEN,
+        seeds = [b"player_stats", user.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Receipt for the player's bet, closed when it is refunded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = BetReceipt::LEN,
+        seeds = [b"bet_receipt", game_session.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub bet_receipt: Account<'info, BetReceipt>,
+
+    /// Referrer of the joining player, recorded on the first referred join
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Referral::LEN,
+        seeds = [b"referral", user.key().as_ref()],
+        bump
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+
+    /// Referrer's cut of the rake on this session, required with `referral`
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ReferralCredit::LEN,
+        seeds = [b"referral_credit", game_session.key().as_ref(), user.key().as_ref()],
+        bump
     )]
-    pub vault: AccountInfo<'info>,
+    pub referral_credit: Option<Account<'info, ReferralCredit>>,
+
+    /// Discovery index listing the session, updated once the lobby fills
+    #[account(
+        mut,
+        seeds = [
+            b"lobby_index",
+            game_session.load()?.mint.as_ref(),
+            &game_session.load()?.session_bet.to_le_bytes(),
+            &[game_session.load()?.game_mode],
+        ],
+        bump = lobby_index.bump,
+    )]
+    pub lobby_index: Option<Account<'info, LobbyIndex>>,
+
+    /// Player's season pass token account, required by pass-gated sessions
+    pub pass_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Bets the player has open across sessions, created on their first
+    /// tracked join; required while the config caps exposure
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ActiveExposure::LEN,
+        seeds = [b"active_exposure", user.key().as_ref()],
+        bump
+    )]
+    pub active_exposure: Option<Box<Account<'info, ActiveExposure>>>,
+
+    /// CHECK: Ban marker PDA of the player, which must not exist
+    #[account(seeds = [b"player_ban", user.key().as_ref()], bump)]
+    pub player_ban: UncheckedAccount<'info>,
+
+    /// CHECK: Kick marker PDA of the player for this session, which must not
+    /// exist
+    #[account(
+        seeds = [b"session_kick", game_session.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub session_kick: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar identifying the calling program, required
+    /// for CPI when the config restricts callers
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
 
     #[account(
         mut,
         associated_token::mint = mint,
         associated_token::authority = vault,
+        associated_token::token_program = token_program,
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        address = TOKEN_ID @ WagerError::InvalidMint
+        address = game_session.load()?.mint @ WagerError::InvalidMint
     )]
-    pub mint: Account<'info, anchor_spl::token::Mint>,
-    pub token_program: Program<'info, Token>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
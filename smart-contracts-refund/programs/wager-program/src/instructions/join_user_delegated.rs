@@ -0,0 +1,186 @@
+use crate::{
+    errors::WagerError,
+    events::BetPledged,
+    instructions::validate_join,
+    state::*,
+    utils::{gross_up_transfer_fee, record_exposure},
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::token_interface::{self, Approve, Mint, TokenAccount, TokenInterface};
+
+/// Seats a player without escrowing their bet: the session's vault PDA is
+/// approved as delegate over the bet on the player's token account, and the
+/// game server pulls it with `collect_pledges` once the lobby locks. Players
+/// keep their tokens while a lobby may never fill. A token account has a
+/// single delegate, so pledging again from it replaces an earlier approval.
+pub fn join_user_delegated_handler(
+    ctx: Context<JoinUserDelegated>,
+    session_id: String,
+    team: u8,
+    credential: Option<JoinCredential>,
+) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    validation::validate_cpi_caller(
+        &ctx.accounts.config.cpi_callers,
+        ctx.accounts.instructions.as_deref(),
+    )?;
+    validation::validate_not_banned(&ctx.accounts.player_ban)?;
+    validation::validate_not_kicked(&ctx.accounts.session_kick)?;
+    validate_join(
+        &game_session,
+        team,
+        ctx.accounts.user.key(),
+        ctx.accounts.player_stats.rating(),
+        credential.as_ref(),
+        ctx.accounts.pass_token_account.as_deref(),
+    )?;
+
+    // Native SOL has no delegate approvals, and freerolls take no bet
+    require!(!game_session.is_native(), WagerError::NativeSolSession);
+    let session_bet = game_session.session_bet;
+    require!(session_bet > 0, WagerError::InvalidBetAmount);
+
+    // Approve enough that escrow receives the full bet after transfer fees
+    let pledge = gross_up_transfer_fee(&ctx.accounts.mint, session_bet)?;
+    token_interface::approve(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Approve {
+                to: ctx.accounts.user_token_account.to_account_info(),
+                delegate: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        pledge,
+    )?;
+
+    let player = ctx.accounts.user.key();
+    game_session.add_pledged_player(team, player)?;
+    ctx.accounts
+        .bet_receipt
+        .issue(game_session_key, player, session_bet, ctx.bumps.bet_receipt)?;
+    game_session.record_rating(player, ctx.accounts.player_stats.rating())?;
+
+    let player_stats = &mut ctx.accounts.player_stats;
+    player_stats.init_if_new(player, ctx.bumps.player_stats);
+    validation::validate_rent_exempt(&player_stats.to_account_info(), PlayerStats::LEN)?;
+    player_stats.record_game(session_bet)?;
+
+    // Whale protection: the pledge counts toward the wallet's open exposure
+    record_exposure(
+        ctx.accounts.active_exposure.as_deref_mut(),
+        ctx.bumps.active_exposure,
+        &ctx.accounts.config,
+        player,
+        game_session_key,
+        session_bet,
+    )?;
+
+    emit!(BetPledged {
+        session_id,
+        player,
+        team,
+        amount: session_bet,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct JoinUserDelegated<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Token account the bet is pledged from and later collected from
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key(),
+        constraint = user_token_account.mint == game_session.load()?.mint
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Vault PDA approved as delegate over the pledged bet
+    #[account(
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Lifetime statistics of the joining player, created on first join
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PlayerStats::LEN,
+        seeds = [b"player_stats", user.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Receipt for the player's bet, closed when it is refunded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = BetReceipt::LEN,
+        seeds = [b"bet_receipt", game_session.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub bet_receipt: Account<'info, BetReceipt>,
+
+    /// Player's season pass token account, required by pass-gated sessions
+    pub pass_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Bets the player has open across sessions, created on their first
+    /// tracked join; required while the config caps exposure
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ActiveExposure::LEN,
+        seeds = [b"active_exposure", user.key().as_ref()],
+        bump
+    )]
+    pub active_exposure: Option<Box<Account<'info, ActiveExposure>>>,
+
+    /// CHECK: Ban marker PDA of the player, which must not exist
+    #[account(seeds = [b"player_ban", user.key().as_ref()], bump)]
+    pub player_ban: UncheckedAccount<'info>,
+
+    /// CHECK: Kick marker PDA of the player for this session, which must not
+    /// exist
+    #[account(
+        seeds = [b"session_kick", game_session.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub session_kick: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar identifying the calling program, required
+    /// for CPI when the config restricts callers
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,188 @@
+use crate::{
+    errors::WagerError, events::*, instructions::validate_join, state::*, utils::record_exposure,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::TokenAccount;
+
+pub fn join_user_sol_handler(
+    ctx: Context<JoinUserSol>,
+    session_id: String,
+    team: u8,
+    credential: Option<JoinCredential>,
+    stake: Option<u64>,
+) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    validation::validate_cpi_caller(
+        &ctx.accounts.config.cpi_callers,
+        ctx.accounts.instructions.as_deref(),
+    )?;
+    validation::validate_not_banned(&ctx.accounts.player_ban)?;
+    validation::validate_not_kicked(&ctx.accounts.session_kick)?;
+    validate_join(
+        &game_session,
+        team,
+        ctx.accounts.user.key(),
+        ctx.accounts.player_stats.rating(),
+        credential.as_ref(),
+        ctx.accounts.pass_token_account.as_deref(),
+    )?;
+
+    require!(game_session.is_native(), WagerError::NotNativeSolSession);
+
+    // Stake-weighted sessions let the player stake more than the bet
+    let stake = stake.unwrap_or(game_session.session_bet);
+
+    // Escrow lamports in the vault PDA using user's signature
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        stake,
+    )?;
+    ctx.accounts.vault.record_deposit(stake)?;
+
+    let player = ctx.accounts.user.key();
+    game_session.add_staked_player(team, player, stake)?;
+    ctx.accounts
+        .bet_receipt
+        .issue(game_session_key, player, stake, ctx.bumps.bet_receipt)?;
+    game_session.record_rating(player, ctx.accounts.player_stats.rating())?;
+
+    let player_stats = &mut ctx.accounts.player_stats;
+    player_stats.init_if_new(player, ctx.bumps.player_stats);
+    validation::validate_rent_exempt(&player_stats.to_account_info(), PlayerStats::LEN)?;
+    player_stats.record_game(stake)?;
+
+    // Whale protection: the bet counts toward the wallet's open exposure
+    record_exposure(
+        ctx.accounts.active_exposure.as_deref_mut(),
+        ctx.bumps.active_exposure,
+        &ctx.accounts.config,
+        player,
+        game_session_key,
+        stake,
+    )?;
+
+    emit!(PlayerJoined {
+        session_id: session_id.clone(),
+        player,
+        team,
+        amount: stake,
+    });
+    if game_session.status()? == SessionStatus::InProgress {
+        // Full lobbies drop out of the discovery index
+        if let Some(lobby_index) = &mut ctx.accounts.lobby_index {
+            lobby_index.remove(game_session_key);
+        }
+        emit!(SessionStarted { session_id });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct JoinUserSol<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA that holds the escrowed lamports and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Lifetime statistics of the joining player, created on first join
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PlayerStats::LEN,
+        seeds = [b"player_stats", user.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Receipt for the player's bet, closed when it is refunded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = BetReceipt::LEN,
+        seeds = [b"bet_receipt", game_session.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub bet_receipt: Account<'info, BetReceipt>,
+
+    /// Discovery index listing the session, updated once the lobby fills
+    #[account(
+        mut,
+        seeds = [
+            b"lobby_index",
+            game_session.load()?.mint.as_ref(),
+            &game_session.load()?.session_bet.to_le_bytes(),
+            &[game_session.load()?.game_mode],
+        ],
+        bump = lobby_index.bump,
+    )]
+    pub lobby_index: Option<Account<'info, LobbyIndex>>,
+
+    /// Player's season pass token account, required by pass-gated sessions
+    pub pass_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Bets the player has open across sessions, created on their first
+    /// tracked join; required while the config caps exposure
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ActiveExposure::LEN,
+        seeds = [b"active_exposure", user.key().as_ref()],
+        bump
+    )]
+    pub active_exposure: Option<Box<Account<'info, ActiveExposure>>>,
+
+    /// CHECK: Ban marker PDA of the player, which must not exist
+    #[account(seeds = [b"player_ban", user.key().as_ref()], bump)]
+    pub player_ban: UncheckedAccount<'info>,
+
+    /// CHECK: Kick marker PDA of the player for this session, which must not
+    /// exist
+    #[account(
+        seeds = [b"session_kick", game_session.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub session_kick: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar identifying the calling program, required
+    /// for CPI when the config restricts callers
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
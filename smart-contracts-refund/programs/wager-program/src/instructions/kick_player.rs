@@ -0,0 +1,198 @@
+use crate::{errors::WagerError, events::PlayerKicked, state::*, utils::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Lets a team captain remove a teammate before the match starts. The kicked
+/// player's bet is refunded in full and their slot reopens.
+pub fn kick_player_handler(
+    ctx: Context<KickPlayer>,
+    session_id: String,
+    team: u8,
+    player: Pubkey,
+) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    // Rosters are only editable while the session is still filling up
+    require!(
+        game_session.status()? == SessionStatus::Filling,
+        WagerError::InvalidGameState
+    );
+    require!(!game_session.is_free_for_all(), WagerError::InvalidTeam);
+    validation::validate_team_number(team, game_session.team_count())?;
+
+    let captain = ctx.accounts.captain.key();
+    require!(
+        game_session.team_captain(team)? == captain,
+        WagerError::NotTeamCaptain
+    );
+    require!(player != captain, WagerError::InvalidPlayer);
+    game_session.get_player_index(team, player)?;
+
+    // Removing the player from the roster prevents a second refund, and
+    // the kick marker keeps them from rejoining
+    let pledged = game_session.is_pledged(player);
+    let stake = game_session.stake_of(player);
+    let receipted = open_bet_receipt(&ctx.accounts.bet_receipt, player, stake)?.is_some();
+    game_session.remove_player(player)?;
+    let session_kick = &mut ctx.accounts.session_kick;
+    session_kick.game_session = ctx.accounts.game_session.key();
+    session_kick.player = player;
+    session_kick.captain = captain;
+    session_kick.bump = ctx.bumps.session_kick;
+
+    // Native SOL sessions refund to the kicked player's wallet itself, and a
+    // pledged bet never left the player's account
+    let refund = if pledged { 0 } else { stake };
+    let destination = if game_session.is_native() {
+        ctx.accounts.player.to_account_info()
+    } else {
+        let player_token_account = ctx
+            .accounts
+            .player_token_account
+            .as_ref()
+            .ok_or(error!(WagerError::InvalidPlayerTokenAccount))?;
+        require!(
+            player_token_account.owner == player,
+            WagerError::InvalidPlayerTokenAccount
+        );
+        require!(
+            player_token_account.mint == game_session.mint,
+            WagerError::InvalidTokenMint
+        );
+        player_token_account.to_account_info()
+    };
+
+    if refund > 0 {
+        release_from_vault(
+            &game_session,
+            &ctx.accounts.vault,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &destination,
+            &session_id,
+            refund,
+        )?;
+        ctx.accounts.vault.record_refund(refund)?;
+    }
+    ctx.accounts.player_stats.revert_game(stake)?;
+    release_exposure(
+        ctx.accounts.active_exposure.as_mut(),
+        ctx.accounts.game_session.key(),
+    )?;
+
+    emit!(PlayerKicked {
+        session_id,
+        team,
+        captain,
+        player,
+        refund,
+    });
+
+    // Kicking the last other player reopens the session as freshly created
+    let next = game_session.resting_status()?;
+    game_session.transition_to(next)?;
+
+    // The receipt backed the seat's only claim
+    if receipted {
+        redeem_bet_receipt(
+            &ctx.accounts.bet_receipt,
+            &ctx.accounts.player.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String, _team: u8, kicked: Pubkey)]
+pub struct KickPlayer<'info> {
+    /// Captain of the kicked player's team, who pays for the kick marker
+    #[account(mut)]
+    pub captain: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Kicked player's wallet, refunded directly in native SOL sessions
+    #[account(mut, constraint = player.key() == kicked @ WagerError::InvalidPlayer)]
+    pub player: SystemAccount<'info>,
+
+    /// Kicked player's token account, omitted for native SOL sessions
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Lifetime statistics of the kicked player
+    #[account(
+        mut,
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// CHECK: Receipt PDA for the player's bet, closed by the refund if they
+    /// joined with one
+    #[account(
+        mut,
+        seeds = [b"bet_receipt", game_session.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub bet_receipt: UncheckedAccount<'info>,
+
+    /// Marker barring the kicked player from taking a seat again
+    #[account(
+        init,
+        payer = captain,
+        space = SessionKick::LEN,
+        seeds = [b"session_kick", game_session.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub session_kick: Account<'info, SessionKick>,
+
+    /// Open bets of the kicked player, released for this session if passed
+    #[account(
+        mut,
+        seeds = [b"active_exposure", player.key().as_ref()],
+        bump = active_exposure.bump,
+    )]
+    pub active_exposure: Option<Account<'info, ActiveExposure>>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
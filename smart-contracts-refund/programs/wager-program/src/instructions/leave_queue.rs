@@ -0,0 +1,91 @@
+use crate::{errors::WagerError, events::*, state::*, utils::transfer_from_escrow};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn leave_queue_handler(ctx: Context<LeaveQueue>) -> Result<()> {
+    let player = ctx.accounts.user.key();
+    let match_queue = &mut ctx.accounts.match_queue;
+    let index = match_queue
+        .players
+        .iter()
+        .position(|queued| *queued == player)
+        .ok_or(error!(WagerError::PlayerNotQueued))?;
+    match_queue.players.remove(index);
+
+    // Nothing has been wagered yet, so the whole bet is returned
+    let bet_amount = match_queue.bet_amount;
+    let mint = match_queue.mint;
+    let game_mode = [match_queue.game_mode as u8];
+    let bet_bytes = bet_amount.to_le_bytes();
+    let bump = [match_queue.bump];
+    let seeds: &[&[u8]] = &[b"match_queue", mint.as_ref(), &bet_bytes, &game_mode, &bump];
+    transfer_from_escrow(
+        &ctx.accounts.queue_token_account.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.match_queue.to_account_info(),
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        bet_amount,
+        &[seeds],
+    )?;
+
+    ctx.accounts.player_stats.revert_game(bet_amount)?;
+
+    emit!(PlayerDequeued {
+        queue: ctx.accounts.match_queue.key(),
+        player,
+        amount: bet_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LeaveQueue<'info> {
+    /// Queued player withdrawing their bet
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"match_queue",
+            match_queue.mint.as_ref(),
+            &match_queue.bet_amount.to_le_bytes(),
+            &[match_queue.game_mode as u8],
+        ],
+        bump = match_queue.bump,
+    )]
+    pub match_queue: Account<'info, MatchQueue>,
+
+    #[account(
+        mut,
+        associated_token::mint = match_queue.mint,
+        associated_token::authority = match_queue,
+        associated_token::token_program = token_program,
+    )]
+    pub queue_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key(),
+        constraint = user_token_account.mint == match_queue.mint
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Lifetime statistics of the leaving player
+    #[account(
+        mut,
+        seeds = [b"player_stats", user.key().as_ref()],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Wager token of the queue
+    #[account(address = match_queue.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,219 @@
+use crate::{errors::WagerError, events::*, state::*, utils::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn leave_session_handler(ctx: Context<LeaveSession>, session_id: String) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    // Players may only back out while the session is still filling up
+    require!(
+        game_session.status()? == SessionStatus::Filling,
+        WagerError::InvalidGameState
+    );
+
+    // Removing the player from the roster prevents a second withdrawal
+    let player = ctx.accounts.player.key();
+    let pledged = game_session.is_pledged(player);
+    let stake = game_session.stake_of(player);
+    let receipted = open_bet_receipt(&ctx.accounts.bet_receipt, player, stake)?.is_some();
+    game_session.remove_player(player)?;
+
+    // A pledged bet never left the player's account, so nothing is refunded
+    let session_bet = if pledged { 0 } else { stake };
+    let fee = if game_session.refund_policy()?.charges_cancellation_fee() {
+        safe_math::apply_bps(session_bet, ctx.accounts.config.cancellation_fee_bps)?
+    } else {
+        0
+    };
+    let refund = safe_math::safe_subtract(session_bet, fee)?;
+    msg!(
+        "Player {} leaving with refund {} and fee {}",
+        player,
+        refund,
+        fee
+    );
+
+    // Native SOL sessions refund to the player wallet itself
+    let (destination, treasury_destination) = if game_session.is_native() {
+        (
+            ctx.accounts.player.to_account_info(),
+            ctx.accounts.treasury.to_account_info(),
+        )
+    } else {
+        let player_token_account = ctx
+            .accounts
+            .player_token_account
+            .as_ref()
+            .ok_or(error!(WagerError::InvalidPlayerTokenAccount))?;
+        require!(
+            player_token_account.owner == player,
+            WagerError::InvalidPlayerTokenAccount
+        );
+        require!(
+            player_token_account.mint == game_session.mint,
+            WagerError::InvalidTokenMint
+        );
+        (
+            player_token_account.to_account_info(),
+            ctx.accounts.treasury_token_account.to_account_info(),
+        )
+    };
+
+    // Transfer the bet minus the cancellation fee back to the player
+    if refund > 0 {
+        release_from_vault(
+            &game_session,
+            &ctx.accounts.vault,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &destination,
+            &session_id,
+            refund,
+        )?;
+        ctx.accounts.vault.record_refund(refund)?;
+    }
+
+    // Move the cancellation fee into the treasury
+    if fee > 0 {
+        release_from_vault(
+            &game_session,
+            &ctx.accounts.vault,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &treasury_destination,
+            &session_id,
+            fee,
+        )?;
+        ctx.accounts.vault.record_fee(fee)?;
+
+        emit!(FeesCollected {
+            session_id: session_id.clone(),
+            amount: fee,
+        });
+    }
+
+    let wagered = if pledged { stake } else { refund };
+    ctx.accounts.player_stats.revert_game(wagered)?;
+    release_exposure(
+        ctx.accounts.active_exposure.as_mut(),
+        ctx.accounts.game_session.key(),
+    )?;
+
+    emit!(PlayerLeft {
+        session_id,
+        player,
+        refund,
+        fee,
+    });
+
+    // The last player leaving reopens the session as freshly created
+    let next = game_session.resting_status()?;
+    game_session.transition_to(next)?;
+
+    // The receipt backed the seat's only claim
+    if receipted {
+        redeem_bet_receipt(
+            &ctx.accounts.bet_receipt,
+            &ctx.accounts.player.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct LeaveSession<'info> {
+    /// Player withdrawing their own bet
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Player's token account, omitted for native SOL sessions
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury PDA that owns the fee token accounts and holds native SOL fees
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Lifetime statistics of the leaving player
+    #[account(
+        mut,
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// CHECK: Receipt PDA for the player's bet, closed by the refund if they
+    /// joined with one
+    #[account(
+        mut,
+        seeds = [b"bet_receipt", game_session.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub bet_receipt: UncheckedAccount<'info>,
+
+    /// Open bets of the leaving player, released for this session if passed
+    #[account(
+        mut,
+        seeds = [b"active_exposure", player.key().as_ref()],
+        bump = active_exposure.bump,
+    )]
+    pub active_exposure: Option<Account<'info, ActiveExposure>>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,72 @@
+use crate::{errors::WagerError, events::*, state::*, validation::*};
+use anchor_lang::prelude::*;
+
+/// Closes joins once a session's join deadline has passed. Callable by
+/// anyone: the session starts with whoever joined if it reached its minimum
+/// players, otherwise it becomes refundable so players can reclaim their bets.
+pub fn lock_session_handler(ctx: Context<LockSession>, session_id: String) -> Result<()> {
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    // Full lobbies start on their own, so only a session still taking joins
+    // can be locked
+    require!(
+        game_session.status()?.is_accepting_players(),
+        WagerError::InvalidGameState
+    );
+    require!(
+        game_session.join_deadline != 0,
+        WagerError::JoinDeadlineNotReached
+    );
+    validation::validate_deadline_reached(
+        Clock::get()?.unix_timestamp,
+        game_session.join_deadline,
+        game_session.clock_skew_secs,
+    )?;
+
+    game_session.locked = 1;
+    let next = game_session.resting_status()?;
+    game_session.transition_to(next)?;
+
+    // Locked lobbies no longer take joins, so they drop out of discovery
+    if let Some(lobby_index) = &mut ctx.accounts.lobby_index {
+        lobby_index.remove(game_session_key);
+    }
+
+    let started = next == SessionStatus::InProgress;
+    emit!(SessionLocked {
+        session_id: session_id.clone(),
+        players: game_session.player_count() as u8,
+        started,
+    });
+    if started {
+        emit!(SessionStarted { session_id });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct LockSession<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Discovery index listing the session, if it is still indexed
+    #[account(
+        mut,
+        seeds = [
+            b"lobby_index",
+            game_session.load()?.mint.as_ref(),
+            &game_session.load()?.session_bet.to_le_bytes(),
+            &[game_session.load()?.game_mode],
+        ],
+        bump = lobby_index.bump,
+    )]
+    pub lobby_index: Option<Account<'info, LobbyIndex>>,
+}
@@ -0,0 +1,66 @@
+use crate::{errors::WagerError, state::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+/// Grows a `PlayerStats` account created before streak tracking to the
+/// current layout. Fields are only ever appended, so the zero-filled space
+/// decodes as a fresh streak.
+pub fn migrate_player_stats_handler(
+    ctx: Context<MigratePlayerStats>,
+    _player: Pubkey,
+) -> Result<()> {
+    let player_stats = &ctx.accounts.player_stats;
+    require!(
+        player_stats.owner == ctx.program_id,
+        WagerError::InvalidPlayerStats
+    );
+    require!(
+        player_stats.try_borrow_data()?[..8] == PlayerStats::DISCRIMINATOR,
+        WagerError::InvalidPlayerStats
+    );
+    require!(
+        player_stats.data_len() < PlayerStats::LEN,
+        WagerError::PlayerStatsAlreadyMigrated
+    );
+
+    // Top up rent for the larger account from the payer
+    let required = Rent::get()?.minimum_balance(PlayerStats::LEN);
+    let shortfall = required.saturating_sub(player_stats.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: player_stats.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    player_stats.realloc(PlayerStats::LEN, true)?;
+    validation::validate_rent_exempt(player_stats, PlayerStats::LEN)?;
+
+    msg!("Migrated player stats to {} bytes", PlayerStats::LEN);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_player: Pubkey)]
+pub struct MigratePlayerStats<'info> {
+    /// Pays for any additional rent required by the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Deserialized manually since older layouts no longer match `PlayerStats`
+    #[account(
+        mut,
+        seeds = [b"player_stats", _player.as_ref()],
+        bump,
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,102 @@
+use crate::{errors::WagerError, state::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+/// Offset of the `version` byte, directly after the account discriminator
+const VERSION_OFFSET: usize = 8;
+
+pub fn migrate_session_handler(ctx: Context<MigrateSession>, _session_id: String) -> Result<()> {
+    let game_session = &ctx.accounts.game_session;
+    require!(
+        game_session.owner == ctx.program_id,
+        WagerError::UnsupportedSessionVersion
+    );
+
+    // Read the stored version without deserializing the outdated layout
+    let version = {
+        let data = game_session.try_borrow_data()?;
+        require!(
+            data.len() > VERSION_OFFSET && data[..8] == GameSession::DISCRIMINATOR,
+            WagerError::UnsupportedSessionVersion
+        );
+        data[VERSION_OFFSET]
+    };
+    require!(
+        (MIN_MIGRATABLE_SESSION_VERSION..=GAME_SESSION_VERSION).contains(&version),
+        WagerError::UnsupportedSessionVersion
+    );
+    require!(
+        version < GAME_SESSION_VERSION,
+        WagerError::SessionAlreadyMigrated
+    );
+
+    // Grow the account by every field appended since the stored version, up
+    // to the last append-only layout
+    let growth: usize = GAME_SESSION_VERSION_GROWTH
+        [(version.min(APPEND_ONLY_SESSION_VERSION) - MIN_MIGRATABLE_SESSION_VERSION) as usize..]
+        .iter()
+        .sum();
+    let legacy_len = game_session.data_len() + growth;
+    require!(
+        legacy_len >= GameSessionV42::LEN,
+        WagerError::UnsupportedSessionVersion
+    );
+
+    // Top up rent for the larger account from the payer
+    let required = Rent::get()?.minimum_balance(legacy_len.max(GameSession::LEN));
+    let shortfall = required.saturating_sub(game_session.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: game_session.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    // Zero-filled trailing space decodes as defaults for the appended fields
+    game_session.realloc(legacy_len, true)?;
+
+    // The per-player counters moved into roster slots, so the session is
+    // copied out to the heap and rewritten in the current layout
+    let mut legacy = bytemuck::zeroed_box::<GameSessionV42>();
+    bytemuck::bytes_of_mut(&mut *legacy)
+        .copy_from_slice(&game_session.try_borrow_data()?[8..GameSessionV42::LEN]);
+    game_session.realloc(GameSession::LEN, false)?;
+    {
+        let mut data = game_session.try_borrow_mut_data()?;
+        data[8..].fill(0);
+        legacy.upgrade(bytemuck::from_bytes_mut(&mut data[8..]))?;
+    }
+    validation::validate_rent_exempt(game_session, GameSession::LEN)?;
+
+    msg!(
+        "Migrated game session from version {} to {}",
+        version,
+        GAME_SESSION_VERSION
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct MigrateSession<'info> {
+    /// Pays for any additional rent required by the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Deserialized manually since older layouts no longer match `GameSession`
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump,
+    )]
+    pub game_session: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,198 @@
+use crate::{errors::WagerError, events::TrophyMinted, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3, mpl_token_metadata::types::DataV2,
+    CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata,
+};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+/// Name of every trophy NFT
+const TROPHY_NAME: &str = "Wager Trophy";
+
+/// Symbol of every trophy NFT
+const TROPHY_SYMBOL: &str = "TROPHY";
+
+/// Mints a one-of-one commemorative NFT to a member of a completed session's
+/// winning team. The metadata URI points under the config's trophy base URI
+/// with the session id and final score; the trophy mint is a PDA of the
+/// session and player, so each winner can receive exactly one.
+pub fn mint_trophy_handler(ctx: Context<MintTrophy>, session_id: String) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(
+        !config.trophy_uri.is_empty(),
+        WagerError::TrophiesUnavailable
+    );
+
+    let score = {
+        let game_session = ctx.accounts.game_session.load()?;
+        require!(
+            game_session.status()? == SessionStatus::Completed,
+            WagerError::GameNotCompleted
+        );
+        let winning_team = game_session
+            .winning_team()
+            .ok_or(error!(WagerError::NotSessionWinner))?;
+        require!(
+            game_session
+                .team(winning_team)?
+                .players
+                .contains(&ctx.accounts.player.key()),
+            WagerError::NotSessionWinner
+        );
+        game_session.final_score()
+    };
+
+    let authority_seeds: &[&[u8]] = &[b"trophy_authority", &[ctx.bumps.trophy_authority]];
+    let signer_seeds = &[authority_seeds];
+
+    // The single trophy token goes to the winner before the edition locks supply
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.trophy_mint.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.trophy_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.trophy_mint.to_account_info(),
+                mint_authority: ctx.accounts.trophy_authority.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                update_authority: ctx.accounts.trophy_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        DataV2 {
+            name: TROPHY_NAME.to_string(),
+            symbol: TROPHY_SYMBOL.to_string(),
+            uri: format!("{}/{}?score={}", config.trophy_uri, session_id, score),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        false,
+        true,
+        None,
+    )?;
+
+    // A master edition with no print supply makes the trophy a true one-of-one
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.master_edition.to_account_info(),
+                mint: ctx.accounts.trophy_mint.to_account_info(),
+                update_authority: ctx.accounts.trophy_authority.to_account_info(),
+                mint_authority: ctx.accounts.trophy_authority.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(0),
+    )?;
+
+    emit!(TrophyMinted {
+        session_id,
+        player: ctx.accounts.player.key(),
+        mint: ctx.accounts.trophy_mint.key(),
+        score,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct MintTrophy<'info> {
+    /// Pays for the trophy's mint, token account and metadata
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// CHECK: Winning player receiving the trophy, checked against the roster
+    pub player: UncheckedAccount<'info>,
+
+    /// CHECK: PDA acting as mint and update authority of every trophy
+    #[account(
+        seeds = [b"trophy_authority"],
+        bump,
+    )]
+    pub trophy_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"trophy", game_session.key().as_ref(), player.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = trophy_authority,
+        mint::freeze_authority = trophy_authority,
+    )]
+    pub trophy_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = trophy_mint,
+        associated_token::authority = player,
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Metadata PDA of the trophy mint, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), trophy_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition PDA of the trophy mint, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            trophy_mint.key().as_ref(),
+            b"edition",
+        ],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -1,12 +1,176 @@
+pub mod abort_match;
+pub mod accept_authority;
+pub mod add_prize;
+pub mod apply_rating;
+pub mod approve_operation;
+pub mod ban_player;
+pub mod cast_dispute_vote;
+pub mod claim_earnings;
+pub mod claim_referral_rewards;
+pub mod claim_spectator_payout;
+pub mod claim_streak_bonus;
+pub mod claim_timeout_refund;
+pub mod claim_unclaimed;
+pub mod close_bet_receipt;
+pub mod close_session;
+pub mod close_session_kick;
+pub mod close_settlement_table;
+pub mod collect_pledges;
+pub mod commit_result;
+pub mod create_bracket_match;
+pub mod create_from_template;
 pub mod create_game_session;
+pub mod create_recurring_match;
+pub mod create_session_template;
+pub mod create_settlement_table;
+pub mod create_spectator_pool;
+pub mod create_tournament;
+pub mod declare_forfeit;
+pub mod delegate_session_authority;
+pub mod deregister_mint;
+pub mod distribute_tournament_prize;
 pub mod distribute_winnings;
+pub mod emergency_pause;
+pub mod estimate_distribution_chunks;
+pub mod extend_settlement_table;
+pub mod finalize_distribution;
+pub mod form_match;
+pub mod fund_freeroll;
+pub mod initialize_config;
+pub mod initialize_jackpot;
+pub mod join_queue;
 pub mod join_user;
+pub mod join_user_delegated;
+pub mod join_user_sol;
+pub mod kick_player;
+pub mod leave_queue;
+pub mod leave_session;
+pub mod lock_session;
+pub mod migrate_player_stats;
+pub mod migrate_session;
+pub mod mint_trophy;
+pub mod override_result;
+pub mod park_tournament_funds;
 pub mod pay_to_spawn;
+pub mod place_spectator_bet;
+pub mod post_announcement;
+pub mod post_bond;
+pub mod propose_authority;
+pub mod prune_lobby_index;
+pub mod reclaim_freeroll_prize;
+pub mod reclaim_sponsored_prize;
 pub mod record_kill;
+pub mod record_kills_batch;
+pub mod record_match_result;
+pub mod record_round_result;
+pub mod recover_settlement;
 pub mod refund_wager;
+pub mod register_mint;
+pub mod register_tournament_team;
+pub mod release_exposure;
+pub mod report_match_result;
+pub mod request_bond_withdrawal;
+pub mod request_match_randomness;
+pub mod reveal_match_randomness;
+pub mod reveal_result;
+pub mod set_price_feed;
+pub mod set_ready;
+pub mod spawn_next_instance;
+pub mod submit_result;
+pub mod submit_score;
+pub mod substitute_player;
+pub mod top_up;
+pub mod transfer_captaincy;
+pub mod trigger_jackpot;
+pub mod update_config;
+pub mod withdraw_bond;
+pub mod withdraw_fees;
+pub mod withdraw_tournament_funds;
+pub use abort_match::*;
+pub use accept_authority::*;
+pub use add_prize::*;
+pub use apply_rating::*;
+pub use approve_operation::*;
+pub use ban_player::*;
+pub use cast_dispute_vote::*;
+pub use claim_earnings::*;
+pub use claim_referral_rewards::*;
+pub use claim_spectator_payout::*;
+pub use claim_streak_bonus::*;
+pub use claim_timeout_refund::*;
+pub use claim_unclaimed::*;
+pub use close_bet_receipt::*;
+pub use close_session::*;
+pub use close_session_kick::*;
+pub use close_settlement_table::*;
+pub use collect_pledges::*;
+pub use commit_result::*;
+pub use create_bracket_match::*;
+pub use create_from_template::*;
 pub use create_game_session::*;
+pub use create_recurring_match::*;
+pub use create_session_template::*;
+pub use create_settlement_table::*;
+pub use create_spectator_pool::*;
+pub use create_tournament::*;
+pub use declare_forfeit::*;
+pub use delegate_session_authority::*;
+pub use deregister_mint::*;
+pub use distribute_tournament_prize::*;
 pub use distribute_winnings::*;
+pub use emergency_pause::*;
+pub use estimate_distribution_chunks::*;
+pub use extend_settlement_table::*;
+pub use finalize_distribution::*;
+pub use form_match::*;
+pub use fund_freeroll::*;
+pub use initialize_config::*;
+pub use initialize_jackpot::*;
+pub use join_queue::*;
 pub use join_user::*;
+pub use join_user_delegated::*;
+pub use join_user_sol::*;
+pub use kick_player::*;
+pub use leave_queue::*;
+pub use leave_session::*;
+pub use lock_session::*;
+pub use migrate_player_stats::*;
+pub use migrate_session::*;
+pub use mint_trophy::*;
+pub use override_result::*;
+pub use park_tournament_funds::*;
 pub use pay_to_spawn::*;
+pub use place_spectator_bet::*;
+pub use post_announcement::*;
+pub use post_bond::*;
+pub use propose_authority::*;
+pub use prune_lobby_index::*;
+pub use reclaim_freeroll_prize::*;
+pub use reclaim_sponsored_prize::*;
 pub use record_kill::*;
+pub use record_kills_batch::*;
+pub use record_match_result::*;
+pub use record_round_result::*;
+pub use recover_settlement::*;
 pub use refund_wager::*;
+pub use register_mint::*;
+pub use register_tournament_team::*;
+pub use release_exposure::*;
+pub use report_match_result::*;
+pub use request_bond_withdrawal::*;
+pub use request_match_randomness::*;
+pub use reveal_match_randomness::*;
+pub use reveal_result::*;
+pub use set_price_feed::*;
+pub use set_ready::*;
+pub use spawn_next_instance::*;
+pub use submit_result::*;
+pub use submit_score::*;
+pub use substitute_player::*;
+pub use top_up::*;
+pub use transfer_captaincy::*;
+pub use trigger_jackpot::*;
+pub use update_config::*;
+pub use withdraw_bond::*;
+pub use withdraw_fees::*;
+pub use withdraw_tournament_funds::*;
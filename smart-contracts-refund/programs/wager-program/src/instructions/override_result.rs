@@ -0,0 +1,126 @@
+use crate::{
+    errors::{err_with_team, WagerError},
+    events::ResultOverridden,
+    state::*,
+    utils::*,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+
+/// Replaces a submitted winner-takes-all result with the arbiter's corrected
+/// outcome while the dispute window is open. The arbiter counts as one
+/// approval towards the config's threshold, the rest come from the game
+/// server signing set. Once the config seats an arbiter council, only a
+/// majority vote through `cast_dispute_vote` can correct a result.
+///
+/// Overturning a result slashes the config's share of the reporting
+/// operator's bond, if they posted one, to the players the submitted result
+/// wronged, the new winning team. Remaining accounts then hold the wallet of
+/// every seated player of that team, in roster order.
+pub fn override_result_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, OverrideResult<'info>>,
+    _session_id: String,
+    winning_team: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    let config = &ctx.accounts.config;
+    let arbiter = ctx.accounts.arbiter.key();
+    let mut approvals = game_server_approvals(
+        config,
+        &arbiter,
+        ctx.accounts.approval.as_ref(),
+        game_session_key,
+        ApprovalKind::OverrideResult,
+        winning_team,
+    )?;
+    if !config.is_game_server(&arbiter) {
+        approvals += 1;
+    }
+    require!(
+        approvals >= config.game_server_threshold as usize,
+        WagerError::InsufficientApprovals
+    );
+
+    require!(
+        game_session.status()? == SessionStatus::ResultSubmitted,
+        WagerError::InvalidGameState
+    );
+    validation::validate_before_deadline(
+        now,
+        game_session.dispute_deadline,
+        game_session.clock_skew_secs,
+    )?;
+    require!(
+        game_session.supports_spectators(),
+        WagerError::ResultNotOverridable
+    );
+    validation::validate_team_number(winning_team, game_session.team_count())
+        .map_err(|_| err_with_team(WagerError::InvalidWinningTeam, winning_team))?;
+
+    // An override always names a winner, replacing a submitted draw too
+    let previous_team = game_session.winning_team;
+    let overturned = game_session.correct_result(winning_team);
+
+    emit!(ResultOverridden {
+        session_id: game_session.session_id(),
+        arbiter: ctx.accounts.arbiter.key(),
+        previous_team,
+        winning_team,
+    });
+
+    // Confirming the submitted result wrongs nobody
+    if overturned {
+        slash_bond(
+            &ctx.accounts.server_bond.to_account_info(),
+            &mut game_session,
+            winning_team,
+            config.bond_slash_bps,
+            ctx.remaining_accounts,
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct OverrideResult<'info> {
+    /// The arbiter registered in the config
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+        constraint = config.arbiter == arbiter.key() @ WagerError::UnauthorizedArbiter,
+        constraint = config.arbiters.is_empty() @ WagerError::CouncilDecidesDisputes,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Signing set approvals for the corrected result, needed when the
+    /// threshold exceeds the arbiter's own signature
+    #[account(
+        seeds = [b"approval", game_session.key().as_ref(), &[ApprovalKind::OverrideResult as u8]],
+        bump = approval.bump,
+    )]
+    pub approval: Option<Account<'info, Approval>>,
+
+    /// CHECK: Bond PDA of the operator that reported the overturned result,
+    /// slashed on an overturn if the operator posted one
+    #[account(
+        mut,
+        seeds = [b"server_bond", game_session.load()?.authority.as_ref()],
+        bump,
+    )]
+    pub server_bond: UncheckedAccount<'info>,
+}
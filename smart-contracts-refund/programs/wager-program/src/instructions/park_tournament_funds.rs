@@ -0,0 +1,109 @@
+use crate::{
+    errors::WagerError, events::TournamentFundsParked, state::*, utils::invoke_yield_adapter,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Parks part of a running tournament's escrowed entry fees in a whitelisted
+/// yield adapter until the bracket is decided. The escrow balance must drop
+/// by exactly the parked amount. Remaining accounts are passed through to the
+/// adapter's `deposit` instruction.
+pub fn park_tournament_funds_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ParkTournamentFunds<'info>>,
+    tournament_id: String,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, WagerError::InvalidYieldTransfer);
+    let yield_program = ctx.accounts.yield_program.key();
+    require!(
+        ctx.accounts.config.yield_programs.contains(&yield_program),
+        WagerError::YieldProgramNotAllowed
+    );
+
+    // Entry fees are only parked while the bracket is being played
+    let tournament = &mut ctx.accounts.tournament;
+    require!(
+        tournament.status == TournamentStatus::InProgress
+            && tournament.champion == Pubkey::default(),
+        WagerError::InvalidTournamentState
+    );
+    tournament.park(yield_program, amount)?;
+
+    let balance_before = ctx.accounts.vault_token_account.amount;
+    invoke_yield_adapter(
+        &ctx.accounts.yield_program.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.remaining_accounts,
+        "deposit",
+        Some(amount),
+        &[&[
+            b"tournament_vault",
+            tournament_id.as_bytes(),
+            &[tournament.vault_bump],
+        ]],
+    )?;
+
+    // The adapter must take exactly what was asked, no more
+    ctx.accounts.vault_token_account.reload()?;
+    let moved = safe_math::safe_subtract(balance_before, ctx.accounts.vault_token_account.amount)?;
+    require!(moved == amount, WagerError::InvalidYieldTransfer);
+
+    emit!(TournamentFundsParked {
+        tournament_id,
+        yield_program,
+        amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: String)]
+pub struct ParkTournamentFunds<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_bytes()],
+        bump = tournament.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Vault PDA that holds the entry fees and signs for the adapter
+    #[account(
+        seeds = [b"tournament_vault", tournament_id.as_bytes()],
+        bump = tournament.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = tournament.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Yield adapter program, checked against the config whitelist
+    #[account(executable)]
+    pub yield_program: UncheckedAccount<'info>,
+
+    /// Wager token of the tournament
+    #[account(address = tournament.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -1,39 +1,66 @@
-use crate::{errors::WagerError, state::*, TOKEN_ID};
+use crate::{
+    errors::WagerError, events::SpawnPurchased, state::*, utils::transfer_spl_tokens, validation::*,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 pub fn pay_to_spawn_handler(ctx: Context<PayToSpawn>, _session_id: String, team: u8) -> Result<()> {
-    let game_session = &mut ctx.accounts.game_session;
+    buy_spawns(ctx.accounts, team, SPAWNS_PER_PURCHASE)
+}
+
+pub fn purchase_spawns_handler(
+    ctx: Context<PayToSpawn>,
+    _session_id: String,
+    team: u8,
+    count: u16,
+) -> Result<()> {
+    require!(count > 0, WagerError::InvalidSpawns);
+    buy_spawns(ctx.accounts, team, count)
+}
+
+/// Sells `count` spawns at the session's price of one bet per
+/// `SPAWNS_PER_PURCHASE` spawns, see `GameSession::spawn_price`
+fn buy_spawns(accounts: &mut PayToSpawn, team: u8, count: u16) -> Result<()> {
+    validation::validate_not_banned(&accounts.player_ban)?;
+
+    let mut game_session = accounts.game_session.load_mut()?;
 
     // Check if game status is in progress and if it is a pay to spawn game
     require!(
-        game_session.status == GameStatus::InProgress && game_session.is_pay_to_spawn(),
+        game_session.status()? == SessionStatus::InProgress && game_session.is_pay_to_spawn(),
         WagerError::InvalidGameState
     );
 
-    // Validate team number (0 for team A, 1 for team B)
-    require!(team == 0 || team == 1, WagerError::InvalidTeamSelection);
+    // Validate team number against the session's teams
+    validation::validate_team_number(team, game_session.team_count())?;
 
     // Check if team is full already
-    let player_index = game_session.get_player_index(team, ctx.accounts.user.key())?;
+    let player_index = game_session.get_player_index(team, accounts.user.key())?;
 
-    let session_bet = game_session.session_bet;
+    let cost = game_session.spawn_price(count)?;
 
     // Transfer SPL tokens from user to vault using user's signature
-    anchor_spl::token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Transfer {
-                from: ctx.accounts.user_token_account.to_account_info(),
-                to: ctx.accounts.vault_token_account.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        ),
-        session_bet,
+    transfer_spl_tokens(
+        &accounts.user_token_account,
+        &accounts.vault_token_account,
+        &accounts.user,
+        &accounts.mint,
+        &accounts.token_program,
+        cost,
     )?;
+    accounts.vault.record_deposit(cost)?;
+
+    game_session.add_spawns(team, player_index, count)?;
+    accounts.player_stats.record_spawns(count as u64, cost)?;
 
-    game_session.add_spawns(team, player_index)?;
+    emit!(SpawnPurchased {
+        session_id: game_session.session_id(),
+        player: accounts.user.key(),
+        team,
+        amount: cost,
+        spawns: count,
+    });
 
     Ok(())
 }
@@ -47,36 +74,61 @@ pub struct PayToSpawn<'info> {
     /// CHECK: Game server authority
     pub game_server: AccountInfo<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         mut,
         seeds = [b"game_session", _session_id.as_bytes()],
-        bump = game_session.bump,
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
     )]
-    pub game_session: Account<'info, GameSession>,
+    pub game_session: AccountLoader<'info, GameSession>,
 
     #[account(
         mut,
         constraint = user_token_account.owner == user.key(),
-        constraint = user_token_account.mint == TOKEN_ID
+        constraint = user_token_account.mint == game_session.load()?.mint
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// CHECK: Vault PDA that holds the funds
+    /// Vault PDA that holds the funds and tracks deposits/payouts
     #[account(
         mut,
         seeds = [b"vault", _session_id.as_bytes()],
-        bump = game_session.vault_bump,
+        bump = game_session.load()?.vault_bump,
     )]
-    pub vault: AccountInfo<'info>,
+    pub vault: Account<'info, Vault>,
 
     #[account(
         mut,
-        associated_token::mint = TOKEN_ID,
+        associated_token::mint = game_session.load()?.mint,
         associated_token::authority = vault,
+        associated_token::token_program = token_program,
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Lifetime statistics of the purchasing player
+    #[account(
+        mut,
+        seeds = [b"player_stats", user.key().as_ref()],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// CHECK: Ban marker PDA of the player, which must not exist
+    #[account(seeds = [b"player_ban", user.key().as_ref()], bump)]
+    pub player_ban: UncheckedAccount<'info>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,119 @@
+use crate::{
+    errors::WagerError, events::SpectatorBetPlaced, state::*, utils::transfer_spl_tokens,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn place_spectator_bet_handler(
+    ctx: Context<PlaceSpectatorBet>,
+    session_id: String,
+    team: u8,
+    amount: u64,
+) -> Result<()> {
+    let game_session = ctx.accounts.game_session.load()?;
+    validation::validate_team_number(team, game_session.team_count())?;
+    require!(amount > 0, WagerError::InvalidBetAmount);
+
+    // Betting closes once the game starts
+    require!(
+        game_session.status()?.is_accepting_players(),
+        WagerError::SpectatorBettingClosed
+    );
+
+    let bettor = ctx.accounts.bettor.key();
+    require!(
+        !game_session.get_all_players().contains(&bettor),
+        WagerError::PlayerCannotSpectate
+    );
+
+    let bet = &mut ctx.accounts.spectator_bet;
+    if bet.bettor == Pubkey::default() {
+        bet.pool = ctx.accounts.spectator_pool.key();
+        bet.bettor = bettor;
+        bet.team = team;
+        bet.bump = ctx.bumps.spectator_bet;
+    }
+    require!(bet.team == team, WagerError::SpectatorTeamMismatch);
+    bet.amount = safe_math::safe_add(bet.amount, amount)?;
+
+    // Transfer the stake into the pool escrow
+    transfer_spl_tokens(
+        &ctx.accounts.bettor_token_account,
+        &ctx.accounts.pool_token_account,
+        &ctx.accounts.bettor,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+    ctx.accounts.spectator_pool.record_bet(team, amount)?;
+
+    emit!(SpectatorBetPlaced {
+        session_id,
+        bettor,
+        team,
+        amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct PlaceSpectatorBet<'info> {
+    #[account(mut)]
+    pub bettor: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        mut,
+        seeds = [b"spectator_pool", session_id.as_bytes()],
+        bump = spectator_pool.bump,
+    )]
+    pub spectator_pool: Account<'info, SpectatorPool>,
+
+    #[account(
+        init_if_needed,
+        payer = bettor,
+        space = SpectatorBet::LEN,
+        seeds = [b"spectator_bet", session_id.as_bytes(), bettor.key().as_ref()],
+        bump
+    )]
+    pub spectator_bet: Account<'info, SpectatorBet>,
+
+    #[account(
+        mut,
+        constraint = bettor_token_account.owner == bettor.key(),
+        constraint = bettor_token_account.mint == game_session.load()?.mint
+    )]
+    pub bettor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = spectator_pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
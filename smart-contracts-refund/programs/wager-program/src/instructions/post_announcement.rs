@@ -0,0 +1,55 @@
+use crate::{errors::WagerError, events::AnnouncementPosted, state::*, validation::*};
+use anchor_lang::prelude::*;
+
+/// Posts a status line, e.g. "map veto complete", to the session's
+/// announcement board so match progress is visible on-chain. The board is
+/// append-only: posts are never edited, and it fills at MAX_ANNOUNCEMENTS.
+pub fn post_announcement_handler(
+    ctx: Context<PostAnnouncement>,
+    session_id: String,
+    text: String,
+) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+    validation::validate_announcement(&text)?;
+
+    let announcements = &mut ctx.accounts.announcements;
+    announcements.init_if_new(ctx.accounts.game_session.key(), ctx.bumps.announcements);
+
+    let clock = Clock::get()?;
+    let index = announcements.post(text.clone(), clock.unix_timestamp, clock.slot)?;
+
+    emit!(AnnouncementPosted {
+        session_id,
+        index,
+        text,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct PostAnnouncement<'info> {
+    #[account(mut)]
+    pub game_server: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == game_server.key() @ WagerError::UnauthorizedAnnouncement,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Announcement board of the session, created with its first post
+    #[account(
+        init_if_needed,
+        payer = game_server,
+        space = Announcements::LEN,
+        seeds = [b"announcements", session_id.as_bytes()],
+        bump
+    )]
+    pub announcements: Account<'info, Announcements>,
+
+    pub system_program: Program<'info, System>,
+}
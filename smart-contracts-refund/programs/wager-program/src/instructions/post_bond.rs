@@ -0,0 +1,48 @@
+use crate::{errors::WagerError, events::BondPosted, state::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Adds lamports to the operator's bond, creating it on the first deposit
+pub fn post_bond_handler(ctx: Context<PostBond>, amount: u64) -> Result<()> {
+    require!(amount > 0, WagerError::InvalidBondAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.operator.to_account_info(),
+                to: ctx.accounts.server_bond.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let server_bond = &mut ctx.accounts.server_bond;
+    server_bond.operator = ctx.accounts.operator.key();
+    server_bond.amount = safe_math::safe_add(server_bond.amount, amount)?;
+    server_bond.bump = ctx.bumps.server_bond;
+
+    emit!(BondPosted {
+        operator: server_bond.operator,
+        amount,
+        total: server_bond.amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PostBond<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = ServerBond::LEN,
+        seeds = [b"server_bond", operator.key().as_ref()],
+        bump
+    )]
+    pub server_bond: Account<'info, ServerBond>,
+
+    pub system_program: Program<'info, System>,
+}
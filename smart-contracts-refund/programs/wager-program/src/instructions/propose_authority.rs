@@ -0,0 +1,32 @@
+use crate::{errors::WagerError, events::AuthorityProposed, state::*};
+use anchor_lang::prelude::*;
+
+/// Proposes a new config authority, which only takes over once it signs
+/// `accept_authority`, so a mistyped key can't lock the admin out. Proposing
+/// the default key withdraws a pending proposal.
+pub fn propose_authority_handler(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.pending_authority = new_authority;
+
+    emit!(AuthorityProposed {
+        authority: config.authority,
+        pending_authority: new_authority,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,38 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Drops sessions that stopped accepting players from a lobby index.
+/// Permissionless; remaining accounts are the listed sessions to check, and
+/// closed sessions are dropped as well.
+pub fn prune_lobby_index_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PruneLobbyIndex<'info>>,
+    _mint: Pubkey,
+    _bet_amount: u64,
+    _game_mode: GameMode,
+) -> Result<()> {
+    let lobby_index = &mut ctx.accounts.lobby_index;
+    for session_info in ctx.remaining_accounts.iter() {
+        if !lobby_index.sessions.contains(session_info.key) {
+            continue;
+        }
+        let accepting = AccountLoader::<GameSession>::try_from(session_info)
+            .and_then(|session| Ok(session.load()?.status()?.is_accepting_players()))
+            .unwrap_or(false);
+        if !accepting {
+            lobby_index.remove(session_info.key());
+            msg!("Pruned session {} from the lobby index", session_info.key());
+        }
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey, bet_amount: u64, game_mode: GameMode)]
+pub struct PruneLobbyIndex<'info> {
+    #[account(
+        mut,
+        seeds = [b"lobby_index", mint.as_ref(), &bet_amount.to_le_bytes(), &[game_mode as u8]],
+        bump = lobby_index.bump,
+    )]
+    pub lobby_index: Account<'info, LobbyIndex>,
+}
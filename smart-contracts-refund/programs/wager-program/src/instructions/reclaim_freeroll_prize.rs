@@ -0,0 +1,151 @@
+use crate::{
+    errors::WagerError, events::FreerollPrizeReclaimed, state::*, utils::release_from_vault,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Returns a refunded freeroll's prize to whoever funded it: the sponsor's
+/// deposits to the session authority and the treasury's share back to the
+/// treasury. Callable by anyone, since the destinations are fixed, and must
+/// run before the session can be closed.
+pub fn reclaim_freeroll_prize_handler(
+    ctx: Context<ReclaimFreerollPrize>,
+    session_id: String,
+) -> Result<()> {
+    validation::validate_session_id(&session_id)?;
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    require!(game_session.is_freeroll(), WagerError::InvalidFreeroll);
+    require!(
+        matches!(
+            game_session.status()?,
+            SessionStatus::Refunded | SessionStatus::Refundable
+        ),
+        WagerError::InvalidGameState
+    );
+
+    let sponsor_prize = game_session.sponsor_prize;
+    let treasury_prize = game_session.treasury_prize;
+    require!(
+        sponsor_prize > 0 || treasury_prize > 0,
+        WagerError::NothingToClaim
+    );
+
+    let native = game_session.is_native();
+    if sponsor_prize > 0 {
+        let destination = if native {
+            ctx.accounts.authority.to_account_info()
+        } else {
+            ctx.accounts
+                .authority_token_account
+                .as_ref()
+                .ok_or(error!(WagerError::MissingSponsorTokenAccount))?
+                .to_account_info()
+        };
+        release_from_vault(
+            &game_session,
+            &ctx.accounts.vault,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &destination,
+            &session_id,
+            sponsor_prize,
+        )?;
+    }
+    if treasury_prize > 0 {
+        let destination = if native {
+            ctx.accounts.treasury.to_account_info()
+        } else {
+            ctx.accounts.treasury_token_account.to_account_info()
+        };
+        release_from_vault(
+            &game_session,
+            &ctx.accounts.vault,
+            &ctx.accounts.vault_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &destination,
+            &session_id,
+            treasury_prize,
+        )?;
+    }
+    ctx.accounts
+        .vault
+        .record_refund(safe_math::safe_add(sponsor_prize, treasury_prize)?)?;
+    game_session.sponsor_prize = 0;
+    game_session.treasury_prize = 0;
+
+    emit!(FreerollPrizeReclaimed {
+        session_id,
+        sponsor_prize,
+        treasury_prize,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct ReclaimFreerollPrize<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Session authority receiving the sponsor's native SOL deposits
+    #[account(mut, address = game_session.load()?.authority @ WagerError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Session authority's token account receiving the sponsor's SPL deposits
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury PDA that owns the fee token accounts and holds native SOL fees
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,104 @@
+use crate::{errors::*, events::SponsoredPrizeReclaimed, state::*, utils::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Returns every prize sponsor's deposit once the session is refunded.
+/// Callable by anyone, and must run before the session can be closed.
+/// Remaining accounts are (sponsor, payout destination) pairs in the order
+/// the sponsors first deposited.
+pub fn reclaim_sponsored_prize_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReclaimSponsoredPrize<'info>>,
+    session_id: String,
+) -> Result<()> {
+    validation::validate_session_id(&session_id)?;
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    require!(
+        matches!(
+            game_session.status()?,
+            SessionStatus::Refunded | SessionStatus::Refundable
+        ),
+        WagerError::InvalidGameState
+    );
+    require!(
+        game_session.sponsored_amount > 0,
+        WagerError::NothingToClaim
+    );
+
+    let sponsors = game_session.prize_sponsors();
+    require!(
+        ctx.remaining_accounts.len() == 2 * sponsors.len(),
+        WagerError::InvalidRemainingAccounts
+    );
+
+    for (index, entry) in sponsors.iter().enumerate() {
+        let sponsor_account = &ctx.remaining_accounts[index * 2];
+        let destination = &ctx.remaining_accounts[index * 2 + 1];
+        if sponsor_account.key() != entry.sponsor {
+            return Err(err_with_player(WagerError::InvalidPlayer, &entry.sponsor));
+        }
+        validate_payout_destination(
+            &game_session,
+            sponsor_account,
+            destination,
+            WagerError::InvalidPlayerTokenAccount,
+        )?;
+
+        if entry.amount > 0 {
+            release_from_vault(
+                &game_session,
+                &ctx.accounts.vault,
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                destination,
+                &session_id,
+                entry.amount,
+            )?;
+            ctx.accounts.vault.record_refund(entry.amount)?;
+        }
+    }
+    game_session.clear_sponsored_prize();
+
+    emit!(SponsoredPrizeReclaimed {
+        session_id,
+        sponsors,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct ReclaimSponsoredPrize<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
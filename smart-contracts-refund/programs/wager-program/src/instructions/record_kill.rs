@@ -1,6 +1,13 @@
-use crate::{errors::WagerError, state::*};
+use crate::{
+    errors::WagerError,
+    events::{KillRecorded, PlayerEliminated, SessionFlagged},
+    state::*,
+    utils::*,
+};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
 
+#[allow(clippy::too_many_arguments)]
 pub fn record_kill_handler(
     ctx: Context<RecordKill>,
     _session_id: String,
@@ -8,22 +15,177 @@ pub fn record_kill_handler(
     killer: Pubkey,
     victim_team: u8,
     victim: Pubkey,
+    assists: [Pubkey; MAX_KILL_ASSISTS],
+    round: u64,
+    sequence: u64,
 ) -> Result<()> {
-    let game_session = &mut ctx.accounts.game_session;
-    game_session.add_kill(killer_team, killer, victim_team, victim)?;
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    // The VRF-drawn match setup must be known before play is recorded
+    require!(
+        !game_session.awaits_randomness()?,
+        WagerError::RandomnessPending
+    );
+
+    // Rounds only move forward so a signed report cannot be replayed
+    require!(
+        round > game_session.last_kill_round,
+        WagerError::StaleKillReport
+    );
+
+    // The report must be signed by the session's reporting key
+    let message = kill_report_message(
+        &game_session.session_id(),
+        &killer,
+        &victim,
+        &assists,
+        round,
+        sequence,
+    );
+    verify_ed25519_signature(
+        &ctx.accounts.instructions,
+        &game_session.kill_report_signer(&ctx.accounts.config.kill_report_signer),
+        &message,
+    )?;
+    game_session.last_kill_round = round;
+
+    // Reports apply strictly in order, one sequence number each
+    game_session.advance_report_sequence(sequence)?;
+
+    let slot = Clock::get()?.slot;
+    let session_id = game_session.session_id();
+    apply_kill(
+        &mut game_session,
+        ctx.accounts.match_log.as_deref_mut(),
+        &session_id,
+        KillRecord {
+            killer_team,
+            killer,
+            victim_team,
+            victim,
+            assists,
+        },
+        slot,
+    )?;
+
+    ctx.accounts.killer_stats.record_kill()?;
+    ctx.accounts.victim_stats.record_death()?;
+    Ok(())
+}
+
+/// Applies a single verified kill to the session: the per-slot rate limit,
+/// scores, assists and eliminations, the match log and the kill events.
+/// Lifetime statistics are left to the caller.
+pub fn apply_kill(
+    game_session: &mut GameSession,
+    match_log: Option<&mut MatchLog>,
+    session_id: &str,
+    kill: KillRecord,
+    slot: u64,
+) -> Result<()> {
+    let KillRecord {
+        killer_team,
+        killer,
+        victim_team,
+        victim,
+        assists,
+    } = kill;
+
+    // Bound how many kills a single slot can add to the session
+    game_session.record_kill_rate(slot)?;
+    let audit_flags = game_session.audit_flags;
+
+    let eliminated = if game_session.is_free_for_all() {
+        game_session.add_free_for_all_kill(killer, victim)?;
+        false
+    } else {
+        game_session.add_kill(killer_team, killer, victim_team, victim)?
+    };
+    game_session.credit_assists(killer_team, killer, &assists)?;
+
+    // Sessions keeping a match log must record every kill in it
+    if game_session.log_capacity > 0 {
+        let match_log = match_log.ok_or(error!(WagerError::MissingMatchLog))?;
+        match_log.record(KillLogEntry {
+            killer,
+            victim,
+            slot,
+        })?;
+    }
+
+    emit!(KillRecorded {
+        session_id: session_id.to_string(),
+        killer,
+        killer_team,
+        victim,
+        victim_team,
+        assists: assists
+            .into_iter()
+            .filter(|assist| *assist != Pubkey::default())
+            .collect(),
+    });
+    if eliminated {
+        emit!(PlayerEliminated {
+            session_id: session_id.to_string(),
+            player: victim,
+            team: victim_team,
+        });
+    }
+    if game_session.audit_flags != audit_flags {
+        emit!(SessionFlagged {
+            session_id: session_id.to_string(),
+            audit_flags: game_session.audit_flags,
+        });
+    }
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(_session_id: String)]
+#[instruction(_session_id: String, _killer_team: u8, killer: Pubkey, _victim_team: u8, victim: Pubkey)]
 pub struct RecordKill<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         mut,
         seeds = [b"game_session", _session_id.as_bytes()],
-        bump = game_session.bump,
-        constraint = game_session.authority == game_server.key() @ WagerError::UnauthorizedKill,
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.is_reporter(&game_server.key()) @ WagerError::UnauthorizedKill,
     )]
-    pub game_session: Account<'info, GameSession>,
+    pub game_session: AccountLoader<'info, GameSession>,
 
     pub game_server: Signer<'info>,
+
+    /// Lifetime statistics of the killer
+    #[account(
+        mut,
+        seeds = [b"player_stats", killer.as_ref()],
+        bump = killer_stats.bump,
+    )]
+    pub killer_stats: Account<'info, PlayerStats>,
+
+    /// Lifetime statistics of the victim
+    #[account(
+        mut,
+        seeds = [b"player_stats", victim.as_ref()],
+        bump = victim_stats.bump,
+    )]
+    pub victim_stats: Account<'info, PlayerStats>,
+
+    /// Ring buffer of recent kills, required when the session keeps one
+    #[account(
+        mut,
+        seeds = [b"match_log", _session_id.as_bytes()],
+        bump = match_log.bump,
+    )]
+    pub match_log: Option<Account<'info, MatchLog>>,
+
+    /// CHECK: Instructions sysvar used to introspect the ed25519 signature check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
 }
@@ -0,0 +1,116 @@
+use crate::{errors::WagerError, instructions::apply_kill, state::*, utils::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+/// Records up to `MAX_KILL_BATCH` kills under a single signed report. The
+/// kills take consecutive sequence numbers starting at `sequence`, and the
+/// killer's then the victim's `PlayerStats` are passed in remaining accounts
+/// for each kill in order.
+pub fn record_kills_batch_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RecordKillsBatch<'info>>,
+    _session_id: String,
+    kills: Vec<KillRecord>,
+    round: u64,
+    sequence: u64,
+) -> Result<()> {
+    require!(
+        !kills.is_empty() && kills.len() <= MAX_KILL_BATCH,
+        WagerError::InvalidKillBatch
+    );
+    require!(
+        ctx.remaining_accounts.len() == kills.len() * 2,
+        WagerError::InvalidRemainingAccounts
+    );
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    // The VRF-drawn match setup must be known before play is recorded
+    require!(
+        !game_session.awaits_randomness()?,
+        WagerError::RandomnessPending
+    );
+
+    // Rounds only move forward so a signed report cannot be replayed
+    require!(
+        round > game_session.last_kill_round,
+        WagerError::StaleKillReport
+    );
+
+    // The whole batch must be signed by the session's reporting key
+    let session_id = game_session.session_id();
+    let message = kill_batch_message(&session_id, round, sequence, &kills);
+    verify_ed25519_signature(
+        &ctx.accounts.instructions,
+        &game_session.kill_report_signer(&ctx.accounts.config.kill_report_signer),
+        &message,
+    )?;
+    game_session.last_kill_round = round;
+
+    let slot = Clock::get()?.slot;
+    let free_for_all = game_session.is_free_for_all();
+    let mut match_log = ctx.accounts.match_log.as_deref_mut();
+    for (i, kill) in kills.iter().enumerate() {
+        if free_for_all {
+            require!(kill.killer != kill.victim, WagerError::InvalidKill);
+        } else {
+            validation::validate_kill_data(
+                &kill.killer,
+                &kill.victim,
+                kill.killer_team,
+                kill.victim_team,
+            )?;
+        }
+
+        // Every kill consumes the next sequence number, and the rate limit
+        // bounds the batch as a whole since all of it lands in this slot
+        game_session.advance_report_sequence(safe_math::safe_add(sequence, i as u64)?)?;
+        apply_kill(
+            &mut game_session,
+            match_log.as_deref_mut(),
+            &session_id,
+            *kill,
+            slot,
+        )?;
+
+        let killer_stats = &ctx.remaining_accounts[i * 2];
+        let victim_stats = &ctx.remaining_accounts[i * 2 + 1];
+        update_player_stats(killer_stats, &kill.killer, |stats| stats.record_kill())?;
+        update_player_stats(victim_stats, &kill.victim, |stats| stats.record_death())?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct RecordKillsBatch<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.is_reporter(&game_server.key()) @ WagerError::UnauthorizedKill,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    pub game_server: Signer<'info>,
+
+    /// Ring buffer of recent kills, required when the session keeps one
+    #[account(
+        mut,
+        seeds = [b"match_log", _session_id.as_bytes()],
+        bump = match_log.bump,
+    )]
+    pub match_log: Option<Account<'info, MatchLog>>,
+
+    /// CHECK: Instructions sysvar used to introspect the ed25519 signature check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+}
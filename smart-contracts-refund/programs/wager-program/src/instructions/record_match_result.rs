@@ -0,0 +1,129 @@
+use crate::{
+    errors::WagerError, events::MatchResultRecorded, instructions::distribution_payees, state::*,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+
+/// Writes the permanent result record of a completed session: the outcome,
+/// the final kill table and what each player was paid. Callable by anyone
+/// paying the rent, once per session; a completed session can only be
+/// closed after its record is written.
+pub fn record_match_result_handler(
+    ctx: Context<RecordMatchResult>,
+    session_id: String,
+) -> Result<()> {
+    validation::validate_session_id(&session_id)?;
+
+    let game_session = ctx.accounts.game_session.load()?;
+    require!(
+        game_session.status()? == SessionStatus::Completed,
+        WagerError::InvalidGameState
+    );
+
+    // Everything paid out or left for players to claim, per player
+    let (payees, _, claimable) = distribution_payees(&game_session, game_session.winning_team)?;
+    let payout_of = |player: &Pubkey| -> Result<u64> {
+        payees
+            .iter()
+            .chain(&claimable)
+            .filter(|(payee, _)| payee == player)
+            .try_fold(0u64, |total, (_, amount)| {
+                safe_math::safe_add(total, *amount)
+            })
+    };
+
+    let mut players = Vec::with_capacity(game_session.player_count());
+    let mut winner = Pubkey::default();
+    if game_session.is_free_for_all() {
+        for entrant in game_session.active_ffa_players() {
+            if entrant.player != Pubkey::default() {
+                players.push(PlayerResult {
+                    player: entrant.player,
+                    team: 0,
+                    kills: entrant.slot.kills.into(),
+                    payout: payout_of(&entrant.player)?,
+                });
+            }
+        }
+        winner = game_session
+            .free_for_all_ranking()
+            .first()
+            .map(|entrant| entrant.player)
+            .unwrap_or_default();
+    } else {
+        for (team, roster) in game_session.active_teams().iter().enumerate() {
+            for (index, player) in roster.players.iter().enumerate() {
+                if *player != Pubkey::default() {
+                    players.push(PlayerResult {
+                        player: *player,
+                        team: team as u8,
+                        kills: roster.slots[index].kills.into(),
+                        payout: payout_of(player)?,
+                    });
+                }
+            }
+        }
+    }
+
+    let record = &mut ctx.accounts.match_result;
+    record.game_session = ctx.accounts.game_session.key();
+    record.session_id = session_id.clone();
+    record.mint = game_session.mint;
+    record.session_bet = game_session.session_bet;
+    record.result = game_session.match_result()?;
+    record.winning_team = game_session.winning_team;
+    record.winner = winner;
+    record.players = players;
+    record.total_paid_out = ctx.accounts.vault.total_paid_out;
+    record.total_fees = ctx.accounts.vault.total_fees;
+    record.created_at = game_session.created_at;
+    record.started_at = game_session.started_at;
+    record.settled_at = game_session.settled_at;
+    record.bump = ctx.bumps.match_result;
+
+    emit!(MatchResultRecorded {
+        session_id,
+        match_result: record.key(),
+        winning_team: record.winning_team,
+        winner,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct RecordMatchResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA whose ledger provides the settlement totals
+    #[account(
+        seeds = [b"vault", session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Result record, keyed by the session's creation time as well so a
+    /// reused session id never collides with an earlier record
+    #[account(
+        init,
+        payer = payer,
+        space = MatchResultRecord::space(game_session.load()?.player_count()),
+        seeds = [
+            b"match_result",
+            session_id.as_bytes(),
+            &game_session.load()?.created_at.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub match_result: Account<'info, MatchResultRecord>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,51 @@
+use crate::{errors::WagerError, events::RoundRecorded, state::*};
+use anchor_lang::prelude::*;
+
+/// Records the winner of one round of a best-of-N series. The session can be
+/// settled once a team has won a majority of the series' rounds.
+pub fn record_round_result_handler(
+    ctx: Context<RecordRoundResult>,
+    _session_id: String,
+    winning_team: u8,
+) -> Result<()> {
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    require!(
+        game_session.status()? == SessionStatus::InProgress,
+        WagerError::GameNotInProgress
+    );
+    game_session.record_round_win(winning_team)?;
+
+    emit!(RoundRecorded {
+        session_id: game_session.session_id(),
+        round: game_session.rounds_played,
+        winning_team,
+        round_wins: game_session.round_wins[winning_team as usize],
+    });
+    if let Some(series_winner) = game_session.series_winner() {
+        msg!("Series won by team {}", series_winner);
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct RecordRoundResult<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.is_reporter(&game_server.key()) @ WagerError::InvalidAuthority,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    pub game_server: Signer<'info>,
+}
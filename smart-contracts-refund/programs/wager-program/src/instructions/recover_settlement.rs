@@ -0,0 +1,52 @@
+use crate::{errors::WagerError, events::SettlementRecovered, state::*};
+use anchor_lang::prelude::*;
+
+pub fn recover_settlement_handler(ctx: Context<RecoverSettlement>, _session_id: String) -> Result<()> {
+    let clock = Clock::get()?;
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    require!(
+        game_session.status()? == SessionStatus::Settling,
+        WagerError::InvalidGameState
+    );
+
+    // A partially paid distribution must be resumed, not unwound
+    require!(
+        game_session.distribution_cursor == 0,
+        WagerError::DistributionInProgress
+    );
+
+    // Give an in-flight settlement time to land before unlocking the session
+    let recoverable_at = game_session
+        .last_processed_at
+        .checked_add(SETTLEMENT_RECOVERY_SECS)
+        .ok_or(error!(WagerError::ArithmeticOverflow))?;
+    require!(
+        clock.unix_timestamp >= recoverable_at,
+        WagerError::SettlementNotStale
+    );
+
+    let status = game_session.resting_status()?;
+    game_session.transition_to(status)?;
+
+    emit!(SettlementRecovered {
+        session_id: game_session.session_id(),
+        status,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct RecoverSettlement<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == game_server.key() @ WagerError::InvalidAuthority,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    pub game_server: Signer<'info>,
+}
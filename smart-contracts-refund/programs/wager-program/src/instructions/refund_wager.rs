@@ -1,127 +1,243 @@
-use crate::{errors::WagerError, state::*, TOKEN_ID};
+use crate::{errors::WagerError, events::*, state::*, utils::*, validation::*};
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 pub fn refund_wager_handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, RefundWager<'info>>,
     session_id: String,
 ) -> Result<()> {
-    let game_session = &ctx.accounts.game_session;
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    // A lobby that filled before the abort holds the creator to a forfeit policy
+    let ready = matches!(
+        game_session.status()?,
+        SessionStatus::InProgress | SessionStatus::ResultSubmitted
+    );
+    game_session.begin_settlement(Clock::get()?.unix_timestamp)?;
     msg!("Starting Refund for session: {}", session_id);
 
-    let players = game_session.get_all_players();
-    msg!("Number of players: {}", players.len());
+    let policy = game_session.refund_policy()?;
+    let deposits = game_session.player_deposits()?;
+    msg!("Number of players: {}", deposits.len());
     msg!(
         "Number of remaining accounts: {}",
         ctx.remaining_accounts.len()
     );
 
-    // We need at least one player and their token account
+    // We need at least one player, their token account and bet receipt
     require!(
         !ctx.remaining_accounts.is_empty(),
         WagerError::InvalidRemainingAccounts
     );
 
-    // Make sure remaining accounts are in pairs
+    // Make sure remaining accounts are in triplets
     require!(
-        ctx.remaining_accounts.len() % 2 == 0,
+        ctx.remaining_accounts.len() % 3 == 0,
         WagerError::InvalidRemainingAccounts
     );
 
-    for player in players {
-        // Skip default player
-        if player == Pubkey::default() {
-            continue;
-        }
-
-        let refund = game_session.session_bet;
+    // Pro-rata aborts split everything still escrowed by what each player
+    // deposited, short of any sponsored prize returned to its sponsors.
+    // Otherwise every player gets back what they deposited, the spawns they
+    // bought or topped up with included, so nothing is left in escrow.
+    let refunds: Vec<(Pubkey, u64)> = if policy == RefundPolicy::ProRataOnAbort {
+        let weights: Vec<u64> = deposits.iter().map(|(_, deposit)| *deposit).collect();
+        let escrowed =
+            safe_math::safe_subtract(ctx.accounts.vault.remaining()?, game_session.prize_pool()?)?;
+        let shares = safe_math::pro_rata_split(escrowed, &weights)?;
+        deposits
+            .iter()
+            .map(|(player, _)| *player)
+            .zip(shares)
+            .collect()
+    } else {
+        deposits
+    };
+
+    let penalty = if policy.creator_forfeits(ready) {
+        safe_math::apply_bps(game_session.session_bet, game_session.creator_penalty_bps)?
+    } else {
+        0
+    };
+
+    for (player, refund) in refunds {
         msg!("Earnings for player {}: {}", player, refund);
 
         // Find the player's account and token account in remaining_accounts
         let player_index = ctx
             .remaining_accounts
             .iter()
-            .step_by(2) // Skip token accounts to only look at player accounts
+            .step_by(3) // Skip token accounts and receipts to only look at player accounts
             .position(|acc| acc.key() == player)
             .ok_or(WagerError::InvalidPlayer)?;
 
-        // Get player and token account from remaining accounts
-        let player_account = &ctx.remaining_accounts[player_index * 2];
-        let player_token_account_info = &ctx.remaining_accounts[player_index * 2 + 1];
-        let player_token_account = Account::<TokenAccount>::try_from(player_token_account_info)?;
+        // Get player, payout destination and bet receipt from remaining accounts
+        let player_account = &ctx.remaining_accounts[player_index * 3];
+        let player_token_account_info = &ctx.remaining_accounts[player_index * 3 + 1];
+        let bet_receipt_info = &ctx.remaining_accounts[player_index * 3 + 2];
+        settle_bet_receipt(
+            bet_receipt_info,
+            ctx.accounts.game_session.key(),
+            player,
+            ReceiptStatus::Refunded,
+        )?;
 
-        // Verify player token account constraints
-        require!(
-            player_token_account.owner == player_account.key(),
-            WagerError::InvalidPlayerTokenAccount
-        );
+        // Verify payout destination constraints
+        validate_payout_destination(
+            &game_session,
+            player_account,
+            player_token_account_info,
+            WagerError::InvalidPlayerTokenAccount,
+        )?;
 
-        // Verify token account mint
-        require!(
-            player_token_account.mint == TOKEN_ID,
-            WagerError::InvalidTokenMint
-        );
+        // Transfer funds from vault to player
+        if refund > 0 {
+            release_from_vault(
+                &game_session,
+                &ctx.accounts.vault,
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                player_token_account_info,
+                &session_id,
+                refund,
+            )?;
+            ctx.accounts.vault.record_refund(refund)?;
+        }
 
-        // Get vault balance before transfer
-        let vault_balance = ctx.accounts.vault_token_account.amount;
-        msg!("Vault balance before transfer: {}", vault_balance);
-
-        // Transfer tokens from vault to player
-        anchor_spl::token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token::Transfer {
-                    from: ctx.accounts.vault_token_account.to_account_info(),
-                    to: player_token_account.to_account_info(),
-                    authority: ctx.accounts.vault.to_account_info(),
-                },
-                &[&[
-                    b"vault",
-                    session_id.as_bytes(),
-                    &[ctx.accounts.game_session.vault_bump],
-                ]],
-            ),
-            refund,
-        )?;
+        emit!(RefundIssued {
+            session_id: session_id.clone(),
+            player,
+            amount: refund,
+        });
+
+        // The creator's penalty comes out of its own funds, not the escrow
+        if penalty > 0 {
+            pay_creator_penalty(
+                ctx.accounts,
+                &game_session,
+                player_token_account_info,
+                penalty,
+            )?;
+
+            emit!(CreatorPenaltyPaid {
+                session_id: session_id.clone(),
+                creator: ctx.accounts.game_server.key(),
+                player,
+                amount: penalty,
+            });
+        }
     }
 
-    // Mark session as completed
-    let game_session = &mut ctx.accounts.game_session;
-    game_session.status = GameStatus::Completed;
+    // Verify vault accounting against the live escrow balance
+    let escrowed = escrow_balance(
+        &game_session,
+        &ctx.accounts.vault,
+        &mut ctx.accounts.vault_token_account,
+    )?;
+    ctx.accounts.vault.check_invariant(escrowed)?;
+
+    // Mark session as refunded
+    game_session.transition_to(SessionStatus::Refunded)?;
+    write_settlement_memo(
+        ctx.accounts.memo_program.as_ref(),
+        &session_id,
+        "refunded",
+        ctx.accounts.vault.total_refunded,
+    )?;
 
     Ok(())
 }
+
+/// Pays a forfeiting creator's penalty to a player's payout destination, in
+/// lamports from the creator wallet or tokens from the creator's token account
+fn pay_creator_penalty<'info>(
+    accounts: &RefundWager<'info>,
+    game_session: &GameSession,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if game_session.is_native() {
+        return system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: accounts.game_server.to_account_info(),
+                    to: destination.clone(),
+                },
+            ),
+            amount,
+        );
+    }
+
+    let creator_token_account = accounts
+        .creator_token_account
+        .as_ref()
+        .ok_or(error!(WagerError::InvalidCreatorTokenAccount))?;
+    transfer_from_escrow(
+        &creator_token_account.to_account_info(),
+        destination,
+        &accounts.game_server.to_account_info(),
+        &accounts.mint,
+        &accounts.token_program,
+        amount,
+        &[],
+    )
+}
+
 #[derive(Accounts)]
 #[instruction(session_id: String)]
 pub struct RefundWager<'info> {
-    /// The game server authority that created the session
+    /// The game server authority that created the session, paying any
+    /// forfeit penalty the session's refund policy imposes
+    #[account(mut)]
     pub game_server: Signer<'info>,
 
     #[account(
         mut,
         seeds = [b"game_session", session_id.as_bytes()],
-        bump = game_session.bump,
-        constraint = game_session.authority == game_server.key() @ WagerError::UnauthorizedDistribution,
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.authority == game_server.key() @ WagerError::UnauthorizedDistribution,
     )]
-    pub game_session: Account<'info, GameSession>,
+    pub game_session: AccountLoader<'info, GameSession>,
 
-    /// CHECK: Vault PDA that holds the funds
+    /// Vault PDA that holds the funds and tracks deposits/payouts
     #[account(
         mut,
         seeds = [b"vault", session_id.as_bytes()],
-        bump = game_session.vault_bump,
+        bump = game_session.load()?.vault_bump,
     )]
-    pub vault: AccountInfo<'info>,
+    pub vault: Account<'info, Vault>,
 
     #[account(
         mut,
-        associated_token::mint = TOKEN_ID,
-        associated_token::authority = vault
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator's token account funding a forfeit penalty, omitted otherwise
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = game_server,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL Memo program, supplied to record a refund summary
+    pub memo_program: Option<Program<'info, Memo>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
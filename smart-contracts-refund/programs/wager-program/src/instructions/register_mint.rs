@@ -0,0 +1,51 @@
+use crate::{errors::WagerError, events::MintRegistered, state::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Permits a mint as a wager token, or updates its bet limit overrides.
+/// Lobbies, queues and tournaments can only be created in registered mints,
+/// so players are never asked to bet an unvetted token.
+pub fn register_mint_handler(ctx: Context<RegisterMint>, min_bet: u64, max_bet: u64) -> Result<()> {
+    let registered_mint = &mut ctx.accounts.registered_mint;
+    registered_mint.mint = ctx.accounts.mint.key();
+    registered_mint.min_bet = min_bet;
+    registered_mint.max_bet = max_bet;
+    registered_mint.bump = ctx.bumps.registered_mint;
+
+    // Overrides must leave a usable range against the current config
+    let (min_bet, max_bet) = registered_mint.bet_limits(&ctx.accounts.config);
+    validation::validate_bet_limits(min_bet, max_bet)?;
+
+    emit!(MintRegistered {
+        mint: registered_mint.mint,
+        min_bet: registered_mint.min_bet,
+        max_bet: registered_mint.max_bet,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RegisteredMint::LEN,
+        seeds = [b"registered_mint", mint.key().as_ref()],
+        bump
+    )]
+    pub registered_mint: Account<'info, RegisteredMint>,
+
+    pub system_program: Program<'info, System>,
+}
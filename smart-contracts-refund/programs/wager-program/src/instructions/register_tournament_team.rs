@@ -0,0 +1,89 @@
+use crate::{
+    errors::WagerError, events::TournamentTeamRegistered, state::*, utils::transfer_spl_tokens,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn register_tournament_team_handler(
+    ctx: Context<RegisterTournamentTeam>,
+    tournament_id: String,
+) -> Result<()> {
+    validation::validate_session_id(&tournament_id)?;
+
+    let tournament = &mut ctx.accounts.tournament;
+    let captain = ctx.accounts.captain.key();
+    tournament.register(captain)?;
+
+    let entry_fee = tournament.entry_fee;
+
+    // Transfer the team's entry fee into the tournament vault
+    transfer_spl_tokens(
+        &ctx.accounts.captain_token_account,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.captain,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        entry_fee,
+    )?;
+    ctx.accounts.vault.record_deposit(entry_fee)?;
+
+    emit!(TournamentTeamRegistered {
+        tournament_id,
+        captain,
+        seed: (tournament.entrants.len() - 1) as u8,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: String)]
+pub struct RegisterTournamentTeam<'info> {
+    /// Team captain paying the entry fee and receiving any prize
+    pub captain: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_bytes()],
+        bump = tournament.bump,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        constraint = captain_token_account.owner == captain.key(),
+        constraint = captain_token_account.mint == tournament.mint
+    )]
+    pub captain_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Vault PDA that holds the entry fees and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament_id.as_bytes()],
+        bump = tournament.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = tournament.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the tournament
+    #[account(address = tournament.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
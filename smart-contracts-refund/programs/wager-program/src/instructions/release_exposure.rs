@@ -0,0 +1,60 @@
+use crate::{errors::WagerError, events::ExposureReleased, state::*, validation::*};
+use anchor_lang::prelude::*;
+
+/// Releases a player's bet in a session from their open exposure once the
+/// session no longer holds it: it settled, was refunded or closed, or the
+/// player's seat was refunded. Callable by anyone, so a settlement crank can
+/// free players' exposure after paying sessions out in bulk. The session PDA
+/// is the single remaining account.
+pub fn release_exposure_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReleaseExposure<'info>>,
+    session_id: String,
+    player: Pubkey,
+) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let session_info = ctx
+        .remaining_accounts
+        .first()
+        .ok_or(error!(WagerError::InvalidRemainingAccounts))?;
+    let (game_session, _) =
+        Pubkey::find_program_address(&[b"game_session", session_id.as_bytes()], ctx.program_id);
+    require!(
+        *session_info.key == game_session,
+        WagerError::InvalidRemainingAccounts
+    );
+
+    // A closed session's account is gone along with every bet it held
+    if !session_info.data_is_empty() {
+        let loader = AccountLoader::<GameSession>::try_from(session_info)?;
+        let game_session = loader.load()?;
+        require!(
+            game_session.status()?.is_terminal() || !game_session.has_player(player),
+            WagerError::ExposureStillActive
+        );
+    }
+
+    let exposure = &mut ctx.accounts.active_exposure;
+    let amount = exposure.release(game_session)?;
+    require!(amount > 0, WagerError::NothingToClaim);
+
+    emit!(ExposureReleased {
+        player,
+        game_session,
+        amount,
+        total: exposure.total,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String, player: Pubkey)]
+pub struct ReleaseExposure<'info> {
+    #[account(
+        mut,
+        seeds = [b"active_exposure", player.as_ref()],
+        bump = active_exposure.bump,
+    )]
+    pub active_exposure: Account<'info, ActiveExposure>,
+}
@@ -0,0 +1,77 @@
+use crate::{errors::WagerError, events::MatchResultReported, state::*};
+use anchor_lang::prelude::*;
+
+pub fn report_match_result_handler(
+    ctx: Context<ReportMatchResult>,
+    _tournament_id: String,
+    winner_side: u8,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    require!(
+        tournament.status == TournamentStatus::InProgress,
+        WagerError::InvalidTournamentState
+    );
+
+    let bracket_match = &mut ctx.accounts.bracket_match;
+    let winner = bracket_match.decide(winner_side)?;
+    let round = bracket_match.round;
+    let match_index = bracket_match.match_index;
+
+    if tournament.is_final_round(round) {
+        // The final decides the champion, the pot is released separately
+        tournament.champion = tournament.entrants[winner as usize];
+    } else {
+        // Advance the winner into its slot of the next round's match
+        let next_match = ctx
+            .accounts
+            .next_match
+            .as_mut()
+            .ok_or(error!(WagerError::InvalidBracketMatch))?;
+        require!(
+            next_match.tournament == tournament.key()
+                && next_match.round == round + 1
+                && next_match.match_index == match_index / 2,
+            WagerError::InvalidBracketMatch
+        );
+        next_match.advance((match_index % 2) as usize, winner)?;
+    }
+
+    emit!(MatchResultReported {
+        tournament_id: tournament.tournament_id.clone(),
+        round,
+        match_index,
+        winner: tournament.entrants[winner as usize],
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_tournament_id: String)]
+pub struct ReportMatchResult<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", _tournament_id.as_bytes()],
+        bump = tournament.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        constraint = bracket_match.tournament == tournament.key() @ WagerError::InvalidBracketMatch,
+    )]
+    pub bracket_match: Account<'info, BracketMatch>,
+
+    /// Match the winner advances into, omitted for the final
+    #[account(mut)]
+    pub next_match: Option<Account<'info, BracketMatch>>,
+}
@@ -0,0 +1,44 @@
+use crate::{errors::WagerError, events::BondWithdrawalRequested, state::*};
+use anchor_lang::prelude::*;
+
+/// Starts withdrawing `amount` lamports of the operator's bond. The lamports
+/// stay slashable for a full dispute window, so every result the operator
+/// reported before the request can still be overturned against them.
+/// Requesting again replaces the pending withdrawal and restarts the wait.
+pub fn request_bond_withdrawal_handler(
+    ctx: Context<RequestBondWithdrawal>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, WagerError::InvalidBondAmount);
+    let server_bond = &mut ctx.accounts.server_bond;
+    require!(amount <= server_bond.amount, WagerError::InsufficientBond);
+
+    let config = &ctx.accounts.config;
+    server_bond.pending_withdrawal = amount;
+    server_bond.unlock_at = Clock::get()?
+        .unix_timestamp
+        .saturating_add(config.dispute_window_secs)
+        .saturating_add(config.clock_skew_secs);
+
+    emit!(BondWithdrawalRequested {
+        operator: server_bond.operator,
+        amount,
+        unlock_at: server_bond.unlock_at,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestBondWithdrawal<'info> {
+    pub operator: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"server_bond", operator.key().as_ref()],
+        bump = server_bond.bump,
+    )]
+    pub server_bond: Account<'info, ServerBond>,
+}
@@ -0,0 +1,95 @@
+use crate::{errors::WagerError, events::RandomnessRequested, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use orao_solana_vrf::program::OraoVrf;
+use orao_solana_vrf::state::NetworkState;
+use orao_solana_vrf::CONFIG_ACCOUNT_SEED;
+
+/// Requests the VRF randomness that decides a full lobby's map and starting
+/// side. Anyone may pay for the request; the seed is fixed by the session so
+/// the caller cannot influence the outcome.
+pub fn request_match_randomness_handler(
+    ctx: Context<RequestMatchRandomness>,
+    _session_id: String,
+) -> Result<()> {
+    let seed = {
+        let game_session = ctx.accounts.game_session.load()?;
+        require!(
+            game_session.status()? == SessionStatus::InProgress,
+            WagerError::GameNotInProgress
+        );
+        require!(
+            game_session.map_pool_size > 0 && game_session.vrf_state()? == VrfState::Idle,
+            WagerError::InvalidRandomnessState
+        );
+        keccak::hashv(&[
+            ctx.accounts.game_session.key().as_ref(),
+            &game_session.created_at.to_le_bytes(),
+        ])
+        .to_bytes()
+    };
+
+    let cpi_accounts = orao_solana_vrf::cpi::accounts::RequestV2 {
+        payer: ctx.accounts.payer.to_account_info(),
+        network_state: ctx.accounts.network_state.to_account_info(),
+        treasury: ctx.accounts.treasury.to_account_info(),
+        request: ctx.accounts.vrf_request.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    orao_solana_vrf::cpi::request_v2(
+        CpiContext::new(ctx.accounts.vrf.to_account_info(), cpi_accounts),
+        seed,
+    )?;
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    game_session.vrf_seed = seed;
+    game_session.vrf_state = VrfState::Requested as u8;
+
+    emit!(RandomnessRequested {
+        session_id: game_session.session_id(),
+        seed,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct RequestMatchRandomness<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// VRF network configuration
+    #[account(
+        mut,
+        seeds = [CONFIG_ACCOUNT_SEED],
+        bump,
+        seeds::program = orao_solana_vrf::ID,
+    )]
+    pub network_state: Account<'info, NetworkState>,
+
+    /// CHECK: VRF fee treasury, validated against the network state by the VRF program
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Randomness request PDA created by the VRF program for the session's seed
+    #[account(mut)]
+    pub vrf_request: AccountInfo<'info>,
+
+    pub vrf: Program<'info, OraoVrf>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,49 @@
+use crate::{errors::WagerError, events::RandomnessRevealed, state::*};
+use anchor_lang::prelude::*;
+use orao_solana_vrf::state::RandomnessV2;
+use orao_solana_vrf::RANDOMNESS_ACCOUNT_SEED;
+
+/// Stores the fulfilled VRF randomness on the session and draws the map and
+/// starting side from it. Permissionless, as the outcome is fixed by the VRF.
+pub fn reveal_match_randomness_handler(
+    ctx: Context<RevealMatchRandomness>,
+    _session_id: String,
+) -> Result<()> {
+    let randomness = ctx
+        .accounts
+        .vrf_request
+        .fulfilled()
+        .ok_or(error!(WagerError::RandomnessNotFulfilled))?
+        .randomness;
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    game_session.reveal_randomness(randomness)?;
+
+    emit!(RandomnessRevealed {
+        session_id: game_session.session_id(),
+        randomness,
+        map_index: game_session.map_index,
+        starting_team: game_session.starting_team,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct RevealMatchRandomness<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Randomness request for the session's VRF seed
+    #[account(
+        seeds = [RANDOMNESS_ACCOUNT_SEED, &game_session.load()?.vrf_seed],
+        bump,
+        seeds::program = orao_solana_vrf::ID,
+    )]
+    pub vrf_request: Account<'info, RandomnessV2>,
+}
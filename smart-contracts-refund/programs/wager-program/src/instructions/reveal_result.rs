@@ -0,0 +1,86 @@
+use crate::{
+    errors::{err_with_deadline, WagerError},
+    instructions::record_submitted_result,
+    state::*,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+
+/// Reveals the result committed with `commit_result` and records it as
+/// `submit_result` would, in a later slot than the commitment reached the
+/// threshold. The signing set approved the result by committing to its hash,
+/// and the commitment's rent goes back to its committer. The reveal closes
+/// once the session's timeout refunds open, so a late result can't race them.
+pub fn reveal_result_handler(
+    ctx: Context<RevealResult>,
+    _session_id: String,
+    declaration: WinnerDeclaration,
+    salt: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    let result_commitment = &ctx.accounts.result_commitment;
+    require!(
+        result_commitment.is_committed(),
+        WagerError::ResultCommitmentPending
+    );
+    require!(
+        clock.slot > result_commitment.committed_slot,
+        WagerError::RevealTooEarly
+    );
+    if validation::deadline_reached(
+        clock.unix_timestamp,
+        game_session.session_expiry,
+        game_session.clock_skew_secs,
+    ) {
+        return Err(err_with_deadline(
+            WagerError::OperationExpired,
+            game_session.session_expiry,
+            clock.unix_timestamp,
+        ));
+    }
+    require!(
+        ResultCommitment::digest(&game_session_key, &declaration, &salt) == result_commitment.hash,
+        WagerError::ResultCommitmentMismatch
+    );
+
+    record_submitted_result(&mut game_session, &declaration, clock.unix_timestamp)
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct RevealResult<'info> {
+    /// A key of the game server signing set
+    pub game_server: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+        constraint = config.is_game_server(&game_server.key()) @ WagerError::UnauthorizedGameServer,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        mut,
+        close = committer,
+        seeds = [b"result_commitment", game_session.key().as_ref()],
+        bump = result_commitment.bump,
+        has_one = committer,
+    )]
+    pub result_commitment: Account<'info, ResultCommitment>,
+
+    /// CHECK: Key that first proposed the commitment, refunded its rent
+    #[account(mut)]
+    pub committer: UncheckedAccount<'info>,
+}
@@ -0,0 +1,39 @@
+use crate::{errors::WagerError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+pub fn set_price_feed_handler(ctx: Context<SetPriceFeed>, feed_id: [u8; 32]) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.mint = ctx.accounts.mint.key();
+    price_feed.feed_id = feed_id;
+    price_feed.bump = ctx.bumps.price_feed;
+
+    msg!("Price feed for mint {}: {:?}", price_feed.mint, feed_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPriceFeed<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PriceFeed::LEN,
+        seeds = [b"price_feed", mint.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,72 @@
+use crate::{
+    errors::WagerError,
+    events::{PlayerReadied, SessionStarted},
+    state::*,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+
+/// Lets a seated player report ready, or withdraw readiness, before the
+/// match starts. Sessions requiring readiness only start once the lobby is
+/// full and enough players are ready, so players who went AFK don't have
+/// their bets played away; the report that completes the threshold starts
+/// the match.
+pub fn set_ready_handler(ctx: Context<SetReady>, session_id: String, ready: bool) -> Result<()> {
+    validation::validate_session_id(&session_id)?;
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    let player = ctx.accounts.player.key();
+    let starts = game_session.set_ready(player, ready)?;
+
+    emit!(PlayerReadied {
+        session_id: session_id.clone(),
+        player,
+        ready,
+        ready_count: game_session.ready_count() as u8,
+    });
+
+    if starts {
+        game_session.transition_to(SessionStatus::InProgress)?;
+        // Full lobbies drop out of discovery once they start
+        if let Some(lobby_index) = &mut ctx.accounts.lobby_index {
+            lobby_index.remove(game_session_key);
+        }
+        emit!(SessionStarted { session_id });
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct SetReady<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Discovery index listing the session, updated once the match starts
+    #[account(
+        mut,
+        seeds = [
+            b"lobby_index",
+            game_session.load()?.mint.as_ref(),
+            &game_session.load()?.session_bet.to_le_bytes(),
+            &[game_session.load()?.game_mode],
+        ],
+        bump = lobby_index.bump,
+    )]
+    pub lobby_index: Option<Account<'info, LobbyIndex>>,
+}
@@ -0,0 +1,263 @@
+use crate::errors::WagerError;
+use crate::events::{RecurringInstanceSpawned, SessionCreated};
+use crate::state::*;
+use crate::utils::validate_bet_usd_bounds;
+use crate::validation::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+/// Launches the next instance of a recurring match once its epoch has come.
+/// Callable by anyone: the caller pays the new accounts' rent and is paid it
+/// back from the lamports the operator funded the schedule with, as far as
+/// they cover it. Instances carry no creation fee, the schedule already
+/// bounds how many lobbies it opens.
+pub fn spawn_next_instance_handler(
+    ctx: Context<SpawnNextInstance>,
+    session_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let recurring_match = &mut ctx.accounts.recurring_match;
+    require!(
+        session_id == recurring_match.next_session_id(),
+        WagerError::InvalidSessionId
+    );
+    let join_window = recurring_match.join_window(now)?;
+    recurring_match.advance(now)?;
+
+    let template_key = ctx.accounts.session_template.key();
+    let template = &ctx.accounts.session_template;
+    let terms = template.terms.clone();
+    let bet_amount = template.bet_amount;
+
+    // Instances only launch on the rake the template advertises
+    let config = &ctx.accounts.config;
+    require!(
+        template.fee_bps == config.fee_bps,
+        WagerError::TemplateOutdated
+    );
+    let (min_bet, max_bet) = ctx.accounts.registered_mint.bet_limits(config);
+    validation::validate_bet_amount(bet_amount, min_bet, max_bet)?;
+    validation::validate_stake_range(bet_amount, terms.max_stake, max_bet)?;
+    validation::validate_session_terms(
+        &terms,
+        join_window.as_ref(),
+        template.mint == native_mint::ID,
+        now,
+    )?;
+
+    // The match log is created exactly when the session keeps one
+    require!(
+        ctx.accounts.match_log.is_some() == (terms.log_capacity > 0),
+        WagerError::MissingMatchLog
+    );
+
+    // Optionally bound the bet in USD using the mint's oracle price
+    validate_bet_usd_bounds(
+        config,
+        &ctx.accounts.mint,
+        ctx.accounts.price_feed.as_ref(),
+        ctx.accounts.price_update.as_ref(),
+        bet_amount,
+        &clock,
+    )?;
+
+    // Every account created for the session must stay rent-exempt at its
+    // final size. The session is checked before loading it holds its data
+    // borrowed.
+    validation::validate_rent_exempt(
+        &ctx.accounts.game_session.to_account_info(),
+        GameSession::LEN,
+    )?;
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_init()?;
+
+    game_session.initialize(
+        &session_id,
+        ctx.accounts.recurring_match.authority,
+        ctx.accounts.mint.key(),
+        bet_amount,
+        terms.game_mode,
+        terms.team_count,
+        config,
+        now,
+    )?;
+    game_session.apply_terms(&terms)?;
+    game_session.set_bumps(
+        &game_session_key,
+        &ctx.accounts.vault.key(),
+        ctx.bumps.game_session,
+        ctx.bumps.vault,
+    )?;
+    game_session.template = template_key;
+    game_session.set_join_window(join_window);
+
+    let mut rent = ctx.accounts.game_session.get_lamports()
+        + ctx.accounts.vault.get_lamports()
+        + ctx.accounts.vault_token_account.get_lamports();
+    if let Some(match_log) = &mut ctx.accounts.match_log {
+        match_log.game_session = game_session_key;
+        match_log.capacity = terms.log_capacity;
+        match_log.bump = ctx
+            .bumps
+            .match_log
+            .ok_or(error!(WagerError::MissingMatchLog))?;
+        rent += match_log.get_lamports();
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.game_session = game_session_key;
+
+    validation::validate_rent_exempt(&ctx.accounts.vault.to_account_info(), Vault::LEN)?;
+
+    let lobby_index = &mut ctx.accounts.lobby_index;
+    lobby_index.init_if_new(
+        ctx.accounts.mint.key(),
+        bet_amount,
+        terms.game_mode,
+        ctx.bumps.lobby_index,
+    );
+    lobby_index.add(game_session_key)?;
+
+    let template = &mut ctx.accounts.session_template;
+    template.sessions_created = safe_math::safe_add(template.sessions_created, 1)?;
+
+    // Pay the caller back for the rent out of the schedule's funding
+    let recurring_match = &mut ctx.accounts.recurring_match;
+    let reserve = Rent::get()?.minimum_balance(RecurringMatch::LEN);
+    let funding = recurring_match.get_lamports().saturating_sub(reserve);
+    let reimbursed = rent.min(funding);
+    if reimbursed > 0 {
+        recurring_match.sub_lamports(reimbursed)?;
+        ctx.accounts.caller.add_lamports(reimbursed)?;
+    }
+
+    emit!(SessionCreated {
+        session_id: session_id.clone(),
+        authority: game_session.authority,
+        mint: game_session.mint,
+        bet_amount,
+        game_mode: terms.game_mode,
+        team_count: terms.team_count,
+    });
+    emit!(RecurringInstanceSpawned {
+        recurring_match: recurring_match.key(),
+        session_id,
+        instance: recurring_match.instances - 1,
+        next_start: recurring_match.next_start,
+        reimbursed,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct SpawnNextInstance<'info> {
+    /// Anyone cranking the schedule, pays the instance's rent
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recurring_match",
+            recurring_match.authority.as_ref(),
+            recurring_match.prefix.as_bytes(),
+        ],
+        bump = recurring_match.bump,
+    )]
+    pub recurring_match: Box<Account<'info, RecurringMatch>>,
+
+    #[account(mut, address = recurring_match.template @ WagerError::InvalidSessionTemplate)]
+    pub session_template: Box<Account<'info, SessionTemplate>>,
+
+    #[account(
+        init,
+        payer = caller,
+        space = GameSession::LEN,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Vault PDA that owns the escrow token account
+    #[account(
+        init,
+        payer = caller,
+        space = Vault::LEN,
+        seeds = [b"vault", session_id.as_bytes()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Ring buffer of recent kills, created when the template keeps a match log
+    #[account(
+        init,
+        payer = caller,
+        space = MatchLog::space(session_template.terms.log_capacity),
+        seeds = [b"match_log", session_id.as_bytes()],
+        bump
+    )]
+    pub match_log: Option<Account<'info, MatchLog>>,
+
+    /// Discovery index of open lobbies with the template's mint, bet and mode
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = LobbyIndex::LEN,
+        seeds = [
+            b"lobby_index",
+            mint.key().as_ref(),
+            &session_template.bet_amount.to_le_bytes(),
+            &[session_template.terms.game_mode as u8],
+        ],
+        bump
+    )]
+    pub lobby_index: Box<Account<'info, LobbyIndex>>,
+
+    /// Wager token of the template
+    #[account(address = session_template.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Registry entry permitting the mint as a wager token
+    #[account(
+        seeds = [b"registered_mint", mint.key().as_ref()],
+        bump = registered_mint.bump,
+    )]
+    pub registered_mint: Account<'info, RegisteredMint>,
+
+    /// Price feed registered for the mint, required when USD bounds are enforced
+    #[account(
+        seeds = [b"price_feed", mint.key().as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    /// Pyth price update for the registered feed
+    pub price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,108 @@
+use crate::{
+    errors::WagerError,
+    events::{ResultSubmitted, SessionFlagged},
+    state::*,
+    utils::*,
+};
+use anchor_lang::prelude::*;
+
+/// Records the game server's result and opens the dispute window, after which
+/// anyone can finalize the distribution. The result needs the config's
+/// threshold of signing set approvals. Sessions flagged for suspicious play
+/// get at least `AUDIT_REVIEW_SECS` for the arbiter to review the result.
+pub fn submit_result_handler(
+    ctx: Context<SubmitResult>,
+    _session_id: String,
+    declaration: WinnerDeclaration,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    let approvals = game_server_approvals(
+        &ctx.accounts.config,
+        &ctx.accounts.game_server.key(),
+        ctx.accounts.approval.as_ref(),
+        game_session_key,
+        ApprovalKind::SubmitResult,
+        declaration.result_code(),
+    )?;
+    require!(
+        approvals >= ctx.accounts.config.game_server_threshold as usize,
+        WagerError::InsufficientApprovals
+    );
+
+    // Committed sessions settle through reveal_result instead
+    require!(
+        !ctx.accounts.config.commit_reveal_results,
+        WagerError::ResultCommitRequired
+    );
+    record_submitted_result(&mut game_session, &declaration, now)
+}
+
+/// Records a result of an in-progress session and opens its dispute window
+pub fn record_submitted_result(
+    game_session: &mut GameSession,
+    declaration: &WinnerDeclaration,
+    now: i64,
+) -> Result<()> {
+    require!(
+        game_session.status()? == SessionStatus::InProgress,
+        WagerError::GameNotInProgress
+    );
+
+    // Only winner-takes-all sessions need a winning team or can draw, other
+    // modes settle on their recorded kills and spawns
+    let audit_flags = game_session.audit_flags;
+    game_session.record_result(declaration)?;
+    if game_session.audit_flags != audit_flags {
+        emit!(SessionFlagged {
+            session_id: game_session.session_id(),
+            audit_flags: game_session.audit_flags,
+        });
+    }
+
+    game_session.dispute_deadline = now
+        .checked_add(game_session.result_dispute_window())
+        .ok_or(error!(WagerError::ArithmeticOverflow))?;
+    game_session.transition_to(SessionStatus::ResultSubmitted)?;
+
+    emit!(ResultSubmitted {
+        session_id: game_session.session_id(),
+        winning_team: game_session.winning_team,
+        draw: game_session.is_draw(),
+        dispute_deadline: game_session.dispute_deadline,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct SubmitResult<'info> {
+    /// A key of the game server signing set
+    pub game_server: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+        constraint = config.is_game_server(&game_server.key()) @ WagerError::UnauthorizedGameServer,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Signing set approvals for the result, needed when the threshold
+    /// exceeds the submitter's own signature
+    #[account(
+        seeds = [b"approval", game_session.key().as_ref(), &[ApprovalKind::SubmitResult as u8]],
+        bump = approval.bump,
+    )]
+    pub approval: Option<Account<'info, Approval>>,
+}
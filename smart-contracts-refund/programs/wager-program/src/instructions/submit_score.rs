@@ -0,0 +1,54 @@
+use crate::{errors::WagerError, events::ScoreSubmitted, state::*};
+use anchor_lang::prelude::*;
+
+/// Records a team's latest score in a score-based session. The first team to
+/// reach the target score, or to capture the objective, is the only winner
+/// the session can then be settled on.
+pub fn submit_score_handler(
+    ctx: Context<SubmitScore>,
+    _session_id: String,
+    team: u8,
+    score: u32,
+) -> Result<()> {
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    require!(
+        game_session.status()? == SessionStatus::InProgress,
+        WagerError::GameNotInProgress
+    );
+    game_session.submit_score(team, score)?;
+
+    let decided = game_session.score_winner()? == Some(team);
+    emit!(ScoreSubmitted {
+        session_id: game_session.session_id(),
+        team,
+        score,
+        decided,
+    });
+    if decided {
+        msg!("Match won on score by team {}", team);
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct SubmitScore<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+        constraint = game_session.load()?.is_reporter(&game_server.key()) @ WagerError::InvalidAuthority,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    pub game_server: Signer<'info>,
+}
@@ -0,0 +1,211 @@
+use crate::{
+    errors::WagerError,
+    events::*,
+    state::*,
+    utils::{open_bet_receipt, redeem_bet_receipt, transfer_spl_tokens},
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Hands a player's slot to a replacement before the match starts. The
+/// replacement buys the slot by paying the bet straight to the leaving
+/// player, so the vault's escrow is untouched.
+pub fn substitute_player_handler(ctx: Context<SubstitutePlayer>, session_id: String) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let game_session_key = ctx.accounts.game_session.key();
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    // Rosters are only open to changes while the session is still filling up
+    require!(
+        game_session.status()? == SessionStatus::Filling,
+        WagerError::InvalidGameState
+    );
+
+    let player = ctx.accounts.player.key();
+    let replacement = ctx.accounts.replacement.key();
+    require!(
+        replacement != Pubkey::default() && !game_session.has_player(replacement),
+        WagerError::InvalidSubstitute
+    );
+    validation::validate_not_banned(&ctx.accounts.replacement_player_ban)?;
+    validation::validate_not_kicked(&ctx.accounts.replacement_session_kick)?;
+    validation::validate_season_pass(
+        game_session.required_pass(),
+        &replacement,
+        ctx.accounts.replacement_pass_account.as_deref(),
+    )?;
+    let replacement_rating = ctx.accounts.replacement_stats.rating();
+    game_session.check_rating(replacement_rating)?;
+    // The replacement takes over the slot's stake
+    let session_bet = game_session.stake_of(player);
+    let receipted = open_bet_receipt(&ctx.accounts.bet_receipt, player, session_bet)?.is_some();
+    let team = game_session.replace_player(player, replacement)?;
+    ctx.accounts.replacement_receipt.issue(
+        game_session_key,
+        replacement,
+        session_bet,
+        ctx.bumps.replacement_receipt,
+    )?;
+    game_session.record_rating(replacement, replacement_rating)?;
+
+    if game_session.is_native() {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.replacement.to_account_info(),
+                    to: ctx.accounts.player.to_account_info(),
+                },
+            ),
+            session_bet,
+        )?;
+    } else {
+        let (Some(player_token_account), Some(replacement_token_account)) = (
+            &ctx.accounts.player_token_account,
+            &ctx.accounts.replacement_token_account,
+        ) else {
+            return Err(error!(WagerError::InvalidPlayerTokenAccount));
+        };
+        require!(
+            player_token_account.owner == player && replacement_token_account.owner == replacement,
+            WagerError::InvalidPlayerTokenAccount
+        );
+        require!(
+            player_token_account.mint == game_session.mint
+                && replacement_token_account.mint == game_session.mint,
+            WagerError::InvalidTokenMint
+        );
+        // The replacement covers any transfer fee so the leaving player
+        // is made whole
+        transfer_spl_tokens(
+            replacement_token_account,
+            player_token_account,
+            &ctx.accounts.replacement,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            session_bet,
+        )?;
+    }
+
+    ctx.accounts.player_stats.revert_game(session_bet)?;
+    let replacement_stats = &mut ctx.accounts.replacement_stats;
+    replacement_stats.init_if_new(replacement, ctx.bumps.replacement_stats);
+    validation::validate_rent_exempt(&replacement_stats.to_account_info(), PlayerStats::LEN)?;
+    replacement_stats.record_game(session_bet)?;
+
+    emit!(PlayerSubstituted {
+        session_id,
+        player,
+        replacement,
+        team,
+        amount: session_bet,
+    });
+
+    // The receipt backed the seat's only claim
+    if receipted {
+        redeem_bet_receipt(
+            &ctx.accounts.bet_receipt,
+            &ctx.accounts.player.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct SubstitutePlayer<'info> {
+    /// Player giving up their slot
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Player taking over the slot and paying for it
+    #[account(mut)]
+    pub replacement: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    /// Leaving player's token account, omitted for native SOL sessions
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Replacement's token account, omitted for native SOL sessions
+    #[account(mut)]
+    pub replacement_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Lifetime statistics of the leaving player
+    #[account(
+        mut,
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Lifetime statistics of the replacement, created on first join
+    #[account(
+        init_if_needed,
+        payer = replacement,
+        space = PlayerStats::LEN,
+        seeds = [b"player_stats", replacement.key().as_ref()],
+        bump
+    )]
+    pub replacement_stats: Account<'info, PlayerStats>,
+
+    /// CHECK: Receipt PDA for the leaving player's bet, closed now that they
+    /// have been paid if they joined with one
+    #[account(
+        mut,
+        seeds = [b"bet_receipt", game_session.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub bet_receipt: UncheckedAccount<'info>,
+
+    /// Receipt for the replacement, who now backs the slot's bet
+    #[account(
+        init_if_needed,
+        payer = replacement,
+        space = BetReceipt::LEN,
+        seeds = [b"bet_receipt", game_session.key().as_ref(), replacement.key().as_ref()],
+        bump
+    )]
+    pub replacement_receipt: Account<'info, BetReceipt>,
+
+    /// Replacement's season pass token account, required by pass-gated sessions
+    pub replacement_pass_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Ban marker PDA of the replacement, which must not exist
+    #[account(seeds = [b"player_ban", replacement.key().as_ref()], bump)]
+    pub replacement_player_ban: UncheckedAccount<'info>,
+
+    /// CHECK: Kick marker PDA of the replacement for this session, which must
+    /// not exist
+    #[account(
+        seeds = [b"session_kick", game_session.key().as_ref(), replacement.key().as_ref()],
+        bump,
+    )]
+    pub replacement_session_kick: UncheckedAccount<'info>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,123 @@
+use crate::{
+    errors::WagerError, events::SpawnsToppedUp, state::*, utils::transfer_spl_tokens, validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn top_up_handler(
+    ctx: Context<TopUp>,
+    _session_id: String,
+    team: u8,
+    amount: u64,
+) -> Result<()> {
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+
+    require!(
+        game_session.status()? == SessionStatus::InProgress && game_session.is_pay_to_spawn(),
+        WagerError::InvalidGameState
+    );
+
+    validation::validate_team_number(team, game_session.team_count())?;
+
+    let player_index = game_session.get_player_index(team, ctx.accounts.user.key())?;
+
+    // Top-ups are bought in whole base bets so spawns keep the pay-to-spawn price
+    let session_bet = game_session.session_bet;
+    require!(
+        amount > 0 && amount % session_bet == 0,
+        WagerError::InvalidTopUpAmount
+    );
+    let multiple =
+        u8::try_from(amount / session_bet).map_err(|_| error!(WagerError::TopUpLimitExceeded))?;
+    let spawns = game_session.top_up(team, player_index, multiple)?;
+
+    transfer_spl_tokens(
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.user,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+    ctx.accounts.vault.record_deposit(amount)?;
+
+    ctx.accounts
+        .player_stats
+        .record_spawns(spawns as u64, amount)?;
+
+    emit!(SpawnsToppedUp {
+        session_id: game_session.session_id(),
+        player: ctx.accounts.user.key(),
+        team,
+        amount,
+        spawns,
+        total_top_up: game_session.team(team)?.slots[player_index].top_ups as u64 * session_bet,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_session_id: String)]
+pub struct TopUp<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Game server authority
+    pub game_server: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", _session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key(),
+        constraint = user_token_account.mint == game_session.load()?.mint
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Vault PDA that holds the funds and tracks deposits/payouts
+    #[account(
+        mut,
+        seeds = [b"vault", _session_id.as_bytes()],
+        bump = game_session.load()?.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = game_session.load()?.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Lifetime statistics of the player topping up
+    #[account(
+        mut,
+        seeds = [b"player_stats", user.key().as_ref()],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
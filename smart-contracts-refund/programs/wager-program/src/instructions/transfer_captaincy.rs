@@ -0,0 +1,51 @@
+use crate::{errors::WagerError, events::CaptaincyTransferred, state::*, validation::*};
+use anchor_lang::prelude::*;
+
+/// Hands a team's captaincy to a teammate
+pub fn transfer_captaincy_handler(
+    ctx: Context<TransferCaptaincy>,
+    session_id: String,
+    team: u8,
+    new_captain: Pubkey,
+) -> Result<()> {
+    // Input validation
+    validation::validate_session_id(&session_id)?;
+
+    let mut game_session = ctx.accounts.game_session.load_mut()?;
+    require!(
+        !game_session.status()?.is_terminal(),
+        WagerError::InvalidGameState
+    );
+    require!(!game_session.is_free_for_all(), WagerError::InvalidTeam);
+    validation::validate_team_number(team, game_session.team_count())?;
+
+    let previous_captain = ctx.accounts.captain.key();
+    require!(
+        game_session.team_captain(team)? == previous_captain,
+        WagerError::NotTeamCaptain
+    );
+    game_session.set_team_captain(team, new_captain)?;
+
+    emit!(CaptaincyTransferred {
+        session_id,
+        team,
+        previous_captain,
+        captain: new_captain,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct TransferCaptaincy<'info> {
+    /// Current captain of the team
+    pub captain: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+}
@@ -0,0 +1,121 @@
+use crate::{errors::WagerError, events::JackpotPaid, state::*, utils::*, validation::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Pays a won jackpot evenly to the winning team of the session that reached
+/// the milestone. Permissionless; remaining accounts hold a `(player,
+/// destination)` pair for every player of the winning team, in roster order.
+pub fn trigger_jackpot_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TriggerJackpot<'info>>,
+    session_id: String,
+) -> Result<()> {
+    let game_session = ctx.accounts.game_session.load()?;
+    require!(
+        game_session.status()? == SessionStatus::Completed,
+        WagerError::GameNotCompleted
+    );
+    require_keys_eq!(
+        ctx.accounts.jackpot.pending_session,
+        ctx.accounts.game_session.key(),
+        WagerError::JackpotNotWon
+    );
+
+    let winning_team = game_session
+        .winning_team()
+        .ok_or(error!(WagerError::JackpotNotWon))?;
+    let winners = game_session.team_capacity(winning_team as usize)?;
+    let roster = game_session.team(winning_team)?;
+    require!(
+        ctx.remaining_accounts.len() == 2 * winners,
+        WagerError::InvalidRemainingAccounts
+    );
+
+    let prize = ctx.accounts.jackpot.pending_prize;
+    let share = safe_math::safe_divide(prize, winners as u64)?;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"jackpot",
+        game_session.mint.as_ref(),
+        &[ctx.accounts.jackpot.bump],
+    ]];
+
+    let mut paid = 0u64;
+    for (i, player) in roster.players[..winners].iter().enumerate() {
+        let player_account = &ctx.remaining_accounts[i * 2];
+        let destination = &ctx.remaining_accounts[i * 2 + 1];
+        require!(player_account.key() == *player, WagerError::InvalidPlayer);
+        validate_payout_destination(
+            &game_session,
+            player_account,
+            destination,
+            WagerError::InvalidPlayerTokenAccount,
+        )?;
+
+        if game_session.is_native() {
+            ctx.accounts.jackpot.sub_lamports(share)?;
+            destination.add_lamports(share)?;
+        } else {
+            transfer_from_escrow(
+                &ctx.accounts.jackpot_token_account.to_account_info(),
+                destination,
+                &ctx.accounts.jackpot.to_account_info(),
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                share,
+                signer_seeds,
+            )?;
+        }
+        paid = safe_math::safe_add(paid, share)?;
+
+        emit!(JackpotPaid {
+            session_id: session_id.clone(),
+            player: *player,
+            amount: share,
+        });
+    }
+
+    ctx.accounts.jackpot.award(paid)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: String)]
+pub struct TriggerJackpot<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ WagerError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"game_session", session_id.as_bytes()],
+        bump = game_session.load()?.bump,
+        constraint = game_session.load()?.version == GAME_SESSION_VERSION @ WagerError::UnsupportedSessionVersion,
+    )]
+    pub game_session: AccountLoader<'info, GameSession>,
+
+    #[account(
+        mut,
+        seeds = [b"jackpot", game_session.load()?.mint.as_ref()],
+        bump = jackpot.bump,
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = jackpot,
+        associated_token::token_program = token_program,
+    )]
+    pub jackpot_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Wager token of the session
+    #[account(address = game_session.load()?.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
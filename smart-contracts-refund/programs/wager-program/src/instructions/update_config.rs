@@ -0,0 +1,24 @@
+use crate::{errors::WagerError, state::*, validation::*};
+use anchor_lang::prelude::*;
+
+pub fn update_config_handler(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+    validation::validate_config_params(&params)?;
+
+    let config = &mut ctx.accounts.config;
+    config.apply(&params);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
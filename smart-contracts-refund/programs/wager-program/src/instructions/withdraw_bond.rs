@@ -0,0 +1,38 @@
+use crate::{errors::WagerError, events::BondWithdrawn, state::*};
+use anchor_lang::prelude::*;
+
+/// Pays out the operator's pending bond withdrawal once its dispute window
+/// has passed, less anything slashed in the meantime
+pub fn withdraw_bond_handler(ctx: Context<WithdrawBond>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let server_bond = &mut ctx.accounts.server_bond;
+    let amount = server_bond.pending_withdrawal;
+    require!(
+        amount > 0 && now >= server_bond.unlock_at,
+        WagerError::BondWithdrawalLocked
+    );
+
+    server_bond.debit(amount)?;
+    server_bond.pending_withdrawal = 0;
+    server_bond.sub_lamports(amount)?;
+    ctx.accounts.operator.add_lamports(amount)?;
+
+    emit!(BondWithdrawn {
+        operator: server_bond.operator,
+        amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawBond<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"server_bond", operator.key().as_ref()],
+        bump = server_bond.bump,
+    )]
+    pub server_bond: Account<'info, ServerBond>,
+}
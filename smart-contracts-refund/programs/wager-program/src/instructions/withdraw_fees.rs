@@ -0,0 +1,93 @@
+use crate::{errors::WagerError, events::FeesWithdrawn, state::*, utils::transfer_from_escrow};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+pub fn withdraw_fees_handler(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    require!(amount > 0, WagerError::InvalidWithdrawAmount);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"treasury", &[ctx.bumps.treasury]]];
+    let mint = ctx.accounts.mint.key();
+
+    if mint == native_mint::ID {
+        // Native SOL fees are held as lamports by the treasury PDA itself,
+        // next to creation fees still refundable to session creators
+        let withdrawable = ctx
+            .accounts
+            .treasury
+            .lamports()
+            .saturating_sub(ctx.accounts.config.held_creation_fees);
+        require!(amount <= withdrawable, WagerError::CreationFeesReserved);
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.treasury.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    } else {
+        // The token program rejects destinations of a different mint
+        transfer_from_escrow(
+            &ctx.accounts.treasury_token_account.to_account_info(),
+            &ctx.accounts.destination.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            amount,
+            signer_seeds,
+        )?;
+    }
+
+    emit!(FeesWithdrawn {
+        authority: ctx.accounts.authority.key(),
+        mint,
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Treasury PDA that owns the fee token accounts and holds native SOL fees
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Wallet for native SOL fees, token account of `mint` otherwise
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,104 @@
+use crate::{
+    errors::WagerError, events::TournamentFundsWithdrawn, state::*, utils::invoke_yield_adapter,
+    validation::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Returns a tournament's parked escrow from its yield adapter. The adapter
+/// must hand back at least the parked principal; anything above it is yield,
+/// split between the champion and the treasury at payout. Remaining accounts
+/// are passed through to the adapter's `withdraw` instruction.
+pub fn withdraw_tournament_funds_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawTournamentFunds<'info>>,
+    tournament_id: String,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    require!(tournament.parked_amount > 0, WagerError::NothingToClaim);
+    let yield_program = ctx.accounts.yield_program.key();
+    require!(
+        tournament.yield_program == yield_program,
+        WagerError::YieldProgramNotAllowed
+    );
+
+    // Withdrawals stay open after the adapter leaves the whitelist, so parked
+    // funds can always come home
+    let balance_before = ctx.accounts.vault_token_account.amount;
+    invoke_yield_adapter(
+        &ctx.accounts.yield_program.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.remaining_accounts,
+        "withdraw",
+        None,
+        &[&[
+            b"tournament_vault",
+            tournament_id.as_bytes(),
+            &[tournament.vault_bump],
+        ]],
+    )?;
+
+    ctx.accounts.vault_token_account.reload()?;
+    let returned =
+        safe_math::safe_subtract(ctx.accounts.vault_token_account.amount, balance_before)?;
+    let principal = tournament.parked_amount;
+    let earned = tournament.unpark(returned)?;
+
+    // Yield joins the vault ledger so payout can be checked against it
+    ctx.accounts.vault.record_deposit(earned)?;
+    ctx.accounts
+        .vault
+        .check_invariant(ctx.accounts.vault_token_account.amount)?;
+
+    emit!(TournamentFundsWithdrawn {
+        tournament_id,
+        yield_program,
+        principal,
+        earned,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: String)]
+pub struct WithdrawTournamentFunds<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_bytes()],
+        bump = tournament.bump,
+        has_one = authority @ WagerError::InvalidAuthority,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Vault PDA that holds the entry fees and signs for the adapter
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament_id.as_bytes()],
+        bump = tournament.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        associated_token::mint = tournament.mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Yield adapter program holding the parked funds
+    #[account(executable)]
+    pub yield_program: UncheckedAccount<'info>,
+
+    /// Wager token of the tournament
+    #[account(address = tournament.mint @ WagerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -10,42 +11,213 @@ use crate::instructions::*;
 
 declare_id!("8PRQvPo16yG8EP5fESDEuJunZBLJ3UFBGvN6CKLZGBUQ");
 
-pub const TOKEN_ID: Pubkey = pubkey!("BzeqmCjLZvMLSTrge9qZnyV8N2zNKBwAxQcZH2XEzFXG");
-
 #[program]
 pub mod wager_program {
     use super::*;
 
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        params: state::ConfigParams,
+    ) -> Result<()> {
+        initialize_config_handler(ctx, params)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: state::ConfigParams) -> Result<()> {
+        update_config_handler(ctx, params)
+    }
+
+    pub fn set_price_feed(ctx: Context<SetPriceFeed>, feed_id: [u8; 32]) -> Result<()> {
+        set_price_feed_handler(ctx, feed_id)
+    }
+
+    pub fn emergency_pause(ctx: Context<SetPause>) -> Result<()> {
+        emergency_pause_handler(ctx)
+    }
+
+    pub fn unpause(ctx: Context<SetPause>) -> Result<()> {
+        unpause_handler(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn create_game_session(
         ctx: Context<CreateGameSession>,
         session_id: String,
         bet_amount: u64,
         game_mode: state::GameMode,
+        team_count: u8,
+        spawn_earnings_bps: u16,
+        log_capacity: u16,
+        team_a_capacity: u8,
+        team_b_capacity: u8,
+        payout_schedule: state::PayoutSchedule,
+        metadata: Option<state::SessionMetadataParams>,
+        access: state::SessionAccess,
+        max_kills_per_player: u16,
+        max_match_kills: u16,
+        series_length: u8,
+        min_rating: u16,
+        max_rating: u16,
+        refund_policy: state::RefundPolicy,
+        requires_pass: Option<Pubkey>,
+        join_window: Option<state::JoinWindow>,
+        win_condition: state::WinCondition,
+        freeroll: bool,
+        assist_weight_bps: u16,
+        min_ready_players: u8,
+        max_stake: u64,
+    ) -> Result<()> {
+        create_game_session_handler(
+            ctx,
+            session_id,
+            bet_amount,
+            game_mode,
+            team_count,
+            spawn_earnings_bps,
+            log_capacity,
+            team_a_capacity,
+            team_b_capacity,
+            payout_schedule,
+            metadata,
+            access,
+            max_kills_per_player,
+            max_match_kills,
+            series_length,
+            min_rating,
+            max_rating,
+            refund_policy,
+            requires_pass,
+            join_window,
+            win_condition,
+            freeroll,
+            assist_weight_bps,
+            min_ready_players,
+            max_stake,
+        )
+    }
+
+    pub fn join_user(
+        ctx: Context<JoinUser>,
+        session_id: String,
+        team: u8,
+        referrer: Option<Pubkey>,
+        credential: Option<state::JoinCredential>,
+        stake: Option<u64>,
+    ) -> Result<()> {
+        join_user_handler(ctx, session_id, team, referrer, credential, stake)
+    }
+
+    pub fn join_user_sol(
+        ctx: Context<JoinUserSol>,
+        session_id: String,
+        team: u8,
+        credential: Option<state::JoinCredential>,
+        stake: Option<u64>,
     ) -> Result<()> {
-        create_game_session_handler(ctx, session_id, bet_amount, game_mode)
+        join_user_sol_handler(ctx, session_id, team, credential, stake)
+    }
+
+    pub fn leave_session(ctx: Context<LeaveSession>, session_id: String) -> Result<()> {
+        leave_session_handler(ctx, session_id)
     }
 
-    pub fn join_user(ctx: Context<JoinUser>, session_id: String, team: u8) -> Result<()> {
-        join_user_handler(ctx, session_id, team)
+    pub fn substitute_player(ctx: Context<SubstitutePlayer>, session_id: String) -> Result<()> {
+        substitute_player_handler(ctx, session_id)
+    }
+
+    pub fn record_round_result(
+        ctx: Context<RecordRoundResult>,
+        session_id: String,
+        winning_team: u8,
+    ) -> Result<()> {
+        record_round_result_handler(ctx, session_id, winning_team)
+    }
+
+    pub fn claim_earnings(ctx: Context<ClaimEarnings>, session_id: String) -> Result<()> {
+        claim_earnings_handler(ctx, session_id)
+    }
+
+    pub fn join_queue(
+        ctx: Context<JoinQueue>,
+        bet_amount: u64,
+        game_mode: state::GameMode,
+    ) -> Result<()> {
+        join_queue_handler(ctx, bet_amount, game_mode)
+    }
+
+    pub fn leave_queue(ctx: Context<LeaveQueue>) -> Result<()> {
+        leave_queue_handler(ctx)
+    }
+
+    pub fn form_match(ctx: Context<FormMatch>, session_id: String) -> Result<()> {
+        form_match_handler(ctx, session_id)
     }
 
     pub fn distribute_winnings<'info>(
         ctx: Context<'_, '_, 'info, 'info, DistributeWinnings<'info>>,
         session_id: String,
+        declaration: state::WinnerDeclaration,
+    ) -> Result<()> {
+        distribute_winnings_handler(ctx, session_id, declaration)
+    }
+
+    pub fn submit_result(
+        ctx: Context<SubmitResult>,
+        session_id: String,
+        declaration: state::WinnerDeclaration,
+    ) -> Result<()> {
+        submit_result_handler(ctx, session_id, declaration)
+    }
+
+    pub fn override_result<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OverrideResult<'info>>,
+        session_id: String,
         winning_team: u8,
     ) -> Result<()> {
-        //if winner takes all, distribute all winnings else distribute winnings for the winners
-        if ctx.accounts.game_session.is_pay_to_spawn() {
-            distribute_pay_spawn_earnings(ctx, session_id)
-        } else {
-            distribute_all_winnings_handler(ctx, session_id, winning_team)
-        }
+        override_result_handler(ctx, session_id, winning_team)
+    }
+
+    pub fn finalize_distribution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeWinnings<'info>>,
+        session_id: String,
+    ) -> Result<()> {
+        finalize_distribution_handler(ctx, session_id)
+    }
+
+    pub fn approve_operation(
+        ctx: Context<ApproveOperation>,
+        session_id: String,
+        kind: state::ApprovalKind,
+        winning_team: u8,
+    ) -> Result<()> {
+        approve_operation_handler(ctx, session_id, kind, winning_team)
+    }
+
+    pub fn claim_referral_rewards(
+        ctx: Context<ClaimReferralRewards>,
+        session_id: String,
+        referee: Pubkey,
+    ) -> Result<()> {
+        claim_referral_rewards_handler(ctx, session_id, referee)
     }
 
     pub fn pay_to_spawn(ctx: Context<PayToSpawn>, session_id: String, team: u8) -> Result<()> {
         pay_to_spawn_handler(ctx, session_id, team)
     }
 
+    pub fn purchase_spawns(
+        ctx: Context<PayToSpawn>,
+        session_id: String,
+        team: u8,
+        count: u16,
+    ) -> Result<()> {
+        purchase_spawns_handler(ctx, session_id, team, count)
+    }
+
+    pub fn top_up(ctx: Context<TopUp>, session_id: String, team: u8, amount: u64) -> Result<()> {
+        top_up_handler(ctx, session_id, team, amount)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn record_kill(
         ctx: Context<RecordKill>,
         session_id: String,
@@ -53,8 +225,108 @@ pub mod wager_program {
         killer: Pubkey,
         victim_team: u8,
         victim: Pubkey,
+        assists: [Pubkey; state::MAX_KILL_ASSISTS],
+        round: u64,
+        sequence: u64,
+    ) -> Result<()> {
+        record_kill_handler(
+            ctx,
+            session_id,
+            killer_team,
+            killer,
+            victim_team,
+            victim,
+            assists,
+            round,
+            sequence,
+        )
+    }
+
+    pub fn claim_timeout_refund(
+        ctx: Context<ClaimTimeoutRefund>,
+        session_id: String,
+    ) -> Result<()> {
+        claim_timeout_refund_handler(ctx, session_id)
+    }
+
+    pub fn recover_settlement(ctx: Context<RecoverSettlement>, session_id: String) -> Result<()> {
+        recover_settlement_handler(ctx, session_id)
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        withdraw_fees_handler(ctx, amount)
+    }
+
+    pub fn close_session(ctx: Context<CloseSession>, session_id: String) -> Result<()> {
+        close_session_handler(ctx, session_id)
+    }
+
+    pub fn migrate_session(ctx: Context<MigrateSession>, session_id: String) -> Result<()> {
+        migrate_session_handler(ctx, session_id)
+    }
+
+    pub fn create_spectator_pool(
+        ctx: Context<CreateSpectatorPool>,
+        session_id: String,
+    ) -> Result<()> {
+        create_spectator_pool_handler(ctx, session_id)
+    }
+
+    pub fn place_spectator_bet(
+        ctx: Context<PlaceSpectatorBet>,
+        session_id: String,
+        team: u8,
+        amount: u64,
+    ) -> Result<()> {
+        place_spectator_bet_handler(ctx, session_id, team, amount)
+    }
+
+    pub fn claim_spectator_payout(
+        ctx: Context<ClaimSpectatorPayout>,
+        session_id: String,
+    ) -> Result<()> {
+        claim_spectator_payout_handler(ctx, session_id)
+    }
+
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        tournament_id: String,
+        entry_fee: u64,
+        team_count: u8,
     ) -> Result<()> {
-        record_kill_handler(ctx, session_id, killer_team, killer, victim_team, victim)
+        create_tournament_handler(ctx, tournament_id, entry_fee, team_count)
+    }
+
+    pub fn register_tournament_team(
+        ctx: Context<RegisterTournamentTeam>,
+        tournament_id: String,
+    ) -> Result<()> {
+        register_tournament_team_handler(ctx, tournament_id)
+    }
+
+    pub fn create_bracket_match(
+        ctx: Context<CreateBracketMatch>,
+        tournament_id: String,
+        round: u8,
+        match_index: u8,
+        session_id: String,
+    ) -> Result<()> {
+        create_bracket_match_handler(ctx, tournament_id, round, match_index, session_id)
+    }
+
+    pub fn report_match_result(
+        ctx: Context<ReportMatchResult>,
+        tournament_id: String,
+        winner_side: u8,
+    ) -> Result<()> {
+        report_match_result_handler(ctx, tournament_id, winner_side)
+    }
+
+    pub fn distribute_tournament_prize(
+        ctx: Context<DistributeTournamentPrize>,
+        tournament_id: String,
+    ) -> Result<()> {
+        distribute_tournament_prize_handler(ctx, tournament_id)
     }
 
     pub fn refund_wager<'info>(
@@ -63,4 +335,345 @@ pub mod wager_program {
     ) -> Result<()> {
         refund_wager_handler(ctx, session_id)
     }
+
+    pub fn request_match_randomness(
+        ctx: Context<RequestMatchRandomness>,
+        session_id: String,
+    ) -> Result<()> {
+        request_match_randomness_handler(ctx, session_id)
+    }
+
+    pub fn reveal_match_randomness(
+        ctx: Context<RevealMatchRandomness>,
+        session_id: String,
+    ) -> Result<()> {
+        reveal_match_randomness_handler(ctx, session_id)
+    }
+
+    pub fn initialize_jackpot(ctx: Context<InitializeJackpot>) -> Result<()> {
+        initialize_jackpot_handler(ctx)
+    }
+
+    pub fn trigger_jackpot<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TriggerJackpot<'info>>,
+        session_id: String,
+    ) -> Result<()> {
+        trigger_jackpot_handler(ctx, session_id)
+    }
+
+    pub fn claim_streak_bonus(ctx: Context<ClaimStreakBonus>) -> Result<()> {
+        claim_streak_bonus_handler(ctx)
+    }
+
+    pub fn migrate_player_stats(ctx: Context<MigratePlayerStats>, player: Pubkey) -> Result<()> {
+        migrate_player_stats_handler(ctx, player)
+    }
+
+    pub fn apply_rating(ctx: Context<ApplyRating>, session_id: String, player: Pubkey) -> Result<()> {
+        apply_rating_handler(ctx, session_id, player)
+    }
+
+    pub fn prune_lobby_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PruneLobbyIndex<'info>>,
+        mint: Pubkey,
+        bet_amount: u64,
+        game_mode: state::GameMode,
+    ) -> Result<()> {
+        prune_lobby_index_handler(ctx, mint, bet_amount, game_mode)
+    }
+
+    pub fn abort_match<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AbortMatch<'info>>,
+        session_id: String,
+        reason: u8,
+    ) -> Result<()> {
+        abort_match_handler(ctx, session_id, reason)
+    }
+
+    pub fn delegate_session_authority(
+        ctx: Context<DelegateSessionAuthority>,
+        session_id: String,
+        reporter: Pubkey,
+    ) -> Result<()> {
+        delegate_session_authority_handler(ctx, session_id, reporter)
+    }
+
+    pub fn mint_trophy(ctx: Context<MintTrophy>, session_id: String) -> Result<()> {
+        mint_trophy_handler(ctx, session_id)
+    }
+
+    pub fn ban_player(ctx: Context<BanPlayer>, player: Pubkey, reason: u8) -> Result<()> {
+        ban_player_handler(ctx, player, reason)
+    }
+
+    pub fn unban_player(ctx: Context<UnbanPlayer>, player: Pubkey) -> Result<()> {
+        unban_player_handler(ctx, player)
+    }
+
+    pub fn record_kills_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordKillsBatch<'info>>,
+        session_id: String,
+        kills: Vec<state::KillRecord>,
+        round: u64,
+        sequence: u64,
+    ) -> Result<()> {
+        record_kills_batch_handler(ctx, session_id, kills, round, sequence)
+    }
+
+    pub fn kick_player(
+        ctx: Context<KickPlayer>,
+        session_id: String,
+        team: u8,
+        player: Pubkey,
+    ) -> Result<()> {
+        kick_player_handler(ctx, session_id, team, player)
+    }
+
+    pub fn transfer_captaincy(
+        ctx: Context<TransferCaptaincy>,
+        session_id: String,
+        team: u8,
+        new_captain: Pubkey,
+    ) -> Result<()> {
+        transfer_captaincy_handler(ctx, session_id, team, new_captain)
+    }
+
+    pub fn claim_unclaimed(ctx: Context<ClaimUnclaimed>, session_id: String) -> Result<()> {
+        claim_unclaimed_handler(ctx, session_id)
+    }
+
+    pub fn estimate_distribution_chunks(
+        ctx: Context<EstimateDistributionChunks>,
+        session_id: String,
+        winning_team: u8,
+    ) -> Result<state::DistributionPlan> {
+        estimate_distribution_chunks_handler(ctx, session_id, winning_team)
+    }
+
+    pub fn lock_session(ctx: Context<LockSession>, session_id: String) -> Result<()> {
+        lock_session_handler(ctx, session_id)
+    }
+
+    pub fn release_exposure<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReleaseExposure<'info>>,
+        session_id: String,
+        player: Pubkey,
+    ) -> Result<()> {
+        release_exposure_handler(ctx, session_id, player)
+    }
+
+    pub fn submit_score(
+        ctx: Context<SubmitScore>,
+        session_id: String,
+        team: u8,
+        score: u32,
+    ) -> Result<()> {
+        submit_score_handler(ctx, session_id, team, score)
+    }
+
+    pub fn declare_forfeit(
+        ctx: Context<DeclareForfeit>,
+        session_id: String,
+        forfeiting_team: u8,
+    ) -> Result<()> {
+        declare_forfeit_handler(ctx, session_id, forfeiting_team)
+    }
+
+    pub fn create_session_template(
+        ctx: Context<CreateSessionTemplate>,
+        template_id: u64,
+        bet_amount: u64,
+        terms: state::SessionTerms,
+    ) -> Result<()> {
+        create_session_template_handler(ctx, template_id, bet_amount, terms)
+    }
+
+    pub fn create_from_template(ctx: Context<CreateFromTemplate>, session_id: String) -> Result<()> {
+        create_from_template_handler(ctx, session_id)
+    }
+
+    pub fn create_recurring_match(
+        ctx: Context<CreateRecurringMatch>,
+        prefix: String,
+        interval_secs: i64,
+        first_start: i64,
+        join_secs: i64,
+        min_players: u8,
+        max_instances: u32,
+    ) -> Result<()> {
+        create_recurring_match_handler(
+            ctx,
+            prefix,
+            interval_secs,
+            first_start,
+            join_secs,
+            min_players,
+            max_instances,
+        )
+    }
+
+    pub fn spawn_next_instance(ctx: Context<SpawnNextInstance>, session_id: String) -> Result<()> {
+        spawn_next_instance_handler(ctx, session_id)
+    }
+
+    pub fn fund_freeroll(
+        ctx: Context<FundFreeroll>,
+        session_id: String,
+        amount: u64,
+        from_treasury: bool,
+    ) -> Result<()> {
+        fund_freeroll_handler(ctx, session_id, amount, from_treasury)
+    }
+
+    pub fn reclaim_freeroll_prize(
+        ctx: Context<ReclaimFreerollPrize>,
+        session_id: String,
+    ) -> Result<()> {
+        reclaim_freeroll_prize_handler(ctx, session_id)
+    }
+
+    pub fn add_prize(ctx: Context<AddPrize>, session_id: String, amount: u64) -> Result<()> {
+        add_prize_handler(ctx, session_id, amount)
+    }
+
+    pub fn reclaim_sponsored_prize<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReclaimSponsoredPrize<'info>>,
+        session_id: String,
+    ) -> Result<()> {
+        reclaim_sponsored_prize_handler(ctx, session_id)
+    }
+
+    pub fn set_ready(ctx: Context<SetReady>, session_id: String, ready: bool) -> Result<()> {
+        set_ready_handler(ctx, session_id, ready)
+    }
+
+    pub fn record_match_result(ctx: Context<RecordMatchResult>, session_id: String) -> Result<()> {
+        record_match_result_handler(ctx, session_id)
+    }
+
+    pub fn join_user_delegated(
+        ctx: Context<JoinUserDelegated>,
+        session_id: String,
+        team: u8,
+        credential: Option<state::JoinCredential>,
+    ) -> Result<()> {
+        join_user_delegated_handler(ctx, session_id, team, credential)
+    }
+
+    pub fn collect_pledges<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CollectPledges<'info>>,
+        session_id: String,
+    ) -> Result<()> {
+        collect_pledges_handler(ctx, session_id)
+    }
+
+    pub fn park_tournament_funds<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ParkTournamentFunds<'info>>,
+        tournament_id: String,
+        amount: u64,
+    ) -> Result<()> {
+        park_tournament_funds_handler(ctx, tournament_id, amount)
+    }
+
+    pub fn withdraw_tournament_funds<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawTournamentFunds<'info>>,
+        tournament_id: String,
+    ) -> Result<()> {
+        withdraw_tournament_funds_handler(ctx, tournament_id)
+    }
+
+    pub fn post_announcement(
+        ctx: Context<PostAnnouncement>,
+        session_id: String,
+        text: String,
+    ) -> Result<()> {
+        post_announcement_handler(ctx, session_id, text)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_authority_handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        accept_authority_handler(ctx)
+    }
+
+    pub fn close_bet_receipt(ctx: Context<CloseBetReceipt>) -> Result<()> {
+        close_bet_receipt_handler(ctx)
+    }
+
+    pub fn close_session_kick(ctx: Context<CloseSessionKick>) -> Result<()> {
+        close_session_kick_handler(ctx)
+    }
+
+    pub fn register_mint(ctx: Context<RegisterMint>, min_bet: u64, max_bet: u64) -> Result<()> {
+        register_mint_handler(ctx, min_bet, max_bet)
+    }
+
+    pub fn deregister_mint(ctx: Context<DeregisterMint>) -> Result<()> {
+        deregister_mint_handler(ctx)
+    }
+
+    pub fn post_bond(ctx: Context<PostBond>, amount: u64) -> Result<()> {
+        post_bond_handler(ctx, amount)
+    }
+
+    pub fn request_bond_withdrawal(
+        ctx: Context<RequestBondWithdrawal>,
+        amount: u64,
+    ) -> Result<()> {
+        request_bond_withdrawal_handler(ctx, amount)
+    }
+
+    pub fn withdraw_bond(ctx: Context<WithdrawBond>) -> Result<()> {
+        withdraw_bond_handler(ctx)
+    }
+
+    pub fn cast_dispute_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastDisputeVote<'info>>,
+        session_id: String,
+        winning_team: u8,
+    ) -> Result<()> {
+        cast_dispute_vote_handler(ctx, session_id, winning_team)
+    }
+
+    pub fn commit_result(
+        ctx: Context<CommitResult>,
+        session_id: String,
+        hash: [u8; 32],
+    ) -> Result<()> {
+        commit_result_handler(ctx, session_id, hash)
+    }
+
+    pub fn reveal_result(
+        ctx: Context<RevealResult>,
+        session_id: String,
+        declaration: state::WinnerDeclaration,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        reveal_result_handler(ctx, session_id, declaration, salt)
+    }
+
+    pub fn create_settlement_table(
+        ctx: Context<CreateSettlementTable>,
+        session_id: String,
+        recent_slot: u64,
+    ) -> Result<()> {
+        create_settlement_table_handler(ctx, session_id, recent_slot)
+    }
+
+    pub fn extend_settlement_table(
+        ctx: Context<ExtendSettlementTable>,
+        session_id: String,
+        first_player: u8,
+    ) -> Result<()> {
+        extend_settlement_table_handler(ctx, session_id, first_player)
+    }
+
+    pub fn close_settlement_table(
+        ctx: Context<CloseSettlementTable>,
+        session_id: String,
+    ) -> Result<()> {
+        close_settlement_table_handler(ctx, session_id)
+    }
 }
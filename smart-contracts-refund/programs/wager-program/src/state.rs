@@ -1,9 +1,13 @@
 //! State accounts for the betting program
-use crate::errors::WagerError;
+use crate::errors::{err_with_amount, err_with_player, err_with_team, WagerError};
+use crate::validation::{safe_math, validation};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_spl::token::spl_token::native_mint;
 
 /// Game mode defining the team sizes
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
 pub enum GameMode {
     WinnerTakesAllOneVsOne,     // 1v1 game mode
     WinnerTakesAllThreeVsThree, // 3v3 game mode
@@ -11,9 +15,26 @@ pub enum GameMode {
     PayToSpawnOneVsOne,         // 1v1 game mode
     PayToSpawnThreeVsThree,     // 3v3 game mode
     PayToSpawnFiveVsFive,       // 5v5 game mode
+    FreeForAllFourPlayers,      // 4-player free-for-all
+    FreeForAllEightPlayers,     // 8-player free-for-all
 }
 
 impl GameMode {
+    /// Decodes a game mode stored as its variant index
+    pub fn from_u8(value: u8) -> Result<Self> {
+        Ok(match value {
+            0 => Self::WinnerTakesAllOneVsOne,
+            1 => Self::WinnerTakesAllThreeVsThree,
+            2 => Self::WinnerTakesAllFiveVsFive,
+            3 => Self::PayToSpawnOneVsOne,
+            4 => Self::PayToSpawnThreeVsThree,
+            5 => Self::PayToSpawnFiveVsFive,
+            6 => Self::FreeForAllFourPlayers,
+            7 => Self::FreeForAllEightPlayers,
+            _ => return Err(error!(WagerError::InvalidGameState)),
+        })
+    }
+
     /// Returns the required number of players per team
     pub fn players_per_team(&self) -> usize {
         match self {
@@ -23,34 +44,579 @@ impl GameMode {
             Self::PayToSpawnOneVsOne => 1,
             Self::PayToSpawnThreeVsThree => 3,
             Self::PayToSpawnFiveVsFive => 5,
+            Self::FreeForAllFourPlayers => 1,
+            Self::FreeForAllEightPlayers => 1,
+        }
+    }
+
+    /// Returns the number of individual entrants in free-for-all modes
+    pub fn free_for_all_players(&self) -> usize {
+        match self {
+            Self::FreeForAllFourPlayers => 4,
+            Self::FreeForAllEightPlayers => 8,
+            _ => 0,
+        }
+    }
+
+    pub fn is_pay_to_spawn(&self) -> bool {
+        matches!(
+            self,
+            Self::PayToSpawnOneVsOne | Self::PayToSpawnThreeVsThree | Self::PayToSpawnFiveVsFive
+        )
+    }
+
+    pub fn is_free_for_all(&self) -> bool {
+        matches!(
+            self,
+            Self::FreeForAllFourPlayers | Self::FreeForAllEightPlayers
+        )
+    }
+}
+
+/// Lifecycle status of a game session
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default)]
+pub enum SessionStatus {
+    #[default]
+    Created,    // Session created, no players yet
+    Filling,    // Players are joining
+    InProgress, // Game is active with all players joined
+    Settling,   // Payouts or refunds are being processed
+    Completed,  // Game has finished and rewards distributed
+    Refunded,   // Bets have been returned to players
+    ResultSubmitted, // Result recorded, payouts wait out the dispute window
+    Refundable, // Join deadline passed short of the minimum players, bets are reclaimable
+}
+
+impl SessionStatus {
+    /// Decodes a status stored as its variant index
+    pub fn from_u8(value: u8) -> Result<Self> {
+        Ok(match value {
+            0 => Self::Created,
+            1 => Self::Filling,
+            2 => Self::InProgress,
+            3 => Self::Settling,
+            4 => Self::Completed,
+            5 => Self::Refunded,
+            6 => Self::ResultSubmitted,
+            7 => Self::Refundable,
+            _ => return Err(error!(WagerError::InvalidGameState)),
+        })
+    }
+
+    /// Whether players may still join the session
+    pub fn is_accepting_players(&self) -> bool {
+        matches!(self, Self::Created | Self::Filling)
+    }
+
+    /// Whether the session has reached a final state
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Refunded)
+    }
+}
+
+/// How a winner-takes-all prize pool is split within the winning team
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub enum PayoutSchedule {
+    WinnerTeamEqual,    // Every winning player receives the same share
+    TopFragger60_30_10, // Top three winning players by kills take 60/30/10%
+    Custom(#[max_len(MAX_PAYOUT_SHARES)] Vec<u16>), // Ranked shares by kills, in basis points summing to 100%
+}
+
+impl PayoutSchedule {
+    /// Ranked shares of the prize pool in basis points, empty for an equal split
+    pub fn shares(&self) -> &[u16] {
+        match self {
+            Self::WinnerTeamEqual => &[],
+            Self::TopFragger60_30_10 => &TOP_FRAGGER_PAYOUT_BPS,
+            Self::Custom(shares) => shares,
+        }
+    }
+}
+
+/// Who may join a session
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum SessionAccess {
+    Open,                // Anyone may join
+    JoinCode([u8; 32]),  // Joiners supply the join code hashing to this keccak digest
+    Allowlist([u8; 32]), // Joiners prove their pubkey is a leaf of this merkle root
+}
+
+/// What players get back when they leave a session or it is aborted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default, InitSpace)]
+pub enum RefundPolicy {
+    #[default]
+    FullAnytimeBeforeStart, // Leaving and aborts return every bet in full, with no cancellation fee
+    ForfeitAfterReady,      // Leaving costs the cancellation fee, and a creator aborting a full lobby pays its players a penalty
+    ProRataOnAbort,         // Leaving costs the cancellation fee, and aborts split the escrow by what each player deposited
+}
+
+impl RefundPolicy {
+    /// Decodes a refund policy stored as its variant index
+    pub fn from_u8(value: u8) -> Result<Self> {
+        Ok(match value {
+            0 => Self::FullAnytimeBeforeStart,
+            1 => Self::ForfeitAfterReady,
+            2 => Self::ProRataOnAbort,
+            _ => return Err(error!(WagerError::InvalidGameState)),
+        })
+    }
+
+    /// Whether leaving a filling session withholds the cancellation fee
+    pub fn charges_cancellation_fee(&self) -> bool {
+        !matches!(self, Self::FullAnytimeBeforeStart)
+    }
+
+    /// Whether a creator aborting the session owes its players a penalty,
+    /// given whether the lobby had filled
+    pub fn creator_forfeits(&self, ready: bool) -> bool {
+        ready && matches!(self, Self::ForfeitAfterReady)
+    }
+}
+
+/// How a team session's winner is determined, which the reported result must
+/// agree with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default, InitSpace)]
+pub enum WinCondition {
+    #[default]
+    ServerDecision,     // The game server's call decides, as for sessions created before win conditions
+    MostKills,          // The team with the most kills wins, ties going to any of the tied teams
+    FirstToScore(u32),  // The first team whose submitted score reaches the target wins
+    ObjectiveCapture,   // The team submitted as capturing the objective wins
+}
+
+impl WinCondition {
+    /// Stored variant index of the condition
+    pub fn index(&self) -> u8 {
+        match self {
+            Self::ServerDecision => 0,
+            Self::MostKills => 1,
+            Self::FirstToScore(_) => 2,
+            Self::ObjectiveCapture => 3,
+        }
+    }
+
+    /// Whether results are decided by scores reported with `submit_score`
+    pub fn is_score_based(&self) -> bool {
+        matches!(self, Self::FirstToScore(_) | Self::ObjectiveCapture)
+    }
+}
+
+/// Outcome of a team match as declared by the game server
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default, InitSpace)]
+pub enum MatchResult {
+    #[default]
+    Win,     // The declared team won the match
+    Draw,    // No team won, every player gets their bet back less any draw rake
+    Forfeit, // The declared team won because its opponents forfeited
+}
+
+impl MatchResult {
+    /// Decodes a match result stored as its variant index
+    pub fn from_u8(value: u8) -> Result<Self> {
+        Ok(match value {
+            0 => Self::Win,
+            1 => Self::Draw,
+            2 => Self::Forfeit,
+            _ => return Err(error!(WagerError::InvalidWinnerDeclaration)),
+        })
+    }
+}
+
+/// Result the game server reports for a session, telling wins, forfeits and
+/// draws apart explicitly
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct WinnerDeclaration {
+    pub result: MatchResult, // How the match ended
+    pub team: u8,            // Winning team, ignored for draws
+}
+
+impl WinnerDeclaration {
+    /// Team the declaration pays, none for a draw
+    pub fn winning_team(&self) -> Option<u8> {
+        (self.result != MatchResult::Draw).then_some(self.team)
+    }
+
+    /// Compact code approvals record the declaration as: the winning team,
+    /// flagged for a forfeit, or `DRAW_RESULT`
+    pub fn result_code(&self) -> u8 {
+        match self.result {
+            MatchResult::Win => self.team,
+            MatchResult::Draw => DRAW_RESULT,
+            MatchResult::Forfeit => self.team | FORFEIT_FLAG,
         }
     }
 }
 
-/// Status of a game session
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
-pub enum GameStatus {
-    WaitingForPlayers, // Waiting for players to join
-    InProgress,        // Game is active with all players joined
-    Completed,         // Game has finished and rewards distributed
+/// Credential a player presents to join a private session
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum JoinCredential {
+    JoinCode(Vec<u8>),          // Preimage of the session's join code hash
+    MerkleProof(Vec<[u8; 32]>), // Sibling hashes from the player's leaf up to the root
+}
+
+/// Single kill carried by a batched kill report
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct KillRecord {
+    pub killer_team: u8, // Team of the killer, ignored in free-for-all
+    pub killer: Pubkey,  // Player scoring the kill
+    pub victim_team: u8, // Team of the victim, ignored in free-for-all
+    pub victim: Pubkey,  // Player killed
+    pub assists: [Pubkey; MAX_KILL_ASSISTS], // Killer's teammates credited with an assist, default keys for none
+}
+
+/// Sensitive game server operations gated by the signing threshold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum ApprovalKind {
+    DistributeWinnings, // Immediate settlement on the game server's result
+    SubmitResult,       // Result submission opening the dispute window
+    OverrideResult,     // Arbiter correction of a submitted result
+}
+
+/// Claim status of a bet receipt
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default, InitSpace)]
+pub enum ReceiptStatus {
+    #[default]
+    Open,     // Bet is escrowed, or pledged, for the seat
+    Refunded, // Bet was returned by a refund or abort
+    PaidOut,  // Session settled with the player among its payees
+}
+
+/// Lifecycle status of a tournament
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default, InitSpace)]
+pub enum TournamentStatus {
+    #[default]
+    Registering, // Teams are paying entry fees
+    InProgress,  // Bracket matches are being played
+    Completed,   // Champion has been paid the tournament pot
+}
+
+/// Seconds a session may sit in `Settling` before it can be recovered
+pub const SETTLEMENT_RECOVERY_SECS: i64 = 300;
+
+/// Compute units a single distribution transaction is planned to stay under
+pub const DISTRIBUTION_CU_BUDGET: u64 = 200_000;
+
+/// Compute units a distribution call spends besides paying its payees:
+/// account validation, payee computation, fee collection and the final
+/// invariant check
+pub const DISTRIBUTION_BASE_CU: u64 = 60_000;
+
+/// Compute units paying a single payee takes: the transfer CPI, Token-2022
+/// fee handling included, the statistics update with its PDA re-derivation
+/// and its events
+pub const DISTRIBUTION_CU_PER_PAYEE: u64 = 16_000;
+
+/// Maximum payees settled by a single distribute_winnings call, sized so a
+/// full page stays within `DISTRIBUTION_CU_BUDGET`
+pub const MAX_DISTRIBUTION_PAGE: usize =
+    ((DISTRIBUTION_CU_BUDGET - DISTRIBUTION_BASE_CU) / DISTRIBUTION_CU_PER_PAYEE) as usize;
+
+/// Players whose settlement accounts one extend_settlement_table call adds,
+/// bounding the token account, statistics and bet receipt PDA derivations
+/// it runs
+pub const MAX_TABLE_EXTEND_PLAYERS: usize = 8;
+
+/// Seconds after settlement before a session's accounts can be closed
+pub const SESSION_CLOSE_COOLDOWN_SECS: i64 = 86_400;
+
+/// Current `GameSession` account layout version
+pub const GAME_SESSION_VERSION: u8 = 43;
+
+/// Last `GameSession` layout that only appended fields to its predecessor.
+/// The next one moved the per-player counters into `PlayerSlot`s, so
+/// sessions at this version are converted rather than grown.
+pub const APPEND_ONLY_SESSION_VERSION: u8 = 42;
+
+/// First zero-copy `GameSession` layout; earlier Borsh layouts cannot be
+/// migrated in place
+pub const MIN_MIGRATABLE_SESSION_VERSION: u8 = 5;
+
+/// Bytes appended to the `GameSession` layout by each version after the first
+/// migratable one up to `APPEND_ONLY_SESSION_VERSION`, indexed by
+/// `version - MIN_MIGRATABLE_SESSION_VERSION`. Those fields were appended at
+/// the end of the account so that zero-filled reallocated space decodes as
+/// their defaults.
+pub const GAME_SESSION_VERSION_GROWTH: [usize;
+    (APPEND_ONLY_SESSION_VERSION - MIN_MIGRATABLE_SESSION_VERSION) as usize] = [
+    8,  // v6: log_capacity and padding
+    16, // v7: dispute_window_secs and dispute_deadline
+    8,  // v8: team_capacities
+    24, // v9: payout schedule
+    40, // v10: access control
+    16, // v11: kill rate limiting
+    8,  // v12: kill caps
+    16, // v13: pay-to-spawn earnings claims
+    128, // v14: best-of-N series
+    136, // v15: pay-to-spawn top-ups
+    272, // v16: spawn accounting and eliminations
+    104, // v17: VRF match setup
+    280, // v18: skill ratings
+    8,  // v19: refund policy
+    32, // v20: delegated reporting key
+    32, // v21: season pass gating
+    8,  // v22: kill report sequencing
+    256, // v23: team captains
+    8,  // v24: creation fee
+    16, // v25: join deadline
+    8,  // v26: short-handed starts
+    144, // v27: fee splits
+    40, // v28: win conditions
+    8,  // v29: draws and forfeits
+    88, // v30: forfeit declarations
+    152, // v31: collusion audit flags
+    32, // v32: session templates
+    24, // v33: freerolls
+    176, // v34: sponsored prizes
+    520, // v35: kill assists
+    24, // v36: player readiness
+    264, // v37: kicked players
+    16, // v38: delegated deposits
+    1032, // v39: stake-weighted bets
+    8,  // v40: clock skew tolerance
+    16, // v41: bet receipts
+    32, // v42: settlement lookup tables
+];
+
+/// Kill records a session accepts within a single slot, bounding how fast a
+/// compromised game server key can inflate kill counts
+pub const MAX_KILLS_PER_SLOT: u16 = 16;
+
+/// Kill records a single batched report can carry, capped by the per-slot
+/// rate limit since every kill in a batch lands in the same slot
+pub const MAX_KILL_BATCH: usize = MAX_KILLS_PER_SLOT as usize;
+
+/// Most players a match result record lists, every slot of every team
+pub const MAX_RECORDED_PLAYERS: usize = MAX_TEAMS * MAX_PLAYERS_PER_TEAM;
+
+/// Length of the kick list earlier layouts kept, unused since kicks are
+/// recorded in `SessionKick` markers
+pub const MAX_KICKED_PLAYERS: usize = 8;
+
+/// Maximum number of teammates credited with an assist on one kill
+pub const MAX_KILL_ASSISTS: usize = 2;
+
+/// Rating of a player who has not played a rated session yet
+pub const DEFAULT_RATING: u64 = 1200;
+
+/// Lowest rating a player can drop to
+pub const RATING_FLOOR: u64 = 100;
+
+/// Longest best-of-N series a session can track
+pub const MAX_SERIES_ROUNDS: usize = 7;
+
+/// Maximum number of teams a single session can hold
+pub const MAX_TEAMS: usize = 8;
+
+/// Roster capacity of a single team
+pub const MAX_PLAYERS_PER_TEAM: usize = 20;
+
+/// Largest roster a team can be given in an asymmetric lobby
+pub const MAX_TEAM_CAPACITY: u8 = MAX_PLAYERS_PER_TEAM as u8;
+
+/// Entrant capacity of a free-for-all session
+pub const MAX_FREE_FOR_ALL_PLAYERS: usize = 8;
+
+/// Share of the pot paid to the top free-for-all finishers, in basis points
+pub const FREE_FOR_ALL_PAYOUT_BPS: [u16; 3] = [5_000, 3_000, 2_000];
+
+/// Share of the prize pool paid to the winning team's top fraggers, in basis points
+pub const TOP_FRAGGER_PAYOUT_BPS: [u16; 3] = [6_000, 3_000, 1_000];
+
+/// Maximum number of ranked shares in a custom payout schedule
+pub const MAX_PAYOUT_SHARES: usize = 8;
+
+/// Maximum number of players waiting in a single matchmaking queue
+pub const MAX_QUEUE_PLAYERS: usize = 32;
+
+/// Open lobbies a single lobby index can list
+pub const MAX_INDEXED_LOBBIES: usize = 32;
+
+/// Maximum byte length of a session or tournament id
+pub const MAX_SESSION_ID_LEN: usize = 32;
+
+/// Maximum byte length of the base URI trophy metadata points under
+pub const MAX_TROPHY_URI_LEN: usize = 128;
+
+/// Partner programs the config can allow to create and join sessions by CPI
+pub const MAX_CPI_CALLERS: usize = 8;
+
+/// Open sessions a wallet's exposure account can track at once
+pub const MAX_EXPOSURE_SESSIONS: usize = 16;
+
+/// Recipients the house rake can be split between
+pub const MAX_FEE_SPLIT_RECIPIENTS: usize = 4;
+
+/// Maximum byte length of a session's game name
+pub const MAX_GAME_NAME_LEN: usize = 32;
+
+/// Maximum byte length of a session's map identifier
+pub const MAX_MAP_ID_LEN: usize = 32;
+
+/// Maximum byte length of a session's region
+pub const MAX_REGION_LEN: usize = 16;
+
+/// Maximum byte length of a session's custom metadata blob
+pub const MAX_METADATA_BLOB_LEN: usize = 256;
+
+/// Maximum house rake the admin can configure, in basis points
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// Decimals of USD-denominated bet bounds (micro-USD)
+pub const USD_DECIMALS: i32 = 6;
+
+/// Maximum number of wallets besides the config authority allowed to
+/// sponsor freerolls
+pub const MAX_FREEROLL_SPONSORS: usize = 8;
+
+/// Maximum number of wallets adding sponsored prizes to one session
+pub const MAX_PRIZE_SPONSORS: usize = 4;
+
+/// Maximum number of yield adapter programs tournament escrow may be parked in
+pub const MAX_YIELD_PROGRAMS: usize = 4;
+
+/// Largest clock skew tolerance the config may grant around deadlines
+pub const MAX_CLOCK_SKEW_SECS: i64 = 300;
+
+/// Maximum number of arbiters on the dispute council
+pub const MAX_ARBITERS: usize = 7;
+
+/// Maximum number of announcements a session's board holds
+pub const MAX_ANNOUNCEMENTS: usize = 32;
+
+/// Maximum length in bytes of a single announcement
+pub const MAX_ANNOUNCEMENT_LEN: usize = 64;
+
+/// Maximum length of a recurring match's session id prefix, leaving room
+/// for the instance number within MAX_SESSION_ID_LEN
+pub const MAX_RECURRING_PREFIX_LEN: usize = 20;
+
+/// Maximum number of teams in a single-elimination tournament
+pub const MAX_TOURNAMENT_TEAMS: usize = 16;
+
+/// Marks an empty or undecided bracket slot
+pub const NO_ENTRANT: u8 = u8::MAX;
+
+/// Result code approvals record for a drawn match
+pub const DRAW_RESULT: u8 = u8::MAX - 1;
+
+/// Flag set on a winning team's result code when the opponents forfeited
+pub const FORFEIT_FLAG: u8 = 0x80;
+
+/// Spawns each player starts a match with
+pub const STARTING_SPAWNS: u8 = 10;
+
+/// Audit flag: a player scored `KILL_FARMING_THRESHOLD` kills or more, every
+/// one against the same victim
+pub const AUDIT_KILL_FARMING: u8 = 1 << 0;
+
+/// Audit flag: a seated player never played a match that had kills reported,
+/// scoring no kills and using none of their spawns
+pub const AUDIT_IDLE_PLAYER: u8 = 1 << 1;
+
+/// Kills against a single victim, and nobody else, that flag a killer for
+/// kill farming
+pub const KILL_FARMING_THRESHOLD: u8 = 5;
+
+/// Slot flag: the player was killed with no spawns remaining
+pub const SLOT_ELIMINATED: u8 = 1 << 0;
+
+/// Slot flag: the slot's pay-to-spawn earnings were claimed
+pub const SLOT_EARNINGS_CLAIMED: u8 = 1 << 1;
+
+/// Slot flag: the player's rating change for the session was applied
+pub const SLOT_RATING_APPLIED: u8 = 1 << 2;
+
+/// Slot flag: the player killed more than one victim
+pub const SLOT_VARIED_VICTIMS: u8 = 1 << 3;
+
+/// Slot flag: the player reported ready
+pub const SLOT_READY: u8 = 1 << 4;
+
+/// Slot flag: the player's bet is approved but not yet collected
+pub const SLOT_PLEDGED: u8 = 1 << 5;
+
+/// Shortest dispute window the result of a session with audit flags gets, so
+/// the arbiter can review it before funds are released
+pub const AUDIT_REVIEW_SECS: i64 = 86_400;
+
+/// Spawns granted by a single pay-to-spawn purchase
+pub const SPAWNS_PER_PURCHASE: u16 = 10;
+
+/// Maximum number of kill events a session's match log can retain
+pub const MAX_MATCH_LOG_CAPACITY: u16 = 128;
+
+/// Maximum number of keys in the game server signing set
+pub const MAX_GAME_SERVER_KEYS: usize = 5;
+
+/// Progress of a session's VRF match setup
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default)]
+pub enum VrfState {
+    #[default]
+    Idle,      // No randomness requested yet
+    Requested, // Waiting on the VRF network to fulfill the request
+    Revealed,  // Randomness stored and the match setup drawn from it
+}
+
+impl VrfState {
+    /// Decodes a VRF state stored as its variant index
+    pub fn from_u8(value: u8) -> Result<Self> {
+        Ok(match value {
+            0 => Self::Idle,
+            1 => Self::Requested,
+            2 => Self::Revealed,
+            _ => return Err(error!(WagerError::InvalidRandomnessState)),
+        })
+    }
+}
+
+/// Counters and state of one roster slot. Counters are a byte wide and fail
+/// with `CounterOverflow` once full rather than wrap.
+#[zero_copy]
+#[derive(Default)]
+pub struct PlayerSlot {
+    pub rating: u16,          // Rating of the slot's player when they joined, 0 if unrated
+    pub spawns: u8,           // Spawns remaining
+    pub kills: u8,            // Kills scored
+    pub spawns_purchased: u8, // Spawns bought after joining
+    pub top_ups: u8,          // Base bets topped up
+    pub assists: u8,          // Assists credited to the slot
+    pub assists_conceded: u8, // Assists credited on the slot's kills
+    pub first_victim: u8,     // First victim slot plus 1, 0 before the first kill
+    pub flags: u8,            // Slot state, see `SLOT_ELIMINATED`
 }
 
-impl Default for GameStatus {
-    fn default() -> Self {
-        Self::WaitingForPlayers
+impl PlayerSlot {
+    /// Whether a slot flag is set
+    pub fn has(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+
+    /// Sets or clears a slot flag
+    pub fn set(&mut self, flag: u8, on: bool) {
+        if on {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
     }
 }
 
 /// Represents a team in the game
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+#[zero_copy]
+#[derive(Default)]
 pub struct Team {
-    pub players: [Pubkey; 5],    // Array of player public keys
-    pub total_bet: u64,          // Total amount bet by team (in lamports)
-    pub player_spawns: [u16; 5], // Number of spawns remaining for each player
-    pub player_kills: [u16; 5],  // Number of kills for each player
+    pub players: [Pubkey; MAX_PLAYERS_PER_TEAM], // Array of player public keys
+    pub total_bet: u64,                          // Total amount bet by team (in lamports)
+    pub stakes: [u64; MAX_PLAYERS_PER_TEAM],     // Bet staked by each slot, 0 for the session bet
+    pub slots: [PlayerSlot; MAX_PLAYERS_PER_TEAM], // Counters and state of each slot
 }
 
 impl Team {
+    /// Kills scored across the roster
+    pub fn total_kills(&self) -> u64 {
+        self.slots.iter().map(|slot| slot.kills as u64).sum()
+    }
+
     /// Finds the first empty slot in the team, if available
     pub fn get_empty_slot(&self, player_count: usize) -> Result<usize> {
         self.players
@@ -62,138 +628,3599 @@ impl Team {
     }
 }
 
-/// Represents a game session between teams with its own pool
-#[account]
+/// Represents an individual entrant in a free-for-all session
+#[zero_copy]
+#[derive(Default)]
+pub struct FreeForAllPlayer {
+    pub player: Pubkey,   // Entrant public key
+    pub slot: PlayerSlot, // Counters and state of the entrant
+}
+
+/// Represents a game session between teams with its own pool. Stored
+/// zero-copy so large rosters never pass through Borsh on the stack; fields
+/// are ordered to keep the `repr(C)` layout free of implicit padding.
+#[account(zero_copy)]
 pub struct GameSession {
-    pub session_id: String,  // Unique identifier for the game
+    pub version: u8,         // Account layout version, see GAME_SESSION_VERSION
+    pub session_id_len: u8,  // Length of the session id in `session_id`
+    pub game_mode: u8,       // Game configuration, see `GameMode`
+    pub status: u8,          // Current lifecycle state, see `SessionStatus`
+    pub team_count: u8,      // Participating teams (2..=MAX_TEAMS), 0 in free-for-all
+    pub winning_team: u8,    // Team that won a winner-takes-all settlement, or NO_ENTRANT
+    pub bump: u8,            // PDA bump
+    pub vault_bump: u8,      // Add this field for vault PDA bump
+    pub session_id: [u8; 32], // Unique identifier for the game
     pub authority: Pubkey,   // Creator of the game session
+    pub mint: Pubkey,        // SPL mint the session is denominated in
     pub session_bet: u64,    // Required bet amount per player
-    pub game_mode: GameMode, // Game configuration (1v1, 2v2, 5v5)
-    pub team_a: Team,        // First team
-    pub team_b: Team,        // Second team
-    pub status: GameStatus,  // Current game state
     pub created_at: i64,     // Creation timestamp
-    pub bump: u8,            // PDA bump
-    pub vault_bump: u8,      // Add this field for vault PDA bump
+    pub session_expiry: i64, // Timestamp after which players may reclaim their bets
+    pub last_processed_at: i64, // Timestamp settlement last started
+    pub last_kill_round: u64, // Highest signed kill report round recorded
+    pub settled_at: i64,      // Timestamp the session reached a terminal status
+    pub teams: [Team; MAX_TEAMS], // Team rosters, the first `team_count` are in play
+    pub ffa_players: [FreeForAllPlayer; MAX_FREE_FOR_ALL_PLAYERS], // Individual entrants in free-for-all mode
+    pub spawn_earnings_bps: u16, // Pay-to-spawn payout per kill/spawn, in bps of the bet
+    pub fee_bps: u16,        // House rake snapshotted from the config at creation
+    pub distribution_cursor: u16, // Payees already paid by a paginated distribution
     pub vault_token_bump: u8,
-    pub is_processing: bool, // Reentrancy guard
-    pub last_processed_at: i64, // Timestamp for additional protection
+    pub ffa_count: u8,       // Free-for-all entrant slots in play
+    pub log_capacity: u16,   // Kill events retained by the session's match log, 0 if none
+    pub padding: [u8; 6],    // Keeps the layout a multiple of 8 bytes
+    pub dispute_window_secs: i64, // Dispute period snapshotted from the config at creation
+    pub dispute_deadline: i64, // Timestamp a submitted result becomes final, 0 until submitted
+    pub team_capacities: [u8; MAX_TEAMS], // Roster size of each team, 0 falls back to the game mode
+    pub payout_share_count: u8, // Ranked shares in `payout_shares_bps`, 0 splits the prize equally
+    pub payout_padding: u8,     // Keeps `payout_shares_bps` aligned
+    pub payout_shares_bps: [u16; MAX_PAYOUT_SHARES], // Winning team's ranked shares by kills
+    pub payout_padding_tail: [u8; 6], // Keeps the layout a multiple of 8 bytes
+    pub access_kind: u8,      // Who may join, see `SessionAccess`
+    pub access_padding: [u8; 7], // Keeps the layout a multiple of 8 bytes
+    pub access_root: [u8; 32], // Join code hash or allowlist merkle root of a private session
+    pub last_kill_slot: u64,  // Slot of the most recent kill record
+    pub kills_this_slot: u16, // Kill records accepted in `last_kill_slot`
+    pub kill_rate_padding: [u8; 6], // Keeps the layout a multiple of 8 bytes
+    pub max_kills_per_player: u16, // Kills a single player may score, 0 if uncapped
+    pub max_match_kills: u16, // Kills the whole match may record, 0 if uncapped
+    pub match_kills: u16,     // Kills recorded so far across the match
+    pub kill_cap_padding: [u8; 2], // Keeps the layout a multiple of 8 bytes
+    pub series_length: u8,    // Rounds in a best-of-N series, 0 for a single game
+    pub rounds_played: u8,    // Series rounds with a recorded result
+    pub round_wins: [u8; MAX_TEAMS], // Series rounds won by each team
+    pub series_padding: [u8; 6], // Keeps `round_kills` aligned
+    pub round_kills: [[u16; MAX_TEAMS]; MAX_SERIES_ROUNDS], // Kills by each team in each series round
+    pub max_top_up_multiple: u8, // Base bets a player may top up mid-session, snapshotted from the config
+    pub top_up_padding: [u8; 7], // Keeps the layout a multiple of 8 bytes
+    pub vrf_seed: [u8; 32],   // Seed of the session's VRF request, zero until requested
+    pub randomness: [u8; 64], // Fulfilled VRF output, zero until revealed
+    pub map_pool_size: u8,    // Maps the VRF picks from, snapshotted from the config, 0 disables VRF setup
+    pub vrf_state: u8,        // Progress of the VRF match setup, see `VrfState`
+    pub map_index: u8,        // Map drawn from the pool once revealed
+    pub starting_team: u8,    // Team (or free-for-all entrant) drawn to take the starting side
+    pub vrf_padding: [u8; 4], // Keeps the layout a multiple of 8 bytes
+    pub min_rating: u16,      // Lowest rating admitted to the lobby
+    pub max_rating: u16,      // Highest rating admitted to the lobby, 0 if unbounded
+    pub rating_padding: [u8; 4], // Keeps the layout a multiple of 8 bytes
+    pub refund_policy: u8,    // What leaving players and aborts refund, see `RefundPolicy`
+    pub refund_padding: u8,   // Keeps `creator_penalty_bps` aligned
+    pub creator_penalty_bps: u16, // Penalty per player, in bps of the bet, a forfeiting creator pays, snapshotted from the config
+    pub refund_padding_tail: [u8; 4], // Keeps the layout a multiple of 8 bytes
+    pub reporter: Pubkey,     // Reporting key delegated by the authority, default if none
+    pub requires_pass: Pubkey, // Mint joiners must hold a token of, default if ungated
+    pub report_sequence: u64, // Sequence number of the last kill report recorded
+    pub captains: [Pubkey; MAX_TEAMS], // Captain of each team, default until someone joins
+    pub creation_fee: u64,    // Lamports the creator paid into the treasury, refunded once the session completes
+    pub join_deadline: i64,   // Timestamp joins close and the session can be locked, 0 if it only starts when full
+    pub min_players: u8,      // Players a locked session needs to start instead of becoming refundable
+    pub locked: u8,           // 1 once `lock_session` closed joins, starting the session short of a full roster
+    pub join_padding: [u8; 6], // Keeps the layout a multiple of 8 bytes
+    pub min_players_per_team: u8, // Players each team of a locked session needs to start short-handed, 0 treated as 1
+    pub short_handed_padding: [u8; 7], // Keeps the layout a multiple of 8 bytes
+    pub fee_split_recipients: [Pubkey; MAX_FEE_SPLIT_RECIPIENTS], // Wallets sharing the rake, snapshotted from the config
+    pub fee_split_bps: [u16; MAX_FEE_SPLIT_RECIPIENTS], // Share of the rake each recipient takes, in basis points
+    pub fee_split_count: u8,  // Recipients in use, 0 sends the whole rake to the treasury
+    pub fee_split_padding: [u8; 7], // Keeps the layout a multiple of 8 bytes
+    pub win_condition: u8,    // How the winner is determined, see `WinCondition`
+    pub win_padding: [u8; 3], // Keeps `target_score` aligned
+    pub target_score: u32,    // Score that wins a first-to-score session
+    pub team_scores: [u32; MAX_TEAMS], // Latest score submitted for each team, 1 marks an objective capture
+    pub match_result: u8,     // How the recorded result ended, see `MatchResult`
+    pub draw_padding: u8,     // Keeps `draw_fee_bps` aligned
+    pub draw_fee_bps: u16,    // Rake withheld from each bet returned in a draw, snapshotted from the config
+    pub draw_padding_tail: [u8; 4], // Keeps the layout a multiple of 8 bytes
+    pub started_at: i64,      // Timestamp the match started, 0 until it does
+    pub team_active_at: [i64; MAX_TEAMS], // Last kill, score or round win of each team, 0 if none since the start
+    pub forfeit_timeout_secs: i64, // Inactivity after which a team can be declared forfeited, snapshotted from the config, 0 disables
+    pub forfeit_penalty_bps: u16, // Share of each forfeiting player's bet paid to the winners, snapshotted from the config
+    pub forfeit_padding: [u8; 6], // Keeps the layout a multiple of 8 bytes
+    pub audit_flags: u8,      // Suspicious play patterns detected, see `AUDIT_KILL_FARMING`, forcing a reviewed result
+    pub bond_slashed: u8,     // 1 once an overturned result slashed the reporting operator's bond
+    pub audit_padding: [u8; 6], // Keeps the layout a multiple of 8 bytes
+    pub template: Pubkey,     // Template the session was launched from, default if created directly
+    pub freeroll: u8,         // 1 if players join for free on a prize funded by the sponsor or the treasury
+    pub freeroll_padding: [u8; 7], // Keeps the layout a multiple of 8 bytes
+    pub sponsor_prize: u64,   // Freeroll prize the sponsor deposited
+    pub treasury_prize: u64,  // Freeroll prize funded from the treasury
+    pub prize_sponsors: [Pubkey; MAX_PRIZE_SPONSORS], // Wallets that added to the prize before the start
+    pub prize_sponsor_amounts: [u64; MAX_PRIZE_SPONSORS], // Amount each prize sponsor deposited
+    pub sponsored_amount: u64, // Total added by prize sponsors, paid out with the pot
+    pub prize_sponsor_count: u8, // Prize sponsors in use
+    pub prize_sponsor_padding: [u8; 7], // Keeps the layout a multiple of 8 bytes
+    pub assist_weight_bps: u16, // Share of a kill's pay-to-spawn earnings an assist takes, in basis points
+    pub assist_padding: [u8; 6], // Keeps the layout a multiple of 8 bytes
+    pub min_ready_players: u8, // Ready players needed to start, 0 skips the check, more than are seated needs everyone
+    pub ready_padding: [u8; 7], // Keeps the layout a multiple of 8 bytes
+    pub max_stake: u64,       // Largest bet a player may stake, 0 requires exactly the session bet
+    pub clock_skew_secs: i64, // Grace on either side of the session's deadlines, snapshotted from the config
+    pub settlement_table: Pubkey, // Lookup table the vault owns for the settlement, default if none is open
 }
 
+/// Space of a `GameSession` account at `MIN_MIGRATABLE_SESSION_VERSION`,
+/// anchor discriminator included
+pub const MIN_MIGRATABLE_SESSION_LEN: usize = 8 + 5_104;
+
 impl GameSession {
-    /// Gets an empty slot for a player in the specified team
-    pub fn get_player_empty_slot(&self, team: u8) -> Result<usize> {
-        let player_count = self.game_mode.players_per_team();
-        match team {
-            0 => self.team_a.get_empty_slot(player_count),
-            1 => self.team_b.get_empty_slot(player_count),
-            _ => Err(error!(WagerError::InvalidTeam)),
-        }
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + std::mem::size_of::<GameSession>();
+
+    /// Initializes a freshly created session on the config's current terms,
+    /// with every team sized by the game mode and an equal payout split
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        session_id: &str,
+        authority: Pubkey,
+        mint: Pubkey,
+        session_bet: u64,
+        game_mode: GameMode,
+        team_count: u8,
+        config: &ProgramConfig,
+        now: i64,
+    ) -> Result<()> {
+        self.version = GAME_SESSION_VERSION;
+        self.set_session_id(session_id)?;
+        self.authority = authority;
+        self.mint = mint;
+        self.session_bet = session_bet;
+        self.fee_bps = config.fee_bps;
+        self.dispute_window_secs = config.dispute_window_secs;
+        self.max_top_up_multiple = config.max_top_up_multiple;
+        self.map_pool_size = config.map_pool_size;
+        self.creator_penalty_bps = config.creator_penalty_bps;
+        self.draw_fee_bps = config.draw_fee_bps;
+        self.forfeit_timeout_secs = config.forfeit_timeout_secs;
+        self.forfeit_penalty_bps = config.forfeit_penalty_bps;
+        self.clock_skew_secs = config.clock_skew_secs;
+        self.set_fee_split(&config.fee_split);
+        self.game_mode = game_mode as u8;
+        self.team_count = team_count;
+        self.ffa_count = game_mode.free_for_all_players() as u8;
+        self.status = SessionStatus::Created as u8;
+        self.created_at = now;
+        self.session_expiry = now
+            .checked_add(config.session_timeout_secs)
+            .ok_or(error!(WagerError::ArithmeticOverflow))?;
+        self.winning_team = NO_ENTRANT;
+        self.team_capacities = [0; MAX_TEAMS];
+        self.team_capacities[..team_count as usize].fill(game_mode.players_per_team() as u8);
+        Ok(())
     }
 
-    /// Checks if both teams are completely filled
-    pub fn check_all_filled(&self) -> Result<bool> {
-        let player_count = self.game_mode.players_per_team();
+    /// Unique identifier for the game
+    pub fn session_id(&self) -> String {
+        String::from_utf8_lossy(&self.session_id[..self.session_id_len as usize]).into_owned()
+    }
 
-        Ok(matches!(
-            (
-                self.team_a.get_empty_slot(player_count),
-                self.team_b.get_empty_slot(player_count)
-            ),
-            (Err(e1), Err(e2)) if is_team_full_error(&e1) && is_team_full_error(&e2)
-        ))
+    /// Stores the session identifier, which must fit the fixed-size buffer
+    pub fn set_session_id(&mut self, session_id: &str) -> Result<()> {
+        let bytes = session_id.as_bytes();
+        require!(bytes.len() <= self.session_id.len(), WagerError::SessionIdTooLong);
+        self.session_id = [0; 32];
+        self.session_id[..bytes.len()].copy_from_slice(bytes);
+        self.session_id_len = bytes.len() as u8;
+        Ok(())
     }
 
-    pub fn is_pay_to_spawn(&self) -> bool {
-        matches!(
-            self.game_mode,
-            GameMode::PayToSpawnOneVsOne
-                | GameMode::PayToSpawnThreeVsThree
-                | GameMode::PayToSpawnFiveVsFive
-        )
+    /// Game configuration (1v1, 3v3, 5v5, free-for-all)
+    pub fn game_mode(&self) -> Result<GameMode> {
+        GameMode::from_u8(self.game_mode)
     }
 
-    pub fn get_all_players(&self) -> Vec<Pubkey> {
-        let mut players = self.team_a.players.to_vec();
-        players.extend(self.team_b.players.to_vec());
-        players
+    /// Current lifecycle state
+    pub fn status(&self) -> Result<SessionStatus> {
+        SessionStatus::from_u8(self.status)
     }
 
-    pub fn get_player_index(&self, team: u8, player: Pubkey) -> Result<usize> {
-        match team {
-            0 => self
-                .team_a
-                .players
-                .iter()
-                .position(|p| *p == player)
-                .ok_or(error!(WagerError::PlayerNotFound)),
-            1 => self
-                .team_b
-                .players
-                .iter()
-                .position(|p| *p == player)
-                .ok_or(error!(WagerError::PlayerNotFound)),
-            _ => return Err(error!(WagerError::InvalidTeam)),
+    /// Team that won a winner-takes-all settlement, if recorded
+    pub fn winning_team(&self) -> Option<u8> {
+        (self.winning_team != NO_ENTRANT).then_some(self.winning_team)
+    }
+
+    /// Replaces the submitted result with a win for `winning_team`, a
+    /// submitted draw too. Returns whether the result changed.
+    pub fn correct_result(&mut self, winning_team: u8) -> bool {
+        let overturned =
+            self.winning_team != winning_team || self.match_result != MatchResult::Win as u8;
+        self.winning_team = winning_team;
+        self.match_result = MatchResult::Win as u8;
+        overturned
+    }
+
+    /// Number of teams participating in the session
+    pub fn team_count(&self) -> usize {
+        self.team_count as usize
+    }
+
+    /// Teams participating in the session
+    pub fn active_teams(&self) -> &[Team] {
+        &self.teams[..self.team_count()]
+    }
+
+    /// Free-for-all entrant slots in play
+    pub fn active_ffa_players(&self) -> &[FreeForAllPlayer] {
+        &self.ffa_players[..self.ffa_count as usize]
+    }
+
+    /// Gets a team by index
+    pub fn team(&self, team: u8) -> Result<&Team> {
+        self.active_teams()
+            .get(team as usize)
+            .ok_or_else(|| error!(WagerError::InvalidTeam))
+    }
+
+    /// Gets a mutable team by index
+    pub fn team_mut(&mut self, team: u8) -> Result<&mut Team> {
+        let team_count = self.team_count();
+        self.teams[..team_count]
+            .get_mut(team as usize)
+            .ok_or_else(|| error!(WagerError::InvalidTeam))
+    }
+
+    /// Counters of a seat as `seat_of` numbers them: a team slot, or the
+    /// entrant's own for free-for-all sessions
+    pub fn seat_slot(&self, team: usize, index: usize) -> &PlayerSlot {
+        if self.is_free_for_all() {
+            &self.ffa_players[index].slot
+        } else {
+            &self.teams[team].slots[index]
         }
     }
 
-    /// Gets the kill and death difference for a player in a team
-    pub fn get_kills_and_spawns(&self, player_pubkey: Pubkey) -> Result<u16> {
-        // search in both teams and return the kill and death difference
-        let team_a_index = self.team_a.players.iter().position(|p| *p == player_pubkey);
-        let team_b_index = self.team_b.players.iter().position(|p| *p == player_pubkey);
-        if let Some(team_a_index) = team_a_index {
-            Ok(self.team_a.player_kills[team_a_index] as u16
-                + self.team_a.player_spawns[team_a_index] as u16)
-        } else if let Some(team_b_index) = team_b_index {
-            Ok(self.team_b.player_kills[team_b_index] as u16
-                + self.team_b.player_spawns[team_b_index] as u16)
+    /// Mutable counters of a seat, see `seat_slot`
+    pub fn seat_slot_mut(&mut self, team: usize, index: usize) -> &mut PlayerSlot {
+        if self.is_free_for_all() {
+            &mut self.ffa_players[index].slot
         } else {
-            return Err(error!(WagerError::PlayerNotFound));
+            &mut self.teams[team].slots[index]
         }
     }
 
-    pub fn add_kill(
-        &mut self,
-        killer_team: u8,
-        killer: Pubkey,
-        victim_team: u8,
-        victim: Pubkey,
-    ) -> Result<()> {
-        let killer_player_index: usize = self.get_player_index(killer_team, killer)?;
-        let victim_player_index: usize = self.get_player_index(victim_team, victim)?;
+    /// Counters of every slot in play, team rosters first
+    fn active_slots(&self) -> impl Iterator<Item = &PlayerSlot> {
+        self.active_teams()
+            .iter()
+            .flat_map(|team| team.slots.iter())
+            .chain(self.active_ffa_players().iter().map(|entrant| &entrant.slot))
+    }
+
+    /// Roster size of a team, the game mode's default for sessions created
+    /// before per-team capacities
+    pub fn team_capacity(&self, team: usize) -> Result<usize> {
+        match self.team_capacities.get(team) {
+            Some(&capacity) if capacity > 0 => Ok(capacity as usize),
+            _ => Ok(self.game_mode()?.players_per_team()),
+        }
+    }
+
+    /// Ranked payout shares of the winning team, empty for an equal split
+    pub fn payout_shares(&self) -> &[u16] {
+        &self.payout_shares_bps[..self.payout_share_count as usize]
+    }
 
+    /// Stores the payout schedule's ranked shares
+    pub fn set_payout_schedule(&mut self, schedule: &PayoutSchedule) -> Result<()> {
+        let shares = schedule.shares();
         require!(
-            self.status == GameStatus::InProgress,
-            WagerError::GameNotInProgress
+            shares.len() <= MAX_PAYOUT_SHARES,
+            WagerError::InvalidPayoutSchedule
         );
+        self.payout_shares_bps = [0; MAX_PAYOUT_SHARES];
+        self.payout_shares_bps[..shares.len()].copy_from_slice(shares);
+        self.payout_share_count = shares.len() as u8;
+        Ok(())
+    }
 
-        match killer_team {
-            0 => self.team_a.player_kills[killer_player_index] += 1,
-            1 => self.team_b.player_kills[killer_player_index] += 1,
-            _ => return Err(error!(WagerError::InvalidTeam)),
-        }
+    /// Applies a session's game configuration on top of `initialize`
+    pub fn apply_terms(&mut self, terms: &SessionTerms) -> Result<()> {
+        self.spawn_earnings_bps = terms.spawn_earnings_bps;
+        self.log_capacity = terms.log_capacity;
+        self.team_capacities = terms.team_capacities();
+        self.set_payout_schedule(&terms.payout_schedule)?;
+        self.max_kills_per_player = terms.max_kills_per_player;
+        self.max_match_kills = terms.max_match_kills;
+        self.series_length = terms.series_length;
+        self.set_refund_policy(terms.refund_policy);
+        self.set_win_condition(terms.win_condition);
+        self.assist_weight_bps = terms.assist_weight_bps;
+        self.min_ready_players = terms.min_ready_players;
+        self.max_stake = terms.max_stake;
+        Ok(())
+    }
 
-        match victim_team {
-            0 => self.team_a.player_spawns[victim_player_index] -= 1,
-            1 => self.team_b.player_spawns[victim_player_index] -= 1,
-            _ => return Err(error!(WagerError::InvalidTeam)),
+    /// Who may join the session
+    pub fn access(&self) -> SessionAccess {
+        match self.access_kind {
+            1 => SessionAccess::JoinCode(self.access_root),
+            2 => SessionAccess::Allowlist(self.access_root),
+            _ => SessionAccess::Open,
         }
+    }
 
-        Ok(())
+    pub fn set_access(&mut self, access: SessionAccess) {
+        (self.access_kind, self.access_root) = match access {
+            SessionAccess::Open => (0, [0; 32]),
+            SessionAccess::JoinCode(hash) => (1, hash),
+            SessionAccess::Allowlist(root) => (2, root),
+        };
     }
 
-    pub fn add_spawns(&mut self, team: u8, player_index: usize) -> Result<()> {
-        match team {
-            0 => self.team_a.player_spawns[player_index] += 10u16,
-            1 => self.team_b.player_spawns[player_index] += 10u16,
-            _ => return Err(error!(WagerError::InvalidTeam)),
+    /// What leaving players and aborts refund
+    pub fn refund_policy(&self) -> Result<RefundPolicy> {
+        RefundPolicy::from_u8(self.refund_policy)
+    }
+
+    pub fn set_refund_policy(&mut self, policy: RefundPolicy) {
+        self.refund_policy = policy as u8;
+    }
+
+    /// Every seated player with what they deposited: the bet plus the cost of
+    /// any spawns bought or topped up after joining. Pledged seats deposited
+    /// nothing and are left out.
+    pub fn player_deposits(&self) -> Result<Vec<(Pubkey, u64)>> {
+        let mut deposits = Vec::new();
+        for (team_index, team) in self.active_teams().iter().enumerate() {
+            for (index, player) in team.players.iter().enumerate() {
+                if *player == Pubkey::default() || team.slots[index].has(SLOT_PLEDGED) {
+                    continue;
+                }
+                deposits.push((*player, self.seat_deposit(team_index, index)?));
+            }
+        }
+        for entrant in self.active_ffa_players() {
+            if entrant.player != Pubkey::default() && !entrant.slot.has(SLOT_PLEDGED) {
+                deposits.push((entrant.player, self.session_bet));
+            }
+        }
+        Ok(deposits)
+    }
+
+    /// What a seated player deposited, as listed by `player_deposits`: zero
+    /// for a pledged seat
+    pub fn deposit_of(&self, player: Pubkey) -> Result<u64> {
+        let (team, index) = self
+            .seat_of(player)
+            .ok_or_else(|| err_with_player(WagerError::PlayerNotFound, &player))?;
+        if self.seat_slot(team, index).has(SLOT_PLEDGED) {
+            return Ok(0);
         }
+        if self.is_free_for_all() {
+            return Ok(self.session_bet);
+        }
+        self.seat_deposit(team, index)
+    }
+
+    /// Price of `count` spawns at one bet per `SPAWNS_PER_PURCHASE`. Only
+    /// counts the bet prices in whole tokens are sold, so a seat's purchased
+    /// spawns always price back to exactly what it paid, see `seat_deposit`.
+    pub fn spawn_price(&self, count: u16) -> Result<u64> {
+        let price = safe_math::safe_multiply(self.session_bet, count as u64)?;
+        require!(
+            price > 0 && price % SPAWNS_PER_PURCHASE as u64 == 0,
+            WagerError::InvalidSpawns
+        );
+        Ok(price / SPAWNS_PER_PURCHASE as u64)
+    }
+
+    /// Bet staked by a team slot plus the cost of the spawns it bought
+    fn seat_deposit(&self, team: usize, index: usize) -> Result<u64> {
+        let spawns_cost = safe_math::proportional_share(
+            self.session_bet,
+            self.teams[team].slots[index].spawns_purchased as u64,
+            SPAWNS_PER_PURCHASE as u64,
+        )?;
+        safe_math::safe_add(self.seat_stake(team, index), spawns_cost)
+    }
+
+    /// Mint of the season pass joiners must hold, if the session is gated
+    pub fn required_pass(&self) -> Option<Pubkey> {
+        (self.requires_pass != Pubkey::default()).then_some(self.requires_pass)
+    }
+
+    pub fn set_required_pass(&mut self, pass_mint: Option<Pubkey>) {
+        self.requires_pass = pass_mint.unwrap_or_default();
+    }
+
+    /// Whether players join for free on a sponsored prize
+    pub fn is_freeroll(&self) -> bool {
+        self.freeroll != 0
+    }
+
+    /// Makes the session a freeroll, which takes no house rake
+    pub fn set_freeroll(&mut self) {
+        self.freeroll = 1;
+        self.fee_bps = 0;
+        self.set_fee_split(&[]);
+    }
+
+    /// Sponsored prize paid out on top of the bets: a freeroll's funding
+    /// and any prize sponsors' deposits
+    pub fn prize_pool(&self) -> Result<u64> {
+        let freeroll_prize = safe_math::safe_add(self.sponsor_prize, self.treasury_prize)?;
+        safe_math::safe_add(freeroll_prize, self.sponsored_amount)
+    }
+
+    /// Records a prize sponsor's deposit before the match starts. Repeat
+    /// deposits by the same wallet add to its entry.
+    pub fn add_sponsored_prize(&mut self, sponsor: Pubkey, amount: u64) -> Result<()> {
+        require!(
+            matches!(self.status()?, SessionStatus::Created | SessionStatus::Filling),
+            WagerError::InvalidGameState
+        );
+        // Pay-to-spawn pots are earned per kill, not split by a winner
+        require!(!self.is_pay_to_spawn(), WagerError::PrizeSponsorshipUnavailable);
+
+        let count = self.prize_sponsor_count as usize;
+        let index = match self.prize_sponsors[..count].iter().position(|key| *key == sponsor) {
+            Some(index) => index,
+            None => {
+                require!(count < MAX_PRIZE_SPONSORS, WagerError::PrizeSponsorLimitReached);
+                self.prize_sponsors[count] = sponsor;
+                self.prize_sponsor_count += 1;
+                count
+            }
+        };
+        self.prize_sponsor_amounts[index] =
+            safe_math::safe_add(self.prize_sponsor_amounts[index], amount)?;
+        self.sponsored_amount = safe_math::safe_add(self.sponsored_amount, amount)?;
         Ok(())
     }
-}
 
-/// Helper function to check if an error is TeamIsFull
+    /// Prize sponsors and what each deposited, in deposit order
+    pub fn prize_sponsors(&self) -> Vec<PrizeSponsor> {
+        (0..self.prize_sponsor_count as usize)
+            .map(|index| PrizeSponsor {
+                sponsor: self.prize_sponsors[index],
+                amount: self.prize_sponsor_amounts[index],
+            })
+            .collect()
+    }
+
+    /// Clears the prize sponsors once their deposits are returned
+    pub fn clear_sponsored_prize(&mut self) {
+        self.prize_sponsor_amounts = [0; MAX_PRIZE_SPONSORS];
+        self.sponsored_amount = 0;
+    }
+
+    /// Adds a deposit to a freeroll's prize, before the session settles
+    pub fn fund_prize(&mut self, amount: u64, from_treasury: bool) -> Result<()> {
+        require!(self.is_freeroll(), WagerError::InvalidFreeroll);
+        require!(
+            matches!(
+                self.status()?,
+                SessionStatus::Created | SessionStatus::Filling | SessionStatus::InProgress
+            ),
+            WagerError::InvalidGameState
+        );
+        let prize = if from_treasury {
+            &mut self.treasury_prize
+        } else {
+            &mut self.sponsor_prize
+        };
+        *prize = safe_math::safe_add(*prize, amount)?;
+        Ok(())
+    }
+
+    /// Snapshots a rake split so config changes don't reroute fees of
+    /// sessions already running
+    pub fn set_fee_split(&mut self, split: &[FeeShare]) {
+        self.fee_split_count = split.len() as u8;
+        for (index, share) in split.iter().enumerate() {
+            self.fee_split_recipients[index] = share.recipient;
+            self.fee_split_bps[index] = share.bps;
+        }
+    }
+
+    /// Recipients sharing the session's rake, empty if the treasury takes it all
+    pub fn fee_split(&self) -> Vec<FeeShare> {
+        (0..self.fee_split_count as usize)
+            .map(|index| FeeShare {
+                recipient: self.fee_split_recipients[index],
+                bps: self.fee_split_bps[index],
+            })
+            .collect()
+    }
+
+    pub fn set_join_window(&mut self, window: Option<JoinWindow>) {
+        if let Some(window) = window {
+            self.join_deadline = window.deadline;
+            self.min_players = window.min_players;
+            self.min_players_per_team = window.min_players_per_team;
+        }
+    }
+
+    /// Rejects joins once the session's join deadline has passed
+    pub fn check_join_open(&self, now: i64) -> Result<()> {
+        if self.join_deadline != 0 {
+            validation::validate_before_deadline(now, self.join_deadline, self.clock_skew_secs)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the session has a join deadline and it has been reached
+    pub fn join_deadline_reached(&self, now: i64) -> bool {
+        self.join_deadline != 0
+            && validation::deadline_reached(now, self.join_deadline, self.clock_skew_secs)
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked != 0
+    }
+
+    /// Number of players currently holding a roster slot
+    pub fn player_count(&self) -> usize {
+        self.get_all_players()
+            .iter()
+            .filter(|player| **player != Pubkey::default())
+            .count()
+    }
+
+    /// Number of players currently seated in a team
+    pub fn team_player_count(&self, team: u8) -> Result<usize> {
+        Ok(self
+            .team(team)?
+            .players
+            .iter()
+            .filter(|player| **player != Pubkey::default())
+            .count())
+    }
+
+    /// Whether enough players joined for a locked session to start, every
+    /// team fielding at least its minimum so matches can run short-handed
+    pub fn has_min_players(&self) -> Result<bool> {
+        if self.player_count() < (self.min_players as usize).max(1) {
+            return Ok(false);
+        }
+        let min_per_team = (self.min_players_per_team as usize).max(1);
+        for team in 0..self.team_count() {
+            if self.team_player_count(team as u8)? < min_per_team {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Accepts a kill report only if it carries the next sequence number, so
+    /// a retried report is rejected instead of counted twice
+    pub fn advance_report_sequence(&mut self, sequence: u64) -> Result<()> {
+        require!(sequence > self.report_sequence, WagerError::DuplicateKillReport);
+        require!(
+            sequence == safe_math::safe_add(self.report_sequence, 1)?,
+            WagerError::KillReportOutOfOrder
+        );
+        self.report_sequence = sequence;
+        Ok(())
+    }
+
+    /// Stores the session and vault bumps found when creating the accounts,
+    /// checking they are the canonical ones for the stored session id
+    pub fn set_bumps(
+        &mut self,
+        game_session: &Pubkey,
+        vault: &Pubkey,
+        bump: u8,
+        vault_bump: u8,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.vault_bump = vault_bump;
+        self.validate_pdas(game_session, vault)
+    }
+
+    /// Re-derives the session and vault PDAs from the stored session id and
+    /// bumps, rejecting anything but the canonical addresses
+    pub fn validate_pdas(&self, game_session: &Pubkey, vault: &Pubkey) -> Result<()> {
+        let session_id = &self.session_id[..self.session_id_len as usize];
+        validation::validate_pda(game_session, &[b"game_session", session_id], self.bump)?;
+        validation::validate_pda(vault, &[b"vault", session_id], self.vault_bump)
+    }
+
+    /// Captain of a team: the recorded captain, or the first seated player
+    /// when none is recorded, e.g. in sessions migrated from older layouts
+    pub fn team_captain(&self, team: u8) -> Result<Pubkey> {
+        let captain = self.captains[team as usize];
+        if captain != Pubkey::default() {
+            return Ok(captain);
+        }
+        Ok(self
+            .team(team)?
+            .players
+            .iter()
+            .copied()
+            .find(|player| *player != Pubkey::default())
+            .unwrap_or_default())
+    }
+
+    /// Hands a team's captaincy to another player on the team
+    pub fn set_team_captain(&mut self, team: u8, captain: Pubkey) -> Result<()> {
+        require!(
+            captain != Pubkey::default() && self.team(team)?.players.contains(&captain),
+            WagerError::PlayerNotFound
+        );
+        self.captains[team as usize] = captain;
+        Ok(())
+    }
+
+    /// Roster slots across every participating team
+    pub fn total_team_slots(&self) -> Result<usize> {
+        (0..self.team_count()).try_fold(0, |total, team| Ok(total + self.team_capacity(team)?))
+    }
+
+    /// Gets an empty slot for a player in the specified team
+    pub fn get_player_empty_slot(&self, team: u8) -> Result<usize> {
+        let player_count = self.team_capacity(team as usize)?;
+        self.team(team)?.get_empty_slot(player_count)
+    }
+
+    /// Checks if every team (or every free-for-all slot) is completely filled
+    pub fn check_all_filled(&self) -> Result<bool> {
+        if self.is_free_for_all() {
+            return Ok(self
+                .active_ffa_players()
+                .iter()
+                .all(|entrant| entrant.player != Pubkey::default()));
+        }
+
+        for (index, team) in self.active_teams().iter().enumerate() {
+            let player_count = self.team_capacity(index)?;
+            if !matches!(team.get_empty_slot(player_count), Err(e) if is_team_full_error(&e)) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    pub fn is_pay_to_spawn(&self) -> bool {
+        matches!(self.game_mode(), Ok(mode) if mode.is_pay_to_spawn())
+    }
+
+    pub fn is_free_for_all(&self) -> bool {
+        matches!(self.game_mode(), Ok(mode) if mode.is_free_for_all())
+    }
+
+    /// Whether spectators can bet on a single winning team
+    pub fn supports_spectators(&self) -> bool {
+        !self.is_pay_to_spawn() && !self.is_free_for_all()
+    }
+
+    /// Native SOL sessions escrow lamports in the vault PDA instead of tokens
+    pub fn is_native(&self) -> bool {
+        self.mint == native_mint::ID
+    }
+
+    /// Seats a player in the first open slot of their team (or the free-for-all
+    /// lobby) and starts the game once every slot is filled
+    pub fn add_player(&mut self, team: u8, player: Pubkey) -> Result<()> {
+        self.seat_player(team, player, self.session_bet)?;
+        self.update_lobby_status()
+    }
+
+    /// Seats a player staking their own bet within the session's range, see
+    /// `check_stake`
+    pub fn add_staked_player(&mut self, team: u8, player: Pubkey, stake: u64) -> Result<()> {
+        self.check_stake(stake)?;
+        self.seat_player(team, player, stake)?;
+        self.update_lobby_status()
+    }
+
+    /// Whether players may stake unequal bets, winners then being paid in
+    /// proportion to their stake
+    pub fn is_stake_weighted(&self) -> bool {
+        self.max_stake > 0
+    }
+
+    /// Checks a joining player's stake: exactly the session bet, or between
+    /// it and `max_stake` in stake-weighted sessions
+    pub fn check_stake(&self, stake: u64) -> Result<()> {
+        let max_stake = self.max_stake.max(self.session_bet);
+        require!(
+            (self.session_bet..=max_stake).contains(&stake),
+            WagerError::InvalidStake
+        );
+        Ok(())
+    }
+
+    /// Bet staked by a team slot, the session bet unless it staked its own
+    pub fn seat_stake(&self, team: usize, index: usize) -> u64 {
+        match self.teams[team].stakes[index] {
+            0 => self.session_bet,
+            stake => stake,
+        }
+    }
+
+    /// Bet a seated player staked
+    pub fn stake_of(&self, player: Pubkey) -> u64 {
+        match self.seat_of(player) {
+            Some((team, index)) if !self.is_free_for_all() => self.seat_stake(team, index),
+            _ => self.session_bet,
+        }
+    }
+
+    /// Total staked across every team, checked against each team's recorded
+    /// `total_bet` so the pot matches what the seats deposited
+    pub fn reconcile_stakes(&self) -> Result<u64> {
+        let mut total = 0u64;
+        for (team_index, team) in self.active_teams().iter().enumerate() {
+            let mut team_total = 0u64;
+            for (index, player) in team.players.iter().enumerate() {
+                if *player != Pubkey::default() {
+                    team_total = safe_math::safe_add(team_total, self.seat_stake(team_index, index))?;
+                }
+            }
+            if team_total != team.total_bet {
+                return Err(err_with_team(WagerError::StakeMismatch, team_index as u8));
+            }
+            total = safe_math::safe_add(total, team_total)?;
+        }
+        Ok(total)
+    }
+
+    /// Seats a player whose bet stays in their own token account under a
+    /// delegate approval until `collect_pledges` pulls it. The session can't
+    /// start while any pledge is outstanding.
+    pub fn add_pledged_player(&mut self, team: u8, player: Pubkey) -> Result<()> {
+        self.seat_player(team, player, self.session_bet)?;
+        let (team, index) = self
+            .seat_of(player)
+            .ok_or_else(|| err_with_player(WagerError::PlayerNotFound, &player))?;
+        self.seat_slot_mut(team, index).set(SLOT_PLEDGED, true);
+        self.update_lobby_status()
+    }
+
+    /// Seated players whose bet is still pledged, in roster order
+    pub fn pledged_players(&self) -> Vec<Pubkey> {
+        self.get_all_players()
+            .into_iter()
+            .filter(|player| self.is_pledged(*player))
+            .collect()
+    }
+
+    /// Whether a seated player's bet is pledged rather than escrowed
+    pub fn is_pledged(&self, player: Pubkey) -> bool {
+        matches!(self.seat_of(player), Some((team, index)) if self.seat_slot(team, index).has(SLOT_PLEDGED))
+    }
+
+    /// Marks a pledged player's bet as collected into the vault
+    pub fn collect_pledge(&mut self, player: Pubkey) -> Result<()> {
+        let (team, index) = self
+            .seat_of(player)
+            .ok_or_else(|| err_with_player(WagerError::PlayerNotFound, &player))?;
+        self.seat_slot_mut(team, index).set(SLOT_PLEDGED, false);
+        Ok(())
+    }
+
+    /// Seats a player staking `stake` in the first open slot of their team (or
+    /// the free-for-all lobby) without moving the session on
+    fn seat_player(&mut self, team: u8, player: Pubkey, stake: u64) -> Result<()> {
+        if self.is_free_for_all() {
+            let empty_index = self.get_free_for_all_empty_slot()?;
+            let entrant = &mut self.ffa_players[empty_index];
+
+            // Race condition protection: verify slot is still empty
+            require!(
+                entrant.player == Pubkey::default(),
+                WagerError::SlotAlreadyOccupied
+            );
+
+            entrant.player = player;
+            entrant.slot.kills = 0;
+        } else {
+            let empty_index = self.get_player_empty_slot(team)?;
+            let selected_team = self.team_mut(team)?;
+
+            // Race condition protection: verify slot is still empty
+            require!(
+                selected_team.players[empty_index] == Pubkey::default(),
+                WagerError::SlotAlreadyOccupied
+            );
+
+            // Add player to the first available slot
+            selected_team.players[empty_index] = player;
+            selected_team.stakes[empty_index] = stake;
+            selected_team.slots[empty_index].spawns = STARTING_SPAWNS;
+            selected_team.slots[empty_index].kills = 0;
+            selected_team.total_bet = safe_math::safe_add(selected_team.total_bet, stake)?;
+
+            // The first player to join a team captains it
+            if self.captains[team as usize] == Pubkey::default() {
+                self.captains[team as usize] = player;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Starts the game once every slot is filled, otherwise keeps it filling
+    fn update_lobby_status(&mut self) -> Result<()> {
+        let next = if self.check_all_filled()? && self.is_ready_to_start() {
+            SessionStatus::InProgress
+        } else {
+            SessionStatus::Filling
+        };
+        self.transition_to(next)
+    }
+
+    /// Team and slot a player is seated in, free-for-all entrants counting
+    /// as the first team
+    fn seat_of(&self, player: Pubkey) -> Option<(usize, usize)> {
+        if self.is_free_for_all() {
+            return self
+                .active_ffa_players()
+                .iter()
+                .position(|entrant| entrant.player == player)
+                .map(|index| (0, index));
+        }
+        self.active_teams().iter().enumerate().find_map(|(team, roster)| {
+            roster
+                .players
+                .iter()
+                .position(|p| *p == player)
+                .map(|index| (team, index))
+        })
+    }
+
+    /// Number of seated players who reported ready
+    pub fn ready_count(&self) -> usize {
+        self.active_slots().filter(|slot| slot.has(SLOT_READY)).count()
+    }
+
+    /// Whether enough players reported ready for the match to start, capped
+    /// at everyone seated, and every pledged bet was collected
+    pub fn is_ready_to_start(&self) -> bool {
+        !self.active_slots().any(|slot| slot.has(SLOT_PLEDGED))
+            && (self.min_ready_players == 0
+                || self.ready_count() >= (self.min_ready_players as usize).min(self.player_count()))
+    }
+
+    /// Records a seated player reporting ready, or withdrawing readiness,
+    /// while the lobby fills. Returns whether the match can now start: the
+    /// lobby is full and enough players are ready.
+    pub fn set_ready(&mut self, player: Pubkey, ready: bool) -> Result<bool> {
+        require!(
+            self.status()? == SessionStatus::Filling,
+            WagerError::InvalidGameState
+        );
+        require!(self.min_ready_players > 0, WagerError::ReadinessNotRequired);
+        let (team, index) = self
+            .seat_of(player)
+            .ok_or_else(|| err_with_player(WagerError::PlayerNotFound, &player))?;
+        self.seat_slot_mut(team, index).set(SLOT_READY, ready);
+        Ok(self.check_all_filled()? && self.is_ready_to_start())
+    }
+
+    /// Moves the session to a new status after validating the transition
+    pub fn transition_to(&mut self, next: SessionStatus) -> Result<()> {
+        validation::validate_status_transition(self.status()?, next)?;
+        if next == SessionStatus::InProgress && self.started_at == 0 {
+            self.started_at = Clock::get()?.unix_timestamp;
+        }
+        self.status = next as u8;
+        if next.is_terminal() {
+            self.settled_at = Clock::get()?.unix_timestamp;
+        }
+        Ok(())
+    }
+
+    /// Enters `Settling`, rejecting concurrent or repeated settlement
+    pub fn begin_settlement(&mut self, now: i64) -> Result<()> {
+        self.transition_to(SessionStatus::Settling)?;
+        self.last_processed_at = now;
+        Ok(())
+    }
+
+    /// Status a session rests in outside of settlement, derived from its roster
+    /// or the result awaiting its dispute window
+    pub fn resting_status(&self) -> Result<SessionStatus> {
+        Ok(if self.dispute_deadline != 0 {
+            SessionStatus::ResultSubmitted
+        } else if self.is_locked() {
+            if self.has_min_players()? && self.is_ready_to_start() {
+                SessionStatus::InProgress
+            } else {
+                SessionStatus::Refundable
+            }
+        } else if self.check_all_filled()? && self.is_ready_to_start() {
+            SessionStatus::InProgress
+        } else if self.is_empty() {
+            SessionStatus::Created
+        } else {
+            SessionStatus::Filling
+        })
+    }
+
+    /// Whether a player currently holds a roster slot
+    pub fn has_player(&self, player: Pubkey) -> bool {
+        self.get_all_players().contains(&player)
+    }
+
+    pub fn get_all_players(&self) -> Vec<Pubkey> {
+        self.active_teams()
+            .iter()
+            .flat_map(|team| team.players.to_vec())
+            .chain(self.active_ffa_players().iter().map(|entrant| entrant.player))
+            .collect()
+    }
+
+    /// Finds the first empty free-for-all slot, if available
+    pub fn get_free_for_all_empty_slot(&self) -> Result<usize> {
+        self.active_ffa_players()
+            .iter()
+            .position(|entrant| entrant.player == Pubkey::default())
+            .ok_or_else(|| error!(WagerError::TeamIsFull))
+    }
+
+    pub fn get_free_for_all_index(&self, player: Pubkey) -> Result<usize> {
+        self.active_ffa_players()
+            .iter()
+            .position(|entrant| entrant.player == player)
+            .ok_or_else(|| err_with_player(WagerError::PlayerNotFound, &player))
+    }
+
+    /// Returns seated free-for-all entrants ordered by kills, ties broken by
+    /// join order
+    pub fn free_for_all_ranking(&self) -> Vec<FreeForAllPlayer> {
+        let mut ranking: Vec<FreeForAllPlayer> = self
+            .active_ffa_players()
+            .iter()
+            .filter(|entrant| entrant.player != Pubkey::default())
+            .copied()
+            .collect();
+        ranking.sort_by_key(|entrant| std::cmp::Reverse(entrant.slot.kills));
+        ranking
+    }
+
+    pub fn get_player_index(&self, team: u8, player: Pubkey) -> Result<usize> {
+        self.team(team)?
+            .players
+            .iter()
+            .position(|p| *p == player)
+            .ok_or_else(|| err_with_player(WagerError::PlayerNotFound, &player))
+    }
+
+    /// Whether the session is played as a best-of-N series
+    pub fn is_series(&self) -> bool {
+        self.series_length > 0
+    }
+
+    /// Round wins a team needs to take the series
+    pub fn series_threshold(&self) -> u8 {
+        self.series_length / 2 + 1
+    }
+
+    /// The team that has won the series, if any
+    pub fn series_winner(&self) -> Option<u8> {
+        let threshold = self.series_threshold();
+        self.round_wins[..self.team_count()]
+            .iter()
+            .position(|wins| *wins >= threshold)
+            .map(|team| team as u8)
+    }
+
+    /// Final score of a team session: series round wins, or kills per team,
+    /// joined in team order, e.g. `2-1`
+    pub fn final_score(&self) -> String {
+        let scores: Vec<String> = if self.is_series() {
+            self.round_wins[..self.team_count()]
+                .iter()
+                .map(|wins| wins.to_string())
+                .collect()
+        } else {
+            self.active_teams()
+                .iter()
+                .map(|team| team.total_kills().to_string())
+                .collect()
+        };
+        scores.join("-")
+    }
+
+    /// Records a series round won by a team
+    pub fn record_round_win(&mut self, team: u8) -> Result<()> {
+        require!(self.is_series(), WagerError::InvalidGameState);
+        require!(
+            self.series_winner().is_none(),
+            WagerError::SeriesAlreadyDecided
+        );
+        validation::validate_team_number(team, self.team_count())?;
+        self.round_wins[team as usize] += 1;
+        self.rounds_played += 1;
+        self.mark_team_active(team)
+    }
+
+    /// Whether a player slot has been eliminated
+    pub fn is_eliminated(&self, team: u8, index: usize) -> bool {
+        self.teams[team as usize].slots[index].has(SLOT_ELIMINATED)
+    }
+
+    /// The only team with players still standing, once every other team has
+    /// been eliminated
+    pub fn last_team_standing(&self) -> Option<u8> {
+        let mut standing = self
+            .active_teams()
+            .iter()
+            .enumerate()
+            .filter(|(team, roster)| {
+                roster.players.iter().enumerate().any(|(index, player)| {
+                    *player != Pubkey::default() && !self.is_eliminated(*team as u8, index)
+                })
+            })
+            .map(|(team, _)| team as u8);
+        match (standing.next(), standing.next()) {
+            (Some(team), None) => Some(team),
+            _ => None,
+        }
+    }
+
+    /// Checks a session whose opponents were all eliminated is only settled
+    /// on the last team standing
+    pub fn check_last_team_standing(&self, winning_team: u8) -> Result<()> {
+        if !self.is_series() {
+            if let Some(team) = self.last_team_standing() {
+                require!(team == winning_team, WagerError::InvalidWinningTeam);
+            }
+        }
+        Ok(())
+    }
+
+    /// Progress of the VRF match setup
+    pub fn vrf_state(&self) -> Result<VrfState> {
+        VrfState::from_u8(self.vrf_state)
+    }
+
+    /// Whether a key may report play for the session: the authority, or the
+    /// reporting key it delegated
+    pub fn is_reporter(&self, key: &Pubkey) -> bool {
+        *key == self.authority || (self.reporter != Pubkey::default() && *key == self.reporter)
+    }
+
+    /// Key kill reports must be signed by: the delegated reporting key, so a
+    /// leaked global signer cannot reach delegated sessions, or else the
+    /// config's global signer
+    pub fn kill_report_signer(&self, global_signer: &Pubkey) -> Pubkey {
+        if self.reporter == Pubkey::default() {
+            *global_signer
+        } else {
+            self.reporter
+        }
+    }
+
+    /// Whether the session's match setup still waits on VRF randomness
+    pub fn awaits_randomness(&self) -> Result<bool> {
+        Ok(self.map_pool_size > 0 && self.vrf_state()? != VrfState::Revealed)
+    }
+
+    /// Stores fulfilled VRF randomness and draws the map and starting side from it
+    pub fn reveal_randomness(&mut self, randomness: [u8; 64]) -> Result<()> {
+        require!(
+            self.vrf_state()? == VrfState::Requested,
+            WagerError::InvalidRandomnessState
+        );
+        let draw = |offset: usize, range: u64| {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&randomness[offset..offset + 8]);
+            (u64::from_le_bytes(word) % range) as u8
+        };
+        let sides = if self.is_free_for_all() {
+            self.ffa_count
+        } else {
+            self.team_count
+        };
+        self.map_index = draw(0, self.map_pool_size as u64);
+        self.starting_team = draw(8, sides as u64);
+        self.randomness = randomness;
+        self.vrf_state = VrfState::Revealed as u8;
+        Ok(())
+    }
+
+    /// Checks a player's rating against the lobby's rating bounds
+    pub fn check_rating(&self, rating: u64) -> Result<()> {
+        require!(
+            rating >= self.min_rating as u64
+                && (self.max_rating == 0 || rating <= self.max_rating as u64),
+            WagerError::RatingOutOfRange
+        );
+        Ok(())
+    }
+
+    /// Finds the rating slot of a player: their roster slot, or their entrant
+    /// slot in team 0 for free-for-all sessions
+    pub fn rating_slot(&self, player: Pubkey) -> Result<(u8, usize)> {
+        if self.is_free_for_all() {
+            return Ok((0, self.get_free_for_all_index(player)?));
+        }
+        self.get_player_slot(player)
+    }
+
+    /// Snapshots the rating a player joined with
+    pub fn record_rating(&mut self, player: Pubkey, rating: u64) -> Result<()> {
+        let (team, index) = self.rating_slot(player)?;
+        self.seat_slot_mut(team as usize, index).rating = rating.min(u16::MAX as u64) as u16;
+        Ok(())
+    }
+
+    /// Joined rating of a slot, unrated sessions migrated from older
+    /// layouts counting as the default rating
+    fn slot_rating(&self, team: usize, index: usize) -> u64 {
+        match self.seat_slot(team, index).rating {
+            0 => DEFAULT_RATING,
+            rating => rating as u64,
+        }
+    }
+
+    /// Average joined rating of every slot other than `slot`
+    fn opponent_rating(&self, slot: (u8, usize)) -> Result<u64> {
+        let slots: Vec<(usize, usize)> = if self.is_free_for_all() {
+            (0..self.active_ffa_players().len())
+                .map(|index| (0, index))
+                .collect()
+        } else {
+            let mut slots = Vec::new();
+            for team in 0..self.team_count() {
+                for index in 0..self.team_capacity(team)? {
+                    slots.push((team, index));
+                }
+            }
+            slots
+        };
+        let opponents: Vec<u64> = slots
+            .into_iter()
+            .filter(|(team, index)| (*team as u8, *index) != slot)
+            .filter(|(team, _)| self.is_free_for_all() || *team != slot.0 as usize)
+            .map(|(team, index)| self.slot_rating(team, index))
+            .collect();
+        require!(!opponents.is_empty(), WagerError::InvalidPlayerCount);
+        let total = opponents
+            .iter()
+            .copied()
+            .try_fold(0u64, safe_math::safe_add)?;
+        safe_math::safe_divide(total, opponents.len() as u64)
+    }
+
+    /// Score of a slot in basis points: a win or loss for team sessions,
+    /// the finishing position for free-for-all entrants
+    fn rating_score_bps(&self, slot: (u8, usize)) -> Result<u64> {
+        if self.is_free_for_all() {
+            let player = self.ffa_players[slot.1].player;
+            let ranking = self.free_for_all_ranking();
+            let rank = ranking
+                .iter()
+                .position(|entrant| entrant.player == player)
+                .ok_or(error!(WagerError::PlayerNotFound))?;
+            let last = ranking.len().saturating_sub(1) as u64;
+            return safe_math::proportional_share(
+                safe_math::BPS_DENOMINATOR,
+                last - rank as u64,
+                last,
+            );
+        }
+        // Every player of a drawn match scores half
+        if self.is_draw() {
+            return Ok(safe_math::BPS_DENOMINATOR / 2);
+        }
+        let winning_team = self
+            .winning_team()
+            .ok_or(error!(WagerError::InvalidWinningTeam))?;
+        Ok(if slot.0 == winning_team {
+            safe_math::BPS_DENOMINATOR
+        } else {
+            0
+        })
+    }
+
+    /// Computes a player's Elo rating change for a session settled on its
+    /// result and marks it applied, rejecting a second application
+    pub fn take_rating_change(&mut self, player: Pubkey, k_factor: u16) -> Result<i64> {
+        require!(!self.is_pay_to_spawn(), WagerError::InvalidGameState);
+        let slot = self.rating_slot(player)?;
+        let counters = self.seat_slot_mut(slot.0 as usize, slot.1);
+        require!(
+            !counters.has(SLOT_RATING_APPLIED),
+            WagerError::RatingAlreadyApplied
+        );
+        counters.set(SLOT_RATING_APPLIED, true);
+
+        if k_factor == 0 {
+            return Ok(0);
+        }
+        safe_math::elo_delta(
+            self.slot_rating(slot.0 as usize, slot.1),
+            self.opponent_rating(slot)?,
+            self.rating_score_bps(slot)?,
+            k_factor,
+        )
+    }
+
+    /// How the session's winner is determined
+    pub fn win_condition(&self) -> Result<WinCondition> {
+        Ok(match self.win_condition {
+            0 => WinCondition::ServerDecision,
+            1 => WinCondition::MostKills,
+            2 => WinCondition::FirstToScore(self.target_score),
+            3 => WinCondition::ObjectiveCapture,
+            _ => return Err(error!(WagerError::InvalidWinCondition)),
+        })
+    }
+
+    pub fn set_win_condition(&mut self, condition: WinCondition) {
+        self.win_condition = condition.index();
+        if let WinCondition::FirstToScore(target) = condition {
+            self.target_score = target;
+        }
+    }
+
+    /// Team whose submitted score already decided the match, if any
+    pub fn score_winner(&self) -> Result<Option<u8>> {
+        let threshold = match self.win_condition()? {
+            WinCondition::FirstToScore(target) => target,
+            WinCondition::ObjectiveCapture => 1,
+            _ => return Ok(None),
+        };
+        Ok(self.team_scores[..self.team_count()]
+            .iter()
+            .position(|score| *score >= threshold)
+            .map(|team| team as u8))
+    }
+
+    /// Records a team's latest score, rejecting scores that go backwards or
+    /// arrive once a team has already won on score
+    pub fn submit_score(&mut self, team: u8, score: u32) -> Result<()> {
+        let condition = self.win_condition()?;
+        require!(condition.is_score_based(), WagerError::InvalidWinCondition);
+        validation::validate_team_number(team, self.team_count())?;
+        require!(self.score_winner()?.is_none(), WagerError::ScoreAlreadyDecided);
+
+        let current = self.team_scores[team as usize];
+        let valid = match condition {
+            WinCondition::ObjectiveCapture => score == 1,
+            _ => score > current,
+        };
+        require!(valid, WagerError::InvalidScore);
+        self.team_scores[team as usize] = score;
+        self.mark_team_active(team)
+    }
+
+    /// Checks a settled winner agrees with the session's win condition
+    pub fn check_win_condition(&self, winning_team: u8) -> Result<()> {
+        let consistent = match self.win_condition()? {
+            WinCondition::ServerDecision => true,
+            WinCondition::MostKills => {
+                let kills: Vec<u64> = self
+                    .active_teams()
+                    .iter()
+                    .map(|team| team.total_kills())
+                    .collect();
+                let most = kills.iter().copied().max().unwrap_or_default();
+                kills.get(winning_team as usize) == Some(&most)
+            }
+            WinCondition::FirstToScore(_) | WinCondition::ObjectiveCapture => {
+                self.score_winner()? == Some(winning_team)
+            }
+        };
+        if !consistent {
+            return Err(err_with_team(WagerError::ResultContradictsWinCondition, winning_team));
+        }
+        Ok(())
+    }
+
+    pub fn match_result(&self) -> Result<MatchResult> {
+        MatchResult::from_u8(self.match_result)
+    }
+
+    /// Whether the session was settled as a draw
+    pub fn is_draw(&self) -> bool {
+        matches!(self.match_result(), Ok(MatchResult::Draw))
+    }
+
+    /// Validates the game server's declared result against the session and
+    /// records it. Only winner-takes-all team sessions have a winner or a
+    /// draw to record; forfeits skip the checks against play since the
+    /// match was never finished. Seated players who never played flag the
+    /// session for review.
+    pub fn record_result(&mut self, declaration: &WinnerDeclaration) -> Result<()> {
+        self.check_idle_players()?;
+        if !self.supports_spectators() {
+            require!(
+                declaration.result != MatchResult::Draw,
+                WagerError::InvalidWinnerDeclaration
+            );
+            return Ok(());
+        }
+
+        match declaration.winning_team() {
+            Some(winning_team) => {
+                validation::validate_team_number(winning_team, self.team_count())
+                    .map_err(|_| err_with_team(WagerError::InvalidWinningTeam, winning_team))?;
+                if declaration.result == MatchResult::Win {
+                    self.check_series_result(winning_team)?;
+                    self.check_win_condition(winning_team)?;
+                    self.check_last_team_standing(winning_team)?;
+                }
+                self.winning_team = winning_team;
+            }
+            None => {
+                self.check_draw()?;
+                self.winning_team = NO_ENTRANT;
+            }
+        }
+        self.match_result = declaration.result as u8;
+        Ok(())
+    }
+
+    /// Whether a declaration matches the result already recorded
+    pub fn matches_result(&self, declaration: &WinnerDeclaration) -> Result<bool> {
+        Ok(self.match_result()? == declaration.result
+            && (declaration.result == MatchResult::Draw
+                || self.winning_team() == Some(declaration.team)))
+    }
+
+    /// Whether a payee of the recorded result is paid as a winner, rather
+    /// than refunded part of their bet as a draw or a forfeiting team
+    pub fn pays_as_winner(&self, player: Pubkey) -> Result<bool> {
+        Ok(match self.match_result()? {
+            MatchResult::Win => true,
+            MatchResult::Draw => false,
+            MatchResult::Forfeit => self
+                .winning_team()
+                .is_some_and(|team| self.get_player_index(team, player).is_ok()),
+        })
+    }
+
+    /// Records play by a team, pushing back when it can be declared
+    /// forfeited for inactivity
+    pub fn mark_team_active(&mut self, team: u8) -> Result<()> {
+        validation::validate_team_number(team, self.team_count())?;
+        self.team_active_at[team as usize] = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Timestamp a team last played, or the match start if it has not
+    pub fn team_last_active(&self, team: u8) -> Result<i64> {
+        validation::validate_team_number(team, self.team_count())?;
+        Ok(self.team_active_at[team as usize].max(self.started_at))
+    }
+
+    /// Team a forfeit by `forfeiting_team` is settled in favour of. Only
+    /// two-team winner-takes-all sessions can be forfeited, so the winner is
+    /// never ambiguous.
+    pub fn forfeit_winner(&self, forfeiting_team: u8) -> Result<u8> {
+        require!(
+            self.supports_spectators() && self.team_count() == 2,
+            WagerError::ForfeitNotSupported
+        );
+        validation::validate_team_number(forfeiting_team, self.team_count())?;
+        Ok(1 - forfeiting_team)
+    }
+
+    /// Checks a team has gone inactive for the session's forfeit timeout
+    /// while its opponent kept playing within it
+    pub fn check_forfeit_inactivity(&self, forfeiting_team: u8, now: i64) -> Result<()> {
+        require!(
+            self.forfeit_timeout_secs > 0,
+            WagerError::ForfeitNotSupported
+        );
+        let winner = self.forfeit_winner(forfeiting_team)?;
+        let idle_since = self.team_last_active(forfeiting_team)?;
+        let opponent_active = self.team_last_active(winner)?;
+        require!(
+            now.saturating_sub(idle_since) >= self.forfeit_timeout_secs
+                && now.saturating_sub(opponent_active) < self.forfeit_timeout_secs,
+            WagerError::TeamNotInactive
+        );
+        Ok(())
+    }
+
+    /// Checks a draw agrees with how the session decides its winner: no
+    /// series winner, no team ahead on kills, and no team won on score
+    fn check_draw(&self) -> Result<()> {
+        // A freeroll's or sponsor's prize always goes to a winner
+        require!(
+            !self.is_freeroll() && self.sponsored_amount == 0,
+            WagerError::DrawNotPossible
+        );
+        if self.is_series() {
+            require!(self.series_winner().is_none(), WagerError::DrawNotPossible);
+        }
+        let tied = match self.win_condition()? {
+            WinCondition::ServerDecision => true,
+            WinCondition::MostKills => {
+                let kills: Vec<u64> = self
+                    .active_teams()
+                    .iter()
+                    .map(|team| team.total_kills())
+                    .collect();
+                let most = kills.iter().copied().max().unwrap_or_default();
+                kills.iter().filter(|team_kills| **team_kills == most).count() > 1
+            }
+            WinCondition::FirstToScore(_) | WinCondition::ObjectiveCapture => {
+                self.score_winner()?.is_none()
+            }
+        };
+        require!(tied, WagerError::DrawNotPossible);
+        Ok(())
+    }
+
+    /// Checks a series session is only settled on the team that won it
+    pub fn check_series_result(&self, winning_team: u8) -> Result<()> {
+        if self.is_series() {
+            require!(
+                self.series_winner() == Some(winning_team),
+                WagerError::SeriesNotDecided
+            );
+        }
+        Ok(())
+    }
+
+    /// Finds the team and slot index a player occupies
+    pub fn get_player_slot(&self, player: Pubkey) -> Result<(u8, usize)> {
+        self.active_teams()
+            .iter()
+            .enumerate()
+            .find_map(|(team, roster)| {
+                roster
+                    .players
+                    .iter()
+                    .position(|p| *p == player)
+                    .map(|index| (team as u8, index))
+            })
+            .ok_or_else(|| err_with_player(WagerError::PlayerNotFound, &player))
+    }
+
+    /// Marks a slot's pay-to-spawn earnings claimed, rejecting a second claim
+    pub fn claim_earnings(&mut self, team: u8, index: usize) -> Result<()> {
+        let slot = &mut self.teams[team as usize].slots[index];
+        require!(
+            !slot.has(SLOT_EARNINGS_CLAIMED),
+            WagerError::EarningsAlreadyClaimed
+        );
+        slot.set(SLOT_EARNINGS_CLAIMED, true);
+        Ok(())
+    }
+
+    /// Gets the kill and death difference for a player in a team
+    pub fn get_kills_and_spawns(&self, player_pubkey: Pubkey) -> Result<u16> {
+        // search in every team and return the kill and death difference
+        for team in self.active_teams().iter() {
+            if let Some(index) = team.players.iter().position(|p| *p == player_pubkey) {
+                let slot = &team.slots[index];
+                return Ok(slot.kills as u16 + slot.spawns as u16);
+            }
+        }
+        Err(err_with_player(WagerError::PlayerNotFound, &player_pubkey))
+    }
+
+    /// A player's pay-to-spawn earning weight, in basis points of one kill:
+    /// their kills and spawns left, plus the assists they made, less the
+    /// assists credited on their own kills. Assists only move weight from
+    /// the killer to the assisting teammates, so the pot stays covered.
+    pub fn earning_weight_bps(&self, player: Pubkey) -> Result<u64> {
+        for team in self.active_teams() {
+            if let Some(index) = team.players.iter().position(|p| *p == player) {
+                let slot = &team.slots[index];
+                let kills_and_spawns = slot.kills as u64 + slot.spawns as u64;
+                let weight = safe_math::safe_add(
+                    safe_math::safe_multiply(kills_and_spawns, safe_math::BPS_DENOMINATOR)?,
+                    safe_math::safe_multiply(
+                        slot.assists as u64,
+                        self.assist_weight_bps as u64,
+                    )?,
+                )?;
+                return safe_math::safe_subtract(
+                    weight,
+                    safe_math::safe_multiply(
+                        slot.assists_conceded as u64,
+                        self.assist_weight_bps as u64,
+                    )?,
+                );
+            }
+        }
+        Err(err_with_player(WagerError::PlayerNotFound, &player))
+    }
+
+    /// Credits the killer's teammates who assisted a kill. Assists must be
+    /// distinct teammates still in play, other than the killer; free-for-all
+    /// kills take none.
+    pub fn credit_assists(
+        &mut self,
+        killer_team: u8,
+        killer: Pubkey,
+        assists: &[Pubkey; MAX_KILL_ASSISTS],
+    ) -> Result<()> {
+        let credited: Vec<Pubkey> = assists
+            .iter()
+            .copied()
+            .filter(|assist| *assist != Pubkey::default())
+            .collect();
+        if credited.is_empty() {
+            return Ok(());
+        }
+        require!(!self.is_free_for_all(), WagerError::InvalidKill);
+
+        let killer_index = self.get_player_index(killer_team, killer)?;
+        for (i, assist) in credited.iter().enumerate() {
+            require!(
+                *assist != killer && !credited[..i].contains(assist),
+                WagerError::InvalidKill
+            );
+            let index = self.get_player_index(killer_team, *assist)?;
+            require!(
+                !self.is_eliminated(killer_team, index),
+                WagerError::PlayerEliminated
+            );
+            let slots = &mut self.teams[killer_team as usize].slots;
+            slots[index].assists = safe_math::safe_increment_u8(slots[index].assists)?;
+            slots[killer_index].assists_conceded =
+                safe_math::safe_increment_u8(slots[killer_index].assists_conceded)?;
+        }
+        Ok(())
+    }
+
+    /// Records a kill, spending one of the victim's spawns or eliminating
+    /// them when none remain, and returns whether the victim was eliminated
+    pub fn add_kill(
+        &mut self,
+        killer_team: u8,
+        killer: Pubkey,
+        victim_team: u8,
+        victim: Pubkey,
+    ) -> Result<bool> {
+        let killer_player_index: usize = self.get_player_index(killer_team, killer)?;
+        let victim_player_index: usize = self.get_player_index(victim_team, victim)?;
+
+        require!(
+            self.status()? == SessionStatus::InProgress,
+            WagerError::GameNotInProgress
+        );
+        require!(
+            !self.is_eliminated(killer_team, killer_player_index)
+                && !self.is_eliminated(victim_team, victim_player_index),
+            WagerError::PlayerEliminated
+        );
+
+        let killer_kills = self.team(killer_team)?.slots[killer_player_index].kills;
+        let kills = safe_math::safe_increment_u8(killer_kills)?;
+        self.count_kill(killer_kills)?;
+        if self.is_series() {
+            let round = (self.rounds_played as usize).min(MAX_SERIES_ROUNDS - 1);
+            let round_kills = &mut self.round_kills[round][killer_team as usize];
+            *round_kills = safe_math::safe_increment_u16(*round_kills)?;
+        }
+
+        self.team_mut(killer_team)?.slots[killer_player_index].kills = kills;
+        self.mark_team_active(killer_team)?;
+        self.track_kill_pattern(
+            killer_team as usize,
+            killer_player_index,
+            kills,
+            victim_team as usize * MAX_PLAYERS_PER_TEAM + victim_player_index,
+        );
+        let victim_slot = &mut self.team_mut(victim_team)?.slots[victim_player_index];
+        if victim_slot.spawns > 0 {
+            victim_slot.spawns -= 1;
+            return Ok(false);
+        }
+        victim_slot.set(SLOT_ELIMINATED, true);
+
+        Ok(true)
+    }
+
+    /// Removes a player from whichever roster slot they occupy
+    pub fn remove_player(&mut self, player: Pubkey) -> Result<()> {
+        // The slot's next occupant reports ready and pays for themselves
+        if let Some((team, index)) = self.seat_of(player) {
+            self.seat_slot_mut(team, index)
+                .set(SLOT_READY | SLOT_PLEDGED, false);
+        }
+
+        let ffa_count = self.ffa_count as usize;
+        if let Some(entrant) = self.ffa_players[..ffa_count]
+            .iter_mut()
+            .find(|e| e.player == player)
+        {
+            entrant.player = Pubkey::default();
+            entrant.slot.kills = 0;
+            return Ok(());
+        }
+
+        let team_count = self.team_count();
+        for team_index in 0..team_count {
+            let seat = self.teams[team_index].players.iter().position(|p| *p == player);
+            if let Some(index) = seat {
+                let stake = self.seat_stake(team_index, index);
+                let team = &mut self.teams[team_index];
+                team.players[index] = Pubkey::default();
+                team.stakes[index] = 0;
+                let slot = &mut team.slots[index];
+                slot.spawns_purchased = 0;
+                slot.top_ups = 0;
+                slot.spawns = 0;
+                slot.kills = 0;
+                team.total_bet = safe_math::safe_subtract(team.total_bet, stake)?;
+
+                // A departing captain passes the role to the next seated player
+                if self.captains[team_index] == player {
+                    self.captains[team_index] = Pubkey::default();
+                }
+                return Ok(());
+            }
+        }
+
+        Err(err_with_player(WagerError::PlayerNotFound, &player))
+    }
+
+    /// Hands a player's roster slot, bet included, to a replacement and
+    /// returns the team of the slot
+    pub fn replace_player(&mut self, player: Pubkey, replacement: Pubkey) -> Result<u8> {
+        let ffa_count = self.ffa_count as usize;
+        if let Some(entrant) = self.ffa_players[..ffa_count]
+            .iter_mut()
+            .find(|e| e.player == player)
+        {
+            entrant.player = replacement;
+            return Ok(0);
+        }
+
+        let team_count = self.team_count();
+        for (team_index, team) in self.teams[..team_count].iter_mut().enumerate() {
+            if let Some(index) = team.players.iter().position(|p| *p == player) {
+                team.players[index] = replacement;
+                if self.captains[team_index] == player {
+                    self.captains[team_index] = replacement;
+                }
+                return Ok(team_index as u8);
+            }
+        }
+
+        Err(err_with_player(WagerError::PlayerNotFound, &player))
+    }
+
+    /// Checks if every roster slot is empty
+    pub fn is_empty(&self) -> bool {
+        self.get_all_players()
+            .iter()
+            .all(|player| *player == Pubkey::default())
+    }
+
+    pub fn add_free_for_all_kill(&mut self, killer: Pubkey, victim: Pubkey) -> Result<()> {
+        let killer_index = self.get_free_for_all_index(killer)?;
+        let victim_index = self.get_free_for_all_index(victim)?;
+
+        require!(
+            self.status()? == SessionStatus::InProgress,
+            WagerError::GameNotInProgress
+        );
+        require!(killer != victim, WagerError::InvalidKill);
+
+        let killer_kills = self.ffa_players[killer_index].slot.kills;
+        let kills = safe_math::safe_increment_u8(killer_kills)?;
+        self.count_kill(killer_kills)?;
+        self.ffa_players[killer_index].slot.kills = kills;
+        self.track_kill_pattern(0, killer_index, kills, victim_index);
+
+        Ok(())
+    }
+
+    /// Tracks who a killer's kills land on, flagging kill farming once they
+    /// reach the threshold all against the same victim. `victim` numbers
+    /// slots across every team's roster, or entrants in free-for-all sessions.
+    fn track_kill_pattern(&mut self, team: usize, killer_index: usize, kills: u8, victim: usize) {
+        let victim_code = victim as u8 + 1;
+        let slot = self.seat_slot_mut(team, killer_index);
+        if slot.first_victim == 0 {
+            slot.first_victim = victim_code;
+        } else if slot.first_victim != victim_code {
+            slot.set(SLOT_VARIED_VICTIMS, true);
+        }
+        if kills >= KILL_FARMING_THRESHOLD && !slot.has(SLOT_VARIED_VICTIMS) {
+            self.audit_flags |= AUDIT_KILL_FARMING;
+        }
+    }
+
+    /// Flags a team session whose match had kills reported but left a seated
+    /// player without a kill or a spent spawn
+    fn check_idle_players(&mut self) -> Result<()> {
+        if self.is_free_for_all() || self.match_kills == 0 {
+            return Ok(());
+        }
+        for team in 0..self.team_count() {
+            let roster = &self.teams[team];
+            let idle = (0..self.team_capacity(team)?).any(|index| {
+                let slot = &roster.slots[index];
+                roster.players[index] != Pubkey::default()
+                    && slot.kills == 0
+                    && slot.spawns >= STARTING_SPAWNS.saturating_add(slot.spawns_purchased)
+            });
+            if idle {
+                self.audit_flags |= AUDIT_IDLE_PLAYER;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether suspicious play was detected, so the result must wait out a
+    /// reviewed dispute window instead of paying out instantly
+    pub fn is_flagged(&self) -> bool {
+        self.audit_flags != 0
+    }
+
+    /// Dispute window a submitted result gets: the session's own, extended
+    /// for flagged sessions so the arbiter can review them
+    pub fn result_dispute_window(&self) -> i64 {
+        if self.is_flagged() {
+            self.dispute_window_secs.max(AUDIT_REVIEW_SECS)
+        } else {
+            self.dispute_window_secs
+        }
+    }
+
+    /// Counts a kill against the session's caps, given the killer's kills so far
+    fn count_kill(&mut self, killer_kills: u8) -> Result<()> {
+        require!(
+            self.max_kills_per_player == 0 || (killer_kills as u16) < self.max_kills_per_player,
+            WagerError::KillCapExceeded
+        );
+        require!(
+            self.max_match_kills == 0 || self.match_kills < self.max_match_kills,
+            WagerError::KillCapExceeded
+        );
+        self.match_kills = safe_math::safe_increment_u16(self.match_kills)?;
+        Ok(())
+    }
+
+    /// Counts a kill record against the per-slot rate limit
+    pub fn record_kill_rate(&mut self, slot: u64) -> Result<()> {
+        if slot != self.last_kill_slot {
+            self.last_kill_slot = slot;
+            self.kills_this_slot = 0;
+        }
+        require!(
+            self.kills_this_slot < MAX_KILLS_PER_SLOT,
+            WagerError::KillRateExceeded
+        );
+        self.kills_this_slot += 1;
+        Ok(())
+    }
+
+    /// Spawns a player has left before their next death eliminates them
+    pub fn spawns_remaining(&self, team: u8, player_index: usize) -> Result<u8> {
+        Ok(self.team(team)?.slots[player_index].spawns)
+    }
+
+    /// Adds purchased spawns to a player's budget. Eliminated players are out
+    /// of the match for good and can't buy their way back in.
+    pub fn add_spawns(&mut self, team: u8, player_index: usize, count: u16) -> Result<()> {
+        require!(
+            !self.is_eliminated(team, player_index),
+            WagerError::PlayerEliminated
+        );
+        let count = u8::try_from(count).map_err(|_| error!(WagerError::CounterOverflow))?;
+        let slot = &mut self.team_mut(team)?.slots[player_index];
+        slot.spawns = safe_math::safe_add_u8(slot.spawns, count)?;
+        slot.spawns_purchased = safe_math::safe_add_u8(slot.spawns_purchased, count)?;
+        Ok(())
+    }
+
+    /// Tops up a player's spawn budget by `multiple` base bets, within the
+    /// session's top-up allowance, returning the spawns granted
+    pub fn top_up(&mut self, team: u8, player_index: usize, multiple: u8) -> Result<u16> {
+        let topped_up = self.team(team)?.slots[player_index]
+            .top_ups
+            .checked_add(multiple)
+            .filter(|total| *total <= self.max_top_up_multiple)
+            .ok_or(error!(WagerError::TopUpLimitExceeded))?;
+        let spawns = SPAWNS_PER_PURCHASE
+            .checked_mul(multiple as u16)
+            .ok_or(error!(WagerError::ArithmeticOverflow))?;
+        self.add_spawns(team, player_index, spawns)?;
+        self.team_mut(team)?.slots[player_index].top_ups = topped_up;
+        Ok(spawns)
+    }
+}
+
+/// Roster slots a team had up to `APPEND_ONLY_SESSION_VERSION`
+pub const V42_PLAYERS_PER_TEAM: usize = 16;
+
+/// `Team` as laid out up to `APPEND_ONLY_SESSION_VERSION`
+#[zero_copy]
+pub struct TeamV42 {
+    pub players: [Pubkey; V42_PLAYERS_PER_TEAM],
+    pub total_bet: u64,
+    pub player_spawns: [u16; V42_PLAYERS_PER_TEAM],
+    pub player_kills: [u16; V42_PLAYERS_PER_TEAM],
+}
+
+/// `FreeForAllPlayer` as laid out up to `APPEND_ONLY_SESSION_VERSION`
+#[zero_copy]
+pub struct FreeForAllPlayerV42 {
+    pub player: Pubkey,
+    pub kills: u16,
+}
+
+/// `GameSession` as laid out at `APPEND_ONLY_SESSION_VERSION`, kept to
+/// convert sessions of that layout. Its per-player counters and bitmasks
+/// sit in per-team arrays, free-for-all entrants using the first row.
+#[zero_copy]
+pub struct GameSessionV42 {
+    pub version: u8,
+    pub session_id_len: u8,
+    pub game_mode: u8,
+    pub status: u8,
+    pub team_count: u8,
+    pub winning_team: u8,
+    pub bump: u8,
+    pub vault_bump: u8,
+    pub session_id: [u8; 32],
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub session_bet: u64,
+    pub created_at: i64,
+    pub session_expiry: i64,
+    pub last_processed_at: i64,
+    pub last_kill_round: u64,
+    pub settled_at: i64,
+    pub teams: [TeamV42; MAX_TEAMS],
+    pub ffa_players: [FreeForAllPlayerV42; MAX_FREE_FOR_ALL_PLAYERS],
+    pub spawn_earnings_bps: u16,
+    pub fee_bps: u16,
+    pub distribution_cursor: u16,
+    pub vault_token_bump: u8,
+    pub ffa_count: u8,
+    pub log_capacity: u16,
+    pub padding: [u8; 6],
+    pub dispute_window_secs: i64,
+    pub dispute_deadline: i64,
+    pub team_capacities: [u8; MAX_TEAMS],
+    pub payout_share_count: u8,
+    pub payout_padding: u8,
+    pub payout_shares_bps: [u16; MAX_PAYOUT_SHARES],
+    pub payout_padding_tail: [u8; 6],
+    pub access_kind: u8,
+    pub access_padding: [u8; 7],
+    pub access_root: [u8; 32],
+    pub last_kill_slot: u64,
+    pub kills_this_slot: u16,
+    pub kill_rate_padding: [u8; 6],
+    pub max_kills_per_player: u16,
+    pub max_match_kills: u16,
+    pub match_kills: u16,
+    pub kill_cap_padding: [u8; 2],
+    pub earnings_claimed: [u16; MAX_TEAMS],
+    pub series_length: u8,
+    pub rounds_played: u8,
+    pub round_wins: [u8; MAX_TEAMS],
+    pub series_padding: [u8; 6],
+    pub round_kills: [[u16; MAX_TEAMS]; MAX_SERIES_ROUNDS],
+    pub max_top_up_multiple: u8,
+    pub top_up_padding: [u8; 7],
+    pub top_ups: [[u8; V42_PLAYERS_PER_TEAM]; MAX_TEAMS],
+    pub spawns_purchased: [[u16; V42_PLAYERS_PER_TEAM]; MAX_TEAMS],
+    pub eliminated: [u16; MAX_TEAMS],
+    pub vrf_seed: [u8; 32],
+    pub randomness: [u8; 64],
+    pub map_pool_size: u8,
+    pub vrf_state: u8,
+    pub map_index: u8,
+    pub starting_team: u8,
+    pub vrf_padding: [u8; 4],
+    pub min_rating: u16,
+    pub max_rating: u16,
+    pub rating_padding: [u8; 4],
+    pub ratings: [[u16; V42_PLAYERS_PER_TEAM]; MAX_TEAMS],
+    pub rating_applied: [u16; MAX_TEAMS],
+    pub refund_policy: u8,
+    pub refund_padding: u8,
+    pub creator_penalty_bps: u16,
+    pub refund_padding_tail: [u8; 4],
+    pub reporter: Pubkey,
+    pub requires_pass: Pubkey,
+    pub report_sequence: u64,
+    pub captains: [Pubkey; MAX_TEAMS],
+    pub creation_fee: u64,
+    pub join_deadline: i64,
+    pub min_players: u8,
+    pub locked: u8,
+    pub join_padding: [u8; 6],
+    pub min_players_per_team: u8,
+    pub short_handed_padding: [u8; 7],
+    pub fee_split_recipients: [Pubkey; MAX_FEE_SPLIT_RECIPIENTS],
+    pub fee_split_bps: [u16; MAX_FEE_SPLIT_RECIPIENTS],
+    pub fee_split_count: u8,
+    pub fee_split_padding: [u8; 7],
+    pub win_condition: u8,
+    pub win_padding: [u8; 3],
+    pub target_score: u32,
+    pub team_scores: [u32; MAX_TEAMS],
+    pub match_result: u8,
+    pub draw_padding: u8,
+    pub draw_fee_bps: u16,
+    pub draw_padding_tail: [u8; 4],
+    pub started_at: i64,
+    pub team_active_at: [i64; MAX_TEAMS],
+    pub forfeit_timeout_secs: i64,
+    pub forfeit_penalty_bps: u16,
+    pub forfeit_padding: [u8; 6],
+    pub audit_flags: u8,
+    pub bond_slashed: u8,
+    pub audit_padding: [u8; 6],
+    pub kill_victims: [[u8; V42_PLAYERS_PER_TEAM]; MAX_TEAMS],
+    pub varied_victims: [u16; MAX_TEAMS],
+    pub template: Pubkey,
+    pub freeroll: u8,
+    pub freeroll_padding: [u8; 7],
+    pub sponsor_prize: u64,
+    pub treasury_prize: u64,
+    pub prize_sponsors: [Pubkey; MAX_PRIZE_SPONSORS],
+    pub prize_sponsor_amounts: [u64; MAX_PRIZE_SPONSORS],
+    pub sponsored_amount: u64,
+    pub prize_sponsor_count: u8,
+    pub prize_sponsor_padding: [u8; 7],
+    pub player_assists: [[u16; V42_PLAYERS_PER_TEAM]; MAX_TEAMS],
+    pub assists_conceded: [[u16; V42_PLAYERS_PER_TEAM]; MAX_TEAMS],
+    pub assist_weight_bps: u16,
+    pub assist_padding: [u8; 6],
+    pub ready: [u16; MAX_TEAMS],
+    pub min_ready_players: u8,
+    pub ready_padding: [u8; 7],
+    pub kicked_players: [Pubkey; MAX_KICKED_PLAYERS],
+    pub kicked_cursor: u8,
+    pub kicked_padding: [u8; 7],
+    pub pledged: [u16; MAX_TEAMS],
+    pub stakes: [[u64; V42_PLAYERS_PER_TEAM]; MAX_TEAMS],
+    pub max_stake: u64,
+    pub clock_skew_secs: i64,
+    pub receipted: [u16; MAX_TEAMS],
+    pub settlement_table: Pubkey,
+}
+
+impl GameSessionV42 {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + std::mem::size_of::<GameSessionV42>();
+
+    /// Writes the session into the current layout, moving each slot's
+    /// counters and bitmask bits into its `PlayerSlot`. Fails with
+    /// `CounterOverflow` if a counter doesn't fit a byte.
+    pub fn upgrade(&self, session: &mut GameSession) -> Result<()> {
+        session.version = GAME_SESSION_VERSION;
+        session.session_id_len = self.session_id_len;
+        session.game_mode = self.game_mode;
+        session.status = self.status;
+        session.team_count = self.team_count;
+        session.winning_team = self.winning_team;
+        session.bump = self.bump;
+        session.vault_bump = self.vault_bump;
+        session.session_id = self.session_id;
+        session.authority = self.authority;
+        session.mint = self.mint;
+        session.session_bet = self.session_bet;
+        session.created_at = self.created_at;
+        session.session_expiry = self.session_expiry;
+        session.last_processed_at = self.last_processed_at;
+        session.last_kill_round = self.last_kill_round;
+        session.settled_at = self.settled_at;
+        session.spawn_earnings_bps = self.spawn_earnings_bps;
+        session.fee_bps = self.fee_bps;
+        session.distribution_cursor = self.distribution_cursor;
+        session.vault_token_bump = self.vault_token_bump;
+        session.ffa_count = self.ffa_count;
+        session.log_capacity = self.log_capacity;
+        session.padding = self.padding;
+        session.dispute_window_secs = self.dispute_window_secs;
+        session.dispute_deadline = self.dispute_deadline;
+        session.team_capacities = self.team_capacities;
+        session.payout_share_count = self.payout_share_count;
+        session.payout_padding = self.payout_padding;
+        session.payout_shares_bps = self.payout_shares_bps;
+        session.payout_padding_tail = self.payout_padding_tail;
+        session.access_kind = self.access_kind;
+        session.access_padding = self.access_padding;
+        session.access_root = self.access_root;
+        session.last_kill_slot = self.last_kill_slot;
+        session.kills_this_slot = self.kills_this_slot;
+        session.kill_rate_padding = self.kill_rate_padding;
+        session.max_kills_per_player = self.max_kills_per_player;
+        session.max_match_kills = self.max_match_kills;
+        session.match_kills = self.match_kills;
+        session.kill_cap_padding = self.kill_cap_padding;
+        session.series_length = self.series_length;
+        session.rounds_played = self.rounds_played;
+        session.round_wins = self.round_wins;
+        session.series_padding = self.series_padding;
+        session.round_kills = self.round_kills;
+        session.max_top_up_multiple = self.max_top_up_multiple;
+        session.top_up_padding = self.top_up_padding;
+        session.vrf_seed = self.vrf_seed;
+        session.randomness = self.randomness;
+        session.map_pool_size = self.map_pool_size;
+        session.vrf_state = self.vrf_state;
+        session.map_index = self.map_index;
+        session.starting_team = self.starting_team;
+        session.vrf_padding = self.vrf_padding;
+        session.min_rating = self.min_rating;
+        session.max_rating = self.max_rating;
+        session.rating_padding = self.rating_padding;
+        session.refund_policy = self.refund_policy;
+        session.refund_padding = self.refund_padding;
+        session.creator_penalty_bps = self.creator_penalty_bps;
+        session.refund_padding_tail = self.refund_padding_tail;
+        session.reporter = self.reporter;
+        session.requires_pass = self.requires_pass;
+        session.report_sequence = self.report_sequence;
+        session.captains = self.captains;
+        session.creation_fee = self.creation_fee;
+        session.join_deadline = self.join_deadline;
+        session.min_players = self.min_players;
+        session.locked = self.locked;
+        session.join_padding = self.join_padding;
+        session.min_players_per_team = self.min_players_per_team;
+        session.short_handed_padding = self.short_handed_padding;
+        session.fee_split_recipients = self.fee_split_recipients;
+        session.fee_split_bps = self.fee_split_bps;
+        session.fee_split_count = self.fee_split_count;
+        session.fee_split_padding = self.fee_split_padding;
+        session.win_condition = self.win_condition;
+        session.win_padding = self.win_padding;
+        session.target_score = self.target_score;
+        session.team_scores = self.team_scores;
+        session.match_result = self.match_result;
+        session.draw_padding = self.draw_padding;
+        session.draw_fee_bps = self.draw_fee_bps;
+        session.draw_padding_tail = self.draw_padding_tail;
+        session.started_at = self.started_at;
+        session.team_active_at = self.team_active_at;
+        session.forfeit_timeout_secs = self.forfeit_timeout_secs;
+        session.forfeit_penalty_bps = self.forfeit_penalty_bps;
+        session.forfeit_padding = self.forfeit_padding;
+        session.audit_flags = self.audit_flags;
+        session.bond_slashed = self.bond_slashed;
+        session.audit_padding = self.audit_padding;
+        session.template = self.template;
+        session.freeroll = self.freeroll;
+        session.freeroll_padding = self.freeroll_padding;
+        session.sponsor_prize = self.sponsor_prize;
+        session.treasury_prize = self.treasury_prize;
+        session.prize_sponsors = self.prize_sponsors;
+        session.prize_sponsor_amounts = self.prize_sponsor_amounts;
+        session.sponsored_amount = self.sponsored_amount;
+        session.prize_sponsor_count = self.prize_sponsor_count;
+        session.prize_sponsor_padding = self.prize_sponsor_padding;
+        session.assist_weight_bps = self.assist_weight_bps;
+        session.assist_padding = self.assist_padding;
+        session.min_ready_players = self.min_ready_players;
+        session.ready_padding = self.ready_padding;
+        session.max_stake = self.max_stake;
+        session.clock_skew_secs = self.clock_skew_secs;
+        session.settlement_table = self.settlement_table;
+
+        for (team, legacy) in self.teams.iter().enumerate() {
+            let roster = &mut session.teams[team];
+            roster.total_bet = legacy.total_bet;
+            for index in 0..V42_PLAYERS_PER_TEAM {
+                roster.players[index] = legacy.players[index];
+                roster.stakes[index] = self.stakes[team][index];
+                let slot = &mut roster.slots[index];
+                slot.spawns = narrow_counter(legacy.player_spawns[index])?;
+                slot.kills = narrow_counter(legacy.player_kills[index])?;
+                slot.spawns_purchased = narrow_counter(self.spawns_purchased[team][index])?;
+                slot.top_ups = self.top_ups[team][index];
+                slot.assists = narrow_counter(self.player_assists[team][index])?;
+                slot.assists_conceded = narrow_counter(self.assists_conceded[team][index])?;
+                slot.set(SLOT_ELIMINATED, self.eliminated[team] & (1 << index) != 0);
+                slot.set(
+                    SLOT_EARNINGS_CLAIMED,
+                    self.earnings_claimed[team] & (1 << index) != 0,
+                );
+            }
+        }
+        for (index, legacy) in self.ffa_players.iter().enumerate() {
+            session.ffa_players[index].player = legacy.player;
+            session.ffa_players[index].slot.kills = narrow_counter(legacy.kills)?;
+        }
+
+        // Ratings, victims, readiness and pledges of free-for-all entrants
+        // were kept in the first row
+        let free_for_all = session.is_free_for_all();
+        for row in 0..MAX_TEAMS {
+            for index in 0..V42_PLAYERS_PER_TEAM {
+                let slot = match (free_for_all, row) {
+                    (false, _) => &mut session.teams[row].slots[index],
+                    (true, 0) if index < MAX_FREE_FOR_ALL_PLAYERS => {
+                        &mut session.ffa_players[index].slot
+                    }
+                    _ => continue,
+                };
+                slot.rating = self.ratings[row][index];
+                slot.first_victim = match self.kill_victims[row][index] {
+                    0 => 0,
+                    _ if free_for_all => self.kill_victims[row][index],
+                    code => {
+                        let victim = (code - 1) as usize;
+                        let team = victim / V42_PLAYERS_PER_TEAM;
+                        (team * MAX_PLAYERS_PER_TEAM + victim % V42_PLAYERS_PER_TEAM + 1) as u8
+                    }
+                };
+                let bit = 1 << index;
+                slot.set(SLOT_RATING_APPLIED, self.rating_applied[row] & bit != 0);
+                slot.set(SLOT_VARIED_VICTIMS, self.varied_victims[row] & bit != 0);
+                slot.set(SLOT_READY, self.ready[row] & bit != 0);
+                slot.set(SLOT_PLEDGED, self.pledged[row] & bit != 0);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Narrows a legacy u16 counter to a `PlayerSlot` byte
+fn narrow_counter(counter: u16) -> Result<u8> {
+    u8::try_from(counter).map_err(|_| error!(WagerError::CounterOverflow))
+}
+
+/// Escrow vault PDA that owns the session's token account and tracks fund flows
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    pub game_session: Pubkey, // Session or tournament this vault escrows funds for
+    pub total_deposited: u64, // Total tokens deposited by players
+    pub total_paid_out: u64,  // Total tokens paid out as winnings
+    pub total_refunded: u64,  // Total tokens refunded to players
+    pub total_fees: u64,      // Total tokens collected as protocol fees
+}
+
+impl Vault {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8;
+
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposited = safe_math::safe_add(self.total_deposited, amount)?;
+        Ok(())
+    }
+
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.total_paid_out = safe_math::safe_add(self.total_paid_out, amount)?;
+        Ok(())
+    }
+
+    pub fn record_refund(&mut self, amount: u64) -> Result<()> {
+        self.total_refunded = safe_math::safe_add(self.total_refunded, amount)?;
+        Ok(())
+    }
+
+    pub fn record_fee(&mut self, amount: u64) -> Result<()> {
+        self.total_fees = safe_math::safe_add(self.total_fees, amount)?;
+        Ok(())
+    }
+
+    /// Tokens the vault should still hold according to its ledger
+    pub fn remaining(&self) -> Result<u64> {
+        let released = safe_math::safe_add(self.total_paid_out, self.total_refunded)?;
+        let released = safe_math::safe_add(released, self.total_fees)?;
+        safe_math::safe_subtract(self.total_deposited, released)
+    }
+
+    /// Enforces deposits == payouts + refunds + fees + remaining, with the remaining
+    /// amount fully backed by the token balance (unsolicited transfers into the
+    /// vault must not be able to block settlement)
+    pub fn check_invariant(&self, token_balance: u64) -> Result<()> {
+        let remaining = self.remaining()?;
+        if remaining > token_balance {
+            msg!("Escrow balance: {}", token_balance);
+            return Err(err_with_amount(WagerError::VaultInvariantViolated, remaining));
+        }
+        Ok(())
+    }
+}
+
+/// Program-wide configuration managed by the admin
+#[account]
+#[derive(InitSpace, Default)]
+pub struct ProgramConfig {
+    pub authority: Pubkey,         // Admin allowed to update the config
+    pub min_bet: u64,              // Minimum bet amount per player
+    pub max_bet: u64,              // Maximum bet amount per player
+    pub session_timeout_secs: i64, // Lifetime of a session before timeout refunds open
+    pub paused: bool,              // Circuit breaker blocking state-mutating instructions
+    pub bump: u8,                  // PDA bump
+    pub kill_report_signer: Pubkey, // Game server key that signs kill reports
+    pub fee_bps: u16,              // House rake taken from winnings, in basis points
+    pub cancellation_fee_bps: u16, // Fee withheld when leaving a filling session, in basis points
+    pub usd_min_bet: u64,          // Minimum bet value in micro-USD, 0 when unused
+    pub usd_max_bet: u64,          // Maximum bet value in micro-USD, 0 disables USD bounds
+    pub max_price_age_secs: u64,   // Oldest oracle price accepted for USD bounds
+    pub arbiter: Pubkey,           // Key allowed to override results during the dispute window
+    pub dispute_window_secs: i64,  // Delay between result submission and payout, 0 disables
+    #[max_len(MAX_GAME_SERVER_KEYS)]
+    pub game_server_keys: Vec<Pubkey>, // Game server signing set for sensitive operations
+    pub game_server_threshold: u8, // Approvals from the signing set required for sensitive operations
+    pub referral_bps: u16,         // Referrer's cut of the rake on each referred bet, in basis points
+    pub max_top_up_multiple: u8,   // Base bets a pay-to-spawn player may top up mid-session, 0 disables
+    pub map_pool_size: u8,         // Maps VRF match setup draws from, 0 disables VRF setup
+    pub jackpot_bps: u16,          // Share of each session's rake routed into the jackpot, in basis points
+    pub jackpot_interval: u64,     // Winner-takes-all team sessions per jackpot award, 0 never awards
+    pub rewards_mint: Pubkey,      // Mint streak bonuses are paid in from the rewards vault
+    pub streak_milestone: u16,     // Consecutive wins that earn a streak bonus, 0 disables bonuses
+    pub streak_bonus: u64,         // Tokens paid for each streak milestone reached
+    pub elo_k_factor: u16,         // Largest rating change a single session can cause, 0 freezes ratings
+    pub creator_penalty_bps: u16,  // Penalty per player, in bps of the bet, a creator pays for aborting a full forfeit-policy lobby
+    #[max_len(MAX_TROPHY_URI_LEN)]
+    pub trophy_uri: String,        // Base URI of winner trophy metadata, empty disables trophies
+    #[max_len(MAX_CPI_CALLERS)]
+    pub cpi_callers: Vec<Pubkey>,  // Programs allowed to create and join sessions by CPI, empty allows any
+    pub creation_fee_lamports: u64, // Lamports a creator deposits into the treasury per session, 0 disables
+    pub max_exposure: u64,         // Total bet a wallet may have open across sessions, 0 disables the cap
+    #[max_len(MAX_FEE_SPLIT_RECIPIENTS)]
+    pub fee_split: Vec<FeeShare>,  // Recipients splitting the house rake, empty sends it all to the treasury
+    pub draw_fee_bps: u16,         // Rake withheld from each bet returned in a draw, 0 returns bets in full
+    pub forfeit_timeout_secs: i64, // Inactivity after which a team can be declared forfeited by anyone, 0 disables
+    pub forfeit_penalty_bps: u16,  // Share of each forfeiting player's bet paid to the winners, the rest is refunded
+    #[max_len(MAX_FREEROLL_SPONSORS)]
+    pub freeroll_sponsors: Vec<Pubkey>, // Wallets besides the authority allowed to create freerolls
+    pub held_creation_fees: u64,   // Creation fees in the treasury still refundable to their creators
+    #[max_len(MAX_YIELD_PROGRAMS)]
+    pub yield_programs: Vec<Pubkey>, // Yield adapters tournament escrow may be parked in, empty disables parking
+    pub yield_treasury_bps: u16,   // Treasury's share of yield earned on parked escrow, in basis points
+    pub clock_skew_secs: i64,      // Grace either side of deadlines for cluster clock drift, at most MAX_CLOCK_SKEW_SECS
+    pub pending_authority: Pubkey, // Admin proposed to take over, who must accept before the handover, default when none
+    pub bond_slash_bps: u16,       // Share of an operator's bond slashed to the wronged players when the arbiter overturns its result
+    #[max_len(MAX_ARBITERS)]
+    pub arbiters: Vec<Pubkey>,     // Council voting on disputes, a majority of which corrects a result, empty disables voting
+    pub commit_reveal_results: bool, // Whether results must be committed to in an earlier slot than they are revealed
+}
+
+impl ProgramConfig {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 1 + 1 + 32 + 2 + 2 + 8 + 8 + 8 + 32 + 8
+        + 4 + 32 * MAX_GAME_SERVER_KEYS + 1 + 2 + 1 + 1 + 2 + 8 + 32 + 2 + 8 + 2 + 2
+        + 4 + MAX_TROPHY_URI_LEN + 4 + 32 * MAX_CPI_CALLERS + 8 + 8 + 8
+        + 4 + (32 + 2) * MAX_FEE_SPLIT_RECIPIENTS + 2 + 8 + 2 + 4 + 32 * MAX_FREEROLL_SPONSORS
+        + 4 + 32 * MAX_YIELD_PROGRAMS + 2 + 8 + 32 + 2 + 4 + 32 * MAX_ARBITERS + 1;
+
+    /// Applies admin-supplied parameters to the config
+    pub fn apply(&mut self, params: &ConfigParams) {
+        self.min_bet = params.min_bet;
+        self.max_bet = params.max_bet;
+        self.session_timeout_secs = params.session_timeout_secs;
+        self.kill_report_signer = params.kill_report_signer;
+        self.fee_bps = params.fee_bps;
+        self.cancellation_fee_bps = params.cancellation_fee_bps;
+        self.usd_min_bet = params.usd_min_bet;
+        self.usd_max_bet = params.usd_max_bet;
+        self.max_price_age_secs = params.max_price_age_secs;
+        self.arbiter = params.arbiter;
+        self.dispute_window_secs = params.dispute_window_secs;
+        self.game_server_keys = params.game_server_keys.clone();
+        self.game_server_threshold = params.game_server_threshold;
+        self.referral_bps = params.referral_bps;
+        self.max_top_up_multiple = params.max_top_up_multiple;
+        self.map_pool_size = params.map_pool_size;
+        self.jackpot_bps = params.jackpot_bps;
+        self.jackpot_interval = params.jackpot_interval;
+        self.rewards_mint = params.rewards_mint;
+        self.streak_milestone = params.streak_milestone;
+        self.streak_bonus = params.streak_bonus;
+        self.elo_k_factor = params.elo_k_factor;
+        self.creator_penalty_bps = params.creator_penalty_bps;
+        self.trophy_uri = params.trophy_uri.clone();
+        self.cpi_callers = params.cpi_callers.clone();
+        self.creation_fee_lamports = params.creation_fee_lamports;
+        self.max_exposure = params.max_exposure;
+        self.fee_split = params.fee_split.clone();
+        self.draw_fee_bps = params.draw_fee_bps;
+        self.forfeit_timeout_secs = params.forfeit_timeout_secs;
+        self.forfeit_penalty_bps = params.forfeit_penalty_bps;
+        self.freeroll_sponsors = params.freeroll_sponsors.clone();
+        self.yield_programs = params.yield_programs.clone();
+        self.yield_treasury_bps = params.yield_treasury_bps;
+        self.clock_skew_secs = params.clock_skew_secs;
+        self.bond_slash_bps = params.bond_slash_bps;
+        self.arbiters = params.arbiters.clone();
+        self.commit_reveal_results = params.commit_reveal_results;
+    }
+
+    /// Council votes needed to correct a result, a strict majority
+    pub fn council_majority(&self) -> usize {
+        self.arbiters.len() / 2 + 1
+    }
+
+    /// Whether a key may create and fund freerolls
+    pub fn is_freeroll_sponsor(&self, key: &Pubkey) -> bool {
+        *key == self.authority || self.freeroll_sponsors.contains(key)
+    }
+
+    /// Whether a key belongs to the game server signing set
+    pub fn is_game_server(&self, key: &Pubkey) -> bool {
+        self.game_server_keys.contains(key)
+    }
+
+    /// Number of distinct signing set keys among the signer and recorded approvers
+    pub fn approval_count(&self, signer: &Pubkey, approvers: &[Pubkey]) -> usize {
+        self.game_server_keys
+            .iter()
+            .filter(|key| *key == signer || approvers.contains(key))
+            .count()
+    }
+
+    /// Whether bets must also fall within USD-denominated bounds
+    pub fn enforces_usd_bounds(&self) -> bool {
+        self.usd_max_bet > 0
+    }
+}
+
+/// Admin-tunable program parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigParams {
+    pub min_bet: u64,              // Minimum bet amount per player
+    pub max_bet: u64,              // Maximum bet amount per player
+    pub session_timeout_secs: i64, // Lifetime of a session before timeout refunds open
+    pub kill_report_signer: Pubkey, // Game server key that signs kill reports
+    pub fee_bps: u16,              // House rake taken from winnings, in basis points
+    pub cancellation_fee_bps: u16, // Fee withheld when leaving a filling session, in basis points
+    pub usd_min_bet: u64,          // Minimum bet value in micro-USD, 0 when unused
+    pub usd_max_bet: u64,          // Maximum bet value in micro-USD, 0 disables USD bounds
+    pub max_price_age_secs: u64,   // Oldest oracle price accepted for USD bounds
+    pub arbiter: Pubkey,           // Key allowed to override results during the dispute window
+    pub dispute_window_secs: i64,  // Delay between result submission and payout, 0 disables
+    pub game_server_keys: Vec<Pubkey>, // Game server signing set for sensitive operations
+    pub game_server_threshold: u8, // Approvals from the signing set required for sensitive operations
+    pub referral_bps: u16,         // Referrer's cut of the rake on each referred bet, in basis points
+    pub max_top_up_multiple: u8,   // Base bets a pay-to-spawn player may top up mid-session, 0 disables
+    pub map_pool_size: u8,         // Maps VRF match setup draws from, 0 disables VRF setup
+    pub jackpot_bps: u16,          // Share of each session's rake routed into the jackpot, in basis points
+    pub jackpot_interval: u64,     // Winner-takes-all team sessions per jackpot award, 0 never awards
+    pub rewards_mint: Pubkey,      // Mint streak bonuses are paid in from the rewards vault
+    pub streak_milestone: u16,     // Consecutive wins that earn a streak bonus, 0 disables bonuses
+    pub streak_bonus: u64,         // Tokens paid for each streak milestone reached
+    pub elo_k_factor: u16,         // Largest rating change a single session can cause, 0 freezes ratings
+    pub creator_penalty_bps: u16,  // Penalty per player, in bps of the bet, a creator pays for aborting a full forfeit-policy lobby
+    pub trophy_uri: String,        // Base URI of winner trophy metadata, empty disables trophies
+    pub cpi_callers: Vec<Pubkey>,  // Programs allowed to create and join sessions by CPI, empty allows any
+    pub creation_fee_lamports: u64, // Lamports a creator deposits into the treasury per session, 0 disables
+    pub max_exposure: u64,         // Total bet a wallet may have open across sessions, 0 disables the cap
+    pub fee_split: Vec<FeeShare>,  // Recipients splitting the house rake, empty sends it all to the treasury
+    pub draw_fee_bps: u16,         // Rake withheld from each bet returned in a draw, 0 returns bets in full
+    pub forfeit_timeout_secs: i64, // Inactivity after which a team can be declared forfeited by anyone, 0 disables
+    pub forfeit_penalty_bps: u16,  // Share of each forfeiting player's bet paid to the winners, the rest is refunded
+    pub freeroll_sponsors: Vec<Pubkey>, // Wallets besides the authority allowed to create freerolls
+    pub yield_programs: Vec<Pubkey>, // Yield adapters tournament escrow may be parked in, empty disables parking
+    pub yield_treasury_bps: u16,   // Treasury's share of yield earned on parked escrow, in basis points
+    pub clock_skew_secs: i64,      // Grace either side of deadlines for cluster clock drift, at most MAX_CLOCK_SKEW_SECS
+    pub bond_slash_bps: u16,       // Share of an operator's bond slashed to the wronged players when the arbiter overturns its result
+    pub arbiters: Vec<Pubkey>,     // Council voting on disputes, a majority of which corrects a result, empty disables voting
+    pub commit_reveal_results: bool, // Whether results must be committed to in an earlier slot than they are revealed
+}
+
+/// Pyth price feed registered by the admin for a wager mint
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    pub mint: Pubkey,        // Mint the feed prices
+    pub feed_id: [u8; 32],   // Pyth feed id quoting the mint in USD
+    pub bump: u8,            // PDA bump
+}
+
+impl PriceFeed {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 32 + 1;
+}
+
+/// Wager token the admin permits lobbies to be created in, optionally with
+/// bet limits of its own in place of the config's
+#[account]
+#[derive(InitSpace)]
+pub struct RegisteredMint {
+    pub mint: Pubkey,        // Permitted wager token
+    pub min_bet: u64,        // Minimum bet in the mint's base units, 0 uses the config's
+    pub max_bet: u64,        // Maximum bet in the mint's base units, 0 uses the config's
+    pub bump: u8,            // PDA bump
+}
+
+impl RegisteredMint {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 1;
+
+    /// Bet limits of the mint, its overrides taking precedence over the config
+    pub fn bet_limits(&self, config: &ProgramConfig) -> (u64, u64) {
+        let min_bet = if self.min_bet > 0 { self.min_bet } else { config.min_bet };
+        let max_bet = if self.max_bet > 0 { self.max_bet } else { config.max_bet };
+        (min_bet, max_bet)
+    }
+}
+
+/// Lamports an operator posts as collateral for the results it reports,
+/// slashed to the wronged players when the arbiter overturns one. Withdrawals
+/// wait out a dispute window so a bond can't be pulled ahead of a slash.
+#[account]
+#[derive(InitSpace)]
+pub struct ServerBond {
+    pub operator: Pubkey,        // Game server authority the bond backs
+    pub amount: u64,             // Bonded lamports, excluding the account's rent
+    pub pending_withdrawal: u64, // Lamports requested for withdrawal, 0 when none
+    pub unlock_at: i64,          // Time the pending withdrawal can be taken
+    pub total_slashed: u64,      // Lamports slashed over the bond's lifetime
+    pub bump: u8,                // PDA bump
+}
+
+impl ServerBond {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 1;
+
+    /// Removes `amount` from the bond, shrinking a pending withdrawal that
+    /// would no longer be covered
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.amount = safe_math::safe_subtract(self.amount, amount)?;
+        self.pending_withdrawal = self.pending_withdrawal.min(self.amount);
+        Ok(())
+    }
+}
+
+/// Single-elimination tournament whose entry fees form one pot for the champion
+#[account]
+#[derive(InitSpace)]
+pub struct Tournament {
+    #[max_len(MAX_SESSION_ID_LEN)]
+    pub tournament_id: String, // Unique identifier for the tournament
+    pub authority: Pubkey,     // Operator that reports match results
+    pub mint: Pubkey,          // SPL mint entry fees are paid in
+    pub entry_fee: u64,        // Fee each team pays to enter
+    pub fee_bps: u16,          // House rake snapshotted from the config at creation
+    pub team_count: u8,        // Bracket size, a power of two
+    #[max_len(MAX_TOURNAMENT_TEAMS)]
+    pub entrants: Vec<Pubkey>, // Registered team captains, in seeding order
+    pub champion: Pubkey,      // Captain of the winning team once the final is decided
+    pub status: TournamentStatus, // Current lifecycle state
+    pub bump: u8,              // PDA bump
+    pub vault_bump: u8,        // Bump of the vault escrowing the entry fees
+    pub yield_program: Pubkey, // Yield adapter holding parked escrow, default when nothing is parked
+    pub parked_amount: u64,    // Entry fees currently parked in the yield adapter
+    pub accrued_yield: u64,    // Yield earned on parked escrow, split at settlement
+    pub yield_treasury_bps: u16, // Treasury's share of the yield snapshotted from the config at creation
+}
+
+impl Tournament {
+    /// Account space for a bracket of `team_count` teams
+    pub const fn space(team_count: u8) -> usize {
+        8 + 4 + MAX_SESSION_ID_LEN + 32 + 32 + 8 + 2 + 1
+            + 4 + team_count as usize * 32 + 32 + 1 + 1 + 1 + 32 + 8 + 8 + 2
+    }
+
+    /// Number of single-elimination rounds in the bracket
+    pub fn round_count(&self) -> u8 {
+        self.team_count.trailing_zeros() as u8
+    }
+
+    /// Number of matches played in the given round
+    pub fn matches_in_round(&self, round: u8) -> usize {
+        (self.team_count as usize) >> (round + 1)
+    }
+
+    /// Whether the given round is the final
+    pub fn is_final_round(&self, round: u8) -> bool {
+        round + 1 == self.round_count()
+    }
+
+    /// Registers a team captain, starting the bracket once every slot is taken
+    pub fn register(&mut self, captain: Pubkey) -> Result<()> {
+        require!(
+            self.status == TournamentStatus::Registering,
+            WagerError::InvalidTournamentState
+        );
+        require!(
+            self.entrants.len() < self.team_count as usize,
+            WagerError::TournamentFull
+        );
+        require!(
+            !self.entrants.contains(&captain),
+            WagerError::SlotAlreadyOccupied
+        );
+
+        self.entrants.push(captain);
+        if self.entrants.len() == self.team_count as usize {
+            self.status = TournamentStatus::InProgress;
+        }
+        Ok(())
+    }
+
+    /// Total entry fees collected from every team
+    pub fn total_pot(&self) -> Result<u64> {
+        safe_math::safe_multiply(self.entry_fee, self.entrants.len() as u64)
+    }
+
+    /// Records escrow parked in a yield adapter, which must be the one
+    /// already holding any earlier parked funds
+    pub fn park(&mut self, yield_program: Pubkey, amount: u64) -> Result<()> {
+        require!(
+            self.parked_amount == 0 || self.yield_program == yield_program,
+            WagerError::YieldProgramNotAllowed
+        );
+        self.yield_program = yield_program;
+        self.parked_amount = safe_math::safe_add(self.parked_amount, amount)?;
+        Ok(())
+    }
+
+    /// Records parked escrow returned from the yield adapter. The adapter must
+    /// hand back at least the principal; anything above it is yield.
+    pub fn unpark(&mut self, returned: u64) -> Result<u64> {
+        require!(
+            returned >= self.parked_amount,
+            WagerError::YieldPrincipalShortfall
+        );
+        let earned = safe_math::safe_subtract(returned, self.parked_amount)?;
+        self.accrued_yield = safe_math::safe_add(self.accrued_yield, earned)?;
+        self.parked_amount = 0;
+        self.yield_program = Pubkey::default();
+        Ok(earned)
+    }
+}
+
+/// One match of a tournament bracket, played as a linked game session
+#[account]
+#[derive(InitSpace)]
+pub struct BracketMatch {
+    pub tournament: Pubkey,  // Tournament this match belongs to
+    pub round: u8,           // Bracket round, 0 for the opening round
+    pub match_index: u8,     // Position of the match within its round
+    #[max_len(MAX_SESSION_ID_LEN)]
+    pub session_id: String,  // Game session the match is played in
+    pub teams: [u8; 2],      // Entrant indices facing off, NO_ENTRANT until advanced
+    pub winner: u8,          // Winning entrant index, NO_ENTRANT until reported
+    pub bump: u8,            // PDA bump
+}
+
+impl BracketMatch {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 1 + 1 + 4 + MAX_SESSION_ID_LEN + 2 + 1 + 1;
+
+    /// Records the winner of the match
+    pub fn decide(&mut self, winner_side: u8) -> Result<u8> {
+        require!(self.winner == NO_ENTRANT, WagerError::MatchAlreadyDecided);
+        require!(
+            !self.teams.contains(&NO_ENTRANT),
+            WagerError::MatchNotReady
+        );
+        let winner = *self
+            .teams
+            .get(winner_side as usize)
+            .ok_or(WagerError::InvalidWinningTeam)?;
+        self.winner = winner;
+        Ok(winner)
+    }
+
+    /// Seats a team advancing from the previous round
+    pub fn advance(&mut self, side: usize, entrant: u8) -> Result<()> {
+        require!(self.teams[side] == NO_ENTRANT, WagerError::SlotAlreadyOccupied);
+        self.teams[side] = entrant;
+        Ok(())
+    }
+}
+
+/// Pari-mutuel pool where spectators bet on the winning team of a session
+#[account]
+#[derive(InitSpace)]
+pub struct SpectatorPool {
+    pub game_session: Pubkey,  // Session the spectators are betting on
+    #[max_len(MAX_TEAMS)]
+    pub team_totals: Vec<u64>, // Total staked on each team
+    pub total_staked: u64,     // Total staked across every team
+    pub bump: u8,              // PDA bump
+}
+
+impl SpectatorPool {
+    /// Account space for a pool over `team_count` teams
+    pub const fn space(team_count: usize) -> usize {
+        8 + 32 + 4 + team_count * 8 + 8 + 1
+    }
+
+    /// Adds a stake on the given team
+    pub fn record_bet(&mut self, team: u8, amount: u64) -> Result<()> {
+        let team_total = self
+            .team_totals
+            .get_mut(team as usize)
+            .ok_or(WagerError::InvalidTeamSelection)?;
+        *team_total = safe_math::safe_add(*team_total, amount)?;
+        self.total_staked = safe_math::safe_add(self.total_staked, amount)?;
+        Ok(())
+    }
+
+    /// Pari-mutuel payout for a stake. Correct bettors split the whole pool
+    /// pro-rata, everyone is refunded when the session was refunded or nobody
+    /// backed the winner.
+    pub fn payout(&self, bet: &SpectatorBet, winning_team: Option<u8>) -> Result<u64> {
+        let Some(winning_team) = winning_team else {
+            return Ok(bet.amount);
+        };
+        let winning_total = self.team_totals[winning_team as usize];
+        if winning_total == 0 {
+            return Ok(bet.amount);
+        }
+        if bet.team != winning_team {
+            return Ok(0);
+        }
+        safe_math::proportional_share(bet.amount, self.total_staked, winning_total)
+    }
+}
+
+/// A single spectator's stake in a session's spectator pool
+#[account]
+#[derive(InitSpace)]
+pub struct SpectatorBet {
+    pub pool: Pubkey,   // Spectator pool the stake belongs to
+    pub bettor: Pubkey, // Spectator who placed the stake
+    pub team: u8,       // Team backed to win
+    pub amount: u64,    // Total amount staked
+    pub claimed: bool,  // Whether the payout has been claimed
+    pub bump: u8,       // PDA bump
+}
+
+impl SpectatorBet {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 1 + 1;
+}
+
+/// Approvals collected from the game server signing set for a sensitive
+/// operation on a session, so the threshold can be met across transactions
+#[account]
+#[derive(InitSpace)]
+pub struct Approval {
+    pub game_session: Pubkey,   // Session the operation applies to
+    pub kind: ApprovalKind,     // Operation being approved
+    pub winning_team: u8,       // Result the approvers agreed on
+    #[max_len(MAX_GAME_SERVER_KEYS)]
+    pub approvers: Vec<Pubkey>, // Signing set keys that approved this result
+    pub bump: u8,               // PDA bump
+}
+
+impl Approval {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 1 + 1 + 4 + 32 * MAX_GAME_SERVER_KEYS + 1;
+
+    /// Records an approval, restarting collection when a different result is proposed
+    pub fn approve(&mut self, approver: Pubkey, winning_team: u8) {
+        if self.approvers.is_empty() || self.winning_team != winning_team {
+            self.approvers.clear();
+            self.winning_team = winning_team;
+        }
+        if !self.approvers.contains(&approver) {
+            self.approvers.push(approver);
+        }
+    }
+}
+
+/// Hash the game server signing set committed a session's result to,
+/// revealed in a later slot so the result can't be picked after watching the
+/// reveal's mempool
+#[account]
+#[derive(InitSpace)]
+pub struct ResultCommitment {
+    pub game_session: Pubkey, // Session the result is for
+    pub committer: Pubkey,    // Signing set key that first proposed a hash, refunded the rent on reveal
+    pub hash: [u8; 32],       // Digest of the session, declaration and salt
+    pub committed_slot: u64,  // Slot the hash reached the threshold, 0 while approvals are collected
+    #[max_len(MAX_GAME_SERVER_KEYS)]
+    pub approvers: Vec<Pubkey>, // Signing set keys that committed to `hash`
+    pub bump: u8,             // PDA bump
+}
+
+impl ResultCommitment {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 4 + 32 * MAX_GAME_SERVER_KEYS + 1;
+
+    /// Whether the hash reached the threshold and can no longer be replaced
+    pub fn is_committed(&self) -> bool {
+        self.committed_slot != 0
+    }
+
+    /// Records a key's commitment to `hash`, restarting collection when a
+    /// different hash is proposed, as `Approval::approve` does
+    pub fn approve(&mut self, approver: Pubkey, hash: [u8; 32]) {
+        if self.approvers.is_empty() || self.hash != hash {
+            self.approvers.clear();
+            self.hash = hash;
+        }
+        if !self.approvers.contains(&approver) {
+            self.approvers.push(approver);
+        }
+    }
+
+    /// Digest committing to `declaration` for the session, blinded by `salt`
+    pub fn digest(game_session: &Pubkey, declaration: &WinnerDeclaration, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[game_session.as_ref(), &[declaration.result_code()], salt]).to_bytes()
+    }
+}
+
+/// One council member's vote on a disputed result
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub struct DisputeVote {
+    pub arbiter: Pubkey,   // Council member that voted
+    pub winning_team: u8,  // Team the member says won
+}
+
+/// Votes the arbiter council has cast on a session's submitted result
+#[account]
+#[derive(InitSpace)]
+pub struct DisputeVotes {
+    pub game_session: Pubkey,    // Session whose result is disputed
+    #[max_len(MAX_ARBITERS)]
+    pub votes: Vec<DisputeVote>, // Latest vote of each member that voted
+    pub bump: u8,                // PDA bump
+}
+
+impl DisputeVotes {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 4 + (32 + 1) * MAX_ARBITERS + 1;
+
+    /// Records a member's vote, replacing any earlier vote of theirs
+    pub fn cast(&mut self, arbiter: Pubkey, winning_team: u8) {
+        self.votes.retain(|vote| vote.arbiter != arbiter);
+        self.votes.push(DisputeVote {
+            arbiter,
+            winning_team,
+        });
+    }
+
+    /// Votes for `winning_team` from keys still sitting on the council
+    pub fn support(&self, winning_team: u8, council: &[Pubkey]) -> usize {
+        self.votes
+            .iter()
+            .filter(|vote| vote.winning_team == winning_team && council.contains(&vote.arbiter))
+            .count()
+    }
+}
+
+/// Side pool of a wager mint funded by a slice of every session's rake and
+/// won by the winning team of every `jackpot_interval`th team session
+#[account]
+#[derive(InitSpace)]
+pub struct Jackpot {
+    pub mint: Pubkey,            // Mint the jackpot accumulates
+    pub balance: u64,            // Tokens accumulated towards the next award
+    pub eligible_sessions: u64,  // Winner-takes-all team sessions settled since creation
+    pub pending_session: Pubkey, // Session whose winners are owed `pending_prize`, default if none
+    pub pending_prize: u64,      // Prize locked for the winners of `pending_session`
+    pub total_awarded: u64,      // Tokens paid out to jackpot winners over the jackpot's lifetime
+    pub bump: u8,                // PDA bump
+}
+
+impl Jackpot {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 32 + 8 + 8 + 1;
+
+    /// Adds a session's cut of the rake and, for sessions with a winning
+    /// team, counts it towards the next milestone. Returns whether the
+    /// session won the jackpot; a milestone reached while an earlier prize is
+    /// still unclaimed rolls over.
+    pub fn contribute(
+        &mut self,
+        amount: u64,
+        eligible_session: Option<Pubkey>,
+        interval: u64,
+    ) -> Result<bool> {
+        self.balance = safe_math::safe_add(self.balance, amount)?;
+        let Some(session) = eligible_session else {
+            return Ok(false);
+        };
+        self.eligible_sessions = safe_math::safe_add(self.eligible_sessions, 1)?;
+        if interval == 0
+            || self.eligible_sessions % interval != 0
+            || self.pending_session != Pubkey::default()
+            || self.balance == 0
+        {
+            return Ok(false);
+        }
+        self.pending_session = session;
+        self.pending_prize = self.balance;
+        self.balance = 0;
+        Ok(true)
+    }
+
+    /// Records the pending prize as paid, returning undistributable dust to
+    /// the balance
+    pub fn award(&mut self, paid: u64) -> Result<()> {
+        let dust = safe_math::safe_subtract(self.pending_prize, paid)?;
+        self.balance = safe_math::safe_add(self.balance, dust)?;
+        self.total_awarded = safe_math::safe_add(self.total_awarded, paid)?;
+        self.pending_session = Pubkey::default();
+        self.pending_prize = 0;
+        Ok(())
+    }
+}
+
+/// Referrer recorded for a player on their first referred join
+#[account]
+#[derive(InitSpace)]
+pub struct Referral {
+    pub referee: Pubkey,  // Player who was referred
+    pub referrer: Pubkey, // Account credited with a cut of the referee's rake
+    pub bump: u8,         // PDA bump
+}
+
+impl Referral {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 32 + 1;
+}
+
+/// Referrer's cut of the rake on a referee's bet in one session, claimable
+/// once the session has been settled
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralCredit {
+    pub game_session: Pubkey, // Session the referee joined
+    pub referee: Pubkey,      // Player whose bet earned the credit
+    pub referrer: Pubkey,     // Account entitled to the credit
+    pub amount: u64,          // Cut of the rake owed once the session completes
+    pub bump: u8,             // PDA bump
+}
+
+impl ReferralCredit {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+}
+
+/// Payout held back for a payee whose token account could not receive it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct UnclaimedEntry {
+    pub player: Pubkey, // Payee owed the payout
+    pub amount: u64,    // Amount still escrowed in the vault for them
+}
+
+/// Payouts of a session withheld because the payee's token account was closed
+/// or frozen at distribution time, claimable later with `claim_unclaimed`
+#[account]
+#[derive(InitSpace)]
+pub struct UnclaimedPayout {
+    pub game_session: Pubkey, // Session the payouts belong to
+    #[max_len(MAX_PLAYERS_PER_TEAM)]
+    pub entries: Vec<UnclaimedEntry>, // Payouts still owed, in distribution order
+    pub bump: u8,             // PDA bump
+}
+
+impl UnclaimedPayout {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 4 + (32 + 8) * MAX_PLAYERS_PER_TEAM + 1;
+
+    /// Holds back a payee's payout
+    pub fn record(&mut self, player: Pubkey, amount: u64) -> Result<()> {
+        require!(
+            self.entries.len() < MAX_PLAYERS_PER_TEAM,
+            WagerError::UnclaimedPayoutsFull
+        );
+        self.entries.push(UnclaimedEntry { player, amount });
+        Ok(())
+    }
+
+    /// Removes and returns a player's held-back payout
+    pub fn take(&mut self, player: Pubkey) -> Result<u64> {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.player == player)
+            .ok_or(error!(WagerError::NothingToClaim))?;
+        Ok(self.entries.remove(index).amount)
+    }
+}
+
+/// One recipient's share of the house rake, e.g. the operator, a map
+/// creator or the protocol
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub struct FeeShare {
+    pub recipient: Pubkey, // Wallet paid the share, in native SOL or to its token account
+    pub bps: u16,          // Share of the rake, in basis points
+}
+
+/// A wallet's deposit into a session's sponsored prize
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct PrizeSponsor {
+    pub sponsor: Pubkey, // Wallet that deposited, refunded if the session is refunded
+    pub amount: u64,     // Amount it added to the prize
+}
+
+/// Bet a wallet has open in one session
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct ExposureEntry {
+    pub game_session: Pubkey, // Session the bet is escrowed in
+    pub amount: u64,          // Bet still at risk in it
+}
+
+/// Bets a wallet has open across unsettled sessions, capped by the config's
+/// `max_exposure`. Joins add to it; leaving, kicks and timeout refunds release
+/// their entry directly, and `release_exposure` releases sessions settled in
+/// bulk once they no longer hold the player's bet.
+#[account]
+#[derive(InitSpace)]
+pub struct ActiveExposure {
+    pub player: Pubkey,       // Wallet the exposure belongs to
+    pub total: u64,           // Sum of the open bets
+    #[max_len(MAX_EXPOSURE_SESSIONS)]
+    pub entries: Vec<ExposureEntry>, // Open bets, one per session
+    pub bump: u8,             // PDA bump
+}
+
+impl ActiveExposure {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 4 + (32 + 8) * MAX_EXPOSURE_SESSIONS + 1;
+
+    /// Initializes an account created on this player's first tracked join
+    pub fn init_if_new(&mut self, player: Pubkey, bump: u8) {
+        if self.player == Pubkey::default() {
+            self.player = player;
+            self.bump = bump;
+        }
+    }
+
+    /// Adds a bet in a session, rejecting it if the open total would exceed
+    /// `cap` (0 leaves the total uncapped)
+    pub fn add(&mut self, game_session: Pubkey, amount: u64, cap: u64) -> Result<()> {
+        let total = safe_math::safe_add(self.total, amount)?;
+        if cap > 0 && total > cap {
+            msg!("Exposure cap: {}", cap);
+            return Err(err_with_amount(WagerError::ExposureCapExceeded, total));
+        }
+
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.game_session == game_session)
+        {
+            entry.amount = safe_math::safe_add(entry.amount, amount)?;
+        } else {
+            require!(
+                self.entries.len() < MAX_EXPOSURE_SESSIONS,
+                WagerError::TooManyOpenSessions
+            );
+            self.entries.push(ExposureEntry { game_session, amount });
+        }
+        self.total = total;
+        Ok(())
+    }
+
+    /// Removes a session's bet, returning the amount released (0 if the
+    /// session held none)
+    pub fn release(&mut self, game_session: Pubkey) -> Result<u64> {
+        let Some(index) = self
+            .entries
+            .iter()
+            .position(|entry| entry.game_session == game_session)
+        else {
+            return Ok(0);
+        };
+        let amount = self.entries.remove(index).amount;
+        self.total = safe_math::safe_subtract(self.total, amount)?;
+        Ok(amount)
+    }
+}
+
+/// A single kill recorded in a session's match log
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct KillLogEntry {
+    pub killer: Pubkey, // Player credited with the kill
+    pub victim: Pubkey, // Player who lost a life
+    pub slot: u64,      // Slot the kill was recorded in
+}
+
+/// Escrow and waiting list of solo players for one (mint, bet, game mode)
+/// bucket, drained into a new session by `form_match`
+#[account]
+#[derive(InitSpace)]
+pub struct MatchQueue {
+    pub mint: Pubkey,         // Token the queued bets are escrowed in
+    pub bet_amount: u64,      // Bet every queued player has deposited
+    pub game_mode: GameMode,  // Game mode of the sessions formed from the queue
+    #[max_len(MAX_QUEUE_PLAYERS)]
+    pub players: Vec<Pubkey>, // Waiting players, oldest first
+    pub bump: u8,             // PDA bump
+}
+
+impl MatchQueue {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 1 + 4 + 32 * MAX_QUEUE_PLAYERS + 1;
+
+    /// Players popped from the queue to fill one session
+    pub fn players_per_match(&self) -> usize {
+        if self.game_mode.is_free_for_all() {
+            self.game_mode.free_for_all_players()
+        } else {
+            2 * self.game_mode.players_per_team()
+        }
+    }
+}
+
+/// Open lobbies sharing one (mint, bet, game mode) bucket, so clients can
+/// list joinable sessions without scanning every program account
+#[account]
+#[derive(InitSpace)]
+pub struct LobbyIndex {
+    pub mint: Pubkey,          // Token the indexed sessions are denominated in
+    pub bet_amount: u64,       // Bet of every indexed session
+    pub game_mode: GameMode,   // Game mode of every indexed session
+    #[max_len(MAX_INDEXED_LOBBIES)]
+    pub sessions: Vec<Pubkey>, // Sessions still accepting players, oldest first
+    pub bump: u8,              // PDA bump
+}
+
+impl LobbyIndex {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 1 + 4 + 32 * MAX_INDEXED_LOBBIES + 1;
+
+    /// Initializes an index created by the first session it lists
+    pub fn init_if_new(&mut self, mint: Pubkey, bet_amount: u64, game_mode: GameMode, bump: u8) {
+        if self.mint == Pubkey::default() {
+            self.mint = mint;
+            self.bet_amount = bet_amount;
+            self.game_mode = game_mode;
+            self.bump = bump;
+        }
+    }
+
+    /// Lists a newly created session
+    pub fn add(&mut self, game_session: Pubkey) -> Result<()> {
+        require!(
+            self.sessions.len() < MAX_INDEXED_LOBBIES,
+            WagerError::LobbyIndexFull
+        );
+        self.sessions.push(game_session);
+        Ok(())
+    }
+
+    /// Drops a session that no longer accepts players, if listed
+    pub fn remove(&mut self, game_session: Pubkey) {
+        self.sessions.retain(|session| *session != game_session);
+    }
+}
+
+/// One player's bet in a session, created when they join and closed when the
+/// bet is refunded, or by the player once the session is settled. A receipt
+/// that still exists has not been claimed back.
+#[account]
+#[derive(InitSpace)]
+pub struct BetReceipt {
+    pub game_session: Pubkey, // Session the bet was placed in
+    pub player: Pubkey,       // Player who placed the bet
+    pub amount: u64,          // Stake escrowed, or pledged for delegated joins
+    pub joined_slot: u64,     // Slot the player took their seat in
+    pub bump: u8,             // PDA bump
+    pub status: ReceiptStatus, // Whether the bet is still open or was refunded or paid out
+}
+
+impl BetReceipt {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1;
+
+    /// Records a bet on a fresh receipt, or on one left behind after the
+    /// player lost their seat without a refund
+    pub fn issue(&mut self, game_session: Pubkey, player: Pubkey, amount: u64, bump: u8) -> Result<()> {
+        self.game_session = game_session;
+        self.player = player;
+        self.amount = amount;
+        self.joined_slot = Clock::get()?.slot;
+        self.bump = bump;
+        self.status = ReceiptStatus::Open;
+        Ok(())
+    }
+
+    /// Records the refund or payout that consumed the bet. A receipt backs a
+    /// single claim, so one already consumed can't be claimed again.
+    pub fn claim(&mut self, status: ReceiptStatus) -> Result<()> {
+        require!(
+            self.status == ReceiptStatus::Open,
+            WagerError::BetReceiptClaimed
+        );
+        self.status = status;
+        Ok(())
+    }
+}
+
+/// Discovery details for a session, created alongside it by the operator
+#[account]
+#[derive(InitSpace)]
+pub struct SessionMetadata {
+    pub game_session: Pubkey, // Session the metadata describes
+    #[max_len(MAX_GAME_NAME_LEN)]
+    pub game_name: String,    // Title of the game being played
+    #[max_len(MAX_MAP_ID_LEN)]
+    pub map_id: String,       // Map identifier
+    #[max_len(MAX_REGION_LEN)]
+    pub region: String,       // Server region
+    #[max_len(MAX_METADATA_BLOB_LEN)]
+    pub custom: String,       // Free-form operator data, e.g. JSON
+    pub bump: u8,             // PDA bump
+}
+
+impl SessionMetadata {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + (4 + MAX_GAME_NAME_LEN) + (4 + MAX_MAP_ID_LEN) + (4 + MAX_REGION_LEN)
+        + (4 + MAX_METADATA_BLOB_LEN) + 1;
+}
+
+/// How a session's remaining distribution splits into transactions that
+/// each stay within the distribution compute budget
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct DistributionPlan {
+    pub payees: u16,    // Payees the session's result pays in total
+    pub paid: u16,      // Payees already paid by earlier pages
+    pub page_size: u16, // Payees to pass to each distribute_winnings call
+    pub chunks: u16,    // distribute_winnings calls left until the session completes
+}
+
+/// Deadline after which a session stops taking joins and can be locked by
+/// anyone, starting with at least `min_players` (and `min_players_per_team`
+/// on every team) or becoming refundable
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct JoinWindow {
+    pub deadline: i64,   // Timestamp joins close
+    pub min_players: u8, // Players needed to start once locked
+    pub min_players_per_team: u8, // Players each team needs to start short-handed, 0 treated as 1
+}
+
+/// Game configuration a session is created with, fixed by a session
+/// template for every lobby launched from it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct SessionTerms {
+    pub game_mode: GameMode,        // Game configuration
+    pub team_count: u8,             // Participating teams, 0 in free-for-all
+    pub spawn_earnings_bps: u16,    // Pay-to-spawn payout per kill/spawn, in bps of the bet
+    pub log_capacity: u16,          // Kill events retained by the match log, 0 keeps none
+    pub team_a_capacity: u8,        // Roster size of the first team of an asymmetric lobby, 0 for the game mode's
+    pub team_b_capacity: u8,        // Roster size of the second team of an asymmetric lobby, 0 for the game mode's
+    pub payout_schedule: PayoutSchedule, // How the winning team splits the prize pool
+    pub max_kills_per_player: u16,  // Kills a single player may score, 0 if uncapped
+    pub max_match_kills: u16,       // Kills the whole match may record, 0 if uncapped
+    pub series_length: u8,          // Rounds in a best-of-N series, 0 for a single game
+    pub refund_policy: RefundPolicy, // What leaving players and aborts refund
+    pub win_condition: WinCondition, // How the winner is determined
+    pub assist_weight_bps: u16,     // Share of a kill's pay-to-spawn earnings an assist takes, in basis points
+    pub min_ready_players: u8,      // Ready players needed to start, 0 skips the check, more than are seated needs everyone
+    pub max_stake: u64,             // Largest bet a player may stake, 0 requires exactly the session bet
+}
+
+impl SessionTerms {
+    /// Roster size of each team: asymmetric lobbies size each of two teams
+    /// explicitly, otherwise every team takes the game mode's roster size
+    pub fn team_capacities(&self) -> [u8; MAX_TEAMS] {
+        let mut team_capacities = [0u8; MAX_TEAMS];
+        if self.team_a_capacity == 0 && self.team_b_capacity == 0 {
+            team_capacities[..(self.team_count as usize).min(MAX_TEAMS)]
+                .fill(self.game_mode.players_per_team() as u8);
+        } else {
+            team_capacities[0] = self.team_a_capacity;
+            team_capacities[1] = self.team_b_capacity;
+        }
+        team_capacities
+    }
+}
+
+/// Reusable game configuration an operator launches lobbies from with
+/// `create_from_template`, so players can check a session runs on the terms
+/// the template advertises
+#[account]
+#[derive(InitSpace)]
+pub struct SessionTemplate {
+    pub authority: Pubkey,     // Operator that created the template and launches its sessions
+    pub template_id: u64,      // Operator-chosen identifier, part of the PDA seeds
+    pub mint: Pubkey,          // Token every session is denominated in
+    pub bet_amount: u64,       // Bet of every session
+    pub fee_bps: u16,          // House rake advertised, launches fail once the config's rake differs
+    pub terms: SessionTerms,   // Game configuration of every session
+    pub sessions_created: u64, // Sessions launched from the template
+    pub bump: u8,              // PDA bump
+}
+
+impl SessionTemplate {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 8 + 2 + SessionTerms::INIT_SPACE + 8 + 1;
+}
+
+/// Schedule launching a new session from a template every interval, so
+/// ladders run without an off-chain cron job. Lamports held above the
+/// account's rent reimburse whoever spawns an instance for its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct RecurringMatch {
+    pub authority: Pubkey,    // Operator owning the schedule and every instance
+    pub template: Pubkey,     // Session template fixing each instance's bet, fees and payouts
+    #[max_len(MAX_RECURRING_PREFIX_LEN)]
+    pub prefix: String,       // Session id prefix, instances are `<prefix>-<instance>`
+    pub interval_secs: i64,   // Time between instances
+    pub next_start: i64,      // Timestamp the next instance can be spawned
+    pub join_secs: i64,       // Join window of each instance, 0 only starts it once full
+    pub min_players: u8,      // Players an instance needs to start once its join window closes
+    pub max_instances: u32,   // Instances the schedule spawns, 0 if unbounded
+    pub instances: u32,       // Instances spawned so far
+    pub bump: u8,             // PDA bump
+}
+
+impl RecurringMatch {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 32 + 4 + MAX_RECURRING_PREFIX_LEN + 8 + 8 + 8 + 1 + 4 + 4 + 1;
+
+    /// Session id of the next instance
+    pub fn next_session_id(&self) -> String {
+        format!("{}-{}", self.prefix, self.instances)
+    }
+
+    /// Join window of an instance spawned at `now`, if instances have one
+    pub fn join_window(&self, now: i64) -> Result<Option<JoinWindow>> {
+        if self.join_secs == 0 {
+            return Ok(None);
+        }
+        Ok(Some(JoinWindow {
+            deadline: now
+                .checked_add(self.join_secs)
+                .ok_or(error!(WagerError::ArithmeticOverflow))?,
+            min_players: self.min_players,
+            min_players_per_team: 0,
+        }))
+    }
+
+    /// Counts a spawned instance and moves the schedule to its next epoch
+    /// after `now`, skipping epochs nobody spawned so the schedule stays on
+    /// its interval
+    pub fn advance(&mut self, now: i64) -> Result<()> {
+        require!(now >= self.next_start, WagerError::RecurringMatchNotDue);
+        require!(
+            self.max_instances == 0 || self.instances < self.max_instances,
+            WagerError::RecurringMatchFinished
+        );
+        let elapsed = (now - self.next_start) / self.interval_secs + 1;
+        self.next_start = elapsed
+            .checked_mul(self.interval_secs)
+            .and_then(|offset| self.next_start.checked_add(offset))
+            .ok_or(error!(WagerError::ArithmeticOverflow))?;
+        self.instances = self
+            .instances
+            .checked_add(1)
+            .ok_or(error!(WagerError::ArithmeticOverflow))?;
+        Ok(())
+    }
+}
+
+/// Operator-supplied discovery details for a new session
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SessionMetadataParams {
+    pub game_name: String, // Title of the game being played
+    pub map_id: String,    // Map identifier
+    pub region: String,    // Server region
+    pub custom: String,    // Free-form operator data, e.g. JSON
+}
+
+/// Ring buffer of the most recent kills in a session, kept so disputes can be
+/// audited against individual events rather than only the aggregate counters
+#[account]
+#[derive(InitSpace)]
+pub struct MatchLog {
+    pub game_session: Pubkey,      // Session the log belongs to
+    pub capacity: u16,             // Maximum number of entries retained
+    pub total_kills: u64,          // Kills recorded over the session's lifetime
+    #[max_len(MAX_MATCH_LOG_CAPACITY)]
+    pub entries: Vec<KillLogEntry>, // Retained kills, oldest at `total_kills % capacity` once full
+    pub bump: u8,                  // PDA bump
+}
+
+impl MatchLog {
+    /// Account space for a log retaining `capacity` entries
+    pub const fn space(capacity: u16) -> usize {
+        8 + 32 + 2 + 8 + 4 + capacity as usize * (32 + 32 + 8) + 1
+    }
+
+    /// Appends a kill, overwriting the oldest entry once the buffer is full
+    pub fn record(&mut self, entry: KillLogEntry) -> Result<()> {
+        require!(self.capacity > 0, WagerError::InvalidLogCapacity);
+        if self.entries.len() < self.capacity as usize {
+            self.entries.push(entry);
+        } else {
+            let index = (self.total_kills % self.capacity as u64) as usize;
+            self.entries[index] = entry;
+        }
+        self.total_kills = self
+            .total_kills
+            .checked_add(1)
+            .ok_or(error!(WagerError::ArithmeticOverflow))?;
+        Ok(())
+    }
+}
+
+/// A status line posted to a session's announcement board
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Announcement {
+    pub posted_at: i64, // Unix timestamp of the post
+    pub slot: u64,      // Slot the post landed in
+    #[max_len(MAX_ANNOUNCEMENT_LEN)]
+    pub text: String,   // UTF-8 status text, e.g. "round 3 started"
+}
+
+/// Append-only board of match status lines the session authority posts,
+/// created with its first announcement and closed with the session
+#[account]
+#[derive(InitSpace)]
+pub struct Announcements {
+    pub game_session: Pubkey, // Session the board belongs to
+    #[max_len(MAX_ANNOUNCEMENTS)]
+    pub entries: Vec<Announcement>, // Posts in order, never edited or removed
+    pub bump: u8,             // PDA bump
+}
+
+impl Announcements {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 4 + MAX_ANNOUNCEMENTS * (8 + 8 + 4 + MAX_ANNOUNCEMENT_LEN) + 1;
+
+    /// Sets up a board created with its first announcement
+    pub fn init_if_new(&mut self, game_session: Pubkey, bump: u8) {
+        if self.game_session == Pubkey::default() {
+            self.game_session = game_session;
+            self.bump = bump;
+        }
+    }
+
+    /// Appends an announcement, returning its index on the board
+    pub fn post(&mut self, text: String, posted_at: i64, slot: u64) -> Result<u8> {
+        require!(
+            self.entries.len() < MAX_ANNOUNCEMENTS,
+            WagerError::AnnouncementsFull
+        );
+        self.entries.push(Announcement { posted_at, slot, text });
+        Ok((self.entries.len() - 1) as u8)
+    }
+}
+
+/// One player's line in a settled match's result record
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct PlayerResult {
+    pub player: Pubkey, // Player who held the slot at settlement
+    pub team: u8,       // Team played for, 0 in free-for-all
+    pub kills: u16,     // Kills scored over the match
+    pub payout: u64,    // Amount paid out or left claimable to the player
+}
+
+/// Permanent record of a settled match, written once the session completes
+/// so indexers and dispute processes keep a compact result after the
+/// mutable session account is closed
+#[account]
+#[derive(InitSpace)]
+pub struct MatchResultRecord {
+    pub game_session: Pubkey,    // Session the result belongs to
+    #[max_len(MAX_SESSION_ID_LEN)]
+    pub session_id: String,      // Id the session was created under
+    pub mint: Pubkey,            // Wager token of the session
+    pub session_bet: u64,        // Per-player bet of the session
+    pub result: MatchResult,     // How a team match ended
+    pub winning_team: u8,        // Winning team, or NO_ENTRANT without one
+    pub winner: Pubkey,          // Free-for-all winner, default for team matches
+    #[max_len(MAX_RECORDED_PLAYERS)]
+    pub players: Vec<PlayerResult>, // Final kill table and payouts in roster order
+    pub total_paid_out: u64,     // Total the vault paid out as winnings
+    pub total_fees: u64,         // Total the vault collected as protocol fees
+    pub created_at: i64,         // Timestamp the session was created
+    pub started_at: i64,         // Timestamp the match started
+    pub settled_at: i64,         // Timestamp the session completed
+    pub bump: u8,                // PDA bump
+}
+
+impl MatchResultRecord {
+    /// Account space for a record of `players` players
+    pub const fn space(players: usize) -> usize {
+        8 + 32 + 4 + MAX_SESSION_ID_LEN + 32 + 8 + 1 + 1 + 32 + 4 + players * (32 + 1 + 2 + 8)
+            + 8 + 8 + 8 + 8 + 8 + 1
+    }
+}
+
+/// Lifetime statistics for a player accumulated across sessions
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerStats {
+    pub player: Pubkey,         // Player these statistics belong to
+    pub kills: u64,             // Lifetime kills scored
+    pub deaths: u64,            // Lifetime deaths suffered
+    pub spawns_purchased: u64,  // Lifetime spawns bought in pay-to-spawn sessions
+    pub games_played: u64,      // Sessions joined
+    pub total_wagered: u64,     // Total bets and spawn purchases paid into vaults
+    pub total_won: u64,         // Total winnings received from vaults
+    pub bump: u8,               // PDA bump
+    pub win_streak: u64,        // Consecutive sessions won, broken by any session joined and not won
+    pub best_win_streak: u64,   // Longest win streak reached
+    pub streak_games: u64,      // `games_played` when the streak last grew
+    pub pending_bonuses: u64,   // Streak milestones reached and not yet claimed
+    pub rating: u64,            // Elo skill rating, 0 until the first rated session
+}
+
+impl PlayerStats {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 * 6 + 1 + 8 * 4 + 8;
+
+    /// Re-derives the statistics PDA from the stored player and bump, for
+    /// statistics passed in remaining accounts where no seeds constraint
+    /// covers them
+    pub fn validate_pda(&self, key: &Pubkey) -> Result<()> {
+        validation::validate_pda(key, &[b"player_stats", self.player.as_ref()], self.bump)
+    }
+
+    /// Binds freshly created statistics to their player
+    pub fn init_if_new(&mut self, player: Pubkey, bump: u8) {
+        if self.player == Pubkey::default() {
+            self.player = player;
+            self.bump = bump;
+        }
+    }
+
+    /// Records a session joined for the given bet
+    pub fn record_game(&mut self, bet: u64) -> Result<()> {
+        self.games_played = safe_math::safe_add(self.games_played, 1)?;
+        self.total_wagered = safe_math::safe_add(self.total_wagered, bet)?;
+        Ok(())
+    }
+
+    /// Reverts a session the player left before it started, keeping any
+    /// cancellation fee counted as wagered
+    pub fn revert_game(&mut self, refund: u64) -> Result<()> {
+        self.games_played = safe_math::safe_subtract(self.games_played, 1)?;
+        self.total_wagered = safe_math::safe_subtract(self.total_wagered, refund)?;
+        Ok(())
+    }
+
+    /// Records a kill scored by the player
+    pub fn record_kill(&mut self) -> Result<()> {
+        self.kills = safe_math::safe_add(self.kills, 1)?;
+        Ok(())
+    }
+
+    /// Records a death suffered by the player
+    pub fn record_death(&mut self) -> Result<()> {
+        self.deaths = safe_math::safe_add(self.deaths, 1)?;
+        Ok(())
+    }
+
+    /// Records spawns bought for the given amount
+    pub fn record_spawns(&mut self, spawns: u64, amount: u64) -> Result<()> {
+        self.spawns_purchased = safe_math::safe_add(self.spawns_purchased, spawns)?;
+        self.total_wagered = safe_math::safe_add(self.total_wagered, amount)?;
+        Ok(())
+    }
+
+    /// Records winnings paid out to the player
+    pub fn record_winnings(&mut self, amount: u64) -> Result<()> {
+        self.total_won = safe_math::safe_add(self.total_won, amount)?;
+        Ok(())
+    }
+
+    /// Current skill rating, the default rating until the first rated session
+    pub fn rating(&self) -> u64 {
+        match self.rating {
+            0 => DEFAULT_RATING,
+            rating => rating,
+        }
+    }
+
+    /// Applies an Elo rating change, returning the new rating
+    pub fn apply_rating_change(&mut self, delta: i64) -> u64 {
+        let rating = self.rating().saturating_add_signed(delta);
+        self.rating = rating.clamp(RATING_FLOOR, u16::MAX as u64);
+        self.rating
+    }
+
+    /// Whether the most recently joined session is the last one won
+    pub fn is_on_streak(&self) -> bool {
+        self.win_streak > 0 && self.games_played == self.streak_games
+    }
+
+    /// Records a session won. The streak only grows if no other session was
+    /// joined since the last win; otherwise that session was lost, so the
+    /// streak and its unclaimed bonuses reset first.
+    pub fn record_win(&mut self, streak_milestone: u16) -> Result<()> {
+        if self.games_played == safe_math::safe_add(self.streak_games, 1)? {
+            self.win_streak = safe_math::safe_add(self.win_streak, 1)?;
+        } else {
+            self.win_streak = 1;
+            self.pending_bonuses = 0;
+        }
+        self.streak_games = self.games_played;
+        self.best_win_streak = self.best_win_streak.max(self.win_streak);
+        if streak_milestone > 0 && self.win_streak % streak_milestone as u64 == 0 {
+            self.pending_bonuses = safe_math::safe_add(self.pending_bonuses, 1)?;
+        }
+        Ok(())
+    }
+
+    /// Takes the streak bonuses earned so far, which are only claimable while
+    /// the streak is unbroken
+    pub fn take_bonuses(&mut self) -> Result<u64> {
+        require!(self.is_on_streak(), WagerError::StreakBroken);
+        require!(self.pending_bonuses > 0, WagerError::NothingToClaim);
+        Ok(std::mem::take(&mut self.pending_bonuses))
+    }
+}
+
+/// Marker excluding a player flagged by the anti-cheat backend from joining
+/// sessions or buying spawns; its absence is what admits a player
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerBan {
+    pub player: Pubkey,         // Banned player
+    pub banned_at: i64,         // Unix timestamp of the ban
+    pub reason: u8,             // Off-chain reason code reported by the anti-cheat backend
+    pub bump: u8,               // PDA bump
+}
+
+impl PlayerBan {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 8 + 1 + 1;
+}
+
+/// Marker barring a player kicked from a session from taking a seat in it
+/// again; its absence is what admits a player
+#[account]
+#[derive(InitSpace)]
+pub struct SessionKick {
+    pub game_session: Pubkey,   // Session the player was kicked from
+    pub player: Pubkey,         // Kicked player
+    pub captain: Pubkey,        // Captain who kicked them, paid the rent and reclaims it
+    pub bump: u8,               // PDA bump
+}
+
+impl SessionKick {
+    /// Space of the account, anchor discriminator included
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1;
+}
+
+/// Helper function to check if an error is TeamIsFull
 fn is_team_full_error(error: &Error) -> bool {
     error.to_string().contains("TeamIsFull")
 }
+
+// Every hand-counted account size must match the space derived from its
+// fields, and the session layout growth table must add up to the last
+// append-only layout
+const _: () = {
+    assert!(Vault::LEN == 8 + Vault::INIT_SPACE);
+    assert!(ProgramConfig::LEN == 8 + ProgramConfig::INIT_SPACE);
+    assert!(PriceFeed::LEN == 8 + PriceFeed::INIT_SPACE);
+    assert!(BracketMatch::LEN == 8 + BracketMatch::INIT_SPACE);
+    assert!(SpectatorBet::LEN == 8 + SpectatorBet::INIT_SPACE);
+    assert!(Approval::LEN == 8 + Approval::INIT_SPACE);
+    assert!(Jackpot::LEN == 8 + Jackpot::INIT_SPACE);
+    assert!(Referral::LEN == 8 + Referral::INIT_SPACE);
+    assert!(ReferralCredit::LEN == 8 + ReferralCredit::INIT_SPACE);
+    assert!(MatchQueue::LEN == 8 + MatchQueue::INIT_SPACE);
+    assert!(LobbyIndex::LEN == 8 + LobbyIndex::INIT_SPACE);
+    assert!(SessionMetadata::LEN == 8 + SessionMetadata::INIT_SPACE);
+    assert!(PlayerStats::LEN == 8 + PlayerStats::INIT_SPACE);
+    assert!(PlayerBan::LEN == 8 + PlayerBan::INIT_SPACE);
+    assert!(SessionKick::LEN == 8 + SessionKick::INIT_SPACE);
+    assert!(UnclaimedPayout::LEN == 8 + UnclaimedPayout::INIT_SPACE);
+    assert!(ActiveExposure::LEN == 8 + ActiveExposure::INIT_SPACE);
+    assert!(SessionTemplate::LEN == 8 + SessionTemplate::INIT_SPACE);
+    assert!(RecurringMatch::LEN == 8 + RecurringMatch::INIT_SPACE);
+    assert!(Announcements::LEN == 8 + Announcements::INIT_SPACE);
+    assert!(BetReceipt::LEN == 8 + BetReceipt::INIT_SPACE);
+    assert!(RegisteredMint::LEN == 8 + RegisteredMint::INIT_SPACE);
+    assert!(ServerBond::LEN == 8 + ServerBond::INIT_SPACE);
+    assert!(DisputeVotes::LEN == 8 + DisputeVotes::INIT_SPACE);
+    assert!(ResultCommitment::LEN == 8 + ResultCommitment::INIT_SPACE);
+    assert!(Tournament::space(MAX_TOURNAMENT_TEAMS as u8) == 8 + Tournament::INIT_SPACE);
+    assert!(SpectatorPool::space(MAX_TEAMS) == 8 + SpectatorPool::INIT_SPACE);
+    assert!(MatchLog::space(MAX_MATCH_LOG_CAPACITY) == 8 + MatchLog::INIT_SPACE);
+    assert!(MatchResultRecord::space(MAX_RECORDED_PLAYERS) == 8 + MatchResultRecord::INIT_SPACE);
+
+    assert!(GameSession::LEN % 8 == 0);
+    // Sessions are created through a system program CPI, which can't
+    // allocate more than MAX_PERMITTED_DATA_INCREASE at once
+    assert!(GameSession::LEN <= MAX_PERMITTED_DATA_INCREASE);
+    let mut len = MIN_MIGRATABLE_SESSION_LEN;
+    let mut index = 0;
+    while index < GAME_SESSION_VERSION_GROWTH.len() {
+        len += GAME_SESSION_VERSION_GROWTH[index];
+        index += 1;
+    }
+    assert!(len == GameSessionV42::LEN);
+    assert!(V42_PLAYERS_PER_TEAM <= MAX_PLAYERS_PER_TEAM);
+};
@@ -1,23 +1,715 @@
+use crate::{
+    errors::{err_with_player, WagerError},
+    events::BondSlashed,
+    state::*,
+    validation::{safe_math, validation},
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, TokenAccount, Transfer as SplTransfer};
+use anchor_lang::solana_program::{
+    ed25519_program, hash,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::memo::{self, Memo};
+use anchor_spl::token_interface::{
+    self,
+    spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+        },
+    },
+    Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
+/// Amount to send so that exactly `net` arrives after the mint's transfer fee
+pub fn gross_up_transfer_fee(mint: &InterfaceAccount<Mint>, net: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(net);
+    }
+    let data = mint_info.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let Ok(fee_config) = state.get_extension::<TransferFeeConfig>() else {
+        return Ok(net);
+    };
+    let fee = fee_config
+        .calculate_inverse_epoch_fee(Clock::get()?.epoch, net)
+        .ok_or(error!(WagerError::ArithmeticOverflow))?;
+    safe_math::safe_add(net, fee)
+}
+
+/// Transfer fee the mint withholds from a transfer of `amount`, zero for
+/// mints without one
+pub fn transfer_fee(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+    let data = mint_info.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let Ok(fee_config) = state.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+    fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(error!(WagerError::ArithmeticOverflow))
+}
+
+/// Token balance of a classic SPL or Token-2022 account
+pub fn token_balance(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    Ok(state.base.amount)
+}
+
+/// Deposits tokens into escrow from an account the signer owns. The signer
+/// pays any transfer fee on top, so escrow receives exactly `amount`; the
+/// gross amount charged is returned.
 pub fn transfer_spl_tokens<'info>(
-    source: &Account<'info, TokenAccount>,
-    destination: &Account<'info, TokenAccount>,
+    source: &InterfaceAccount<'info, TokenAccount>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
     authority: &Signer<'info>,
-    token_program: &Program<'info, token::Token>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<u64> {
+    let gross = gross_up_transfer_fee(mint, amount)?;
+    transfer_from_escrow(
+        &source.to_account_info(),
+        &destination.to_account_info(),
+        &authority.to_account_info(),
+        mint,
+        token_program,
+        gross,
+        &[],
+    )?;
+    Ok(gross)
+}
+
+/// Moves tokens out of an account owned by `authority` with `transfer_checked`,
+/// signed with the given PDA seeds. Escrow is debited exactly `amount`; any
+/// transfer fee is withheld from what the destination receives.
+///
+/// Both balances are read around the CPI and must move by exactly the
+/// intended amounts, so a transfer hook or an unexpected fee can't silently
+/// short a deposit or payout.
+pub fn transfer_from_escrow<'info>(
+    source: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
     amount: u64,
+    signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
-    let cpi_accounts = SplTransfer {
-        from: source.to_account_info(),
-        to: destination.to_account_info(),
-        authority: authority.to_account_info(),
-    };
+    let source_before = token_balance(source)?;
+    let destination_before = token_balance(destination)?;
 
-    token::transfer(
-        CpiContext::new(token_program.to_account_info(), cpi_accounts),
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: source.clone(),
+                mint: mint.to_account_info(),
+                to: destination.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
         amount,
+        mint.decimals,
+    )?;
+
+    // A self-transfer leaves both balances where they were
+    if source.key() == destination.key() {
+        return Ok(());
+    }
+    let debited = source_before
+        .checked_sub(token_balance(source)?)
+        .ok_or(error!(WagerError::TransferAmountMismatch))?;
+    let credited = token_balance(destination)?
+        .checked_sub(destination_before)
+        .ok_or(error!(WagerError::TransferAmountMismatch))?;
+    let expected_credit = safe_math::safe_subtract(amount, transfer_fee(mint, amount)?)?;
+    require!(
+        debited == amount && credited == expected_credit,
+        WagerError::TransferAmountMismatch
+    );
+    Ok(())
+}
+
+/// Sweeps transfer fees withheld in a Token-2022 account to its mint so the
+/// account can be closed, a no-op for classic SPL mints
+pub fn harvest_withheld_fees<'info>(
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    account: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<()> {
+    if mint.to_account_info().owner != &spl_token_2022::ID {
+        return Ok(());
+    }
+    token_interface::harvest_withheld_tokens_to_mint(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_interface::HarvestWithheldTokensToMint {
+                token_program_id: token_program.to_account_info(),
+                mint: mint.to_account_info(),
+            },
+        ),
+        vec![account.to_account_info()],
+    )
+}
+
+/// Calls an instruction of a yield adapter signed by the tournament vault.
+/// Adapters expose anchor-style `deposit(amount: u64)` and `withdraw()`
+/// instructions taking the vault, its escrow token account, the mint and the
+/// token program first, followed by their own accounts. `withdraw` returns
+/// the whole position, principal and yield, to the escrow token account.
+#[allow(clippy::too_many_arguments)]
+pub fn invoke_yield_adapter<'info>(
+    yield_program: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    vault_token_account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    adapter_accounts: &[AccountInfo<'info>],
+    method: &str,
+    amount: Option<u64>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = hash::hash(format!("global:{}", method).as_bytes()).to_bytes()[..8].to_vec();
+    if let Some(amount) = amount {
+        data.extend_from_slice(&amount.to_le_bytes());
+    }
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(vault.key(), true),
+        AccountMeta::new(vault_token_account.key(), false),
+        AccountMeta::new_readonly(mint.key(), false),
+        AccountMeta::new_readonly(token_program.key(), false),
+    ];
+    let mut infos = vec![
+        vault.clone(),
+        vault_token_account.clone(),
+        mint.clone(),
+        token_program.clone(),
+    ];
+    for account in adapter_accounts {
+        accounts.push(if account.is_writable {
+            AccountMeta::new(account.key(), account.is_signer)
+        } else {
+            AccountMeta::new_readonly(account.key(), account.is_signer)
+        });
+        infos.push(account.clone());
+    }
+    infos.push(yield_program.clone());
+
+    invoke_signed(
+        &Instruction {
+            program_id: yield_program.key(),
+            accounts,
+            data,
+        },
+        &infos,
+        signer_seeds,
     )?;
+    Ok(())
+}
+
+/// Validates the payout destination paired with a player in remaining accounts.
+/// Native SOL sessions pay the player wallet itself, SPL sessions require a
+/// token account of the session mint owned by the player.
+pub fn validate_payout_destination<'info>(
+    game_session: &GameSession,
+    player: &AccountInfo<'info>,
+    destination: &'info AccountInfo<'info>,
+    owner_error: WagerError,
+) -> Result<()> {
+    if game_session.is_native() {
+        if destination.key() != player.key() {
+            return Err(err_with_player(owner_error, player.key));
+        }
+        return Ok(());
+    }
+
+    let token_account = InterfaceAccount::<TokenAccount>::try_from(destination)?;
+    if token_account.owner != player.key() {
+        return Err(err_with_player(owner_error, player.key));
+    }
+    require!(
+        token_account.mint == game_session.mint,
+        WagerError::InvalidTokenMint
+    );
+    Ok(())
+}
+
+/// Whether an SPL payout destination cannot take a transfer because the
+/// account was closed or frozen. Only the player's own associated token
+/// account may be skipped this way, so a caller cannot hold back a payout by
+/// supplying some other account.
+pub fn payout_destination_unusable(
+    game_session: &GameSession,
+    player: &AccountInfo,
+    destination: &AccountInfo,
+    token_program: &Pubkey,
+) -> Result<bool> {
+    if game_session.is_native() {
+        return Ok(false);
+    }
+
+    let closed = destination.owner != token_program || destination.data_is_empty();
+    if !closed {
+        let token_account =
+            TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])?;
+        if !token_account.is_frozen() {
+            return Ok(false);
+        }
+    }
+    require!(
+        destination.key()
+            == get_associated_token_address_with_program_id(
+                player.key,
+                &game_session.mint,
+                token_program
+            ),
+        WagerError::InvalidPlayerTokenAccount
+    );
+    Ok(true)
+}
+
+/// Releases escrowed funds from the session vault to a payout destination.
+/// Native SOL sessions move lamports held by the vault PDA directly, SPL
+/// sessions transfer from the vault token account signed by the vault PDA.
+#[allow(clippy::too_many_arguments)]
+pub fn release_from_vault<'info>(
+    game_session: &GameSession,
+    vault: &Account<'info, Vault>,
+    vault_token_account: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    destination: &AccountInfo<'info>,
+    session_id: &str,
+    amount: u64,
+) -> Result<()> {
+    if game_session.is_native() {
+        vault.sub_lamports(amount)?;
+        destination.add_lamports(amount)?;
+        return Ok(());
+    }
+
+    transfer_from_escrow(
+        &vault_token_account.to_account_info(),
+        destination,
+        &vault.to_account_info(),
+        mint,
+        token_program,
+        amount,
+        &[&[b"vault", session_id.as_bytes(), &[game_session.vault_bump]]],
+    )
+}
+
+/// Funds currently escrowed for the session: the vault token balance, or the
+/// vault PDA's lamports above its rent-exempt minimum for native SOL sessions
+pub fn escrow_balance<'info>(
+    game_session: &GameSession,
+    vault: &Account<'info, Vault>,
+    vault_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+) -> Result<u64> {
+    if game_session.is_native() {
+        let rent_exempt = Rent::get()?.minimum_balance(vault.to_account_info().data_len());
+        return safe_math::safe_subtract(vault.get_lamports(), rent_exempt);
+    }
+
+    vault_token_account.reload()?;
+    Ok(vault_token_account.amount)
+}
+
+/// Credits a won session, its payout and the player's rating change to the
+/// lifetime statistics passed in remaining accounts, returning the old and new
+/// rating
+pub fn record_player_winnings<'info>(
+    stats_info: &'info AccountInfo<'info>,
+    player: &Pubkey,
+    amount: u64,
+    streak_milestone: u16,
+    rating_change: i64,
+    won: bool,
+) -> Result<(u64, u64)> {
+    let mut player_stats = Account::<PlayerStats>::try_from(stats_info)?;
+    if player_stats.player != *player {
+        return Err(err_with_player(WagerError::InvalidPlayerStats, player));
+    }
+    player_stats.validate_pda(stats_info.key)?;
+    player_stats.record_winnings(amount)?;
+    if won {
+        player_stats.record_win(streak_milestone)?;
+    }
+    let old_rating = player_stats.rating();
+    let new_rating = player_stats.apply_rating_change(rating_change);
+    player_stats.exit(&crate::ID)?;
+    Ok((old_rating, new_rating))
+}
+
+/// Writes a compact settlement summary through the SPL Memo program so wallets
+/// and explorers show what a payout transaction settled; a no-op when the
+/// memo program is not supplied
+pub fn write_settlement_memo<'info>(
+    memo_program: Option<&Program<'info, Memo>>,
+    session_id: &str,
+    outcome: &str,
+    total: u64,
+) -> Result<()> {
+    let Some(memo_program) = memo_program else {
+        return Ok(());
+    };
+    let memo = format!("wager {} {} total={}", session_id, outcome, total);
+    memo::build_memo(
+        CpiContext::new(memo_program.to_account_info(), memo::BuildMemo {}),
+        memo.as_bytes(),
+    )
+}
+
+/// Size of the ed25519 program instruction header plus one offsets entry
+const ED25519_HEADER_LEN: usize = 2 + 14;
+
+/// Message a kill report signer attests to: session id, killer, victim, the
+/// assist slots, round and report sequence number
+pub fn kill_report_message(
+    session_id: &str,
+    killer: &Pubkey,
+    victim: &Pubkey,
+    assists: &[Pubkey; MAX_KILL_ASSISTS],
+    round: u64,
+    sequence: u64,
+) -> Vec<u8> {
+    let mut message =
+        Vec::with_capacity(session_id.len() + 32 + 32 + 32 * MAX_KILL_ASSISTS + 8 + 8);
+    message.extend_from_slice(session_id.as_bytes());
+    message.extend_from_slice(killer.as_ref());
+    message.extend_from_slice(victim.as_ref());
+    for assist in assists {
+        message.extend_from_slice(assist.as_ref());
+    }
+    message.extend_from_slice(&round.to_le_bytes());
+    message.extend_from_slice(&sequence.to_le_bytes());
+    message
+}
+
+/// Message a kill report signer attests to for a batch: session id, round,
+/// sequence number of the first kill, then the killer, victim and assist
+/// slots of each kill
+pub fn kill_batch_message(
+    session_id: &str,
+    round: u64,
+    sequence: u64,
+    kills: &[KillRecord],
+) -> Vec<u8> {
+    let mut message =
+        Vec::with_capacity(session_id.len() + 8 + 8 + kills.len() * (64 + 32 * MAX_KILL_ASSISTS));
+    message.extend_from_slice(session_id.as_bytes());
+    message.extend_from_slice(&round.to_le_bytes());
+    message.extend_from_slice(&sequence.to_le_bytes());
+    for kill in kills {
+        message.extend_from_slice(kill.killer.as_ref());
+        message.extend_from_slice(kill.victim.as_ref());
+        for assist in &kill.assists {
+            message.extend_from_slice(assist.as_ref());
+        }
+    }
+    message
+}
+
+/// Adds a joining player's bet to their exposure across open sessions. The
+/// exposure account may only be omitted while the config sets no cap.
+pub fn record_exposure(
+    exposure: Option<&mut Account<ActiveExposure>>,
+    bump: Option<u8>,
+    config: &ProgramConfig,
+    player: Pubkey,
+    game_session: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let Some(exposure) = exposure else {
+        require!(config.max_exposure == 0, WagerError::MissingActiveExposure);
+        return Ok(());
+    };
+    exposure.init_if_new(
+        player,
+        bump.ok_or(error!(WagerError::MissingActiveExposure))?,
+    );
+    validation::validate_rent_exempt(&exposure.to_account_info(), ActiveExposure::LEN)?;
+    exposure.add(game_session, amount, config.max_exposure)
+}
+
+/// Releases a refunded player's bet from their exposure, if they passed the
+/// account
+pub fn release_exposure(
+    exposure: Option<&mut Account<ActiveExposure>>,
+    game_session: Pubkey,
+) -> Result<()> {
+    if let Some(exposure) = exposure {
+        exposure.release(game_session)?;
+    }
+    Ok(())
+}
+
+/// Applies one lifetime statistics update to a `PlayerStats` account passed
+/// in remaining accounts
+pub fn update_player_stats<'info>(
+    stats_info: &'info AccountInfo<'info>,
+    player: &Pubkey,
+    update: impl FnOnce(&mut PlayerStats) -> Result<()>,
+) -> Result<()> {
+    let mut player_stats = Account::<PlayerStats>::try_from(stats_info)?;
+    if player_stats.player != *player {
+        return Err(err_with_player(WagerError::InvalidPlayerStats, player));
+    }
+    player_stats.validate_pda(stats_info.key)?;
+    update(&mut player_stats)?;
+    player_stats.exit(&crate::ID)
+}
+
+/// Verifies that the instruction immediately preceding the current one is an
+/// ed25519 program instruction checking a single signature by `signer` over
+/// `message`. The precompile itself rejects the transaction if the signature is
+/// invalid, so only the signer and message bound into it need to be checked.
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, WagerError::MissingKillReportSignature);
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    require!(
+        ix.program_id == ed25519_program::ID,
+        WagerError::MissingKillReportSignature
+    );
+    require!(
+        ix.accounts.is_empty(),
+        WagerError::InvalidKillReportSignature
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN && data[0] == 1,
+        WagerError::InvalidKillReportSignature
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let public_key_offset = read_u16(6) as usize;
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+
+    // Signature, key and message must all live in the ed25519 instruction itself
+    require!(
+        read_u16(4) == u16::MAX && read_u16(8) == u16::MAX && read_u16(14) == u16::MAX,
+        WagerError::InvalidKillReportSignature
+    );
 
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(WagerError::InvalidKillReportSignature)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(WagerError::InvalidKillReportSignature)?;
+    require!(
+        public_key == signer.as_ref() && signed_message == message,
+        WagerError::InvalidKillReportSignature
+    );
     Ok(())
 }
+
+/// Counts the game server signing set keys approving a sensitive operation:
+/// the signer itself plus any approvals recorded on chain for the same result
+pub fn game_server_approvals(
+    config: &ProgramConfig,
+    signer: &Pubkey,
+    approval: Option<&Account<Approval>>,
+    game_session: Pubkey,
+    kind: ApprovalKind,
+    winning_team: u8,
+) -> Result<usize> {
+    let approvers: &[Pubkey] = match approval {
+        Some(approval) => {
+            require!(
+                approval.game_session == game_session
+                    && approval.kind == kind
+                    && approval.winning_team == winning_team,
+                WagerError::ApprovalMismatch
+            );
+            &approval.approvers
+        }
+        None => &[],
+    };
+    Ok(config.approval_count(signer, approvers))
+}
+
+/// Checks a bet's USD value against the config's bounds using the mint's
+/// registered oracle price, when USD bounds are enforced
+pub fn validate_bet_usd_bounds(
+    config: &ProgramConfig,
+    mint: &InterfaceAccount<Mint>,
+    price_feed: Option<&Account<PriceFeed>>,
+    price_update: Option<&Account<PriceUpdateV2>>,
+    bet_amount: u64,
+    clock: &Clock,
+) -> Result<()> {
+    if !config.enforces_usd_bounds() {
+        return Ok(());
+    }
+    let (Some(price_feed), Some(price_update)) = (price_feed, price_update) else {
+        return Err(error!(WagerError::MissingPriceFeed));
+    };
+    let price = price_update
+        .get_price_no_older_than(clock, config.max_price_age_secs, &price_feed.feed_id)
+        .map_err(|_| error!(WagerError::InvalidOraclePrice))?;
+    validation::validate_bet_amount_usd(
+        bet_amount,
+        mint.decimals,
+        price.price,
+        price.exponent,
+        config.usd_min_bet,
+        config.usd_max_bet,
+    )
+}
+
+/// Splits the slashed share of the operator's bond evenly between the seated
+/// players of the corrected winning team, leaving division dust in the bond.
+/// An operator that never posted a bond has nothing to slash, and a session
+/// slashes it at most once however often its result is overturned.
+pub fn slash_bond<'info>(
+    server_bond: &AccountInfo<'info>,
+    game_session: &mut GameSession,
+    winning_team: u8,
+    slash_bps: u16,
+    players: &[AccountInfo<'info>],
+) -> Result<()> {
+    if server_bond.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(
+        *server_bond.owner,
+        crate::ID,
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+    require!(
+        game_session.bond_slashed == 0,
+        WagerError::BondAlreadySlashed
+    );
+    let mut bond = ServerBond::try_deserialize(&mut &server_bond.try_borrow_data()?[..])?;
+
+    // Seats a short-handed team never filled get no share
+    let capacity = game_session.team_capacity(winning_team as usize)?;
+    let winners: Vec<Pubkey> = game_session.team(winning_team)?.players[..capacity]
+        .iter()
+        .copied()
+        .filter(|player| *player != Pubkey::default())
+        .collect();
+    require!(
+        players.len() == winners.len(),
+        WagerError::InvalidRemainingAccounts
+    );
+    if winners.is_empty() {
+        return Ok(());
+    }
+
+    let slashed = safe_math::apply_bps(bond.amount, slash_bps)?;
+    let share = safe_math::safe_divide(slashed, winners.len() as u64)?;
+    if share == 0 {
+        return Ok(());
+    }
+
+    for (player_account, player) in players.iter().zip(&winners) {
+        require!(player_account.key() == *player, WagerError::InvalidPlayer);
+        bond.debit(share)?;
+        bond.total_slashed = safe_math::safe_add(bond.total_slashed, share)?;
+        server_bond.sub_lamports(share)?;
+        player_account.add_lamports(share)?;
+
+        emit!(BondSlashed {
+            session_id: game_session.session_id(),
+            operator: bond.operator,
+            player: *player,
+            amount: share,
+        });
+    }
+    game_session.bond_slashed = 1;
+    bond.try_serialize(&mut &mut server_bond.try_borrow_mut_data()?[..])
+}
+
+/// Reads the bet receipt at a player's receipt PDA, `None` if they hold none,
+/// as players seated from a match queue don't. The receipt must cover the
+/// player's stake and still be open, so a bet can't be returned twice.
+pub fn open_bet_receipt(
+    receipt_info: &AccountInfo,
+    player: Pubkey,
+    stake: u64,
+) -> Result<Option<BetReceipt>> {
+    if receipt_info.data_is_empty() {
+        return Ok(None);
+    }
+    require_keys_eq!(
+        *receipt_info.owner,
+        crate::ID,
+        WagerError::BetReceiptMismatch
+    );
+    let receipt = BetReceipt::try_deserialize(&mut &receipt_info.try_borrow_data()?[..])?;
+    require!(
+        receipt.player == player && receipt.amount == stake,
+        WagerError::BetReceiptMismatch
+    );
+    require!(
+        receipt.status == ReceiptStatus::Open,
+        WagerError::BetReceiptClaimed
+    );
+    Ok(Some(receipt))
+}
+
+/// Closes the bet receipt a single player's refund consumed, returning its
+/// rent to the player
+pub fn redeem_bet_receipt<'info>(
+    receipt_info: &AccountInfo<'info>,
+    player_info: &AccountInfo<'info>,
+) -> Result<()> {
+    player_info.add_lamports(receipt_info.lamports())?;
+    receipt_info.sub_lamports(receipt_info.lamports())?;
+    receipt_info.assign(&anchor_lang::system_program::ID);
+    receipt_info.realloc(0, false)?;
+    Ok(())
+}
+
+/// Marks the bet receipt a settlement passes for one of its players as
+/// refunded or paid out. Players seated from a match queue hold none, and
+/// pass their empty receipt PDA instead. The player reclaims the rent with
+/// `close_bet_receipt`.
+pub fn settle_bet_receipt(
+    receipt_info: &AccountInfo,
+    game_session: Pubkey,
+    player: Pubkey,
+    status: ReceiptStatus,
+) -> Result<()> {
+    if receipt_info.data_is_empty() {
+        let (address, _) = Pubkey::find_program_address(
+            &[b"bet_receipt", game_session.as_ref(), player.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(receipt_info.key(), address, WagerError::BetReceiptMismatch);
+        return Ok(());
+    }
+    require_keys_eq!(
+        *receipt_info.owner,
+        crate::ID,
+        WagerError::BetReceiptMismatch
+    );
+    let mut receipt = BetReceipt::try_deserialize(&mut &receipt_info.try_borrow_data()?[..])?;
+    require!(
+        receipt.game_session == game_session && receipt.player == player,
+        WagerError::BetReceiptMismatch
+    );
+    receipt.claim(status)?;
+    receipt.try_serialize(&mut &mut receipt_info.try_borrow_mut_data()?[..])
+}
@@ -2,13 +2,28 @@ use anchor_lang::prelude::*;
 use crate::errors::WagerError;
 
 /// Input validation utilities for security
+#[allow(clippy::module_inception)]
 pub mod validation {
     use super::*;
+    use crate::errors::{err_with_amount, err_with_deadline};
+    use crate::state::{
+        ConfigParams, FeeShare, JoinCredential, JoinWindow, PayoutSchedule, RefundPolicy, SessionAccess, SessionMetadataParams, SessionStatus, SessionTerms, WinCondition, FREE_FOR_ALL_PAYOUT_BPS, MAX_ANNOUNCEMENT_LEN, MAX_ARBITERS, MAX_CLOCK_SKEW_SECS, MAX_FEE_BPS, MAX_GAME_NAME_LEN,
+        MAX_GAME_SERVER_KEYS, MAX_KILL_ASSISTS, MAX_MAP_ID_LEN, MAX_MATCH_LOG_CAPACITY, MAX_METADATA_BLOB_LEN,
+        MAX_PAYOUT_SHARES, MAX_REGION_LEN, MAX_SESSION_ID_LEN, MAX_TROPHY_URI_LEN, MAX_CPI_CALLERS, MAX_FEE_SPLIT_RECIPIENTS, MAX_FREEROLL_SPONSORS, MAX_YIELD_PROGRAMS, MAX_SERIES_ROUNDS, MAX_TEAMS, MAX_TEAM_CAPACITY, MAX_TOURNAMENT_TEAMS,
+        USD_DECIMALS,
+    };
+    use crate::validation::safe_math;
+    use anchor_lang::solana_program::instruction::{
+        get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT,
+    };
+    use anchor_lang::solana_program::keccak;
+    use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+    use anchor_spl::token_interface::TokenAccount;
 
     /// Validates session ID format and length
     pub fn validate_session_id(session_id: &str) -> Result<()> {
         require!(!session_id.is_empty(), WagerError::InvalidSessionId);
-        require!(session_id.len() <= 32, WagerError::SessionIdTooLong);
+        require!(session_id.len() <= MAX_SESSION_ID_LEN, WagerError::SessionIdTooLong);
         require!(
             session_id.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_'),
             WagerError::InvalidSessionIdFormat
@@ -16,16 +31,620 @@ pub mod validation {
         Ok(())
     }
 
-    /// Validates team number (must be 0 or 1)
-    pub fn validate_team_number(team: u8) -> Result<()> {
-        require!(team == 0 || team == 1, WagerError::InvalidTeamSelection);
+    /// Validates team number is within the session's team count
+    pub fn validate_team_number(team: u8, team_count: usize) -> Result<()> {
+        require!((team as usize) < team_count, WagerError::InvalidTeamSelection);
+        Ok(())
+    }
+
+    /// Validates that no player occupies more than one roster slot, on the
+    /// same team or across teams. Empty slots are ignored.
+    pub fn validate_unique_players(players: &[Pubkey]) -> Result<()> {
+        for (index, player) in players.iter().enumerate() {
+            if *player == Pubkey::default() {
+                continue;
+            }
+            require!(
+                !players[index + 1..].contains(player),
+                WagerError::PlayerAlreadyJoined
+            );
+        }
+        Ok(())
+    }
+
+    /// Validates the number of teams requested for a session (2..=MAX_TEAMS)
+    pub fn validate_team_count(team_count: u8) -> Result<()> {
+        require!(
+            (2..=MAX_TEAMS).contains(&(team_count as usize)),
+            WagerError::InvalidTeamCount
+        );
+        Ok(())
+    }
+
+    /// Validates the roster size of a team in an asymmetric lobby (1..=MAX_TEAM_CAPACITY)
+    pub fn validate_team_capacity(capacity: u8) -> Result<()> {
+        require!(
+            (1..=MAX_TEAM_CAPACITY).contains(&capacity),
+            WagerError::InvalidTeamCapacity
+        );
+        Ok(())
+    }
+
+    /// Validates a join window: the deadline lies in the future, the minimum
+    /// needs at least two players (every ranked place in free-for-all) without
+    /// exceeding the lobby's seats, and the per-team minimum fits the smallest
+    /// team. Free-for-all entrants have no teams to set a minimum for.
+    pub fn validate_join_window(
+        window: &JoinWindow,
+        now: i64,
+        seats: usize,
+        min_team_capacity: usize,
+        free_for_all: bool,
+    ) -> Result<()> {
+        require!(window.deadline > now, WagerError::InvalidJoinWindow);
+        let fewest = if free_for_all { FREE_FOR_ALL_PAYOUT_BPS.len() } else { 2 };
+        require!(
+            (fewest..=seats).contains(&(window.min_players as usize)),
+            WagerError::InvalidJoinWindow
+        );
+        if free_for_all {
+            require!(window.min_players_per_team == 0, WagerError::InvalidJoinWindow);
+        } else {
+            require!(
+                window.min_players_per_team as usize <= min_team_capacity,
+                WagerError::InvalidJoinWindow
+            );
+        }
+        Ok(())
+    }
+
+    /// Validates a session's game configuration against its game mode, and
+    /// its join window against the resulting rosters
+    pub fn validate_session_terms(
+        terms: &SessionTerms,
+        join_window: Option<&JoinWindow>,
+        native: bool,
+        now: i64,
+    ) -> Result<()> {
+        let game_mode = terms.game_mode;
+        validate_spawn_earnings_bps(terms.spawn_earnings_bps)?;
+        // Every assist on a kill together may take at most the whole kill
+        require!(
+            terms.assist_weight_bps as u64 * MAX_KILL_ASSISTS as u64
+                <= safe_math::BPS_DENOMINATOR,
+            WagerError::InvalidAssistWeight
+        );
+        validate_series_length(terms.series_length)?;
+        validate_log_capacity(terms.log_capacity)?;
+
+        // Free-for-all sessions have no teams, every entrant plays for themselves
+        if game_mode.is_free_for_all() {
+            require!(terms.team_count == 0, WagerError::InvalidTeamCount);
+        } else {
+            validate_team_count(terms.team_count)?;
+        }
+
+        // Only two-team lobbies can size their teams asymmetrically
+        if terms.team_a_capacity != 0 || terms.team_b_capacity != 0 {
+            require!(
+                !game_mode.is_free_for_all() && terms.team_count == 2,
+                WagerError::InvalidTeamCapacity
+            );
+            validate_team_capacity(terms.team_a_capacity)?;
+            validate_team_capacity(terms.team_b_capacity)?;
+        }
+        let team_capacities = terms.team_capacities();
+        let min_team_capacity = team_capacities[..terms.team_count as usize]
+            .iter()
+            .min()
+            .copied()
+            .unwrap_or_default() as usize;
+
+        // Ranked payouts split a single winning team's prize pool, which a team
+        // starting short-handed must still have enough players to take
+        if terms.payout_schedule != PayoutSchedule::WinnerTeamEqual {
+            require!(
+                !game_mode.is_free_for_all() && !game_mode.is_pay_to_spawn(),
+                WagerError::InvalidPayoutSchedule
+            );
+            let min_roster = join_window.map_or(min_team_capacity, |window| {
+                (window.min_players_per_team as usize).clamp(1, min_team_capacity.max(1))
+            });
+            validate_payout_shares(terms.payout_schedule.shares(), min_roster)?;
+        }
+
+        // Stake-weighted payouts split a single winning team's pot by stake
+        require!(
+            terms.max_stake == 0
+                || (!game_mode.is_free_for_all()
+                    && !game_mode.is_pay_to_spawn()
+                    && terms.payout_schedule == PayoutSchedule::WinnerTeamEqual),
+            WagerError::InvalidStakeRange
+        );
+
+        // Series are decided by round wins, which only winner-takes-all team
+        // sessions have
+        require!(
+            terms.series_length == 0
+                || (!game_mode.is_free_for_all() && !game_mode.is_pay_to_spawn()),
+            WagerError::InvalidSeriesLength
+        );
+
+        // Win conditions pick a single winning team, and series are decided by
+        // their rounds instead
+        if terms.win_condition != WinCondition::ServerDecision {
+            require!(
+                !game_mode.is_free_for_all()
+                    && !game_mode.is_pay_to_spawn()
+                    && terms.series_length == 0,
+                WagerError::InvalidWinCondition
+            );
+        }
+        if let WinCondition::FirstToScore(target) = terms.win_condition {
+            require!(target > 0, WagerError::InvalidWinCondition);
+        }
+
+        // Native SOL escrow only covers fixed entry bets
+        require!(
+            !(native && game_mode.is_pay_to_spawn()),
+            WagerError::UnsupportedNativeGameMode
+        );
+
+        // A join deadline lets the session start short of a full roster
+        if let Some(window) = join_window {
+            let seats = if game_mode.is_free_for_all() {
+                game_mode.free_for_all_players()
+            } else {
+                team_capacities
+                    .iter()
+                    .map(|capacity| *capacity as usize)
+                    .sum()
+            };
+            validate_join_window(
+                window,
+                now,
+                seats,
+                min_team_capacity,
+                game_mode.is_free_for_all(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Validates a stake-weighted session's stake range: from the bet up to
+    /// `max_stake`, which the config's maximum bet also bounds
+    pub fn validate_stake_range(bet_amount: u64, max_stake: u64, max_bet: u64) -> Result<()> {
+        require!(
+            max_stake == 0 || (bet_amount..=max_bet).contains(&max_stake),
+            WagerError::InvalidStakeRange
+        );
+        Ok(())
+    }
+
+    /// Validates a freeroll's terms: players join without a bet and never
+    /// pay for spawns, and leaving or aborting simply frees the seat
+    pub fn validate_freeroll_terms(bet_amount: u64, terms: &SessionTerms) -> Result<()> {
+        require!(
+            bet_amount == 0
+                && !terms.game_mode.is_pay_to_spawn()
+                && terms.refund_policy == RefundPolicy::FullAnytimeBeforeStart
+                && terms.max_stake == 0,
+            WagerError::InvalidFreeroll
+        );
+        Ok(())
+    }
+
+    /// Validates a best-of-N series length: odd, so a series can't end tied,
+    /// and within MAX_SERIES_ROUNDS. 0 plays a single game.
+    pub fn validate_series_length(series_length: u8) -> Result<()> {
+        require!(
+            series_length == 0
+                || (series_length % 2 == 1 && series_length as usize <= MAX_SERIES_ROUNDS),
+            WagerError::InvalidSeriesLength
+        );
+        Ok(())
+    }
+
+    /// Validates a lobby's rating bounds, where a zero maximum is unbounded
+    pub fn validate_rating_bounds(min_rating: u16, max_rating: u16) -> Result<()> {
+        require!(
+            max_rating == 0 || min_rating <= max_rating,
+            WagerError::InvalidRatingBounds
+        );
+        Ok(())
+    }
+
+    /// Validates ranked payout shares fit the smallest team and sum to 100%
+    pub fn validate_payout_shares(shares: &[u16], min_team_capacity: usize) -> Result<()> {
+        require!(
+            shares.len() <= MAX_PAYOUT_SHARES && shares.len() <= min_team_capacity,
+            WagerError::InvalidPayoutSchedule
+        );
+        let total: u64 = shares.iter().map(|bps| *bps as u64).sum();
+        require!(
+            total == safe_math::BPS_DENOMINATOR,
+            WagerError::InvalidPayoutSchedule
+        );
+        Ok(())
+    }
+
+    /// Validates that the payouts still owed, rake included, never exceed the
+    /// funds escrowed for them
+    pub fn validate_payout_invariant(
+        payouts: impl IntoIterator<Item = u64>,
+        escrowed: u64,
+    ) -> Result<()> {
+        let total = payouts.into_iter().try_fold(0u64, safe_math::safe_add)?;
+        if total > escrowed {
+            msg!("Escrow balance: {}", escrowed);
+            return Err(err_with_amount(WagerError::PayoutExceedsEscrow, total));
+        }
+        Ok(())
+    }
+
+    /// Validates a joiner's credential against the session's access control.
+    /// Join codes must hash to the stored keccak digest; allowlist proofs fold
+    /// the keccak hash of the player's pubkey with each sibling, smaller hash
+    /// first, up to the stored merkle root.
+    pub fn validate_join_credential(
+        access: SessionAccess,
+        player: &Pubkey,
+        credential: Option<&JoinCredential>,
+    ) -> Result<()> {
+        let valid = match (access, credential) {
+            (SessionAccess::Open, _) => true,
+            (SessionAccess::JoinCode(hash), Some(JoinCredential::JoinCode(code))) => {
+                keccak::hash(code).to_bytes() == hash
+            }
+            (SessionAccess::Allowlist(root), Some(JoinCredential::MerkleProof(proof))) => {
+                let leaf = keccak::hash(player.as_ref()).to_bytes();
+                let computed = proof.iter().fold(leaf, |node, sibling| {
+                    if node <= *sibling {
+                        keccak::hashv(&[&node, sibling]).to_bytes()
+                    } else {
+                        keccak::hashv(&[sibling, &node]).to_bytes()
+                    }
+                });
+                computed == root
+            }
+            _ => false,
+        };
+        require!(valid, WagerError::InvalidJoinCredential);
+        Ok(())
+    }
+
+    /// Validates session metadata fits its bounded fields and holds no control
+    /// characters (other than whitespace in the custom blob)
+    pub fn validate_session_metadata(params: &SessionMetadataParams) -> Result<()> {
+        require!(
+            !params.game_name.is_empty() && params.game_name.len() <= MAX_GAME_NAME_LEN,
+            WagerError::InvalidSessionMetadata
+        );
+        require!(
+            params.map_id.len() <= MAX_MAP_ID_LEN,
+            WagerError::InvalidSessionMetadata
+        );
+        require!(
+            params.region.len() <= MAX_REGION_LEN,
+            WagerError::InvalidSessionMetadata
+        );
+        require!(
+            params.custom.len() <= MAX_METADATA_BLOB_LEN,
+            WagerError::InvalidSessionMetadata
+        );
+        require!(
+            [&params.game_name, &params.map_id, &params.region]
+                .iter()
+                .all(|field| !field.chars().any(char::is_control)),
+            WagerError::InvalidSessionMetadata
+        );
+        require!(
+            !params
+                .custom
+                .chars()
+                .any(|c| c.is_control() && !c.is_whitespace()),
+            WagerError::InvalidSessionMetadata
+        );
+        Ok(())
+    }
+
+    /// Validates an announcement: non-empty, within MAX_ANNOUNCEMENT_LEN
+    /// bytes and free of control characters
+    pub fn validate_announcement(text: &str) -> Result<()> {
+        require!(
+            !text.is_empty() && text.len() <= MAX_ANNOUNCEMENT_LEN,
+            WagerError::InvalidAnnouncement
+        );
+        require!(
+            !text.chars().any(char::is_control),
+            WagerError::InvalidAnnouncement
+        );
+        Ok(())
+    }
+
+    /// Validates a clock skew tolerance: at most MAX_CLOCK_SKEW_SECS, and
+    /// narrow enough that a dispute window stays open after the grace on
+    /// both of its ends
+    pub fn validate_clock_skew(clock_skew_secs: i64, dispute_window_secs: i64) -> Result<()> {
+        require!(
+            (0..=MAX_CLOCK_SKEW_SECS).contains(&clock_skew_secs),
+            WagerError::InvalidClockSkew
+        );
+        require!(
+            dispute_window_secs == 0 || dispute_window_secs > 2 * clock_skew_secs,
+            WagerError::InvalidClockSkew
+        );
+        Ok(())
+    }
+
+    /// Whether a deadline counts as reached at `now`, reading the cluster
+    /// clock up to `tolerance` seconds fast
+    pub fn deadline_reached(now: i64, deadline: i64, tolerance: i64) -> bool {
+        now.saturating_add(tolerance) >= deadline
+    }
+
+    /// Checks an operation that closes at `deadline` is still open, granting
+    /// `tolerance` seconds of grace for clock skew
+    pub fn validate_before_deadline(now: i64, deadline: i64, tolerance: i64) -> Result<()> {
+        if now.saturating_sub(tolerance) >= deadline {
+            return Err(err_with_deadline(WagerError::OperationExpired, deadline, now));
+        }
+        Ok(())
+    }
+
+    /// Checks an operation that opens at `deadline` may run, up to
+    /// `tolerance` seconds early for clock skew
+    pub fn validate_deadline_reached(now: i64, deadline: i64, tolerance: i64) -> Result<()> {
+        if !deadline_reached(now, deadline, tolerance) {
+            return Err(err_with_deadline(WagerError::OperationPremature, deadline, now));
+        }
+        Ok(())
+    }
+
+    /// Validates a tournament bracket size (power of two, 2..=MAX_TOURNAMENT_TEAMS)
+    pub fn validate_tournament_size(team_count: u8) -> Result<()> {
+        require!(
+            team_count.is_power_of_two()
+                && (2..=MAX_TOURNAMENT_TEAMS).contains(&(team_count as usize)),
+            WagerError::InvalidTournamentSize
+        );
+        Ok(())
+    }
+
+    /// Validates a session's match log capacity (0 disables the log)
+    pub fn validate_log_capacity(log_capacity: u16) -> Result<()> {
+        require!(
+            log_capacity <= MAX_MATCH_LOG_CAPACITY,
+            WagerError::InvalidLogCapacity
+        );
         Ok(())
     }
 
-    /// Validates bet amount is within reasonable bounds
-    pub fn validate_bet_amount(amount: u64) -> Result<()> {
+    /// Validates bet amount is within the configured bounds
+    pub fn validate_bet_amount(amount: u64, min_bet: u64, max_bet: u64) -> Result<()> {
         require!(amount > 0, WagerError::InvalidBetAmount);
-        require!(amount <= 1_000_000_000_000, WagerError::InvalidBetAmount); // Max 1000 tokens
+        require!(amount >= min_bet, WagerError::InvalidBetAmount);
+        require!(amount <= max_bet, WagerError::InvalidBetAmount);
+        Ok(())
+    }
+
+    /// Validates the USD value of a bet, priced by an oracle quote of
+    /// `price * 10^exponent` USD per whole token, is within the configured bounds
+    pub fn validate_bet_amount_usd(
+        amount: u64,
+        mint_decimals: u8,
+        price: i64,
+        exponent: i32,
+        usd_min_bet: u64,
+        usd_max_bet: u64,
+    ) -> Result<()> {
+        require!(price > 0, WagerError::InvalidOraclePrice);
+        let value = safe_math::scale_by_pow10(
+            amount as u128 * price as u128,
+            exponent + USD_DECIMALS - mint_decimals as i32,
+        )?;
+        require!(value >= usd_min_bet as u128, WagerError::InvalidBetAmount);
+        require!(value <= usd_max_bet as u128, WagerError::InvalidBetAmount);
+        Ok(())
+    }
+
+    /// Validates configured bet limits are consistent
+    pub fn validate_bet_limits(min_bet: u64, max_bet: u64) -> Result<()> {
+        require!(min_bet > 0, WagerError::InvalidBetLimits);
+        require!(min_bet <= max_bet, WagerError::InvalidBetLimits);
+        Ok(())
+    }
+
+    /// Validates admin-supplied program parameters
+    pub fn validate_config_params(params: &ConfigParams) -> Result<()> {
+        validate_bet_limits(params.min_bet, params.max_bet)?;
+        require!(
+            params.session_timeout_secs > 0,
+            WagerError::InvalidSessionTimeout
+        );
+        require!(
+            params.kill_report_signer != Pubkey::default(),
+            WagerError::InvalidKillReportSigner
+        );
+        require!(params.fee_bps <= MAX_FEE_BPS, WagerError::InvalidFeeBps);
+        require!(
+            params.cancellation_fee_bps <= MAX_FEE_BPS,
+            WagerError::InvalidFeeBps
+        );
+        require!(params.draw_fee_bps <= MAX_FEE_BPS, WagerError::InvalidFeeBps);
+        require!(
+            params.dispute_window_secs >= 0,
+            WagerError::InvalidDisputeWindow
+        );
+        if params.dispute_window_secs > 0 {
+            require!(
+                params.arbiter != Pubkey::default(),
+                WagerError::InvalidDisputeWindow
+            );
+        }
+        validate_clock_skew(params.clock_skew_secs, params.dispute_window_secs)?;
+        validate_game_server_keys(&params.game_server_keys, params.game_server_threshold)?;
+        require!(
+            params.referral_bps as u64 <= safe_math::BPS_DENOMINATOR,
+            WagerError::InvalidReferralBps
+        );
+        // Both cuts come out of the same rake
+        require!(
+            params.referral_bps as u64 + params.jackpot_bps as u64 <= safe_math::BPS_DENOMINATOR,
+            WagerError::InvalidJackpotBps
+        );
+        require!(
+            params.creator_penalty_bps as u64 <= safe_math::BPS_DENOMINATOR,
+            WagerError::InvalidCreatorPenaltyBps
+        );
+        require!(
+            params.bond_slash_bps as u64 <= safe_math::BPS_DENOMINATOR,
+            WagerError::InvalidBondSlashBps
+        );
+        require!(
+            params.forfeit_timeout_secs >= 0,
+            WagerError::InvalidForfeitTerms
+        );
+        require!(
+            params.forfeit_penalty_bps as u64 <= safe_math::BPS_DENOMINATOR,
+            WagerError::InvalidForfeitTerms
+        );
+        require!(
+            params.trophy_uri.len() <= MAX_TROPHY_URI_LEN,
+            WagerError::InvalidTrophyUri
+        );
+        require!(
+            params.cpi_callers.len() <= MAX_CPI_CALLERS,
+            WagerError::InvalidCpiCallers
+        );
+        for (i, caller) in params.cpi_callers.iter().enumerate() {
+            require!(
+                *caller != Pubkey::default() && !params.cpi_callers[..i].contains(caller),
+                WagerError::InvalidCpiCallers
+            );
+        }
+        validate_fee_split(&params.fee_split)?;
+        require!(
+            params.arbiters.len() <= MAX_ARBITERS,
+            WagerError::InvalidArbiterCouncil
+        );
+        for (i, arbiter) in params.arbiters.iter().enumerate() {
+            require!(
+                *arbiter != Pubkey::default() && !params.arbiters[..i].contains(arbiter),
+                WagerError::InvalidArbiterCouncil
+            );
+        }
+        require!(
+            params.freeroll_sponsors.len() <= MAX_FREEROLL_SPONSORS,
+            WagerError::InvalidFreerollSponsors
+        );
+        for (i, sponsor) in params.freeroll_sponsors.iter().enumerate() {
+            require!(
+                *sponsor != Pubkey::default() && !params.freeroll_sponsors[..i].contains(sponsor),
+                WagerError::InvalidFreerollSponsors
+            );
+        }
+        require!(
+            params.yield_programs.len() <= MAX_YIELD_PROGRAMS,
+            WagerError::InvalidYieldPrograms
+        );
+        for (i, program) in params.yield_programs.iter().enumerate() {
+            require!(
+                *program != Pubkey::default() && !params.yield_programs[..i].contains(program),
+                WagerError::InvalidYieldPrograms
+            );
+        }
+        require!(
+            params.yield_treasury_bps as u64 <= safe_math::BPS_DENOMINATOR,
+            WagerError::InvalidYieldPrograms
+        );
+        if params.usd_max_bet > 0 {
+            require!(
+                params.usd_min_bet <= params.usd_max_bet,
+                WagerError::InvalidBetLimits
+            );
+            require!(
+                params.max_price_age_secs > 0,
+                WagerError::InvalidOraclePrice
+            );
+        }
+        Ok(())
+    }
+
+    /// Validates the game server signing set and its approval threshold
+    pub fn validate_game_server_keys(keys: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !keys.is_empty() && keys.len() <= MAX_GAME_SERVER_KEYS,
+            WagerError::InvalidGameServerKeys
+        );
+        for (i, key) in keys.iter().enumerate() {
+            require!(
+                *key != Pubkey::default() && !keys[..i].contains(key),
+                WagerError::InvalidGameServerKeys
+            );
+        }
+        require!(
+            threshold > 0 && threshold as usize <= keys.len(),
+            WagerError::InvalidGameServerKeys
+        );
+        Ok(())
+    }
+
+    /// Validates the pay-to-spawn earnings rate does not exceed 100%
+    pub fn validate_spawn_earnings_bps(spawn_earnings_bps: u16) -> Result<()> {
+        require!(
+            spawn_earnings_bps as u64 <= safe_math::BPS_DENOMINATOR,
+            WagerError::InvalidSpawnEarningsBps
+        );
+        Ok(())
+    }
+
+    /// Validates a session lifecycle transition
+    pub fn validate_status_transition(from: SessionStatus, to: SessionStatus) -> Result<()> {
+        use SessionStatus::*;
+
+        // A second settlement while one is underway is a reentrancy attempt
+        require!(
+            !(from == Settling && to == Settling),
+            WagerError::AlreadyProcessing
+        );
+
+        let allowed = matches!(
+            (from, to),
+            (Created, Filling | Settling | Refunded | Refundable)
+                | (Filling, Created | Filling | InProgress | Settling | Refunded | Refundable)
+                | (InProgress, ResultSubmitted | Settling | Refunded)
+                | (ResultSubmitted, Settling | Refunded)
+                | (Refundable, Settling | Refunded)
+                | (Settling, Completed | Refunded)
+                // Recovery of a stuck settlement back to its resting state
+                | (Settling, Created | Filling | InProgress | ResultSubmitted | Refundable)
+        );
+        require!(allowed, WagerError::InvalidStatusTransition);
+        Ok(())
+    }
+
+    /// Validates a rake split: at most MAX_FEE_SPLIT_RECIPIENTS distinct
+    /// recipients with non-zero shares summing to 100%. Empty sends the whole
+    /// rake to the treasury.
+    pub fn validate_fee_split(split: &[FeeShare]) -> Result<()> {
+        if split.is_empty() {
+            return Ok(());
+        }
+        require!(
+            split.len() <= MAX_FEE_SPLIT_RECIPIENTS,
+            WagerError::InvalidFeeSplit
+        );
+        for (i, share) in split.iter().enumerate() {
+            require!(
+                share.recipient != Pubkey::default()
+                    && share.bps > 0
+                    && !split[..i].iter().any(|other| other.recipient == share.recipient),
+                WagerError::InvalidFeeSplit
+            );
+        }
+        let total: u64 = split.iter().map(|share| share.bps as u64).sum();
+        require!(total == safe_math::BPS_DENOMINATOR, WagerError::InvalidFeeSplit);
         Ok(())
     }
 
@@ -35,6 +654,78 @@ pub mod validation {
         Ok(())
     }
 
+    /// Validates a player joining a pass-gated session holds at least one
+    /// token of the pass mint, e.g. a non-transferable season pass
+    pub fn validate_season_pass(
+        required_pass: Option<Pubkey>,
+        player: &Pubkey,
+        pass_account: Option<&TokenAccount>,
+    ) -> Result<()> {
+        let Some(pass_mint) = required_pass else {
+            return Ok(());
+        };
+        let pass_account = pass_account.ok_or(error!(WagerError::MissingSeasonPass))?;
+        require!(
+            pass_account.owner == *player && pass_account.mint == pass_mint && pass_account.amount > 0,
+            WagerError::MissingSeasonPass
+        );
+        Ok(())
+    }
+
+    /// Validates a session instruction invoked by CPI comes from an allowed
+    /// partner program. The caller is the program of the transaction's
+    /// top-level instruction, read from the instructions sysvar, which only
+    /// has to be supplied when the config restricts callers.
+    pub fn validate_cpi_caller(
+        cpi_callers: &[Pubkey],
+        instructions: Option<&AccountInfo>,
+    ) -> Result<()> {
+        if cpi_callers.is_empty() || get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT {
+            return Ok(());
+        }
+        let instructions = instructions.ok_or(error!(WagerError::UnauthorizedCpiCaller))?;
+        let top_level = get_instruction_relative(0, instructions)?;
+        require!(
+            cpi_callers.contains(&top_level.program_id),
+            WagerError::UnauthorizedCpiCaller
+        );
+        Ok(())
+    }
+
+    /// Validates an account is the program address of `seeds` under the
+    /// stored `bump`, and that the bump is the canonical one, so an address
+    /// ground from a different bump is never accepted
+    pub fn validate_pda(key: &Pubkey, seeds: &[&[u8]], bump: u8) -> Result<()> {
+        let (expected, canonical_bump) = Pubkey::find_program_address(seeds, &crate::ID);
+        require!(bump == canonical_bump, WagerError::InvalidPdaBump);
+        require!(*key == expected, WagerError::InvalidPdaBump);
+        Ok(())
+    }
+
+    /// Validates the player's ban marker PDA has not been created
+    pub fn validate_not_banned(player_ban: &AccountInfo) -> Result<()> {
+        require!(player_ban.data_is_empty(), WagerError::PlayerIsBanned);
+        Ok(())
+    }
+
+    /// Validates the player's kick marker PDA for the session has not been
+    /// created
+    pub fn validate_not_kicked(session_kick: &AccountInfo) -> Result<()> {
+        require!(session_kick.data_is_empty(), WagerError::PlayerEliminated);
+        Ok(())
+    }
+
+    /// Validates a newly created account was allocated its type's final size
+    /// and holds enough lamports to stay rent-exempt at that size
+    pub fn validate_rent_exempt(account: &AccountInfo, len: usize) -> Result<()> {
+        require!(account.data_len() == len, WagerError::InvalidAccountSize);
+        require!(
+            Rent::get()?.is_exempt(account.lamports(), len),
+            WagerError::AccountNotRentExempt
+        );
+        Ok(())
+    }
+
     /// Validates remaining accounts count is within limits
     pub fn validate_remaining_accounts_count(count: usize, max_count: usize) -> Result<()> {
         require!(count <= max_count, WagerError::TooManyRemainingAccounts);
@@ -77,27 +768,125 @@ pub mod safe_math {
         a.checked_sub(b).ok_or(error!(WagerError::ArithmeticUnderflow))
     }
 
-    /// Safe calculation for earnings in pay-to-spawn mode
-    pub fn safe_earnings_calculation(kills_and_spawns: u16, session_bet: u64) -> Result<u64> {
-        let kills_spawns_u64 = kills_and_spawns as u64;
-        let multiplied = safe_multiply(kills_spawns_u64, session_bet)?;
-        safe_divide(multiplied, 10)
+    /// Adds to a compact u16 counter, failing once it would pass u16::MAX
+    /// rather than aborting the transaction on overflow
+    pub fn safe_add_u16(a: u16, b: u16) -> Result<u16> {
+        a.checked_add(b).ok_or(error!(WagerError::CounterOverflow))
     }
-}
 
-/// Macro for reentrancy guard
-#[macro_export]
-macro_rules! reentrancy_guard {
-    ($game_session:expr) => {
-        require!(!$game_session.is_processing, WagerError::AlreadyProcessing);
-        $game_session.is_processing = true;
-    };
-}
+    /// Counts one more on a compact u16 counter
+    pub fn safe_increment_u16(counter: u16) -> Result<u16> {
+        safe_add_u16(counter, 1)
+    }
 
-/// Macro to release reentrancy guard
-#[macro_export]
-macro_rules! release_reentrancy_guard {
-    ($game_session:expr) => {
-        $game_session.is_processing = false;
-    };
-}
\ No newline at end of file
+    /// Adds to a byte-wide roster slot counter, failing once it would pass
+    /// u8::MAX
+    pub fn safe_add_u8(a: u8, b: u8) -> Result<u8> {
+        a.checked_add(b).ok_or(error!(WagerError::CounterOverflow))
+    }
+
+    /// Counts one more on a byte-wide roster slot counter
+    pub fn safe_increment_u8(counter: u8) -> Result<u8> {
+        safe_add_u8(counter, 1)
+    }
+
+    /// Denominator for basis-point rates
+    pub const BPS_DENOMINATOR: u64 = 10_000;
+
+    /// Safe application of a basis-point rate to an amount
+    pub fn apply_bps(amount: u64, bps: u16) -> Result<u64> {
+        safe_divide(safe_multiply(amount, bps as u64)?, BPS_DENOMINATOR)
+    }
+
+    /// Safe pro-rata share: `amount * numerator / denominator` with a wide
+    /// intermediate so large pools cannot overflow
+    pub fn proportional_share(amount: u64, numerator: u64, denominator: u64) -> Result<u64> {
+        require!(denominator > 0, WagerError::ArithmeticError);
+        let share = (amount as u128)
+            .checked_mul(numerator as u128)
+            .ok_or(error!(WagerError::ArithmeticOverflow))?
+            / denominator as u128;
+        u64::try_from(share).map_err(|_| error!(WagerError::ArithmeticOverflow))
+    }
+
+    /// Splits an amount in proportion to the given weights, the last share
+    /// taking the rounding remainder so the shares always sum to `amount`
+    pub fn pro_rata_split(amount: u64, weights: &[u64]) -> Result<Vec<u64>> {
+        // Nothing to split, e.g. a freeroll's zero bets
+        if amount == 0 {
+            return Ok(vec![0; weights.len()]);
+        }
+        let total_weight = weights
+            .iter()
+            .try_fold(0u64, |total, weight| safe_add(total, *weight))?;
+        let mut unallocated = amount;
+        let mut shares = Vec::with_capacity(weights.len());
+        for (index, weight) in weights.iter().enumerate() {
+            let share = if index + 1 == weights.len() {
+                unallocated
+            } else {
+                proportional_share(amount, *weight, total_weight)?
+            };
+            unallocated = safe_subtract(unallocated, share)?;
+            shares.push(share);
+        }
+        Ok(shares)
+    }
+
+    /// Safe scaling of a value by a signed power of ten, truncating when negative
+    pub fn scale_by_pow10(value: u128, exponent: i32) -> Result<u128> {
+        let factor = 10u128
+            .checked_pow(exponent.unsigned_abs())
+            .ok_or(error!(WagerError::ArithmeticOverflow))?;
+        if exponent >= 0 {
+            value
+                .checked_mul(factor)
+                .ok_or(error!(WagerError::ArithmeticOverflow))
+        } else {
+            Ok(value / factor)
+        }
+    }
+
+    /// Elo expected score in basis points at every 25 points of rating
+    /// advantage, from even up to 800 points
+    const ELO_EXPECTED_BPS: [u64; 33] = [
+        5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083, 8296, 8490, 8666, 8823,
+        8965, 9091, 9203, 9302, 9390, 9468, 9536, 9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848,
+        9868, 9886, 9901,
+    ];
+
+    /// Expected score, in basis points, of a player rated `rating` against an
+    /// `opponent`, interpolated linearly between table entries and saturating
+    /// beyond 800 points of difference
+    pub fn elo_expected_bps(rating: u64, opponent: u64) -> u64 {
+        let gap = rating.abs_diff(opponent).min(800);
+        let step = (gap / 25) as usize;
+        let low = ELO_EXPECTED_BPS[step];
+        let high = ELO_EXPECTED_BPS[(step + 1).min(ELO_EXPECTED_BPS.len() - 1)];
+        let expected = low + (high - low) * (gap % 25) / 25;
+        if rating >= opponent {
+            expected
+        } else {
+            BPS_DENOMINATOR - expected
+        }
+    }
+
+    /// Elo rating change `k * (score - expected)` for an actual score in
+    /// basis points, rounded towards zero
+    pub fn elo_delta(rating: u64, opponent: u64, score_bps: u64, k_factor: u16) -> Result<i64> {
+        require!(score_bps <= BPS_DENOMINATOR, WagerError::ArithmeticError);
+        let surprise = score_bps as i64 - elo_expected_bps(rating, opponent) as i64;
+        Ok(k_factor as i64 * surprise / BPS_DENOMINATOR as i64)
+    }
+
+    /// Safe calculation for earnings in pay-to-spawn mode, from a player's
+    /// earning weight in basis points of one kill
+    pub fn safe_earnings_calculation(
+        earning_weight_bps: u64,
+        session_bet: u64,
+        spawn_earnings_bps: u16,
+    ) -> Result<u64> {
+        let multiplied = proportional_share(session_bet, earning_weight_bps, BPS_DENOMINATOR)?;
+        apply_bps(multiplied, spawn_earnings_bps)
+    }
+}
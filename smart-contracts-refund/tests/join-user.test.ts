@@ -2,272 +2,100 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { WagerProgram } from "../app/src/app/types/wager_program";
 import { BN } from "@coral-xyz/anchor";
-import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { assert } from "chai";
 import {
   generateSessionId,
   deriveGameSessionPDA,
   deriveVaultPDA,
-  getBalance,
+  deriveBetReceiptPDA,
+  loadKeypair,
+  setupTestAccounts,
+  setupTokenAccount,
+  setupProgram,
+  createGameSession,
+  joinUser,
   TOKEN_ID,
   getTokenBalance,
-  getVaultTokenAccount
+  getVaultTokenAccount,
+  GameMode
 } from "./utils";
-import { 
-  getAssociatedTokenAddress, 
-  getOrCreateAssociatedTokenAccount,
-  TOKEN_PROGRAM_ID,
-  ASSOCIATED_TOKEN_PROGRAM_ID,
-  getAccount
-} from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
-import { readFileSync } from "fs";
 
 describe("Join User Tests", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
   const program = anchor.workspace.WagerProgram as Program<WagerProgram>;
 
-  // Load keypairs from files
-  const loadKeypair = (path: string) => {
-    const data = JSON.parse(readFileSync(path, 'utf-8'));
-    return anchor.web3.Keypair.fromSecretKey(new Uint8Array(data));
-  };
-
   const gameServer = loadKeypair('./tests/kps/gameserver.json');
   const user1 = loadKeypair('./tests/kps/user1.json');
   const user2 = loadKeypair('./tests/kps/user2.json');
-  
+
   let user1TokenAccount: PublicKey;
   let user2TokenAccount: PublicKey;
-  let vaultTokenAccount: PublicKey;
 
   before(async () => {
     console.log("Setting up test accounts...");
-    console.log("GameServer pubkey:", gameServer.publicKey.toString());
-    console.log("User1 pubkey:", user1.publicKey.toString());
-    console.log("User2 pubkey:", user2.publicKey.toString());
-
-    // Airdrop SOL to accounts
-    console.log("\nAirdropping SOL to accounts...");
-    const airdropSignature = await provider.connection.requestAirdrop(
-      gameServer.publicKey,
-      2 * LAMPORTS_PER_SOL
-    );
-    await provider.connection.confirmTransaction(airdropSignature);
-
-    const airdropSignature1 = await provider.connection.requestAirdrop(
-      user1.publicKey,
-      2 * LAMPORTS_PER_SOL
-    );
-    await provider.connection.confirmTransaction(airdropSignature1);
-
-    const airdropSignature2 = await provider.connection.requestAirdrop(
-      user2.publicKey,
-      2 * LAMPORTS_PER_SOL
-    );
-    await provider.connection.confirmTransaction(airdropSignature2);
+    await setupTestAccounts(provider.connection, [gameServer, user1, user2]);
+    await setupProgram(program, gameServer);
 
-    // Get or create token accounts
-    console.log("\nSetting up token accounts...");
-    try {
-      const user1TokenAccountInfo = await getOrCreateAssociatedTokenAccount(
-        provider.connection,
-        gameServer, // payer
-        TOKEN_ID,
-        user1.publicKey
-      );
-      user1TokenAccount = user1TokenAccountInfo.address;
-      console.log("User1 token account:", user1TokenAccount.toString());
-
-      const user2TokenAccountInfo = await getOrCreateAssociatedTokenAccount(
-        provider.connection,
-        gameServer, // payer
-        TOKEN_ID,
-        user2.publicKey
-      );
-      user2TokenAccount = user2TokenAccountInfo.address;
-      console.log("User2 token account:", user2TokenAccount.toString());
-
-      // Verify token accounts
-      const user1TokenInfo = await getAccount(provider.connection, user1TokenAccount);
-      const user2TokenInfo = await getAccount(provider.connection, user2TokenAccount);
-      
-      console.log("\nToken Account Balances:");
-      console.log("User1 token balance:", user1TokenInfo.amount.toString());
-      console.log("User2 token balance:", user2TokenInfo.amount.toString());
-
-    } catch (error) {
-      console.error("Error setting up token accounts:", error);
-      throw error;
-    }
+    user1TokenAccount = await setupTokenAccount(provider.connection, gameServer, TOKEN_ID, user1.publicKey);
+    user2TokenAccount = await setupTokenAccount(provider.connection, gameServer, TOKEN_ID, user2.publicKey);
+    console.log("User1 token balance:", await getTokenBalance(provider.connection, user1TokenAccount));
+    console.log("User2 token balance:", await getTokenBalance(provider.connection, user2TokenAccount));
   });
 
-  it("Successfully joins users to both teams", async () => {
-    console.log("\nStarting join user test...");
+  const joinBothTeams = async (gameMode: GameMode) => {
     const sessionId = generateSessionId();
-    console.log("Session ID:", sessionId);
-    
     const betAmount = new BN(100000000); // 0.1 tokens with 9 decimals
-    console.log("Bet amount:", betAmount.toString());
+    console.log("Session ID:", sessionId);
 
     const [gameSessionPda] = deriveGameSessionPDA(program.programId, sessionId);
-    console.log("Game session PDA:", gameSessionPda.toString());
-
-    const [vaultPda] = deriveVaultPDA(program.programId, gameSessionPda);
-    console.log("Vault PDA:", vaultPda.toString());
-
-    // Get vault token account address
-    vaultTokenAccount = await getAssociatedTokenAddress(
-      TOKEN_ID,
-      vaultPda,
-      true // allowOwnerOffCurve
-    );
-    console.log("Vault token account:", vaultTokenAccount.toString());
-
-    console.log("user1 balance: ", await getTokenBalance(provider.connection, user1TokenAccount));
-    console.log("user2 balance: ", await getTokenBalance(provider.connection, user2TokenAccount));
-
-    console.log("\nCreating game session...");
-    try {
-      await program.methods
-        .createGameSession(sessionId, betAmount, { winnerTakesAllOneVsOne: {} })
-        .accounts({
-          gameServer: gameServer.publicKey,
-        })
-        .signers([gameServer])
-        .rpc();
-      console.log("Game session created successfully");
-    } catch (error) {
-      console.error("Error creating game session:", error);
-      throw error;
-    }
-
-    console.log("\nJoining user1 to team...");
-    try {
-      console.log("user1 token account: ", user1TokenAccount.toString());
-      console.log("user1 balance: ", await getTokenBalance(provider.connection, user1TokenAccount));
-
-      await program.methods
-        .joinUser(sessionId, 0)
-        .accounts({
-          user: user1.publicKey,
-          gameServer: gameServer.publicKey,
-          userTokenAccount: user1TokenAccount,
-        })
-        .signers([user1])
-        .rpc();
-      console.log("User joined successfully");
+    const [vaultPda] = deriveVaultPDA(program.programId, sessionId);
+    const vaultTokenAccount = await getVaultTokenAccount(TOKEN_ID, vaultPda);
 
-      const account = await program.account.gameSession.fetch(gameSessionPda);
-      console.log("\nGame session state after join:");
-      console.log("Team A players:", account.teamA.players.map(p => p.toString()));
-      assert.equal(account.teamA.players[0].toString(), user1.publicKey.toString());
-    } catch (error) {
-      console.error("Error joining user:", error);
-      throw error;
+    await createGameSession(program, gameServer, sessionId, betAmount, gameMode);
+
+    await joinUser(program, gameServer, user1, sessionId, 0);
+    let account = await program.account.gameSession.fetch(gameSessionPda);
+    console.log("Team A players:", account.teams[0].players.map(p => p.toString()));
+    assert.equal(account.teams[0].players[0].toString(), user1.publicKey.toString());
+
+    await joinUser(program, gameServer, user2, sessionId, 1);
+    account = await program.account.gameSession.fetch(gameSessionPda);
+    console.log("Team B players:", account.teams[1].players.map(p => p.toString()));
+    assert.equal(account.teams[1].players[0].toString(), user2.publicKey.toString());
+
+    // Both bets are escrowed, each with a receipt for its player
+    const vault = await getAccount(provider.connection, vaultTokenAccount);
+    assert.equal(vault.amount.toString(), betAmount.muln(2).toString());
+    for (const user of [user1, user2]) {
+      const [receiptPda] = deriveBetReceiptPDA(program.programId, gameSessionPda, user.publicKey);
+      const receipt = await program.account.betReceipt.fetch(receiptPda);
+      assert.equal(receipt.amount.toString(), betAmount.toString());
     }
+  };
 
-    console.log("\nJoining user2 to team...");
-    try {
-      await program.methods
-        .joinUser(sessionId, 1)
-        .accounts({
-          user: user2.publicKey,
-          gameServer: gameServer.publicKey,
-          userTokenAccount: user2TokenAccount,
-        })
-        .signers([user2])
-        .rpc();
-      console.log("User joined successfully");
-
-      const account = await program.account.gameSession.fetch(gameSessionPda);
-      console.log("\nGame session state after join:");
-      console.log("Team B players:", account.teamB.players.map(p => p.toString()));
-      assert.equal(account.teamB.players[0].toString(), user2.publicKey.toString());
-    } catch (error) {
-      console.error("Error joining user:", error);
-      throw error;
-    }
+  it("Successfully joins users to both teams", async () => {
+    await joinBothTeams({ winnerTakesAllOneVsOne: {} });
   });
 
   // Test case for pay-to-spawn 1v1 mode
   it("Successfully joins users to both teams in pay-to-spawn 1v1 mode", async () => {
-    const sessionId = generateSessionId();
-    const betAmount = new BN(100000000); // 0.1 tokens with 9 decimals
-
-    const [gameSessionPda] = deriveGameSessionPDA(program.programId, sessionId);
-    console.log("Game session PDA:", gameSessionPda.toString());
-
-    const [vaultPda] = deriveVaultPDA(program.programId, gameSessionPda);
-    console.log("Vault PDA:", vaultPda.toString());
-
-    const vaultTokenAccount = await getVaultTokenAccount(TOKEN_ID, vaultPda);
-    console.log("Vault token account:", vaultTokenAccount.toString());
-
-    console.log("user1 balance: ", await getTokenBalance(provider.connection, user1TokenAccount));
-    console.log("user2 balance: ", await getTokenBalance(provider.connection, user2TokenAccount));
-
-    console.log("\nCreating game session...");
-    try {
-      await program.methods
-        .createGameSession(sessionId, betAmount, { payToSpawnOneVsOne: {} })
-        .accounts({
-          gameServer: gameServer.publicKey,
-        })
-        .signers([gameServer])
-        .rpc();
-      console.log("Game session created successfully");
-    } catch (error) {
-      console.error("Error creating game session:", error);
-      throw error;
-    }
-
-    console.log("\nJoining user1 to team...");
-    try {
-      console.log("user1 token account: ", user1TokenAccount.toString());
-      console.log("user1 balance: ", await getTokenBalance(provider.connection, user1TokenAccount));
-
-      await program.methods
-        .joinUser(sessionId, 0)
-        .accounts({
-          user: user1.publicKey,
-          gameServer: gameServer.publicKey,
-          userTokenAccount: user1TokenAccount,
-        })
-        .signers([user1])
-        .rpc();
-      console.log("User joined successfully");
+    await joinBothTeams({ payToSpawnOneVsOne: {} });
+  });
 
-      const account = await program.account.gameSession.fetch(gameSessionPda);
-      console.log("\nGame session state after join:");
-      console.log("Team A players:", account.teamA.players.map(p => p.toString()));
-      assert.equal(account.teamA.players[0].toString(), user1.publicKey.toString());
-    } catch (error) {
-      console.error("Error joining user:", error);
-      throw error;
-    }
+  it("Fails to join a session twice", async () => {
+    const sessionId = generateSessionId();
+    await createGameSession(program, gameServer, sessionId, new BN(100000000), { winnerTakesAllThreeVsThree: {} });
+    await joinUser(program, gameServer, user1, sessionId, 0);
 
-    console.log("\nJoining user2 to team...");
     try {
-      await program.methods
-        .joinUser(sessionId, 1)
-        .accounts({
-          user: user2.publicKey,
-          gameServer: gameServer.publicKey,
-          userTokenAccount: user2TokenAccount,
-        })
-        .signers([user2])
-        .rpc();
-      console.log("User joined successfully");
-
-      const account = await program.account.gameSession.fetch(gameSessionPda);
-      console.log("\nGame session state after join:");
-      console.log("Team B players:", account.teamB.players.map(p => p.toString()));
-      assert.equal(account.teamB.players[0].toString(), user2.publicKey.toString());
-    } catch (error) {
-      console.error("Error joining user:", error);
-      throw error;
+      await joinUser(program, gameServer, user1, sessionId, 1);
+      assert.fail("Should have failed with the player already seated");
+    } catch (e) {
+      // The player's receipt for the session already exists
+      assert.include(e.logs.join('\n'), "already in use");
     }
   });
-}); 
\ No newline at end of file
+});
@@ -2,241 +2,69 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { WagerProgram } from "../app/src/app/types/wager_program";
 import { BN } from "@coral-xyz/anchor";
-import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { assert } from "chai";
 import {
   generateSessionId,
   deriveGameSessionPDA,
   deriveVaultPDA,
+  deriveBetReceiptPDA,
   loadKeypair,
   setupTokenAccount,
   setupTestAccounts,
-  getBalance,
+  setupProgram,
+  createGameSession,
+  joinUser,
+  refundWager,
   getVaultTokenAccount,
-  TOKEN_ID,
-  getTokenBalance
+  TOKEN_ID
 } from "./utils";
-import { PublicKey } from "@solana/web3.js";
-import * as borsh from "borsh";
-import { ConfirmOptions } from "@solana/web3.js";
-import {AccountLayout, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAccount } from "@solana/spl-token";
 
-describe("Distribute Winnings Tests", () => {
+describe("Refund Tests", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
   const program = anchor.workspace.WagerProgram as Program<WagerProgram>;
 
   const gameServer = loadKeypair('./tests/kps/gameserver.json');
-  const user1 = loadKeypair('./tests/kps/user1.json');
-  const user2 = loadKeypair('./tests/kps/user2.json');
-  const user3 = loadKeypair('./tests/kps/user3.json');
-  const user4 = loadKeypair('./tests/kps/user4.json');
-  const user5 = loadKeypair('./tests/kps/user5.json');
-  const user6 = loadKeypair('./tests/kps/user6.json');
-  const user7 = loadKeypair('./tests/kps/user7.json');
-  const user8 = loadKeypair('./tests/kps/user8.json');
-  const user9 = loadKeypair('./tests/kps/user9.json');
-  const user10 = loadKeypair('./tests/kps/user10.json');
-
-  let user1TokenAccount: PublicKey;
-  let user2TokenAccount: PublicKey;
-  let user3TokenAccount: PublicKey;
-  let user4TokenAccount: PublicKey;
-  let user5TokenAccount: PublicKey;
-  let user6TokenAccount: PublicKey;
-  let user7TokenAccount: PublicKey;
-  let user8TokenAccount: PublicKey;
-  let user9TokenAccount: PublicKey;
-  let user10TokenAccount: PublicKey;
+  const users: Keypair[] = [1, 2, 3].map((n) => loadKeypair(`./tests/kps/user${n}.json`));
+  const tokenAccounts: PublicKey[] = [];
 
   before(async () => {
-    await setupTestAccounts(provider.connection, [gameServer, user1, user2]);
-    
-    user1TokenAccount = await setupTokenAccount(
-      provider.connection,
-      gameServer,
-      TOKEN_ID,
-      user1.publicKey
-    );
-    
-    user2TokenAccount = await setupTokenAccount(
-      provider.connection,
-      gameServer,
-      TOKEN_ID,
-      user2.publicKey
-    );
-
-    user3TokenAccount = await setupTokenAccount(
-      provider.connection,
-      gameServer,
-      TOKEN_ID,
-      user3.publicKey
-    );
+    await setupTestAccounts(provider.connection, [gameServer, ...users]);
+    await setupProgram(program, gameServer);
 
-    user4TokenAccount = await setupTokenAccount(
-      provider.connection,
-      gameServer,
-      TOKEN_ID,
-      user4.publicKey
-    );
+    for (const user of users) {
+      tokenAccounts.push(await setupTokenAccount(provider.connection, gameServer, TOKEN_ID, user.publicKey));
+    }
+  });
 
-    user5TokenAccount = await setupTokenAccount(
-      provider.connection,
-      gameServer,
-      TOKEN_ID,
-      user5.publicKey
-    );
+  const balance = async (tokenAccount: PublicKey): Promise<BN> =>
+    new BN(String((await getAccount(provider.connection, tokenAccount)).amount));
 
-    user6TokenAccount = await setupTokenAccount(
-      provider.connection,
-      gameServer,
-      TOKEN_ID,
-      user6.publicKey
-    );
-  });
   it("Successfully refunds wager", async () => {
-    console.log("\n=== Starting Refund Wager test ===");
     const sessionId = generateSessionId();
     const betAmount = new BN(100000000); // 0.1 tokens with 9 decimals
-    console.log(`Session ID: ${sessionId}`);
-    console.log(`Bet amount: ${betAmount.toString()} (0.1 tokens)`);
-    
-    console.log("user1 pubkey: ", user1.publicKey.toString());
-    console.log("user2 pubkey: ", user2.publicKey.toString());
-    console.log("user3 pubkey: ", user3.publicKey.toString());
-    console.log("user4 pubkey: ", user4.publicKey.toString());
-    console.log("gameServer pubkey: ", gameServer.publicKey.toString());
-
-    // Derive PDAs first since we'll need them multiple times
-
-    const confirmOptions: ConfirmOptions = { commitment: "confirmed" };
-
-    // Create game session and join users
-    console.log("\nCreating game session...");
-    const tx = await program.methods
-      .createGameSession(sessionId, betAmount, { winnerTakesAllThreeVsThree: {} })
-      .accounts({
-        gameServer: gameServer.publicKey,
-      })
-      .signers([gameServer])
-      .rpc(confirmOptions);
-
-    console.log("tx: ", tx);
-    
-
-    const transaction = await provider.connection.getTransaction(tx, {
-      commitment: "confirmed",
-    });
-    console.log("transaction: ", transaction);
-
-    // get the log message that contains the vault token account
-    let receiveLog = transaction.meta.logMessages.find(
-      (log) => log.includes("Vault token account:")
-    );
-
-    const vaultTokenAccount = receiveLog.split("Vault token account: ")[1];
-    console.log("vaultTokenAccount: ", vaultTokenAccount);
-    assert(receiveLog !== undefined);
-    console.log("Game session created successfully");
-
-    console.log("vault token account: and balance", vaultTokenAccount, await getTokenBalance(provider.connection, new PublicKey(vaultTokenAccount)));
-    console.log("user1 token address: ", user1TokenAccount.toString());
-    console.log("user2 token address: ", user2TokenAccount.toString());
-    console.log("user3 token address: ", user3TokenAccount.toString());
-
-    console.log("user1 balance: ", await getTokenBalance(provider.connection, user1TokenAccount));
-    console.log("user2 balance: ", await getTokenBalance(provider.connection, user2TokenAccount));
-    console.log("user3 balance: ", await getTokenBalance(provider.connection, user3TokenAccount));
-
-    // Join users
-    console.log("\nJoining user 1...");
-    const joinUser1Tx = await program.methods
-      .joinUser(sessionId, 0)
-      .accounts({
-        user: user1.publicKey,
-        gameServer: gameServer.publicKey,
-        userTokenAccount: user1TokenAccount,
-      })
-      .signers([user1])
-      .rpc(confirmOptions);
-    console.log("User 1 joined successfully with tx: ", joinUser1Tx);
-
-    console.log("\nJoining user 2...");
-    const joinUser2Tx = await program.methods
-      .joinUser(sessionId, 1)
-      .accounts({
-        user: user2.publicKey,
-        gameServer: gameServer.publicKey,
-        userTokenAccount: user2TokenAccount,
-      })
-      .signers([user2])
-      .rpc(confirmOptions);
-    console.log("User 2 joined successfully with tx: ", joinUser2Tx);
-
-    console.log("\nJoining user 3...");
-    const joinUser3Tx = await program.methods
-      .joinUser(sessionId, 0)
-      .accounts({
-        user: user3.publicKey,
-        gameServer: gameServer.publicKey,
-        userTokenAccount: user3TokenAccount,
-      })
-      .signers([user3])
-      .rpc(confirmOptions);
-    console.log("User 3 joined successfully with tx: ", joinUser3Tx);
-
-    // Invoke refund wager
-    console.log("\nRefunding wager...");
-    let remainingAccounts = [
-      {
-        pubkey: new PublicKey(user1.publicKey),
-        isSigner: false,
-        isWritable: true,
-      },
-      {
-        pubkey: new PublicKey(user1TokenAccount),
-        isSigner: false,
-        isWritable: true,
-      },
-      {
-        pubkey: new PublicKey(user2.publicKey),
-        isSigner: false,
-        isWritable: true,
-      },
-      {
-        pubkey: new PublicKey(user2TokenAccount),
-        isSigner: false,
-        isWritable: true,
-      },
-      {
-        pubkey: new PublicKey(user3.publicKey),
-        isSigner: false,
-        isWritable: true,
-      },
-      {
-        pubkey: new PublicKey(user3TokenAccount),
-        isSigner: false,
-        isWritable: true,
-      },
-    ]
-
-    const refundWagerTx = await program.methods
-      .refundWager(sessionId)
-      .accounts({
-        gameServer: gameServer.publicKey,
-      })
-      .remainingAccounts(remainingAccounts)
-      .signers([gameServer])
-      .rpc(confirmOptions);
-    console.log("Refund wager tx: ", refundWagerTx);
-
-
-    console.log("user1 balance: ", await getTokenBalance(provider.connection, user1TokenAccount));
-    console.log("user2 balance: ", await getTokenBalance(provider.connection, user2TokenAccount));
-    console.log("user3 balance: ", await getTokenBalance(provider.connection, user3TokenAccount));
-    console.log("vault token account: and balance", vaultTokenAccount, await getTokenBalance(provider.connection, new PublicKey(vaultTokenAccount)));
-
-    console.log("=== Refund wager test completed successfully ===\n");
+    const [gameSessionPda] = deriveGameSessionPDA(program.programId, sessionId);
+    const [vaultPda] = deriveVaultPDA(program.programId, sessionId);
+    const vaultTokenAccount = await getVaultTokenAccount(TOKEN_ID, vaultPda);
+
+    // A 3v3 lobby that never fills
+    await createGameSession(program, gameServer, sessionId, betAmount, { winnerTakesAllThreeVsThree: {} });
+    const before = await Promise.all(tokenAccounts.map(balance));
+    for (let i = 0; i < users.length; i++) {
+      await joinUser(program, gameServer, users[i], sessionId, i % 2);
+    }
+
+    await refundWager(program, gameServer, sessionId);
+
+    // Every depositor gets their bet back and their receipt is consumed
+    for (let i = 0; i < users.length; i++) {
+      assert.equal((await balance(tokenAccounts[i])).toString(), before[i].toString());
+      const [receiptPda] = deriveBetReceiptPDA(program.programId, gameSessionPda, users[i].publicKey);
+      const receipt = await program.account.betReceipt.fetch(receiptPda);
+      assert.deepEqual(receipt.status, { refunded: {} });
+    }
+    assert.equal((await balance(vaultTokenAccount)).toString(), "0");
   });
-
-}); 
\ No newline at end of file
+});
@@ -1,16 +1,35 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Keypair, LAMPORTS_PER_SOL, PublicKey, Connection } from "@solana/web3.js";
-import { BN } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, Connection, Ed25519Program, SYSVAR_INSTRUCTIONS_PUBKEY, SystemProgram } from "@solana/web3.js";
+import { BN, IdlTypes, Program } from "@coral-xyz/anchor";
 import { readFileSync } from "fs";
-import { 
-  getAssociatedTokenAddress, 
+import {
+  getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
   getOrCreateAssociatedTokenAccount,
   getAccount,
-  TOKEN_PROGRAM_ID 
+  TOKEN_PROGRAM_ID
 } from "@solana/spl-token";
+import { WagerProgram } from "../app/src/app/types/wager_program";
 
 export const TOKEN_ID = new PublicKey("BzeqmCjLZvMLSTrge9qZnyV8N2zNKBwAxQcZH2XEzFXG");
 
+export const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+export const MEMO_PROGRAM_ID = new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+// Game modes in the order of the program's `GameMode` enum
+export const GAME_MODES = [
+  "winnerTakesAllOneVsOne",
+  "winnerTakesAllThreeVsThree",
+  "winnerTakesAllFiveVsFive",
+  "payToSpawnOneVsOne",
+  "payToSpawnThreeVsThree",
+  "payToSpawnFiveVsFive",
+  "freeForAllFourPlayers",
+  "freeForAllEightPlayers",
+];
+
+export type GameMode = IdlTypes<WagerProgram>["gameMode"];
+
 export function randomIntFromInterval(min: number, max: number): number {
   return Math.floor(Math.random() * (max - min + 1) + min);
 }
@@ -41,6 +60,14 @@ export async function createAndFundAccount(
   return account;
 }
 
+export function deriveConfigPDA(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], programId);
+}
+
+export function deriveTreasuryPDA(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("treasury")], programId);
+}
+
 export function deriveGameSessionPDA(programId: PublicKey, sessionId: string): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("game_session"), Buffer.from(sessionId)],
@@ -48,13 +75,84 @@ export function deriveGameSessionPDA(programId: PublicKey, sessionId: string): [
   );
 }
 
-export function deriveVaultPDA(programId: PublicKey, gameSessionPda: PublicKey): [PublicKey, number] {
+export function deriveVaultPDA(programId: PublicKey, sessionId: string): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), Buffer.from(sessionId)],
+    programId
+  );
+}
+
+export function derivePlayerStatsPDA(programId: PublicKey, player: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("player_stats"), player.toBuffer()],
+    programId
+  );
+}
+
+export function derivePlayerBanPDA(programId: PublicKey, player: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("player_ban"), player.toBuffer()],
+    programId
+  );
+}
+
+export function deriveActiveExposurePDA(programId: PublicKey, player: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("active_exposure"), player.toBuffer()],
+    programId
+  );
+}
+
+export function deriveBetReceiptPDA(programId: PublicKey, gameSessionPda: PublicKey, player: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bet_receipt"), gameSessionPda.toBuffer(), player.toBuffer()],
+    programId
+  );
+}
+
+export function deriveSessionKickPDA(programId: PublicKey, gameSessionPda: PublicKey, player: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("session_kick"), gameSessionPda.toBuffer(), player.toBuffer()],
+    programId
+  );
+}
+
+export function deriveUnclaimedPayoutPDA(programId: PublicKey, gameSessionPda: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("unclaimed_payout"), gameSessionPda.toBuffer()],
+    programId
+  );
+}
+
+export function deriveRegisteredMintPDA(programId: PublicKey, mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("registered_mint"), mint.toBuffer()],
+    programId
+  );
+}
+
+// Discovery index of open lobbies sharing a mint, bet and game mode
+export function deriveLobbyIndexPDA(
+  programId: PublicKey,
+  mint: PublicKey,
+  betAmount: BN,
+  gameMode: GameMode
+): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("vault"), gameSessionPda.toBuffer()],
+    [
+      Buffer.from("lobby_index"),
+      mint.toBuffer(),
+      betAmount.toArrayLike(Buffer, "le", 8),
+      Buffer.from([gameModeIndex(gameMode)]),
+    ],
     programId
   );
 }
 
+export function gameModeIndex(gameMode: GameMode): number {
+  return GAME_MODES.indexOf(Object.keys(gameMode)[0]);
+}
+
 export function loadKeypair(path: string): Keypair {
   const data = JSON.parse(readFileSync(path, 'utf-8'));
   return anchor.web3.Keypair.fromSecretKey(new Uint8Array(data));
@@ -70,7 +168,8 @@ export async function setupTokenAccount(
     connection,
     payer,
     tokenMint,
-    owner
+    owner,
+    true // allowOwnerOffCurve, for the treasury PDA
   );
   return tokenAccountInfo.address;
 }
@@ -105,7 +204,408 @@ export async function getTokenBalance(connection: Connection, tokenAccount: Publ
   const info = await connection.getTokenAccountBalance(tokenAccount);
   if (info.value.uiAmount == null) throw new Error('No balance found');
   return info.value.uiAmount;
-} 
+}
+
+// Initializes the config with `gameServer` as the only game server and kill
+// report signer, unless it already exists, then registers the wager token.
+// The provider wallet must be the program's upgrade authority.
+export async function setupProgram(
+  program: Program<WagerProgram>,
+  gameServer: Keypair
+): Promise<void> {
+  const provider = program.provider as anchor.AnchorProvider;
+  const [configPda] = deriveConfigPDA(program.programId);
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+
+  let config = await program.account.programConfig.fetchNullable(configPda);
+  if (config === null) {
+    await program.methods
+      .initializeConfig({
+        minBet: new BN(1),
+        maxBet: new BN("1000000000000000"),
+        sessionTimeoutSecs: new BN(86400),
+        killReportSigner: gameServer.publicKey,
+        feeBps: 500,
+        cancellationFeeBps: 0,
+        usdMinBet: new BN(0),
+        usdMaxBet: new BN(0),
+        maxPriceAgeSecs: new BN(0),
+        arbiter: PublicKey.default,
+        disputeWindowSecs: new BN(0),
+        gameServerKeys: [gameServer.publicKey],
+        gameServerThreshold: 1,
+        referralBps: 0,
+        maxTopUpMultiple: 1,
+        mapPoolSize: 0,
+        jackpotBps: 0,
+        jackpotInterval: new BN(0),
+        rewardsMint: PublicKey.default,
+        streakMilestone: 0,
+        streakBonus: new BN(0),
+        eloKFactor: 0,
+        creatorPenaltyBps: 0,
+        trophyUri: "",
+        cpiCallers: [],
+        creationFeeLamports: new BN(0),
+        maxExposure: new BN(0),
+        feeSplit: [],
+        drawFeeBps: 0,
+        forfeitTimeoutSecs: new BN(0),
+        forfeitPenaltyBps: 0,
+        freerollSponsors: [],
+        yieldPrograms: [],
+        yieldTreasuryBps: 0,
+        clockSkewSecs: new BN(0),
+        bondSlashBps: 0,
+        arbiters: [],
+        commitRevealResults: false,
+      })
+      .accountsPartial({
+        authority: provider.wallet.publicKey,
+        config: configPda,
+        program: program.programId,
+        programData,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    config = await program.account.programConfig.fetch(configPda);
+  }
+  if (!config.gameServerKeys.some((key) => key.equals(gameServer.publicKey))) {
+    throw new Error(`Game server ${gameServer.publicKey} is not in the config's game server keys`);
+  }
+
+  const [registeredMint] = deriveRegisteredMintPDA(program.programId, TOKEN_ID);
+  if ((await provider.connection.getAccountInfo(registeredMint)) === null) {
+    await program.methods
+      .registerMint(new BN(0), new BN(0))
+      .accountsPartial({
+        authority: config.authority,
+        config: configPda,
+        mint: TOKEN_ID,
+        registeredMint,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  // The house rake is paid to the treasury's token account
+  const [treasuryPda] = deriveTreasuryPDA(program.programId);
+  await setupTokenAccount(provider.connection, gameServer, TOKEN_ID, treasuryPda);
+}
+
+// Creates a session of `gameMode` with the defaults of a plain lobby: two
+// teams, no match log or metadata, open access and a full refund until start.
+// Pay-to-spawn sessions pay `spawnEarningsBps` of the bet per kill and spawn.
+export async function createGameSession(
+  program: Program<WagerProgram>,
+  gameServer: Keypair,
+  sessionId: string,
+  betAmount: BN,
+  gameMode: GameMode,
+  spawnEarningsBps: number = 0
+): Promise<string> {
+  const programId = program.programId;
+  const [vaultPda] = deriveVaultPDA(programId, sessionId);
+  return await program.methods
+    .createGameSession(
+      sessionId,
+      betAmount,
+      gameMode,
+      gameModeIndex(gameMode) >= 6 ? 0 : 2, // team_count
+      spawnEarningsBps,
+      0, // log_capacity
+      0, // team_a_capacity
+      0, // team_b_capacity
+      { winnerTeamEqual: {} },
+      null, // metadata
+      { open: {} },
+      0, // max_kills_per_player
+      0, // max_match_kills
+      0, // series_length
+      0, // min_rating
+      0, // max_rating
+      { fullAnytimeBeforeStart: {} },
+      null, // requires_pass
+      null, // join_window
+      { serverDecision: {} },
+      false, // freeroll
+      0, // assist_weight_bps
+      0, // min_ready_players
+      new BN(0) // max_stake
+    )
+    .accountsPartial({
+      gameServer: gameServer.publicKey,
+      config: deriveConfigPDA(programId)[0],
+      treasury: deriveTreasuryPDA(programId)[0],
+      gameSession: deriveGameSessionPDA(programId, sessionId)[0],
+      vault: vaultPda,
+      vaultTokenAccount: await getVaultTokenAccount(TOKEN_ID, vaultPda),
+      matchLog: null,
+      sessionMetadata: null,
+      lobbyIndex: deriveLobbyIndexPDA(programId, TOKEN_ID, betAmount, gameMode)[0],
+      mint: TOKEN_ID,
+      registeredMint: deriveRegisteredMintPDA(programId, TOKEN_ID)[0],
+      priceFeed: null,
+      priceUpdate: null,
+      instructions: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([gameServer])
+    .rpc({ commitment: "confirmed" });
+}
+
+// Joins `user` to `team`, paying the bet from their associated token account
+export async function joinUser(
+  program: Program<WagerProgram>,
+  gameServer: Keypair,
+  user: Keypair,
+  sessionId: string,
+  team: number
+): Promise<string> {
+  const programId = program.programId;
+  const [gameSessionPda] = deriveGameSessionPDA(programId, sessionId);
+  const [vaultPda] = deriveVaultPDA(programId, sessionId);
+  const account = await program.account.gameSession.fetch(gameSessionPda);
+  const gameMode = { [GAME_MODES[account.gameMode]]: {} } as GameMode;
+  return await program.methods
+    .joinUser(sessionId, team, null, null, null)
+    .accountsPartial({
+      user: user.publicKey,
+      gameServer: gameServer.publicKey,
+      config: deriveConfigPDA(programId)[0],
+      gameSession: gameSessionPda,
+      userTokenAccount: getAssociatedTokenAddressSync(TOKEN_ID, user.publicKey),
+      vault: vaultPda,
+      playerStats: derivePlayerStatsPDA(programId, user.publicKey)[0],
+      betReceipt: deriveBetReceiptPDA(programId, gameSessionPda, user.publicKey)[0],
+      referral: null,
+      referralCredit: null,
+      lobbyIndex: deriveLobbyIndexPDA(programId, TOKEN_ID, account.sessionBet, gameMode)[0],
+      passTokenAccount: null,
+      activeExposure: deriveActiveExposurePDA(programId, user.publicKey)[0],
+      playerBan: derivePlayerBanPDA(programId, user.publicKey)[0],
+      sessionKick: deriveSessionKickPDA(programId, gameSessionPda, user.publicKey)[0],
+      instructions: null,
+      vaultTokenAccount: await getVaultTokenAccount(TOKEN_ID, vaultPda),
+      mint: TOKEN_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([user])
+    .rpc({ commitment: "confirmed" });
+}
+
+// Reports that `killer` killed `victim`, signed by the game server as the
+// config's kill report signer. Reports carry the next round and sequence
+// number of the session.
+export async function recordKill(
+  program: Program<WagerProgram>,
+  gameServer: Keypair,
+  sessionId: string,
+  killer: PublicKey,
+  victim: PublicKey
+): Promise<string> {
+  const programId = program.programId;
+  const [gameSessionPda] = deriveGameSessionPDA(programId, sessionId);
+  const account = await program.account.gameSession.fetch(gameSessionPda);
+  const [killerTeam] = findPlayerSlot(account, killer);
+  const [victimTeam] = findPlayerSlot(account, victim);
+  const assists = [PublicKey.default, PublicKey.default];
+  const round = account.lastKillRound.addn(1);
+  const sequence = account.reportSequence.addn(1);
+
+  const message = Buffer.concat([
+    Buffer.from(sessionId),
+    killer.toBuffer(),
+    victim.toBuffer(),
+    ...assists.map((assist) => assist.toBuffer()),
+    round.toArrayLike(Buffer, "le", 8),
+    sequence.toArrayLike(Buffer, "le", 8),
+  ]);
+  const signature = Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: gameServer.secretKey,
+    message,
+  });
+
+  return await program.methods
+    .recordKill(sessionId, killerTeam, killer, victimTeam, victim, assists, round, sequence)
+    .accountsPartial({
+      config: deriveConfigPDA(programId)[0],
+      gameSession: gameSessionPda,
+      gameServer: gameServer.publicKey,
+      killerStats: derivePlayerStatsPDA(programId, killer)[0],
+      victimStats: derivePlayerStatsPDA(programId, victim)[0],
+      matchLog: null,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .preInstructions([signature])
+    .signers([gameServer])
+    .rpc({ commitment: "confirmed" });
+}
+
+// Declares `team` the winner and pays it out. Winners are passed as
+// (player, token account, statistics, bet receipt) quadruplets in roster
+// order; pay-to-spawn players claim their earnings themselves instead.
+export async function distributeWinnings(
+  program: Program<WagerProgram>,
+  gameServer: Keypair,
+  sessionId: string,
+  team: number
+): Promise<string> {
+  const programId = program.programId;
+  const [gameSessionPda] = deriveGameSessionPDA(programId, sessionId);
+  const [vaultPda] = deriveVaultPDA(programId, sessionId);
+  const [treasuryPda] = deriveTreasuryPDA(programId);
+  const account = await program.account.gameSession.fetch(gameSessionPda);
+
+  const payToSpawn = GAME_MODES[account.gameMode].startsWith("payToSpawn");
+  const winners = payToSpawn ? [] : seatedPlayers(account, team);
+  const remainingAccounts = winners.flatMap((player) => [
+    { pubkey: player, isSigner: false, isWritable: false },
+    { pubkey: getAssociatedTokenAddressSync(TOKEN_ID, player), isSigner: false, isWritable: true },
+    { pubkey: derivePlayerStatsPDA(programId, player)[0], isSigner: false, isWritable: true },
+    { pubkey: deriveBetReceiptPDA(programId, gameSessionPda, player)[0], isSigner: false, isWritable: true },
+  ]);
+
+  return await program.methods
+    .distributeWinnings(sessionId, { result: { win: {} }, team })
+    .accountsPartial({
+      caller: gameServer.publicKey,
+      config: deriveConfigPDA(programId)[0],
+      gameSession: gameSessionPda,
+      vault: vaultPda,
+      vaultTokenAccount: await getVaultTokenAccount(TOKEN_ID, vaultPda),
+      treasury: treasuryPda,
+      treasuryTokenAccount: getAssociatedTokenAddressSync(TOKEN_ID, treasuryPda, true),
+      approval: null,
+      jackpot: null,
+      jackpotTokenAccount: null,
+      mint: TOKEN_ID,
+      memoProgram: MEMO_PROGRAM_ID,
+      unclaimedPayout: deriveUnclaimedPayoutPDA(programId, gameSessionPda)[0],
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .remainingAccounts(remainingAccounts)
+    .signers([gameServer])
+    .rpc({ commitment: "confirmed" });
+}
+
+// Refunds every depositor, passed as (player, token account, bet receipt)
+// triplets in roster order
+export async function refundWager(
+  program: Program<WagerProgram>,
+  gameServer: Keypair,
+  sessionId: string
+): Promise<string> {
+  const programId = program.programId;
+  const [gameSessionPda] = deriveGameSessionPDA(programId, sessionId);
+  const [vaultPda] = deriveVaultPDA(programId, sessionId);
+  const account = await program.account.gameSession.fetch(gameSessionPda);
+
+  const depositors = [];
+  for (let team = 0; team < account.teamCount; team++) {
+    depositors.push(...seatedPlayers(account, team));
+  }
+  const remainingAccounts = depositors.flatMap((player) => [
+    { pubkey: player, isSigner: false, isWritable: false },
+    { pubkey: getAssociatedTokenAddressSync(TOKEN_ID, player), isSigner: false, isWritable: true },
+    { pubkey: deriveBetReceiptPDA(programId, gameSessionPda, player)[0], isSigner: false, isWritable: true },
+  ]);
+
+  return await program.methods
+    .refundWager(sessionId)
+    .accountsPartial({
+      gameServer: gameServer.publicKey,
+      gameSession: gameSessionPda,
+      vault: vaultPda,
+      vaultTokenAccount: await getVaultTokenAccount(TOKEN_ID, vaultPda),
+      creatorTokenAccount: null,
+      mint: TOKEN_ID,
+      memoProgram: MEMO_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .remainingAccounts(remainingAccounts)
+    .signers([gameServer])
+    .rpc({ commitment: "confirmed" });
+}
+
+// Buys another batch of spawns for `user`, who must be seated on `team`
+export async function payToSpawn(
+  program: Program<WagerProgram>,
+  gameServer: Keypair,
+  user: Keypair,
+  sessionId: string,
+  team: number
+): Promise<string> {
+  const programId = program.programId;
+  const [vaultPda] = deriveVaultPDA(programId, sessionId);
+  return await program.methods
+    .payToSpawn(sessionId, team)
+    .accountsPartial({
+      user: user.publicKey,
+      gameServer: gameServer.publicKey,
+      config: deriveConfigPDA(programId)[0],
+      gameSession: deriveGameSessionPDA(programId, sessionId)[0],
+      userTokenAccount: getAssociatedTokenAddressSync(TOKEN_ID, user.publicKey),
+      vault: vaultPda,
+      vaultTokenAccount: await getVaultTokenAccount(TOKEN_ID, vaultPda),
+      playerStats: derivePlayerStatsPDA(programId, user.publicKey)[0],
+      playerBan: derivePlayerBanPDA(programId, user.publicKey)[0],
+      mint: TOKEN_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([user])
+    .rpc({ commitment: "confirmed" });
+}
+
+// Pays a pay-to-spawn player the earnings of a completed session
+export async function claimEarnings(
+  program: Program<WagerProgram>,
+  player: Keypair,
+  sessionId: string
+): Promise<string> {
+  const programId = program.programId;
+  const [vaultPda] = deriveVaultPDA(programId, sessionId);
+  return await program.methods
+    .claimEarnings(sessionId)
+    .accountsPartial({
+      player: player.publicKey,
+      gameSession: deriveGameSessionPDA(programId, sessionId)[0],
+      playerTokenAccount: getAssociatedTokenAddressSync(TOKEN_ID, player.publicKey),
+      vault: vaultPda,
+      vaultTokenAccount: await getVaultTokenAccount(TOKEN_ID, vaultPda),
+      playerStats: derivePlayerStatsPDA(programId, player.publicKey)[0],
+      mint: TOKEN_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([player])
+    .rpc({ commitment: "confirmed" });
+}
+
+// Session id as created, the account storing it in a fixed 32 byte array
+export function decodeSessionId(gameState: any): string {
+  return Buffer.from(gameState.sessionId.slice(0, gameState.sessionIdLen)).toString();
+}
+
+// Players seated on `team`, in roster order
+export function seatedPlayers(gameState: any, team: number): PublicKey[] {
+  return gameState.teams[team].players.filter(
+    (player: PublicKey) => !player.equals(PublicKey.default)
+  );
+}
+
+// Team and roster index of a seated player
+export function findPlayerSlot(gameState: any, player: PublicKey): [number, number] {
+  for (let team = 0; team < gameState.teamCount; team++) {
+    const index = gameState.teams[team].players.findIndex((seated: PublicKey) => seated.equals(player));
+    if (index >= 0) {
+      return [team, index];
+    }
+  }
+  throw new Error(`Player ${player} is not seated`);
+}
 
 export const printGameState = async (
     gameState: any,
@@ -114,28 +614,21 @@ export const printGameState = async (
     connection?: Connection
 ) => {
     console.log(`\n${message}:`);
-    console.log("\nTeam A:");
-    gameState.teamA.players.forEach((player: PublicKey, index: number) => {
-        if (player.toString() !== PublicKey.default.toString()) {
-            console.log(`Player ${player.toString()}:`);
-            console.log(`  Kills: ${gameState.teamA.playerKills[index]}`);
-            console.log(`  Spawns remaining: ${gameState.teamA.playerSpawns[index]}`);
-        }
-    });
-    
-    console.log("\nTeam B:");
-    gameState.teamB.players.forEach((player: PublicKey, index: number) => {
-        if (player.toString() !== PublicKey.default.toString()) {
-            console.log(`Player ${player.toString()}:`);
-            console.log(`  Kills: ${gameState.teamB.playerKills[index]}`);
-            console.log(`  Spawns remaining: ${gameState.teamB.playerSpawns[index]}`);
-        }
-    });
-    
+    for (let team = 0; team < gameState.teamCount; team++) {
+        console.log(`\nTeam ${String.fromCharCode(65 + team)}:`);
+        gameState.teams[team].players.forEach((player: PublicKey, index: number) => {
+            if (player.toString() !== PublicKey.default.toString()) {
+                console.log(`Player ${player.toString()}:`);
+                console.log(`  Kills: ${gameState.teams[team].slots[index].kills}`);
+                console.log(`  Spawns remaining: ${gameState.teams[team].slots[index].spawns}`);
+            }
+        });
+    }
+
     console.log("Game status:", gameState.status);
-    
+
     if (vaultTokenAccount && connection) {
         const vaultBalance = await getTokenBalance(connection, new PublicKey(vaultTokenAccount));
         console.log("Vault balance:", vaultBalance);
     }
-}; 
\ No newline at end of file
+};